@@ -11,9 +11,10 @@ pub struct Clear {
         long,
         short,
         value_delimiter = ',',
-        default_value = "CexPrice,DexPrice,CexTrades,BlockInfo,InitializedState,MevBlocks,\
+        default_value = "CexPrice,DexPrice,CexTrades,BlockInfo,InitializedState,MevBlocksV2,\
                          TokenDecimals,AddressToProtocolInfo,PoolCreationBlocks,Builder,\
-                         AddressMeta,SearcherEOAs,SearcherContracts,SubGraphs,TxTraces"
+                         AddressMeta,SearcherEOAs,SearcherContracts,SubGraphs,TxTraces,\
+                         PricingCheckpoints,DedupStats,UniswapV4Pools"
     )]
     pub tables:                  Vec<Tables>,
     /// Mark metadata as uninitialized in the initialized state table
@@ -58,7 +59,7 @@ impl Clear {
                 InitializedState,
                 BlockInfo,
                 DexPrice,
-                MevBlocks,
+                MevBlocksV2,
                 TokenDecimals,
                 AddressToProtocolInfo,
                 PoolCreationBlocks,
@@ -66,7 +67,10 @@ impl Clear {
                 AddressMeta,
                 SearcherEOAs,
                 SearcherContracts,
-                TxTraces
+                TxTraces,
+                PricingCheckpoints,
+                DedupStats,
+                UniswapV4Pools
             )
         });
 
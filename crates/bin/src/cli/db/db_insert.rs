@@ -44,7 +44,7 @@ impl Insert {
             CexTrades,
             BlockInfo,
             DexPrice,
-            MevBlocks,
+            MevBlocksV2,
             AddressToProtocolInfo,
             TokenDecimals,
             TxTraces,
@@ -53,6 +53,9 @@ impl Insert {
             SearcherEOAs,
             SearcherContracts,
             InitializedState,
+            PricingCheckpoints,
+            DedupStats,
+            UniswapV4Pools,
             PoolCreationBlocks = &self.key,
             &self.value
         );
@@ -69,7 +69,7 @@ impl DatabaseQuery {
                     InitializedState,
                     BlockInfo,
                     DexPrice,
-                    MevBlocks,
+                    MevBlocksV2,
                     TokenDecimals,
                     AddressToProtocolInfo,
                     PoolCreationBlocks,
@@ -77,7 +77,10 @@ impl DatabaseQuery {
                     AddressMeta,
                     SearcherEOAs,
                     SearcherContracts,
-                    TxTraces
+                    TxTraces,
+                    PricingCheckpoints,
+                    DedupStats,
+                    UniswapV4Pools
                 );
             } else {
                 match_table!(
@@ -88,7 +91,7 @@ impl DatabaseQuery {
                     CexTrades,
                     BlockInfo,
                     DexPrice,
-                    MevBlocks,
+                    MevBlocksV2,
                     TokenDecimals,
                     AddressToProtocolInfo,
                     Builder,
@@ -97,6 +100,9 @@ impl DatabaseQuery {
                     SearcherEOAs,
                     SearcherContracts,
                     TxTraces,
+                    PricingCheckpoints,
+                    DedupStats,
+                    UniswapV4Pools,
                     PoolCreationBlocks = &self.key
                 );
             }
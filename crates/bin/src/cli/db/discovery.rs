@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use brontes_classifier::discovery_only::DiscoveryOnlyClassifier;
 use brontes_core::decoding::Parser as DParser;
 use brontes_metrics::ParserMetricsListener;
 use brontes_types::{init_thread_pools, UnboundedYapperReceiver};
@@ -50,6 +51,7 @@ impl DiscoveryFill {
             get_tracing_provider(Path::new(&db_path), max_tasks as u64, ctx.task_executor.clone());
 
         let parser = static_object(DParser::new(metrics_tx, libmdbx, tracer.clone()).await);
+        let classifier = DiscoveryOnlyClassifier::new(libmdbx, parser.get_tracer());
 
         let start_block = if let Some(s) = self.start_block {
             s
@@ -91,11 +93,12 @@ impl DiscoveryFill {
         futures::stream::iter(chunks)
             .map(|(start_block, end_block)| {
                 let bar = bar.clone();
+                let classifier = classifier.clone();
                 ctx.task_executor
                     .spawn_critical_with_graceful_shutdown_signal(
                         "Discovery",
                         |shutdown| async move {
-                            DiscoveryExecutor::new(start_block, end_block, libmdbx, parser, bar)
+                            DiscoveryExecutor::new(start_block, end_block, classifier, parser, bar)
                                 .run_until_graceful_shutdown(shutdown)
                                 .await
                         },
@@ -0,0 +1,136 @@
+use brontes_classifier::discovery_only::DiscoveryOnlyClassifier;
+use brontes_core::{decoding::Parser as DParser, offline_provider::OfflineProvider};
+use brontes_metrics::ParserMetricsListener;
+use brontes_types::{init_thread_pools, UnboundedYapperReceiver};
+use clap::Parser;
+use futures::StreamExt;
+use indicatif::{ProgressBar, ProgressDrawTarget, ProgressState, ProgressStyle};
+use itertools::Itertools;
+use tokio::sync::mpsc::unbounded_channel;
+
+use crate::{
+    cli::{load_libmdbx, static_object},
+    discovery_only::DiscoveryExecutor,
+    runner::CliContext,
+};
+
+/// Replays the factory calls and pool registrations over a range of blocks
+/// from the traces and block info stored in libmdbx, blocks without stored
+/// traces are skipped. Discovered pools are written to `AddressToProtocolInfo`
+/// and `PoolCreationBlocks` without going through clickhouse or the node, so
+/// the pools whose discovery queries the chain, such as curve metapools and
+/// maker clippers, aren't discovered.
+#[derive(Debug, Parser)]
+pub struct DiscoveryBackfill {
+    /// Start Block
+    #[arg(long, short)]
+    pub start_block: u64,
+    /// End Block, defaults to the highest block with traces in libmdbx
+    #[arg(long, short)]
+    pub end_block:   Option<u64>,
+    /// Max number of tasks to run concurrently
+    #[arg(long, short)]
+    pub max_tasks:   Option<usize>,
+}
+
+impl DiscoveryBackfill {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let max_tasks = self.max_tasks.unwrap_or(num_cpus::get_physical());
+        init_thread_pools(max_tasks);
+
+        let (metrics_tx, metrics_rx) = unbounded_channel();
+
+        let metrics_listener = ParserMetricsListener::new(UnboundedYapperReceiver::new(
+            metrics_rx,
+            10_000,
+            "metrics".to_string(),
+        ));
+
+        ctx.task_executor
+            .spawn_critical("metrics", metrics_listener);
+
+        let libmdbx = static_object(load_libmdbx(&ctx.task_executor, brontes_db_path)?);
+
+        let parser = static_object(DParser::new(metrics_tx, libmdbx, OfflineProvider).await);
+        let classifier = DiscoveryOnlyClassifier::new(libmdbx, parser.get_tracer());
+
+        let start_block = self.start_block;
+        let end_block = match self.end_block {
+            Some(end_block) => end_block,
+            None => libmdbx.get_highest_traced_block()?,
+        };
+
+        if end_block < start_block {
+            return Err(eyre::eyre!("end block {end_block} is before start block {start_block}"))
+        }
+
+        let bar = ProgressBar::with_draw_target(
+            Some(end_block - start_block + 1),
+            ProgressDrawTarget::stderr_with_hz(100),
+        );
+        let style = ProgressStyle::default_bar()
+            .template(
+                "{msg}\n[{elapsed_precise}] [{wide_bar:.cyan/blue}] {pos}/{len} blocks \
+                 ({percent}%) | ETA: {eta}",
+            )
+            .expect("Invalid progress bar template")
+            .progress_chars("█>-")
+            .with_key("eta", |state: &ProgressState, f: &mut dyn std::fmt::Write| {
+                write!(f, "{:.1}s", state.eta().as_secs_f64()).unwrap()
+            })
+            .with_key("percent", |state: &ProgressState, f: &mut dyn std::fmt::Write| {
+                write!(f, "{:.1}", state.fraction() * 100.0).unwrap()
+            });
+        bar.set_style(style);
+        bar.set_message("Running discovery:");
+
+        // the executor stops before its end block
+        let chunks = (start_block..=end_block)
+            .chunks(max_tasks)
+            .into_iter()
+            .map(|mut c| {
+                let start = c.next().unwrap();
+                let end = c.last().unwrap_or(start);
+                (start, end + 1)
+            })
+            .collect_vec();
+
+        futures::stream::iter(chunks)
+            .map(|(start_block, end_block)| {
+                let bar = bar.clone();
+                let classifier = classifier.clone();
+                ctx.task_executor
+                    .spawn_critical_with_graceful_shutdown_signal(
+                        "Discovery Backfill",
+                        |shutdown| async move {
+                            DiscoveryExecutor::new(start_block, end_block, classifier, parser, bar)
+                                .run_until_graceful_shutdown(shutdown)
+                                .await
+                        },
+                    )
+            })
+            .buffer_unordered(max_tasks)
+            .collect::<Vec<_>>()
+            .await;
+
+        bar.finish();
+
+        let discovered = classifier
+            .discovered_pools()
+            .into_iter()
+            .sorted_by(|a, b| b.1.cmp(&a.1))
+            .collect_vec();
+
+        if discovered.is_empty() {
+            println!("No new pools found between blocks {start_block} and {end_block}");
+            return Ok(())
+        }
+
+        println!("New pools found between blocks {start_block} and {end_block}:");
+        for (protocol, count) in discovered {
+            println!("  {protocol}: {count}");
+        }
+
+        Ok(())
+    }
+}
@@ -13,7 +13,7 @@ use crate::{
 #[derive(Debug, Parser)]
 pub struct Export {
     /// Optional tables to exports, if omitted will export all supported tables
-    #[arg(long, short, default_values = &["MevBlocks", "AddressMeta", "SearcherContracts", "Builder"], value_delimiter = ',', ignore_case=true)]
+    #[arg(long, short, default_values = &["MevBlocksV2", "AddressMeta", "SearcherContracts", "Builder"], value_delimiter = ',', ignore_case=true)]
     pub tables:      Vec<Tables>,
     /// Optional Start Block, if omitted it will export the entire range to
     /// parquet
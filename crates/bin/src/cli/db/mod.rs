@@ -10,6 +10,7 @@ mod db_insert;
 mod db_query;
 #[cfg(feature = "local-clickhouse")]
 mod discovery;
+mod discovery_backfill;
 #[cfg(feature = "local-clickhouse")]
 mod ensure_test_traces;
 mod export;
@@ -80,6 +81,10 @@ pub enum DatabaseCommands {
     #[cfg(feature = "local-clickhouse")]
     #[command(name = "run-discovery")]
     Discovery(discovery::DiscoveryFill),
+    /// Only runs discovery against the traces in libmdbx and inserts the
+    /// discovered protocols into libmdbx
+    #[command(name = "discovery-backfill")]
+    DiscoveryBackfill(discovery_backfill::DiscoveryBackfill),
 }
 
 impl Database {
@@ -95,6 +100,7 @@ impl Database {
             DatabaseCommands::TableStats(cmd) => cmd.execute(brontes_db_path),
             DatabaseCommands::DownloadSnapshot(cmd) => cmd.execute(brontes_db_path, ctx).await,
             DatabaseCommands::CexData(cmd) => cmd.execute(brontes_db_path, ctx).await,
+            DatabaseCommands::DiscoveryBackfill(cmd) => cmd.execute(brontes_db_path, ctx).await,
             #[cfg(feature = "local-clickhouse")]
             DatabaseCommands::DownloadClickhouse(cmd) => cmd.execute(brontes_db_path, ctx).await,
             #[cfg(feature = "local-clickhouse")]
@@ -2,6 +2,7 @@ use clap::{Parser, Subcommand};
 
 mod db;
 mod misc;
+mod price;
 mod run;
 mod utils;
 mod version_data;
@@ -38,4 +39,7 @@ pub enum Commands {
     /// Brontes database commands
     #[command(name = "db")]
     Database(db::Database),
+    /// Price a token over a range of blocks without running the inspectors
+    #[command(name = "price")]
+    Price(price::PriceArgs),
 }
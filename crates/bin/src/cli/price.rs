@@ -0,0 +1,375 @@
+use std::{
+    fs::File,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicBool, Ordering::SeqCst},
+        Arc,
+    },
+    time::Duration,
+};
+
+use alloy_primitives::Address;
+use brontes_classifier::Classifier;
+use brontes_core::decoding::{Parser as DParser, TracingProvider};
+use brontes_database::libmdbx::LibmdbxReadWriter;
+use brontes_metrics::ParserMetricsListener;
+use brontes_pricing::{BrontesBatchPricer, GraphManager};
+use brontes_types::{
+    constants::{ETH_ADDRESS, USDT_ADDRESS_STRING, WETH_ADDRESS},
+    db::{dex::DexQuotes, traits::LibmdbxReader},
+    init_thread_pools,
+    pair::Pair,
+    price_graph_types::SubGraphEdge,
+    FastHashMap, ToFloatNearest, UnboundedYapperReceiver,
+};
+use clap::Parser;
+use comfy_table::Table as ComfyTable;
+use futures::StreamExt;
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::BlockNumberOrTag;
+use serde::Serialize;
+use tokio::sync::mpsc::unbounded_channel;
+
+use super::{determine_max_tasks, get_env_vars, get_tracing_provider, load_libmdbx, static_object};
+use crate::runner::CliContext;
+
+/// Prices a token over a range of blocks without running the inspectors. The
+/// DEX prices stored in libmdbx are used when they cover the whole range,
+/// otherwise the DEX pricer is run over it.
+#[derive(Debug, Parser)]
+pub struct PriceArgs {
+    /// The token to price
+    #[arg(long, short)]
+    pub token:             String,
+    /// The token the price is in, if omitted it defaults to the quote asset
+    #[arg(long)]
+    pub priced_in:         Option<String>,
+    /// The asset the pricing graph prices every token against. Prices in
+    /// libmdbx are only found for the quote asset they were generated with.
+    #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
+    pub quote_asset:       String,
+    /// Start Block
+    #[arg(long, short)]
+    pub start_block:       u64,
+    /// Inclusive end block, if omitted only the start block is priced
+    #[arg(long, short, conflicts_with = "tx_index")]
+    pub end_block:         Option<u64>,
+    /// Only price the token at this tx index of the start block
+    #[arg(long)]
+    pub tx_index:          Option<usize>,
+    /// Run the DEX pricer even when the prices of the range are in libmdbx.
+    /// The pools each price is generated over are only known when it is run.
+    #[arg(long, short, default_value = "false")]
+    pub force_dex_pricing: bool,
+    /// Export the price series as json to this file instead of printing it
+    #[arg(long, short)]
+    pub output:            Option<PathBuf>,
+    /// Optional Max Tasks, if omitted it will default to 80% of the number of
+    /// physical cores on your machine
+    #[arg(long, short)]
+    pub max_tasks:         Option<u64>,
+}
+
+/// The price of the token at a transaction
+#[derive(Debug, Serialize)]
+struct PricePoint {
+    block:        u64,
+    tx_idx:       usize,
+    pre_state:    f64,
+    post_state:   f64,
+    /// the pairs of the pools whose actions generated the prices of the token
+    /// and of the token it is priced in
+    goes_through: Vec<Pair>,
+    /// the pools the prices are generated over, as of the end of the block.
+    /// Empty when the prices are read from libmdbx.
+    path:         Vec<PathEdge>,
+}
+
+#[derive(Debug, Serialize)]
+struct PathEdge {
+    pool:      Address,
+    protocol:  String,
+    token_in:  Address,
+    token_out: Address,
+}
+
+impl From<SubGraphEdge> for PathEdge {
+    fn from(edge: SubGraphEdge) -> Self {
+        Self {
+            pool:      edge.pool_addr,
+            protocol:  format!("{:?}", edge.dex_type),
+            token_in:  edge.get_base_token(),
+            token_out: edge.get_quote_token(),
+        }
+    }
+}
+
+impl PriceArgs {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        let token: Address = self.token.parse()?;
+        let quote_asset: Address = self.quote_asset.parse()?;
+        let priced_in: Address = match &self.priced_in {
+            Some(priced_in) => priced_in.parse()?,
+            None => quote_asset,
+        };
+        let start_block = self.start_block;
+        let end_block = self.end_block.unwrap_or(start_block);
+
+        if end_block < start_block {
+            return Err(eyre::eyre!("end block {end_block} is before start block {start_block}"))
+        }
+
+        let max_tasks = determine_max_tasks(self.max_tasks);
+        init_thread_pools(max_tasks as usize);
+
+        let libmdbx = static_object(load_libmdbx(&ctx.task_executor, brontes_db_path)?);
+
+        let has_stored_prices = !self.force_dex_pricing
+            && (start_block..=end_block)
+                .map(|block| libmdbx.has_dex_quotes(block))
+                .fold_ok(true, |acc, has| acc && has)?;
+
+        // the tracer is only needed to run the pricer or to check the tx index
+        let tracer = (!has_stored_prices || self.tx_index.is_some())
+            .then(|| {
+                get_env_vars().map(|db_path| {
+                    get_tracing_provider(Path::new(&db_path), max_tasks, ctx.task_executor.clone())
+                })
+            })
+            .transpose()?;
+        if let Some((tx_idx, tracer)) = self.tx_index.zip(tracer.as_ref()) {
+            Self::check_tx_index(tracer, start_block, tx_idx).await?;
+        }
+
+        let legs = (Pair(token, quote_asset), Pair(priced_in, quote_asset));
+        let points = match tracer {
+            Some(tracer) if !has_stored_prices => {
+                self.run_pricer(libmdbx, &ctx, tracer, quote_asset, legs, end_block)
+                    .await?
+            }
+            _ => (start_block..=end_block)
+                .map(|block| {
+                    let quotes = libmdbx.get_dex_quotes(block)?;
+                    Ok(self.price_points(block, &quotes, legs, |_, _| vec![]))
+                })
+                .flatten_ok()
+                .collect::<eyre::Result<Vec<_>>>()?,
+        };
+
+        let Some(output) = &self.output else {
+            println!("{}", Self::price_table(&points));
+            return Ok(())
+        };
+
+        serde_json::to_writer_pretty(File::create(output)?, &points)?;
+        println!("Exported {} prices to {}", points.len(), output.display());
+
+        Ok(())
+    }
+
+    /// Errors if the block has no transaction at the tx index
+    async fn check_tx_index(
+        tracer: &impl TracingProvider,
+        block: u64,
+        tx_idx: usize,
+    ) -> eyre::Result<()> {
+        let tx_count = tracer
+            .block_receipts(BlockNumberOrTag::Number(block))
+            .await?
+            .ok_or_else(|| eyre::eyre!("block {block} wasn't found"))?
+            .len();
+
+        if tx_idx >= tx_count {
+            return Err(eyre::eyre!(
+                "tx index {tx_idx} is out of range, block {block} has {tx_count} transactions"
+            ))
+        }
+
+        Ok(())
+    }
+
+    /// Classifies the blocks of the range and prices them with the DEX pricer,
+    /// taking the pools each price is generated over from the pricing graph.
+    /// Errors if the traces of a block can't be loaded, as it can't be priced.
+    async fn run_pricer<T: TracingProvider>(
+        &self,
+        libmdbx: &'static LibmdbxReadWriter,
+        ctx: &CliContext,
+        tracer: T,
+        quote_asset: Address,
+        legs: (Pair, Pair),
+        end_block: u64,
+    ) -> eyre::Result<Vec<PricePoint>> {
+        let (metrics_tx, metrics_rx) = unbounded_channel();
+
+        let metrics_listener = ParserMetricsListener::new(UnboundedYapperReceiver::new(
+            metrics_rx,
+            10_000,
+            "metrics".to_string(),
+        ));
+
+        ctx.task_executor
+            .spawn_critical("metrics", metrics_listener);
+
+        let parser = static_object(DParser::new(metrics_tx, libmdbx, tracer).await);
+
+        let start_block = self.start_block;
+        let pairs = libmdbx.protocols_created_before(start_block)?;
+        let rest_pairs = libmdbx
+            .protocols_created_range(start_block + 1, end_block + 1)?
+            .into_iter()
+            .flat_map(|(_, pools)| {
+                pools
+                    .into_iter()
+                    .filter(|(_, p, _)| p.has_state_updater())
+                    .map(|(addr, protocol, pair)| (addr, (protocol, pair)))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<FastHashMap<_, _>>();
+
+        let finished = Arc::new(AtomicBool::new(false));
+        let data_req = Arc::new(AtomicBool::new(true));
+        let (tx, rx) = unbounded_channel();
+        let classifier = static_object(Classifier::new(libmdbx, tx, parser.get_tracer()));
+
+        let mut pricer = BrontesBatchPricer::new(
+            0,
+            finished.clone(),
+            quote_asset,
+            GraphManager::init_from_db_state(pairs, None),
+            UnboundedYapperReceiver::new(rx, 100_000, "batch pricer".into()),
+            parser.get_tracer(),
+            start_block,
+            rest_pairs,
+            data_req.clone(),
+            None,
+            ctx.task_executor.clone(),
+        );
+
+        let classify = tokio::spawn(async move {
+            for block in start_block..=end_block {
+                // keeps the classifier from running too far ahead of the pricer
+                while !data_req.load(SeqCst) {
+                    tokio::time::sleep(Duration::from_millis(10)).await;
+                }
+
+                let Some((_, traces, header)) = parser.execute(block, 0, None).await else {
+                    // stops the pricer at the blocks before it
+                    classifier.block_load_failure(block);
+                    finished.store(true, SeqCst);
+                    return Err(eyre::eyre!("no traces found for block {block}"))
+                };
+                classifier.build_block_tree(traces, header, true).await;
+            }
+            finished.store(true, SeqCst);
+
+            Ok(())
+        });
+
+        let mut points = Vec::new();
+        while let Some((block, quotes)) = pricer.next().await {
+            tracing::info!(%block, "priced block");
+            points.extend(self.price_points(block, &quotes, legs, |pair, goes_through| {
+                pricer.subgraph_edges(pair, goes_through)
+            }));
+        }
+        classify.await??;
+
+        Ok(points)
+    }
+
+    /// The price of the token for each transaction of the block it or the
+    /// token it is priced in has a price at, or only at the tx index when set
+    fn price_points(
+        &self,
+        block: u64,
+        quotes: &DexQuotes,
+        (token, priced_in): (Pair, Pair),
+        subgraph_edges: impl Fn(Pair, Pair) -> Vec<SubGraphEdge>,
+    ) -> Vec<PricePoint> {
+        let (token, priced_in) = (with_weth(token), with_weth(priced_in));
+        let tx_idxs = match self.tx_index {
+            Some(tx_idx) => vec![tx_idx],
+            None => (0..quotes.0.len())
+                .filter(|tx_idx| {
+                    quotes.has_quote(&token, *tx_idx) || quotes.has_quote(&priced_in, *tx_idx)
+                })
+                .collect_vec(),
+        };
+
+        tx_idxs
+            .into_iter()
+            .filter_map(|tx_idx| {
+                let token_price = quotes.price_at_or_before(token, tx_idx)?;
+                let priced_in_price = quotes.price_at_or_before(priced_in, tx_idx)?;
+
+                let price = |token: &Rational, priced_in: &Rational| {
+                    if *priced_in == Rational::ZERO {
+                        return 0.0
+                    }
+                    (token / priced_in).to_float()
+                };
+
+                let goes_through = [(token, &token_price), (priced_in, &priced_in_price)]
+                    .into_iter()
+                    .filter(|(pair, _)| pair.0 != pair.1)
+                    .collect_vec();
+
+                Some(PricePoint {
+                    block,
+                    tx_idx,
+                    pre_state: price(&token_price.pre_state, &priced_in_price.pre_state),
+                    post_state: price(&token_price.post_state, &priced_in_price.post_state),
+                    path: goes_through
+                        .iter()
+                        .flat_map(|(pair, prices)| subgraph_edges(*pair, prices.goes_through))
+                        .map(PathEdge::from)
+                        .collect(),
+                    goes_through: goes_through
+                        .iter()
+                        .map(|(_, prices)| prices.goes_through)
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+
+    fn price_table(points: &[PricePoint]) -> ComfyTable {
+        let mut table = ComfyTable::new();
+        table.load_preset(comfy_table::presets::ASCII_MARKDOWN);
+        table.set_header(["Block", "Tx Index", "Pre State", "Post State", "Goes Through", "Path"]);
+
+        for point in points {
+            table.add_row([
+                point.block.to_string(),
+                point.tx_idx.to_string(),
+                point.pre_state.to_string(),
+                point.post_state.to_string(),
+                point
+                    .goes_through
+                    .iter()
+                    .map(|pair| format!("{:?} - {:?}", pair.0, pair.1))
+                    .join("\n"),
+                point
+                    .path
+                    .iter()
+                    .map(|edge| {
+                        format!(
+                            "{:?} ({}): {:?} -> {:?}",
+                            edge.pool, edge.protocol, edge.token_in, edge.token_out
+                        )
+                    })
+                    .join("\n"),
+            ]);
+        }
+
+        table
+    }
+}
+
+/// The pair with eth swapped for weth, which the quotes price it as
+fn with_weth(pair: Pair) -> Pair {
+    let weth = |token| if token == ETH_ADDRESS { WETH_ADDRESS } else { token };
+    Pair(weth(pair.0), weth(pair.1))
+}
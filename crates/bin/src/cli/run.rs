@@ -1,12 +1,23 @@
-use std::{path::Path, time::Duration};
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
 
+use alloy_primitives::Address;
 use brontes_core::decoding::Parser as DParser;
 use brontes_database::clickhouse::cex_config::CexDownloadConfig;
-use brontes_inspect::Inspectors;
+use brontes_inspect::{
+    composer::{init_mev_precedence, MevPrecedenceConfig, MEV_PRECEDENCE_FILE},
+    config::INSPECTOR_CONFIG_FILE,
+    InspectorRegistry, Inspectors, InspectorsConfig,
+};
 use brontes_metrics::ParserMetricsListener;
 use brontes_types::{
     constants::USDT_ADDRESS_STRING,
-    db::cex::{trades::CexDexTradeConfig, CexExchange},
+    db::{
+        cex::{trades::CexDexTradeConfig, CexExchange},
+        traits::LibmdbxReader,
+    },
     db_write_trigger::{backup_server_heartbeat, start_hr_monitor, HeartRateMonitor},
     init_thread_pools, UnboundedYapperReceiver,
 };
@@ -16,7 +27,7 @@ use tokio::sync::mpsc::unbounded_channel;
 use super::{determine_max_tasks, get_env_vars, load_clickhouse, load_database, static_object};
 use crate::{
     banner::rain,
-    cli::{get_tracing_provider, init_inspectors, load_tip_database},
+    cli::{get_tracing_provider, init_inspector_registry, load_tip_database},
     runner::CliContext,
     BrontesRunConfig, MevProcessor, RangeType,
 };
@@ -27,35 +38,44 @@ const SECONDS_TO_US_FLOAT: f64 = 1_000_000.0;
 pub struct RunArgs {
     /// Optional Start Block, if omitted it will run at tip until killed
     #[arg(long, short)]
-    pub start_block:          Option<u64>,
+    pub start_block: Option<u64>,
     /// Optional End Block, if omitted it will run historically & at tip until
     /// killed
     #[arg(long, short)]
-    pub end_block:            Option<u64>,
+    pub end_block: Option<u64>,
     /// starts running at tip from where brontes was last left at.
     #[arg(long, default_value_t = false)]
-    pub from_db_tip:          bool,
+    pub from_db_tip: bool,
     /// Optional Multiple Ranges, format: "start1-end1 start2-end2 ..."
     /// Use this if you want to specify the exact, non continuous block ranges
     /// you want to run
     #[arg(long, num_args = 1.., value_delimiter = ' ')]
-    pub ranges:               Option<Vec<String>>,
+    pub ranges: Option<Vec<String>>,
     /// Optional Max Tasks, if omitted it will default to 80% of the number of
     /// physical cores on your machine
     #[arg(long, short)]
-    pub max_tasks:            Option<u64>,
+    pub max_tasks: Option<u64>,
     /// Optional minimum batch size
     #[arg(long, default_value = "500")]
-    pub min_batch_size:       u64,
+    pub min_batch_size: u64,
     /// Optional quote asset, if omitted it will default to USDT
     #[arg(long, short, default_value = USDT_ADDRESS_STRING)]
-    pub quote_asset:          String,
-    /// Inspectors to run. If omitted it defaults to running all inspectors
+    pub quote_asset: String,
+    /// Inspectors to run, by the name they are registered under. If omitted it
+    /// defaults to running all registered inspectors
     #[arg(long, short, value_delimiter = ',')]
-    pub inspectors:           Option<Vec<Inspectors>>,
+    pub inspectors: Option<Vec<String>>,
+    /// Path to the inspector config, which sets the profit bounds, outlier
+    /// thresholds and heuristics of each inspector
+    #[arg(long, default_value = INSPECTOR_CONFIG_FILE)]
+    pub inspector_config: PathBuf,
+    /// Path to the mev precedence config, which sets the rules used to
+    /// deduplicate overlapping bundles
+    #[arg(long, default_value = MEV_PRECEDENCE_FILE)]
+    pub mev_precedence: PathBuf,
     /// Time window arguments for cex data downloads
     #[clap(flatten)]
-    pub time_window_args:     TimeWindowArgs,
+    pub time_window_args: TimeWindowArgs,
     /// CEX exchanges to consider for cex-dex analysis
     #[arg(
         long,
@@ -63,40 +83,51 @@ pub struct RunArgs {
         default_value = "Binance,Coinbase,Okex,BybitSpot,Kucoin",
         value_delimiter = ','
     )]
-    pub cex_exchanges:        Vec<CexExchange>,
+    pub cex_exchanges: Vec<CexExchange>,
     /// Force DEX price calculation for every block, ignoring existing database
     /// values.
     #[arg(long, short, default_value = "false")]
-    pub force_dex_pricing:    bool,
+    pub force_dex_pricing: bool,
     /// Disables DEX pricing. Inspectors needing DEX prices will only calculate
     /// token PnL, not USD PnL, if DEX pricing is unavailable in the
     /// database.
     #[arg(long, default_value = "false")]
     pub force_no_dex_pricing: bool,
+    /// Price DEX pairs over all verified paths, weighted by their depth at this
+    /// notional in the quote asset, instead of over the most liquid path. The
+    /// spread between the paths is reported as the uncertainty of the PnL.
+    #[arg(long, value_parser = parse_notional)]
+    pub path_weighted_pricing: Option<f64>,
+    /// Checkpoint the DEX pricing graph state to libmdbx every this many
+    /// blocks. An interrupted range resumes from its latest checkpoint up to
+    /// which all its mev blocks are written, skipping the blocks before it.
+    /// The checkpoints of a range are removed once it completes.
+    #[arg(long, value_parser = clap::value_parser!(u64).range(1..))]
+    pub pricing_checkpoint_interval: Option<u64>,
     /// Number of blocks to lag behind the chain tip when processing.
     #[arg(long, default_value = "10")]
-    pub behind_tip:           u64,
+    pub behind_tip: u64,
     /// Legacy, run in CLI only mode (no TUI) - will output progress bars to
     /// stdout
     #[arg(long, default_value = "true")]
-    pub cli_only:             bool,
+    pub cli_only: bool,
     /// Export metrics
     #[arg(long, default_value = "false")]
-    pub with_metrics:         bool,
+    pub with_metrics: bool,
     /// Wether or not to use a fallback server.
     #[arg(long, default_value_t = false)]
-    pub enable_fallback:      bool,
+    pub enable_fallback: bool,
     /// Address of the fallback server.
     /// Triggers database writes if the main connection fails, preventing data
     /// loss.
     #[arg(long)]
-    pub fallback_server:      Option<String>,
+    pub fallback_server: Option<String>,
     /// Set a custom run ID used when inserting data into the Clickhouse
     ///
     /// If omitted, the ID will be automatically incremented from the last run
     /// stored in the Clickhouse database.
     #[arg(long, short)]
-    pub run_id:               Option<u64>,
+    pub run_id: Option<u64>,
 
     /// shows a cool display at startup
     #[arg(long, short, default_value_t = false)]
@@ -104,7 +135,23 @@ pub struct RunArgs {
 }
 
 impl RunArgs {
-    pub async fn execute(mut self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+    pub async fn execute(self, brontes_db_path: String, ctx: CliContext) -> eyre::Result<()> {
+        self.execute_with_inspectors(brontes_db_path, ctx, |_, _, _| Ok(()))
+            .await
+    }
+
+    /// Runs brontes with additional inspectors, which `register_inspectors`
+    /// adds to the registry the `--inspectors` flag is resolved against.
+    pub async fn execute_with_inspectors<F>(
+        mut self,
+        brontes_db_path: String,
+        ctx: CliContext,
+        register_inspectors: F,
+    ) -> eyre::Result<()>
+    where
+        F: FnOnce(&mut InspectorRegistry, Address, &'static dyn LibmdbxReader) -> eyre::Result<()>
+            + Send,
+    {
         self.check_proper_range()?;
 
         if self.waterfall {
@@ -159,7 +206,8 @@ impl RunArgs {
             .as_ref()
             .map(|f| {
                 f.len() == 1
-                    && (f.contains(&Inspectors::CexDex) || f.contains(&Inspectors::CexDexMarkout))
+                    && (f[0] == Inspectors::CexDex.to_string()
+                        || f[0] == Inspectors::CexDexMarkout.to_string())
             })
             .unwrap_or(false);
 
@@ -168,15 +216,19 @@ impl RunArgs {
         }
 
         let trade_config = self.time_window_args.trade_config();
+        let inspector_config = self.load_inspector_config()?;
+        self.init_mev_precedence()?;
 
-        let inspectors = init_inspectors(
+        let mut registry = init_inspector_registry(
             quote_asset,
             libmdbx,
-            self.inspectors,
             self.cex_exchanges,
             trade_config,
+            &inspector_config,
             self.with_metrics,
         );
+        register_inspectors(&mut registry, quote_asset, libmdbx)?;
+        let inspectors = registry.resolve(self.inspectors.as_deref())?;
 
         let tracer =
             get_tracing_provider(Path::new(&reth_db_path), max_tasks, task_executor.clone());
@@ -193,6 +245,8 @@ impl RunArgs {
                     quote_asset,
                     self.force_dex_pricing,
                     self.force_no_dex_pricing,
+                    self.path_weighted_pricing,
+                    self.pricing_checkpoint_interval,
                     inspectors,
                     clickhouse,
                     parser,
@@ -218,6 +272,30 @@ impl RunArgs {
         Ok(())
     }
 
+    /// Falls back to the default config if no config exists at the default
+    /// path. A config that was explicitly passed has to exist.
+    fn load_inspector_config(&self) -> eyre::Result<InspectorsConfig> {
+        if !self.inspector_config.exists()
+            && self.inspector_config == Path::new(INSPECTOR_CONFIG_FILE)
+        {
+            tracing::warn!(target: "brontes", "no inspector config found, using the defaults");
+            return Ok(InspectorsConfig::default())
+        }
+
+        InspectorsConfig::load(&self.inspector_config)
+    }
+
+    /// Keeps the built in precedence rules if no config exists at the default
+    /// path. A config that was explicitly passed has to exist.
+    fn init_mev_precedence(&self) -> eyre::Result<()> {
+        if !self.mev_precedence.exists() && self.mev_precedence == Path::new(MEV_PRECEDENCE_FILE) {
+            tracing::warn!(target: "brontes", "no mev precedence config found, using the defaults");
+            return Ok(())
+        }
+
+        init_mev_precedence(MevPrecedenceConfig::load(&self.mev_precedence)?.into_precedence()?)
+    }
+
     pub fn get_range_type(&self) -> eyre::Result<RangeType> {
         if let Some(ranges) = &self.ranges {
             let parsed_ranges = parse_ranges(ranges).map_err(|e| eyre::eyre!(e))?;
@@ -295,6 +373,16 @@ fn parse_ranges(ranges: &[String]) -> Result<Vec<(u64, u64)>, String> {
         .collect()
 }
 
+fn parse_notional(notional: &str) -> Result<f64, String> {
+    let notional: f64 = notional
+        .parse()
+        .map_err(|_| format!("invalid notional: {}", notional))?;
+    if !notional.is_finite() || notional <= 0.0 {
+        return Err(format!("notional {} must be a finite amount greater than 0", notional))
+    }
+    Ok(notional)
+}
+
 #[derive(Debug, Parser)]
 pub struct TimeWindowArgs {
     /// The initial sliding time window (BEFORE) for cex prices or trades
@@ -16,7 +16,7 @@ use brontes_database::clickhouse::ReadOnlyMiddleware;
 #[cfg(feature = "local-clickhouse")]
 use brontes_database::clickhouse::{dbms::BrontesClickhouseData, ClickhouseBuffered};
 use brontes_database::{clickhouse::cex_config::CexDownloadConfig, libmdbx::LibmdbxReadWriter};
-use brontes_inspect::{Inspector, Inspectors};
+use brontes_inspect::{InspectorRegistry, InspectorsConfig};
 use brontes_metrics::inspectors::OutlierMetrics;
 #[cfg(feature = "local-clickhouse")]
 use brontes_types::UnboundedYapperReceiver;
@@ -26,13 +26,10 @@ use brontes_types::{
         traits::LibmdbxReader,
     },
     db_write_trigger::HeartRateMonitor,
-    mev::Bundle,
     BrontesTaskExecutor,
 };
-use itertools::Itertools;
 #[cfg(feature = "local-reth")]
 use reth_tracing_ext::TracingClient;
-use strum::IntoEnumIterator;
 use tracing::info;
 
 #[cfg(not(feature = "local-clickhouse"))]
@@ -150,30 +147,22 @@ pub fn static_object<T>(obj: T) -> &'static T {
     &*Box::leak(Box::new(obj))
 }
 
-pub fn init_inspectors<DB: LibmdbxReader>(
+pub fn init_inspector_registry<DB: LibmdbxReader>(
     quote_token: Address,
     db: &'static DB,
-    inspectors: Option<Vec<Inspectors>>,
     cex_exchanges: Vec<CexExchange>,
     trade_config: CexDexTradeConfig,
+    config: &InspectorsConfig,
     metrics: bool,
-) -> &'static [&'static dyn Inspector<Result = Vec<Bundle>>] {
-    let mut res = Vec::new();
-    let metrics = metrics.then(OutlierMetrics::new);
-    for inspector in inspectors
-        .map(|i| i.into_iter())
-        .unwrap_or_else(|| Inspectors::iter().collect_vec().into_iter())
-    {
-        res.push(inspector.init_mev_inspector(
-            quote_token,
-            db,
-            &cex_exchanges,
-            trade_config,
-            metrics.clone(),
-        ));
-    }
-
-    &*Box::leak(res.into_boxed_slice())
+) -> InspectorRegistry {
+    InspectorRegistry::with_builtin_inspectors(
+        quote_token,
+        db,
+        &cex_exchanges,
+        trade_config,
+        config,
+        metrics.then(OutlierMetrics::new),
+    )
 }
 
 pub fn get_env_vars() -> eyre::Result<String> {
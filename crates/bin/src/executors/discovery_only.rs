@@ -27,11 +27,10 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryExecutor<T, DB>
     pub fn new(
         start_block: u64,
         end_block: u64,
-        db: &'static DB,
+        classifier: DiscoveryOnlyClassifier<'static, T, DB>,
         parser: &'static Parser<T, DB>,
         progress_bar: ProgressBar,
     ) -> Self {
-        let classifier = DiscoveryOnlyClassifier::new(db, parser.get_tracer());
         Self {
             progress_bar,
             current_block: start_block,
@@ -30,18 +30,21 @@ use brontes_database::libmdbx::LibmdbxInit;
 use brontes_inspect::Inspector;
 use brontes_pricing::{BrontesBatchPricer, GraphManager, LoadState};
 use brontes_types::{
-    db::traits::LibmdbxReader, BrontesTaskExecutor, FastHashMap, UnboundedYapperReceiver,
+    db::traits::{DBWriter, LibmdbxReader},
+    BrontesTaskExecutor, FastHashMap, UnboundedYapperReceiver,
 };
 use futures::{stream::FuturesUnordered, Future, StreamExt};
 use indicatif::MultiProgress;
 use itertools::Itertools;
+use malachite::Rational;
 pub use range::RangeExecutorWithPricing;
 use reth_tasks::shutdown::GracefulShutdown;
 pub use tip::TipInspector;
 use tokio::{sync::mpsc::unbounded_channel, task::JoinHandle};
 
 use self::shared::{
-    dex_pricing::WaitingForPricerFuture, metadata_loader::MetadataLoader,
+    dex_pricing::{PricingCheckpoints, WaitingForPricerFuture},
+    metadata_loader::MetadataLoader,
     state_collector::StateCollector,
 };
 use crate::cli::static_object;
@@ -56,6 +59,8 @@ pub struct BrontesRunConfig<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseH
     pub quote_asset: Address,
     pub force_dex_pricing: bool,
     pub force_no_dex_pricing: bool,
+    pub path_weighted_pricing: Option<f64>,
+    pub pricing_checkpoint_interval: Option<u64>,
     pub inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
     pub clickhouse: &'static CH,
     pub parser: &'static Parser<T, DB>,
@@ -79,6 +84,8 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
         quote_asset: Address,
         force_dex_pricing: bool,
         force_no_dex_pricing: bool,
+        path_weighted_pricing: Option<f64>,
+        pricing_checkpoint_interval: Option<u64>,
         inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
         clickhouse: &'static CH,
         parser: &'static Parser<T, DB>,
@@ -100,6 +107,8 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             inspectors,
             quote_asset,
             force_no_dex_pricing,
+            path_weighted_pricing,
+            pricing_checkpoint_interval,
             cli_only,
             metrics,
             tip_db,
@@ -315,23 +324,32 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
                             .unwrap();
                     }
 
+                    let (resume_block, pair_graph) = self
+                        .resume_pricing_graph(batch_id, start_block, end_block, &pricing_metrics)
+                        .await;
+                    if let Some(pb) = &prgrs_bar {
+                        pb.inc(resume_block - start_block);
+                    }
+
                     #[allow(clippy::async_yields_async)]
                     RangeExecutorWithPricing::new(
                         batch_id,
-                        start_block,
+                        resume_block,
                         end_block,
                         self.init_state_collector(
                             batch_id,
                             executor.clone(),
-                            start_block,
+                            resume_block,
                             end_block,
                             false,
                             pricing_metrics,
+                            pair_graph,
                         ),
                         self.libmdbx,
                         self.inspectors,
                         prgrs_bar,
                         metrics,
+                        self.pricing_checkpoint_interval.map(|_| start_block),
                     )
                 }
             },
@@ -347,6 +365,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
         back_from_tip: u64,
         pricing_metrics: Option<DexPricingMetrics>,
     ) -> TipInspector<T, DB, CH, P> {
+        let pair_graph = self.init_pricing_graph(start_block, &pricing_metrics);
         let state_collector = self.init_state_collector(
             range_id,
             executor,
@@ -354,6 +373,7 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             start_block,
             true,
             pricing_metrics,
+            pair_graph,
         );
         TipInspector::new(
             start_block,
@@ -379,11 +399,13 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
     /// * `end_block` - The last block in the range.
     /// * `tip` - Boolean flag indicating if this is for tip processing.
     /// * `pricing_metrics` - Optional metrics for DEX pricing.
+    /// * `pair_graph` - The pricing graph as of the block before `start_block`.
     ///
     /// # Returns
     ///
     /// Returns a `StateCollector<T, DB, CH>` initialized with the specified
     /// parameters.
+    #[allow(clippy::too_many_arguments)]
     fn init_state_collector(
         &self,
         range_id: usize,
@@ -392,13 +414,12 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
         end_block: u64,
         tip: bool,
         pricing_metrics: Option<DexPricingMetrics>,
+        pair_graph: GraphManager,
     ) -> StateCollector<T, DB, CH> {
         let shutdown = Arc::new(AtomicBool::new(false));
         let (tx, rx) = unbounded_channel();
         let classifier = static_object(Classifier::new(self.libmdbx, tx, self.parser.get_tracer()));
 
-        let pairs = self.libmdbx.protocols_created_before(start_block).unwrap();
-
         let rest_pairs = self
             .libmdbx
             .protocols_created_range(start_block + 1, end_block)
@@ -413,8 +434,6 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             })
             .collect::<FastHashMap<_, _>>();
 
-        let pair_graph = GraphManager::init_from_db_state(pairs, pricing_metrics.clone());
-
         let data_req = Arc::new(AtomicBool::new(true));
 
         let pricer = BrontesBatchPricer::new(
@@ -431,7 +450,13 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
             executor.clone(),
         );
 
-        let pricing = WaitingForPricerFuture::new(pricer, executor);
+        // the tip doesn't resume, so it isn't checkpointed
+        let checkpoints = self
+            .pricing_checkpoint_interval
+            .filter(|_| !tip)
+            .map(|interval| PricingCheckpoints::new(interval, self.libmdbx));
+
+        let pricing = WaitingForPricerFuture::new(pricer, executor, checkpoints);
         let fetcher = MetadataLoader::new(
             tip.then_some(self.clickhouse),
             pricing,
@@ -461,6 +486,100 @@ impl<T: TracingProvider, DB: LibmdbxInit, CH: ClickhouseHandle, P: Processor>
         )
     }
 
+    /// Builds the pricing graph from the pools created before `start_block`
+    fn init_pricing_graph(
+        &self,
+        start_block: u64,
+        pricing_metrics: &Option<DexPricingMetrics>,
+    ) -> GraphManager {
+        let pairs = self.libmdbx.protocols_created_before(start_block).unwrap();
+        let pair_graph = GraphManager::init_from_db_state(pairs, pricing_metrics.clone());
+
+        match self.path_weighted_notional() {
+            Some(notional) => pair_graph.with_path_weighted_pricing(notional),
+            None => pair_graph,
+        }
+    }
+
+    /// the notional is validated to be finite and positive when it is parsed
+    fn path_weighted_notional(&self) -> Option<Rational> {
+        self.path_weighted_pricing
+            .and_then(|notional| Rational::try_from(notional).ok())
+    }
+
+    /// Restores the pricing graph of a range from its latest checkpoint when
+    /// checkpointing is enabled, returning the block after the checkpoint to
+    /// resume the range at along with the graph.
+    ///
+    /// Only checkpoints taken with the same quote asset and path weighting, at
+    /// a block up to which the mev blocks of the range are all written, are
+    /// resumed from, so that no block without output is skipped. The older
+    /// checkpoints of the range are pruned. Falls back to pricing the range
+    /// from `start_block` when there is no such checkpoint or it can't be
+    /// restored.
+    async fn resume_pricing_graph(
+        &self,
+        range_id: usize,
+        start_block: u64,
+        end_block: u64,
+        pricing_metrics: &Option<DexPricingMetrics>,
+    ) -> (u64, GraphManager) {
+        let from_start = || (start_block, self.init_pricing_graph(start_block, pricing_metrics));
+        if self.pricing_checkpoint_interval.is_none() {
+            return from_start()
+        }
+
+        let notional = self.path_weighted_notional();
+        let checkpoint = self
+            .libmdbx
+            .first_block_without_mev(start_block, end_block)
+            .and_then(|written_until| {
+                self.libmdbx
+                    .try_fetch_pricing_checkpoint(start_block, written_until)
+            })
+            .unwrap_or_else(|e| {
+                tracing::error!(%range_id, err=%e, "failed to load pricing checkpoint");
+                None
+            })
+            .filter(|(block, checkpoint)| {
+                let compatible = checkpoint.is_compatible(self.quote_asset, notional.as_ref());
+                if !compatible {
+                    tracing::warn!(
+                        %range_id,
+                        %block,
+                        checkpoint_quote_asset=?checkpoint.quote_asset,
+                        "ignoring pricing checkpoint taken with another quote asset or path \
+                         weighting"
+                    );
+                }
+                compatible
+            });
+
+        let Some((block, checkpoint)) = checkpoint else { return from_start() };
+
+        let mut pair_graph = self.init_pricing_graph(block + 1, pricing_metrics);
+        if let Err(e) = pair_graph.restore_checkpoint(&checkpoint.state) {
+            tracing::error!(
+                %range_id,
+                %block,
+                err=%e,
+                "failed to restore pricing checkpoint, pricing the range from its start"
+            );
+            return from_start()
+        }
+
+        if let Err(e) = self
+            .libmdbx
+            .prune_pricing_checkpoints(start_block, block)
+            .await
+        {
+            tracing::error!(%range_id, err=%e, "failed to prune pricing checkpoints");
+        }
+
+        tracing::info!(%range_id, %block, "resuming range from pricing checkpoint");
+        (block + 1, pair_graph)
+    }
+
     async fn init_block_range_tables(
         &self,
         ranges: Vec<(Tables, Vec<RangeInclusive<u64>>)>,
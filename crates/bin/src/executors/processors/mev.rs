@@ -17,7 +17,7 @@ use brontes_types::tree::BlockTree;
 use brontes_types::{
     db::block_analysis::BlockAnalysis,
     execute_on,
-    mev::{Bundle, MevBlock, MevType},
+    mev::{Bundle, DeduplicationStats, MevBlock, MevType},
     BlockData, MultiBlockData,
 };
 use tracing::debug;
@@ -51,10 +51,20 @@ impl Processor for MevProcessor {
             insert_tree(db, inner_tree, metadata.block_num).await;
         }
 
-        let ComposerResults { block_details, mev_details, block_analysis, .. } =
+        let ComposerResults { block_details, mev_details, block_analysis, dedup_stats, .. } =
             execute_on!(async_inspect, { run_block_inspection(inspectors, data, db) }).await;
 
-        insert_mev_results(db, block_details, mev_details, block_analysis).await;
+        debug!(
+            target: "brontes::results",
+            block_num = metadata.block_num,
+            inspected = dedup_stats.inspected_bundles,
+            reported = dedup_stats.reported_bundles,
+            suppressed = dedup_stats.total_suppressed(),
+            suppressed_profit_usd = dedup_stats.total_suppressed_profit_usd(),
+            "deduplicated bundles"
+        );
+
+        insert_mev_results(db, block_details, mev_details, block_analysis, dedup_stats).await;
     }
 }
 
@@ -79,6 +89,7 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
     block_details: MevBlock,
     mev_details: Vec<Bundle>,
     analysis: BlockAnalysis,
+    dedup_stats: DeduplicationStats,
 ) {
     debug!(
         target: "brontes::results",
@@ -107,6 +118,9 @@ async fn insert_mev_results<DB: DBWriter + LibmdbxReader>(
             block_number
         );
     }
+    if let Err(e) = database.write_dedup_stats(dedup_stats).await {
+        tracing::error!("Failed to insert dedup stats into db: {:?} at block: {}", e, block_number);
+    }
 }
 async fn output_mev_and_update_searcher_info<DB: DBWriter + LibmdbxReader>(
     database: &DB,
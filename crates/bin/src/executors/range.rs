@@ -36,6 +36,9 @@ pub struct RangeExecutorWithPricing<
     inspectors:     &'static [&'static dyn Inspector<Result = P::InspectType>],
     progress_bar:   Option<ProgressBar>,
     global_metrics: Option<GlobalRangeMetrics>,
+    /// the first block of the range when its pricing is checkpointed, the
+    /// checkpoints are pruned once the whole range is written
+    checkpointed:   Option<u64>,
     _p:             PhantomData<P>,
 }
 
@@ -51,6 +54,7 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
         inspectors: &'static [&'static dyn Inspector<Result = P::InspectType>],
         progress_bar: Option<ProgressBar>,
         global_metrics: Option<GlobalRangeMetrics>,
+        checkpointed: Option<u64>,
     ) -> Self {
         Self {
             id,
@@ -62,6 +66,7 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
             inspectors,
             progress_bar,
             global_metrics,
+            checkpointed,
             _p: PhantomData,
         }
     }
@@ -86,6 +91,21 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter, CH: ClickhouseHandle, P:
                 .inspect(|m| m.finished_block(data_batching.id));
         }
 
+        // a range that was shut down early resumes from its checkpoints
+        if let Some(start_block) = data_batching
+            .checkpointed
+            .filter(|_| graceful_guard.is_none())
+        {
+            if let Err(e) = data_batching
+                .libmdbx
+                .prune_pricing_checkpoints(start_block, data_batching.end_block)
+                .await
+            {
+                let id = data_batching.id;
+                tracing::error!(%id, err=%e, "failed to prune pricing checkpoints");
+            }
+        }
+
         drop(graceful_guard);
     }
 
@@ -1,6 +1,7 @@
 use std::{
     collections::VecDeque,
     pin::Pin,
+    sync::Arc,
     task::{Context, Poll},
     time::Duration,
 };
@@ -9,17 +10,60 @@ use brontes_core::decoding::TracingProvider;
 use brontes_pricing::BrontesBatchPricer;
 use brontes_types::{
     constants::START_OF_CHAINBOUND_MEMPOOL_DATA,
-    db::{dex::DexQuotes, metadata::Metadata},
+    db::{
+        dex::DexQuotes, metadata::Metadata, pricing_checkpoint::PricingGraphCheckpoint,
+        traits::DBWriter,
+    },
     normalized_actions::Action,
+    price_graph_types::SharedPoolStates,
     tree::BlockTree,
     BrontesTaskExecutor, FastHashMap, FastHashSet,
 };
-use futures::{Stream, StreamExt};
+use futures::{future::BoxFuture, Stream, StreamExt};
 use tokio::sync::mpsc::{channel, error::TrySendError, Receiver, Sender};
 use tracing::{debug, span, Instrument, Level};
 
-pub type PricingReceiver<T> = Receiver<(BrontesBatchPricer<T>, Option<(u64, DexQuotes)>)>;
-pub type PricingSender<T> = Sender<(BrontesBatchPricer<T>, Option<(u64, DexQuotes)>)>;
+/// The prices of a block along with the state of the pools it updated
+pub type ResolvedPricing = (u64, DexQuotes, Option<SharedPoolStates>);
+
+pub type PricingReceiver<T> = Receiver<(BrontesBatchPricer<T>, Option<ResolvedPricing>)>;
+pub type PricingSender<T> = Sender<(BrontesBatchPricer<T>, Option<ResolvedPricing>)>;
+
+type CheckpointWriter =
+    Arc<dyn Fn(u64, PricingGraphCheckpoint) -> BoxFuture<'static, eyre::Result<()>> + Send + Sync>;
+
+/// Writes a checkpoint of the pricing graph state every `interval` blocks, so
+/// that a range can resume pricing from it
+#[derive(Clone)]
+pub struct PricingCheckpoints {
+    interval: u64,
+    write:    CheckpointWriter,
+}
+
+impl PricingCheckpoints {
+    pub fn new<DB: DBWriter + Sync>(interval: u64, db: &'static DB) -> Self {
+        Self {
+            interval,
+            write: Arc::new(move |block, checkpoint| {
+                Box::pin(db.write_pricing_checkpoint(block, checkpoint))
+            }),
+        }
+    }
+
+    fn is_due(&self, block: u64) -> bool {
+        block % self.interval == 0
+    }
+
+    async fn write(&self, block: u64, checkpoint: eyre::Result<PricingGraphCheckpoint>) {
+        let res = match checkpoint {
+            Ok(checkpoint) => (self.write)(block, checkpoint).await,
+            Err(e) => Err(e),
+        };
+        if let Err(e) = res {
+            tracing::error!(%block, err=%e, "failed to checkpoint the pricing graph");
+        }
+    }
+}
 
 pub struct WaitingForPricerFuture<T: TracingProvider> {
     receiver: PricingReceiver<T>,
@@ -31,14 +75,19 @@ pub struct WaitingForPricerFuture<T: TracingProvider> {
     pub tmp_trees:            FastHashSet<u64>,
     task_executor:            BrontesTaskExecutor,
     max_tree_block:           u64,
-    pricing_resolved_cache:   VecDeque<(u64, DexQuotes)>,
+    pricing_resolved_cache:   VecDeque<ResolvedPricing>,
+    checkpoints:              Option<PricingCheckpoints>,
 }
 
 impl<T: TracingProvider> WaitingForPricerFuture<T> {
-    pub fn new(pricer: BrontesBatchPricer<T>, task_executor: BrontesTaskExecutor) -> Self {
+    pub fn new(
+        pricer: BrontesBatchPricer<T>,
+        task_executor: BrontesTaskExecutor,
+        checkpoints: Option<PricingCheckpoints>,
+    ) -> Self {
         let (tx, rx) = channel(100);
         let tx_clone = tx.clone();
-        let fut = Box::pin(Self::pricing_thread(pricer, tx_clone));
+        let fut = Box::pin(Self::pricing_thread(pricer, tx_clone, checkpoints.clone()));
 
         task_executor.spawn_critical("dex pricer", fut);
         Self {
@@ -49,16 +98,32 @@ impl<T: TracingProvider> WaitingForPricerFuture<T> {
             tmp_trees: FastHashSet::default(),
             max_tree_block: 0,
             pricing_resolved_cache: VecDeque::new(),
+            checkpoints,
         }
     }
 
-    async fn pricing_thread(mut pricer: BrontesBatchPricer<T>, tx: PricingSender<T>) {
+    async fn pricing_thread(
+        mut pricer: BrontesBatchPricer<T>,
+        tx: PricingSender<T>,
+        checkpoints: Option<PricingCheckpoints>,
+    ) {
         let block = pricer.current_block_processing();
         let mut res = pricer
             .next()
             .instrument(span!(Level::ERROR, "Brontes Dex Pricing",
             block_number=%block))
-            .await;
+            .await
+            .map(|(block, prices)| (block, prices, pricer.take_pool_states(block)));
+
+        // the graph state is as of the end of the returned block until the pricer is
+        // polled again
+        if let Some(block) = res.as_ref().map(|(block, ..)| *block) {
+            if let Some(checkpoints) = checkpoints.as_ref().filter(|c| c.is_due(block)) {
+                checkpoints
+                    .write(block, pricer.checkpoint_graph_state())
+                    .await;
+            }
+        }
 
         // we will keep trying to send util it is resolved or the channel is dropped
         while let Err(e) = tx.try_send((pricer, res)) {
@@ -80,7 +145,7 @@ impl<T: TracingProvider> WaitingForPricerFuture<T> {
 
     fn reschedule(&mut self, pricer: BrontesBatchPricer<T>) {
         let tx = self.tx.clone();
-        let fut = Box::pin(Self::pricing_thread(pricer, tx));
+        let fut = Box::pin(Self::pricing_thread(pricer, tx, self.checkpoints.clone()));
 
         self.task_executor.spawn_critical("dex pricer", fut);
     }
@@ -99,8 +164,7 @@ impl<T: TracingProvider> WaitingForPricerFuture<T> {
 
     fn process_resolved_pricing(
         &mut self,
-        block: u64,
-        prices: DexQuotes,
+        (block, prices, pool_states): ResolvedPricing,
     ) -> Poll<Option<(BlockTree<Action>, Metadata)>> {
         let Some((mut tree, meta)) = self.pending_trees.remove(&block) else {
             let _ = self.tmp_trees.remove(&block);
@@ -123,7 +187,9 @@ impl<T: TracingProvider> WaitingForPricerFuture<T> {
             tree.label_private_txes(&meta);
         }
 
-        let finalized_meta = meta.into_full_metadata(prices);
+        let finalized_meta = meta
+            .into_full_metadata(prices)
+            .with_pool_states(pool_states);
 
         Poll::Ready(Some((tree, finalized_meta)))
     }
@@ -135,14 +201,13 @@ impl<T: TracingProvider> Stream for WaitingForPricerFuture<T> {
     fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
         // check to see if there is cached block
         if !self.pricing_resolved_cache.is_empty() {
-            let (resolved_block, pricing) = self.pricing_resolved_cache.pop_front().unwrap();
-            if resolved_block <= self.max_tree_block {
-                return self.process_resolved_pricing(resolved_block, pricing)
+            let resolved = self.pricing_resolved_cache.pop_front().unwrap();
+            if resolved.0 <= self.max_tree_block {
+                return self.process_resolved_pricing(resolved)
             }
 
             // not ready yet so push to front
-            self.pricing_resolved_cache
-                .push_front((resolved_block, pricing));
+            self.pricing_resolved_cache.push_front(resolved);
         }
 
         if let Poll::Ready(handle) = self.receiver.poll_recv(cx) {
@@ -154,7 +219,8 @@ impl<T: TracingProvider> Stream for WaitingForPricerFuture<T> {
             self.reschedule(pricer);
             cx.waker().wake_by_ref();
 
-            if let Some((block, prices)) = inner {
+            if let Some(resolved) = inner {
+                let block = resolved.0;
                 debug!(target:"brontes","Generated dex prices for block: {} ", block);
 
                 if block > self.max_tree_block {
@@ -164,10 +230,10 @@ impl<T: TracingProvider> Stream for WaitingForPricerFuture<T> {
                         "Pricing completed for block before metadata"
                     );
 
-                    self.pricing_resolved_cache.push_back((block, prices));
+                    self.pricing_resolved_cache.push_back(resolved);
                     return Poll::Pending
                 }
-                return self.process_resolved_pricing(block, prices)
+                return self.process_resolved_pricing(resolved)
             }
 
             tracing::info!("pricing returned completed");
@@ -69,6 +69,11 @@ fn run() -> eyre::Result<()> {
                 command.execute(brontes_db_path, ctx)
             })
         }
+        Commands::Price(command) => {
+            runner::run_command_until_exit(None, Duration::from_secs(5), |ctx| {
+                command.execute(brontes_db_path, ctx)
+            })
+        }
     }
 }
 
@@ -1,9 +1,12 @@
 use brontes_macros::action_impl;
 use brontes_types::{
-    normalized_actions::{NormalizedFlashLoan, NormalizedLiquidation},
+    normalized_actions::{
+        LiquidationKind, NormalizedDeposit, NormalizedFlashLoan, NormalizedLiquidation,
+        NormalizedLoan, NormalizedRepayment, NormalizedWithdraw,
+    },
     structured_trace::CallInfo,
     utils::ToScaledRational,
-    Protocol,
+    FastHashMap, Protocol,
 };
 use malachite::{num::basic::traits::Zero, Rational};
 
@@ -34,6 +37,9 @@ action_impl!(
             // filled in later
             liquidated_collateral: Rational::ZERO,
             msg_value: info.msg_value,
+            kind: LiquidationKind::Repaid,
+            auction_price: None,
+            oracle_price: None,
         })
     }
 );
@@ -74,3 +80,116 @@ action_impl!(
 
     }
 );
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::borrowCall,
+    Loan,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: borrowCall,
+    db_tx: &DB| {
+        let loaned_token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let loan_amount = call_data.amount.to_scaled_rational(loaned_token.decimals);
+
+        return Ok(NormalizedLoan {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: call_data.onBehalfOf,
+            loaned_token,
+            loan_amount,
+            // the collateral backing the loan isn't part of the call
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::repayCall,
+    Repayment,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: repayCall,
+    return_data: repayReturn,
+    db_tx: &DB| {
+        let repayed_token = db_tx.try_fetch_token_info(call_data.asset)?;
+        // the call amount can be uint max to repay the full debt, the return value
+        // is the amount that was actually repaid
+        let repayment_amount = return_data._0.to_scaled_rational(repayed_token.decimals);
+
+        return Ok(NormalizedRepayment {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            lender: info.from_address,
+            borrower: call_data.onBehalfOf,
+            repayed_token,
+            repayment_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::depositCall,
+    Deposit,
+    [],
+    call_data: true,
+    |
+    info: CallInfo,
+    call_data: depositCall,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        let amount = call_data.amount.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedDeposit {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            from: info.msg_sender,
+            on_behalf_of: call_data.onBehalfOf,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::AaveV2,
+    crate::AaveV2::withdrawCall,
+    Withdraw,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: withdrawCall,
+    return_data: withdrawReturn,
+    db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.asset)?;
+        // the call amount can be uint max to withdraw the full balance, the return
+        // value is the amount that was actually withdrawn
+        let amount = return_data._0.to_scaled_rational(token.decimals);
+
+        return Ok(NormalizedWithdraw {
+            protocol: Protocol::AaveV2,
+            trace_index: info.trace_idx,
+            pool: info.from_address,
+            from: info.msg_sender,
+            to: call_data.to,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
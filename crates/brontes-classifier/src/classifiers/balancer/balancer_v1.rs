@@ -90,7 +90,8 @@ action_impl!(
             protocol: Protocol::BalancerV1,
             trace_index: info.trace_idx,
             pool_address: info.target_address,
-            tokens
+            tokens,
+            pool_id: None,
         })
     }
 );
@@ -170,6 +171,7 @@ mod tests {
             trace_index:  0,
             pool_address: Address::new(hex!("9A2181cf0bC57FC0177517dB21d457BDd1b2b32e")),
             tokens:       vec![Address::new(hex!("5eD9e47679422c2F78568af8728EC3C3C8591146"))],
+            pool_id:      None,
         });
 
         classifier_utils
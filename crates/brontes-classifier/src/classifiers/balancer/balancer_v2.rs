@@ -192,6 +192,7 @@ action_impl!(
             protocol: Protocol::BalancerV2,
             pool_address: logs.poolAddress,
             tokens: vec![],
+            pool_id: None,
         })
     }
 );
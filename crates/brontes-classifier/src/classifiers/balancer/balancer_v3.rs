@@ -0,0 +1,304 @@
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedAggregator, NormalizedBurn, NormalizedMint, NormalizedSwap},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+use malachite::Rational;
+
+action_impl!(
+    Protocol::BalancerV3,
+    crate::BalancerV3Vault::swapCall,
+    Swap,
+    [..Swap],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: swapCall, return_data: swapReturn, db: &DB| {
+        let params = call_data.vaultSwapParams;
+        let token_in = db.try_fetch_token_info(params.tokenIn)?;
+        let token_out = db.try_fetch_token_info(params.tokenOut)?;
+
+        // the swapper and the recipient are only known once the deltas are
+        // settled, this is resolved when classifying the unlock call
+        Ok(NormalizedSwap {
+            protocol: Protocol::BalancerV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: info.from_address,
+            pool: params.pool,
+            amount_in: return_data.amountInRaw.to_scaled_rational(token_in.decimals),
+            amount_out: return_data.amountOutRaw.to_scaled_rational(token_out.decimals),
+            token_in,
+            token_out,
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::BalancerV3,
+    crate::BalancerV3Vault::addLiquidityCall,
+    Mint,
+    [..LiquidityAdded],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: addLiquidityCall, return_data: addLiquidityReturn, db: &DB| {
+        let pool = call_data.params.pool;
+        let (token, amount) = pool_token_amounts(pool, &return_data.amountsIn, db)?;
+
+        Ok(NormalizedMint {
+            protocol: Protocol::BalancerV3,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: call_data.params.to,
+            pool,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::BalancerV3,
+    crate::BalancerV3Vault::removeLiquidityCall,
+    Burn,
+    [..LiquidityRemoved],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: removeLiquidityCall,
+    return_data: removeLiquidityReturn,
+    db: &DB
+    | {
+        let pool = call_data.params.pool;
+        let (token, amount) = pool_token_amounts(pool, &return_data.amountsOut, db)?;
+
+        Ok(NormalizedBurn {
+            protocol: Protocol::BalancerV3,
+            trace_index: info.trace_idx,
+            from: call_data.params.from,
+            recipient: info.from_address,
+            pool,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::BalancerV3,
+    crate::BalancerV3Vault::unlockCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _| {
+        Ok(NormalizedAggregator {
+            protocol:      Protocol::BalancerV3,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.from_address,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+/// The vault reports liquidity amounts in the order the pool tokens were
+/// registered in
+fn pool_token_amounts<DB: LibmdbxReader>(
+    pool: Address,
+    amounts: &[U256],
+    db: &DB,
+) -> eyre::Result<(Vec<TokenInfoWithAddress>, Vec<Rational>)> {
+    let mut tokens = Vec::new();
+    let mut token_amounts = Vec::new();
+
+    for (token, amount) in db.get_protocol_details(pool)?.into_iter().zip(amounts) {
+        if amount.is_zero() {
+            continue
+        }
+
+        let token = db.try_fetch_token_info(token)?;
+        token_amounts.push(amount.to_scaled_rational(token.decimals));
+        tokens.push(token);
+    }
+
+    Ok((tokens, token_amounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Bytes};
+    use alloy_sol_types::SolCall;
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{db::token_info::TokenInfo, normalized_actions::Action};
+
+    use super::*;
+    use crate::BalancerV3Vault::{
+        addLiquidityCall, removeLiquidityCall, swapCall, AddLiquidityParams, RemoveLiquidityParams,
+        VaultSwapParams,
+    };
+
+    struct VaultSetup {
+        utils: ClassifierTestUtils,
+        vault: Address,
+        pool:  Address,
+        usdc:  TokenInfoWithAddress,
+        weth:  TokenInfoWithAddress,
+    }
+
+    async fn setup() -> VaultSetup {
+        let utils = ClassifierTestUtils::new().await;
+        let (vault, pool) = (Address::repeat_byte(0x55), Address::repeat_byte(0x56));
+
+        let usdc = TokenInfoWithAddress {
+            address: Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        };
+        let weth = TokenInfoWithAddress {
+            address: Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            inner:   TokenInfo { decimals: 18, symbol: "WETH".to_string() },
+        };
+        utils.ensure_token(usdc.clone());
+        utils.ensure_token(weth.clone());
+
+        for address in [vault, pool] {
+            utils.ensure_protocol(
+                Protocol::BalancerV3,
+                address,
+                usdc.address,
+                Some(weth.address),
+                None,
+                None,
+                None,
+                None,
+            );
+        }
+
+        VaultSetup { utils, vault, pool, usdc, weth }
+    }
+
+    #[brontes_macros::test]
+    async fn test_balancer_v3_swap() {
+        let VaultSetup { utils, vault, pool, usdc, weth } = setup().await;
+        let router = Address::repeat_byte(0x57);
+
+        let amount_in = U256::from(3_000_000_000u64);
+        let amount_out = U256::from(1_000_000_000_000_000_000u128);
+        let input = swapCall {
+            vaultSwapParams: VaultSwapParams {
+                kind: 0,
+                pool,
+                tokenIn: usdc.address,
+                tokenOut: weth.address,
+                amountGivenRaw: amount_in,
+                limitRaw: U256::ZERO,
+                userData: Bytes::new(),
+            },
+        }
+        .abi_encode();
+        let output = swapCall::abi_encode_returns(&(amount_out, amount_in, amount_out));
+
+        let action = utils.classify_call(router, vault, input, output, vec![]);
+
+        assert_eq!(
+            action,
+            Some(Action::Swap(NormalizedSwap {
+                protocol: Protocol::BalancerV3,
+                trace_index: 0,
+                from: router,
+                recipient: router,
+                pool,
+                token_in: usdc,
+                amount_in: amount_in.to_scaled_rational(6),
+                token_out: weth,
+                amount_out: amount_out.to_scaled_rational(18),
+                msg_value: U256::ZERO,
+            }))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_balancer_v3_add_liquidity() {
+        let VaultSetup { utils, vault, pool, usdc, .. } = setup().await;
+        let (router, provider) = (Address::repeat_byte(0x57), Address::repeat_byte(0x58));
+
+        // only usdc is added
+        let amounts_in = vec![U256::from(5_000_000u64), U256::ZERO];
+        let input = addLiquidityCall {
+            params: AddLiquidityParams {
+                pool,
+                to: provider,
+                maxAmountsIn: amounts_in.clone(),
+                minBptAmountOut: U256::ZERO,
+                kind: 0,
+                userData: Bytes::new(),
+            },
+        }
+        .abi_encode();
+        let output =
+            addLiquidityCall::abi_encode_returns(&(amounts_in, U256::from(1_000u64), Bytes::new()));
+
+        let action = utils.classify_call(router, vault, input, output, vec![]);
+
+        assert_eq!(
+            action,
+            Some(Action::Mint(NormalizedMint {
+                protocol: Protocol::BalancerV3,
+                trace_index: 0,
+                from: router,
+                recipient: provider,
+                pool,
+                token: vec![usdc],
+                amount: vec![U256::from(5_000_000u64).to_scaled_rational(6)],
+            }))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_balancer_v3_remove_liquidity() {
+        let VaultSetup { utils, vault, pool, usdc, weth } = setup().await;
+        let (router, provider) = (Address::repeat_byte(0x57), Address::repeat_byte(0x58));
+
+        let amounts_out = vec![U256::from(5_000_000u64), U256::from(2_000_000_000_000_000u64)];
+        let input = removeLiquidityCall {
+            params: RemoveLiquidityParams {
+                pool,
+                from: provider,
+                maxBptAmountIn: U256::from(1_000u64),
+                minAmountsOut: vec![U256::ZERO, U256::ZERO],
+                kind: 0,
+                userData: Bytes::new(),
+            },
+        }
+        .abi_encode();
+        let output = removeLiquidityCall::abi_encode_returns(&(
+            U256::from(1_000u64),
+            amounts_out.clone(),
+            Bytes::new(),
+        ));
+
+        let action = utils.classify_call(router, vault, input, output, vec![]);
+
+        assert_eq!(
+            action,
+            Some(Action::Burn(NormalizedBurn {
+                protocol: Protocol::BalancerV3,
+                trace_index: 0,
+                from: provider,
+                recipient: router,
+                pool,
+                token: vec![usdc, weth],
+                amount: vec![
+                    amounts_out[0].to_scaled_rational(6),
+                    amounts_out[1].to_scaled_rational(18),
+                ],
+            }))
+        );
+    }
+}
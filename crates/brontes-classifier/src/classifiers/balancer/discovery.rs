@@ -14,6 +14,7 @@ discovery_impl!(
             protocol: Protocol::BalancerV1,
             pool_address: deployed_address,
             tokens: vec![],
+            pool_id: None,
         }]
     }
 );
@@ -28,16 +29,43 @@ discovery_impl!(
             protocol: Protocol::BalancerV1CRP,
             pool_address: deployed_address,
             tokens: vec![],
+            pool_id: None,
+        }]
+    }
+);
+
+// Balancer V3 pools aren't deployed by the vault, pool factories register them
+// on the vault once deployed.
+discovery_impl!(
+    BalancerV3VaultDiscovery,
+    crate::BalancerV3Vault::registerPoolCall,
+    0xbA1333333333a1BA1108E8412f11850A5C319bA9,
+    |_, trace_index: u64, call_data: registerPoolCall, _| async move {
+        vec![NormalizedNewPool {
+            trace_index,
+            protocol: Protocol::BalancerV3,
+            pool_address: call_data.pool,
+            tokens: call_data
+                .tokenConfig
+                .into_iter()
+                .map(|config| config.token)
+                .collect(),
+            pool_id: None,
         }]
     }
 );
 
 #[cfg(test)]
 mod tests {
-    use alloy_primitives::{hex, Address, B256};
+    use alloy_primitives::{hex, Address, B256, U256};
+    use alloy_sol_types::SolCall;
     use brontes_types::{normalized_actions::pool::NormalizedNewPool, Protocol};
 
-    use crate::test_utils::ClassifierTestUtils;
+    use crate::{
+        test_utils::ClassifierTestUtils,
+        BalancerV3Vault::{registerPoolCall, LiquidityManagement, PoolRoleAccounts, TokenConfig},
+        FactoryDiscoveryDispatch, RegistrationDiscoveryClassifier,
+    };
 
     #[brontes_macros::test]
     async fn test_balancer_v1_discovery() {
@@ -53,6 +81,7 @@ mod tests {
                 hex!("b2b88912edc5f5fece07ed821de80440c0bae618").into(),
                 hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").into(),
             ],
+            pool_id:      None,
         };
 
         utils
@@ -70,4 +99,59 @@ mod tests {
             .await
             .unwrap();
     }
+
+    #[brontes_macros::test]
+    async fn test_balancer_v3_registration_discovery() {
+        let utils = ClassifierTestUtils::new().await;
+        let vault = Address::new(hex!("bA1333333333a1BA1108E8412f11850A5C319bA9"));
+        let pool = Address::with_last_byte(1);
+        let tokens = vec![
+            Address::new(hex!("c02aaa39b223fe8d0a0e5c4f27ead9083c756cc2")),
+            Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48")),
+        ];
+
+        let calldata = registerPoolCall {
+            pool,
+            tokenConfig: tokens
+                .iter()
+                .map(|token| TokenConfig {
+                    token:         *token,
+                    tokenType:     0,
+                    rateProvider:  Address::ZERO,
+                    paysYieldFees: false,
+                })
+                .collect(),
+            swapFeePercentage: U256::ZERO,
+            pauseWindowEndTime: 0,
+            protocolFeeExempt: false,
+            roleAccounts: PoolRoleAccounts {
+                pauseManager:   Address::ZERO,
+                swapFeeManager: Address::ZERO,
+                poolCreator:    Address::ZERO,
+            },
+            poolHooksContract: Address::ZERO,
+            liquidityManagement: LiquidityManagement {
+                disableUnbalancedLiquidity:  false,
+                enableAddLiquidityCustom:    false,
+                enableRemoveLiquidityCustom: false,
+                enableDonation:              false,
+            },
+        }
+        .abi_encode();
+
+        let pools = RegistrationDiscoveryClassifier::default()
+            .dispatch(utils.get_tracing_provider(), vec![(vault, calldata.into())], vault, 3)
+            .await;
+
+        assert_eq!(
+            pools,
+            vec![NormalizedNewPool {
+                trace_index: 3,
+                protocol: Protocol::BalancerV3,
+                pool_address: pool,
+                tokens,
+                pool_id: None,
+            }]
+        );
+    }
 }
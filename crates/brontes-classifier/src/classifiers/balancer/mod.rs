@@ -6,6 +6,10 @@ mod balancer_v2;
 
 pub use balancer_v2::*;
 
+mod balancer_v3;
+
+pub use balancer_v3::*;
+
 mod discovery;
 
 pub use discovery::*;
@@ -0,0 +1,93 @@
+use alloy_primitives::Address;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{Action, NormalizedAggregator, NormalizedNftTrade},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use crate::BlurExchange::Order;
+
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchange::executeCall,
+    NftTrade,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: executeCall, db: &DB| {
+        nft_trade(&info, &call_data.sell.order, &call_data.buy.order, db)
+    }
+);
+
+// the executions of a bulk execute that fail are skipped, the ones that are
+// filled are the ones with an orders matched event
+action_impl!(
+    Protocol::Blur,
+    crate::BlurExchange::bulkExecuteCall,
+    Action,
+    [..OrdersMatched*],
+    logs: true,
+    include_delegated_logs: true,
+    |info: CallInfo, log_data: BlurBulkExecuteCallLogs, db: &DB| {
+        let mut trades = log_data
+            .orders_matched_field?
+            .iter()
+            .map(|matched| nft_trade(&info, &matched.sell, &matched.buy, db).map(Action::NftTrade))
+            .collect::<eyre::Result<Vec<_>>>()?;
+
+        match trades.len() {
+            0 => Err(eyre::eyre!("bulk execute didn't fill any order")),
+            1 => Ok(trades.remove(0)),
+            _ => Ok(Action::Aggregator(NormalizedAggregator {
+                protocol: Protocol::Blur,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                to: info.target_address,
+                recipient: info.from_address,
+                child_actions: trades,
+                msg_value: info.msg_value,
+            })),
+        }
+    }
+);
+
+/// The order that was listed first is the maker, its terms are the ones the
+/// orders are matched at
+fn nft_trade<DB: LibmdbxReader>(
+    info: &CallInfo,
+    sell: &Order,
+    buy: &Order,
+    db: &DB,
+) -> eyre::Result<NormalizedNftTrade> {
+    let maker = if sell.listingTime <= buy.listingTime { sell } else { buy };
+    let payment_token = payment_token_info(maker.paymentToken, db)?;
+
+    Ok(NormalizedNftTrade {
+        protocol: Protocol::Blur,
+        trace_index: info.trace_idx,
+        marketplace: info.target_address,
+        seller: sell.trader,
+        buyer: buy.trader,
+        collection: maker.collection,
+        token_ids: vec![maker.tokenId],
+        amounts: vec![maker.amount],
+        price: maker.price.to_scaled_rational(payment_token.decimals),
+        payment_token,
+        msg_value: info.msg_value,
+    })
+}
+
+/// Listings are paid in eth, which is represented by the zero address
+fn payment_token_info<DB: LibmdbxReader>(
+    token: Address,
+    db: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    if token.is_zero() {
+        return Ok(TokenInfoWithAddress::native_eth())
+    }
+
+    db.try_fetch_token_info(token)
+}
@@ -1,7 +1,15 @@
+use alloy_primitives::{hex, Address};
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
 use brontes_types::{
-    normalized_actions::NormalizedLiquidation, structured_trace::CallInfo, utils::ToScaledRational,
+    constants::ETH_ADDRESS,
+    normalized_actions::{
+        LiquidationKind, NormalizedDeposit, NormalizedLiquidation, NormalizedLoan,
+        NormalizedRepayment, NormalizedWithdraw,
+    },
+    structured_trace::CallInfo,
+    utils::ToScaledRational,
+    FastHashMap,
 };
 
 action_impl!(
@@ -33,22 +41,320 @@ action_impl!(
             covered_debt: debt_covered,
             liquidated_collateral: collateral_liquidated,
             msg_value: info.msg_value,
+            kind: LiquidationKind::Repaid,
+            auction_price: None,
+            oracle_price: None,
         })
     }
 );
 
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::borrowCall,
+    Loan,
+    [..Borrow],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    _call_data: borrowCall,
+    log_data: CompoundV2BorrowCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.borrow_field?;
+        let loaned_token = db_tx.try_fetch_token_info(underlying(info.target_address)?)?;
+        let loan_amount = logs.borrowAmount.to_scaled_rational(loaned_token.decimals);
+        return Ok(NormalizedLoan {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            loaned_token,
+            loan_amount,
+            // collateral is tracked per account by the comptroller, not per borrow
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::repayBorrowCall,
+    Repayment,
+    [..RepayBorrow],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    _call_data: repayBorrowCall,
+    log_data: CompoundV2RepayBorrowCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.repay_borrow_field?;
+        let repayed_token = db_tx.try_fetch_token_info(underlying(info.target_address)?)?;
+        let repayment_amount = logs.repayAmount.to_scaled_rational(repayed_token.decimals);
+        return Ok(NormalizedRepayment {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            repayed_token,
+            repayment_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::repayBorrowBehalfCall,
+    Repayment,
+    [..RepayBorrow],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    _call_data: repayBorrowBehalfCall,
+    log_data: CompoundV2RepayBorrowBehalfCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.repay_borrow_field?;
+        let repayed_token = db_tx.try_fetch_token_info(underlying(info.target_address)?)?;
+        let repayment_amount = logs.repayAmount.to_scaled_rational(repayed_token.decimals);
+        return Ok(NormalizedRepayment {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            lender: info.target_address,
+            borrower: logs.borrower,
+            repayed_token,
+            repayment_amount,
+            collateral: FastHashMap::default(),
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::mintCall,
+    Deposit,
+    [..Mint],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    _call_data: mintCall,
+    log_data: CompoundV2MintCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.mint_field?;
+        let token = db_tx.try_fetch_token_info(underlying(info.target_address)?)?;
+        let amount = logs.mintAmount.to_scaled_rational(token.decimals);
+        return Ok(NormalizedDeposit {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: logs.minter,
+            on_behalf_of: logs.minter,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::redeemCall,
+    Withdraw,
+    [..Redeem],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    _call_data: redeemCall,
+    log_data: CompoundV2RedeemCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.redeem_field?;
+        let token = db_tx.try_fetch_token_info(underlying(info.target_address)?)?;
+        let amount = logs.redeemAmount.to_scaled_rational(token.decimals);
+        return Ok(NormalizedWithdraw {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: logs.redeemer,
+            to: logs.redeemer,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CompoundV2,
+    crate::CompoundV2CToken::redeemUnderlyingCall,
+    Withdraw,
+    [..Redeem],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    _call_data: redeemUnderlyingCall,
+    log_data: CompoundV2RedeemUnderlyingCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.redeem_field?;
+        let token = db_tx.try_fetch_token_info(underlying(info.target_address)?)?;
+        let amount = logs.redeemAmount.to_scaled_rational(token.decimals);
+        return Ok(NormalizedWithdraw {
+            protocol: Protocol::CompoundV2,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: logs.redeemer,
+            to: logs.redeemer,
+            token,
+            amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// The token lent by a Compound V2 market. Every market was listed by the
+/// comptroller long before the protocol was frozen, so they are kept here
+/// instead of being read from the cToken on each call.
+const C_TOKEN_UNDERLYING: [(Address, Address); 20] = [
+    // cETH
+    (Address::new(hex!("4Ddc2D193948926D02f9B1fE9e1daa0718270ED5")), ETH_ADDRESS),
+    // cDAI
+    (
+        Address::new(hex!("5d3a536E4D6DbD6114cc1Ead35777bAB948E3643")),
+        Address::new(hex!("6B175474E89094C44Da98b954EedeAC495271d0F")),
+    ),
+    // cSAI
+    (
+        Address::new(hex!("F5DCe57282A584D2746FaF1593d3121Fcac444dC")),
+        Address::new(hex!("89d24A6b4CcB1B6fAA2625fE562bDD9a23260359")),
+    ),
+    // cUSDC
+    (
+        Address::new(hex!("39AA39c021dfbaE8faC545936693aC917d5E7563")),
+        Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+    ),
+    // cUSDT
+    (
+        Address::new(hex!("f650C3d88D12dB855b8bf7D11Be6C55A4e07dCC9")),
+        Address::new(hex!("dAC17F958D2ee523a2206206994597C13D831ec7")),
+    ),
+    // cWBTC
+    (
+        Address::new(hex!("C11b1268C1A384e55C48c2391d8d480264A3A7F4")),
+        Address::new(hex!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599")),
+    ),
+    // cWBTC2
+    (
+        Address::new(hex!("ccF4429DB6322D5C611ee964527D42E5d685DD6a")),
+        Address::new(hex!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599")),
+    ),
+    // cBAT
+    (
+        Address::new(hex!("6C8c6b02E7b2BE14d4fA6022Dfd6d75921D90E4E")),
+        Address::new(hex!("0D8775F648430679A709E98d2b0Cb6250d2887EF")),
+    ),
+    // cZRX
+    (
+        Address::new(hex!("B3319f5D18Bc0D84dD1b4825Dcde5d5f7266d407")),
+        Address::new(hex!("E41d2489571d322189246DaFA5ebDe1F4699F498")),
+    ),
+    // cREP
+    (
+        Address::new(hex!("158079Ee67Fce2f58472A96584A73C7Ab9AC95c1")),
+        Address::new(hex!("1985365e9f78359a9B6AD760e32412f4a445E862")),
+    ),
+    // cUNI
+    (
+        Address::new(hex!("35A18000230DA775CAc24873d00Ff85BccdeD550")),
+        Address::new(hex!("1f9840a85d5aF5bf1D1762F925BDADdC4201F984")),
+    ),
+    // cCOMP
+    (
+        Address::new(hex!("70e36f6BF80a52b3B46b3aF8e106CC0ed743E8e4")),
+        Address::new(hex!("c00e94Cb662C3520282E6f5717214004A7f26888")),
+    ),
+    // cLINK
+    (
+        Address::new(hex!("FAce851a4921ce59e912d19329929CE6da6EB0c7")),
+        Address::new(hex!("514910771AF9Ca656af840dff83E8264EcF986CA")),
+    ),
+    // cTUSD
+    (
+        Address::new(hex!("12392F67bdf24faE0AF363c24aC620a2f67DAd86")),
+        Address::new(hex!("0000000000085d4780B73119b644AE5ecd22b376")),
+    ),
+    // cUSDP
+    (
+        Address::new(hex!("041171993284df560249B57358F931D9eB7b925D")),
+        Address::new(hex!("8E870D67F660D95d5be530380D0eC0bd388289E1")),
+    ),
+    // cFEI
+    (
+        Address::new(hex!("7713DD9Ca933848F6819F38B8352D9A15EA73F67")),
+        Address::new(hex!("956F47F50A910163D8BF957Cf5846D573E7f87CA")),
+    ),
+    // cAAVE
+    (
+        Address::new(hex!("e65cdB6479BaC1e22340E4E755fAE7E509EcD06c")),
+        Address::new(hex!("7Fc66500c84A76Ad7e9c93437bFc5Ac33E2DDaE9")),
+    ),
+    // cSUSHI
+    (
+        Address::new(hex!("4B0181102A0112A2ef11AbEE5563bb4a3176c9d7")),
+        Address::new(hex!("6B3595068778DD592e39A122f4f5a5cF09C90fE2")),
+    ),
+    // cMKR
+    (
+        Address::new(hex!("95b4eF2869eBD94BEb4eEE400a99824BF5DC325b")),
+        Address::new(hex!("9f8F72aA9304c8B593d555F12eF6589cC3A579A2")),
+    ),
+    // cYFI
+    (
+        Address::new(hex!("80a2AE356fc9ef4305676f7a3E2Ed04e12C33946")),
+        Address::new(hex!("0bc529c00C6401aEF6D220BE8C6Ea1667F6Ad93e")),
+    ),
+];
+
+/// Amounts lent, repaid, supplied and redeemed are in the underlying token, not
+/// in the cToken the call is made on
+fn underlying(c_token: Address) -> eyre::Result<Address> {
+    C_TOKEN_UNDERLYING
+        .iter()
+        .find_map(|(market, underlying)| (*market == c_token).then_some(*underlying))
+        .ok_or(eyre::eyre!("no underlying token known for cToken {c_token:?}"))
+}
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{hex, Address, B256, U256};
     use brontes_types::{
         db::token_info::TokenInfoWithAddress,
-        normalized_actions::{Action, NormalizedLiquidation},
+        normalized_actions::{Action, LiquidationKind, NormalizedLiquidation},
         Protocol, TreeSearchBuilder,
     };
     use malachite::Rational;
 
+    use super::underlying;
     use crate::test_utils::ClassifierTestUtils;
 
+    #[test]
+    fn test_compound_v2_underlying() {
+        assert_eq!(
+            underlying(hex!("4Ddc2D193948926D02f9B1fE9e1daa0718270ED5").into()).unwrap(),
+            brontes_types::constants::ETH_ADDRESS
+        );
+        assert_eq!(
+            underlying(hex!("39AA39c021dfbaE8faC545936693aC917d5E7563").into()).unwrap(),
+            Address::from(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"))
+        );
+        assert!(underlying(Address::ZERO).is_err());
+    }
+
     #[brontes_macros::test]
     async fn test_compound_v2_liquidation() {
         let classifier_utils = ClassifierTestUtils::new().await;
@@ -98,6 +404,9 @@ mod tests {
             pool:                  Address::from(hex!("39AA39c021dfbaE8faC545936693aC917d5E7563")),
             trace_index:           2,
             msg_value:             U256::ZERO,
+            kind:                  LiquidationKind::Repaid,
+            auction_price:         None,
+            oracle_price:          None,
         });
 
         classifier_utils
@@ -0,0 +1,333 @@
+use alloy_primitives::Address;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{Action, LiquidationKind, NormalizedAggregator, NormalizedLiquidation},
+    structured_trace::CallInfo,
+    utils::ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+use crate::CompoundV3Comet::AbsorbCollateral;
+
+// Comet liquidates in two steps. `absorb` takes over the collateral and debt of
+// underwater accounts, the absorbed collateral is then sold at a discount
+// through `buyCollateral`. An absorb gives a liquidation for each collateral of
+// each account it absorbs, the debt of an account is split over its collaterals
+// by their value.
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::absorbCall,
+    Action,
+    [..(AbsorbCollateral | AbsorbDebt)*],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    call_data: absorbCall,
+    log_data: CompoundV3AbsorbCallLogs,
+    db_tx: &DB | {
+        let collaterals = log_data.absorb_collateral_field?;
+        let debts = log_data.absorb_debt_field?;
+        let debt_asset = base_token(info.target_address, db_tx)?;
+
+        let mut liquidations = Vec::new();
+        for debt in debts {
+            let covered_debt = debt.basePaidOut.to_scaled_rational(debt_asset.decimals);
+
+            for (collateral, share) in debt_shares(&collaterals, debt.borrower) {
+                let collateral_asset = db_tx.try_fetch_token_info(collateral.asset)?;
+
+                liquidations.push(Action::Liquidation(NormalizedLiquidation {
+                    protocol: Protocol::CompoundV3,
+                    trace_index: info.trace_idx,
+                    pool: info.target_address,
+                    liquidator: call_data.absorber,
+                    debtor: debt.borrower,
+                    covered_debt: &covered_debt * share,
+                    liquidated_collateral: collateral
+                        .collateralAbsorbed
+                        .to_scaled_rational(collateral_asset.decimals),
+                    collateral_asset,
+                    debt_asset: debt_asset.clone(),
+                    msg_value: info.msg_value,
+                    kind: LiquidationKind::Absorbed,
+                    auction_price: None,
+                    oracle_price: None,
+                }));
+            }
+        }
+
+        // an absorb of several positions is grouped under the absorb call
+        match liquidations.len() {
+            0 => Err(eyre::eyre!("absorb of {:?} had no collateral", call_data.accounts)),
+            1 => Ok(liquidations.remove(0)),
+            _ => Ok(Action::Aggregator(NormalizedAggregator {
+                protocol: Protocol::CompoundV3,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                to: info.target_address,
+                recipient: call_data.absorber,
+                child_actions: liquidations,
+                msg_value: info.msg_value,
+            })),
+        }
+    }
+);
+
+// The collateral being bought is owned by the protocol at this point, so the
+// market itself is the debtor. This is what links a purchase to the absorbs it
+// buys from.
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Comet::buyCollateralCall,
+    Liquidation,
+    [..BuyCollateral],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    call_data: buyCollateralCall,
+    log_data: CompoundV3BuyCollateralCallLogs,
+    db_tx: &DB | {
+        let logs = log_data.buy_collateral_field?;
+        let debt_asset = base_token(info.target_address, db_tx)?;
+        let collateral_asset = db_tx.try_fetch_token_info(logs.asset)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::CompoundV3,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: call_data.recipient,
+            debtor: info.target_address,
+            covered_debt: logs.baseAmount.to_scaled_rational(debt_asset.decimals),
+            liquidated_collateral: logs
+                .collateralAmount
+                .to_scaled_rational(collateral_asset.decimals),
+            collateral_asset,
+            debt_asset,
+            msg_value: info.msg_value,
+            kind: LiquidationKind::Repaid,
+            auction_price: None,
+            oracle_price: None,
+        })
+    }
+);
+
+/// The share of the debt of `borrower` covered by each of its absorbed
+/// collaterals, by their value
+fn debt_shares(
+    collaterals: &[AbsorbCollateral],
+    borrower: Address,
+) -> Vec<(&AbsorbCollateral, Rational)> {
+    let account_collaterals = collaterals
+        .iter()
+        .filter(|collateral| collateral.borrower == borrower)
+        .collect::<Vec<_>>();
+    let total_value = account_collaterals
+        .iter()
+        .map(|collateral| collateral.usdValue.to_scaled_rational(0))
+        .sum::<Rational>();
+
+    account_collaterals
+        .into_iter()
+        .map(|collateral| {
+            let share = if total_value == Rational::ZERO {
+                Rational::ZERO
+            } else {
+                collateral.usdValue.to_scaled_rational(0) / &total_value
+            };
+            (collateral, share)
+        })
+        .collect()
+}
+
+/// The base token of a market is stored as the first token of the pool
+fn base_token<DB: LibmdbxReader>(comet: Address, db: &DB) -> eyre::Result<TokenInfoWithAddress> {
+    let base = db.get_protocol_details(comet)?.token0;
+    db.try_fetch_token_info(base)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::db::token_info::TokenInfo;
+
+    use super::*;
+    use crate::CompoundV3Comet::{absorbCall, buyCollateralCall, AbsorbDebt, BuyCollateral};
+
+    struct CometSetup {
+        utils: ClassifierTestUtils,
+        comet: Address,
+        usdc:  TokenInfoWithAddress,
+        weth:  TokenInfoWithAddress,
+    }
+
+    async fn setup() -> CometSetup {
+        let utils = ClassifierTestUtils::new().await;
+        let comet = Address::repeat_byte(0x61);
+
+        let usdc = TokenInfoWithAddress {
+            address: Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        };
+        let weth = TokenInfoWithAddress {
+            address: Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            inner:   TokenInfo { decimals: 18, symbol: "WETH".to_string() },
+        };
+        utils.ensure_token(usdc.clone());
+        utils.ensure_token(weth.clone());
+        // the base token of the market is its first token
+        utils.ensure_protocol(
+            Protocol::CompoundV3,
+            comet,
+            usdc.address,
+            Some(weth.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        CometSetup { utils, comet, usdc, weth }
+    }
+
+    fn absorbed(borrower: Address, asset: u8, usd_value: u64) -> AbsorbCollateral {
+        AbsorbCollateral {
+            absorber: Address::ZERO,
+            borrower,
+            asset: Address::with_last_byte(asset),
+            collateralAbsorbed: U256::from(1),
+            usdValue: U256::from(usd_value),
+        }
+    }
+
+    #[test]
+    fn test_debt_shares() {
+        let (alice, bob) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let collaterals =
+            vec![absorbed(alice, 10, 300), absorbed(bob, 10, 50), absorbed(alice, 11, 100)];
+
+        let shares = debt_shares(&collaterals, alice)
+            .into_iter()
+            .map(|(collateral, share)| (collateral.asset, share))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            shares,
+            vec![
+                (Address::with_last_byte(10), Rational::from_signeds(3, 4)),
+                (Address::with_last_byte(11), Rational::from_signeds(1, 4)),
+            ]
+        );
+
+        assert_eq!(debt_shares(&collaterals, bob)[0].1, Rational::from(1));
+        assert!(debt_shares(&collaterals, Address::ZERO).is_empty());
+    }
+
+    #[brontes_macros::test]
+    async fn test_compound_v3_absorb() {
+        let CometSetup { utils, comet, usdc, weth } = setup().await;
+        let (absorber, borrower) = (Address::repeat_byte(0x62), Address::repeat_byte(0x63));
+
+        let collateral = U256::from(10u64.pow(18));
+        let base_paid_out = U256::from(2_500_000_000u64);
+        let input = absorbCall { absorber, accounts: vec![borrower] }.abi_encode();
+        let logs = vec![
+            Log {
+                address: comet,
+                data:    AbsorbCollateral {
+                    absorber,
+                    borrower,
+                    asset: weth.address,
+                    collateralAbsorbed: collateral,
+                    usdValue: U256::from(2_600u64),
+                }
+                .encode_log_data(),
+            },
+            Log {
+                address: comet,
+                data:    AbsorbDebt {
+                    absorber,
+                    borrower,
+                    basePaidOut: base_paid_out,
+                    usdValue: U256::from(2_500u64),
+                }
+                .encode_log_data(),
+            },
+        ];
+
+        let action = utils.classify_call(absorber, comet, input, vec![], logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::CompoundV3,
+                trace_index:           0,
+                pool:                  comet,
+                liquidator:            absorber,
+                debtor:                borrower,
+                collateral_asset:      weth,
+                debt_asset:            usdc,
+                covered_debt:          base_paid_out.to_scaled_rational(6),
+                liquidated_collateral: collateral.to_scaled_rational(18),
+                msg_value:             U256::ZERO,
+                kind:                  LiquidationKind::Absorbed,
+                auction_price:         None,
+                oracle_price:          None,
+            }))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_compound_v3_buy_collateral() {
+        let CometSetup { utils, comet, usdc, weth } = setup().await;
+        let buyer = Address::repeat_byte(0x64);
+
+        let (base_amount, collateral) = (U256::from(2_450_000_000u64), U256::from(10u64.pow(18)));
+        let input = buyCollateralCall {
+            asset:      weth.address,
+            minAmount:  collateral,
+            baseAmount: base_amount,
+            recipient:  buyer,
+        }
+        .abi_encode();
+        let logs = vec![Log {
+            address: comet,
+            data:    BuyCollateral {
+                buyer,
+                asset: weth.address,
+                baseAmount: base_amount,
+                collateralAmount: collateral,
+            }
+            .encode_log_data(),
+        }];
+
+        let action = utils.classify_call(buyer, comet, input, vec![], logs);
+
+        // the absorbed collateral is owned by the market, which is the debtor
+        assert_eq!(
+            action,
+            Some(Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::CompoundV3,
+                trace_index:           0,
+                pool:                  comet,
+                liquidator:            buyer,
+                debtor:                comet,
+                collateral_asset:      weth,
+                debt_asset:            usdc,
+                covered_debt:          base_amount.to_scaled_rational(6),
+                liquidated_collateral: collateral.to_scaled_rational(18),
+                msg_value:             U256::ZERO,
+                kind:                  LiquidationKind::Repaid,
+                auction_price:         None,
+                oracle_price:          None,
+            }))
+        );
+    }
+}
@@ -13,7 +13,8 @@ action_impl!(
             trace_index: info.trace_idx,
             protocol: Protocol::CompoundV2,
             pool_address: info.from_address,
-            tokens: vec![info.from_address]
+            tokens: vec![info.from_address],
+            pool_id: None,
         })
     }
 );
@@ -29,12 +30,47 @@ action_impl!(
             trace_index: info.trace_idx,
             protocol: Protocol::CompoundV2,
             pool_address: info.from_address,
-            tokens: vec![info.from_address]
+            tokens: vec![info.from_address],
+            pool_id: None,
         })
 
     }
 );
 
+// The configuration of a market is set before it is deployed, and is only
+// changed afterwards. Markets are discovered when their configuration is first
+// set, which is when they are still without a base token.
+action_impl!(
+    Protocol::CompoundV3,
+    crate::CompoundV3Configurator::setConfigurationCall,
+    NewPool,
+    [..SetConfiguration],
+    call_data: true,
+    logs: true,
+    include_delegated_logs: true,
+    |
+    info: CallInfo,
+    call_data: setConfigurationCall,
+    log_data: CompoundV3SetConfigurationCallLogs,
+    _| {
+        let logs = log_data.set_configuration_field?;
+        if !logs.oldConfiguration.baseToken.is_zero() {
+            eyre::bail!("market {:?} is already configured", call_data.cometProxy)
+        }
+        let config = call_data.newConfiguration;
+
+        Ok(NormalizedNewPool {
+            trace_index: info.trace_idx,
+            protocol: Protocol::CompoundV3,
+            pool_address: call_data.cometProxy,
+            tokens: std::iter::once(config.baseToken)
+                .chain(config.assetConfigs.iter().map(|asset| asset.asset))
+                .collect(),
+            pool_id: None,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use alloy_primitives::{hex, B256};
@@ -56,6 +92,7 @@ mod tests {
             protocol:     Protocol::CompoundV2,
             pool_address: hex!("5d3a536e4d6dbd6114cc1ead35777bab948e3643").into(),
             tokens:       vec![hex!("5d3a536e4d6dbd6114cc1ead35777bab948e3643").into()],
+            pool_id:      None,
         });
         let search = TreeSearchBuilder::default().with_action(Action::is_new_pool);
 
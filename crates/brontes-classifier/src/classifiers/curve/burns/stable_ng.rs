@@ -0,0 +1,149 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedBurn, structured_trace::CallInfo, ToScaledRational,
+};
+
+use crate::curve::utils::{liquidity_amounts, pool_coin};
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::remove_liquidity_0Call,
+    Burn,
+    [..RemoveLiquidity],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgPlainPoolRemove_liquidity_0CallLogs, db: &DB| {
+        let log = log.remove_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedBurn {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::remove_liquidity_1Call,
+    Burn,
+    [..RemoveLiquidity],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: remove_liquidity_1Call,
+    log: CurveStableSwapNgPlainPoolRemove_liquidity_1CallLogs,
+    db: &DB
+    | {
+        let log = log.remove_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedBurn {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: call_data._receiver,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::remove_liquidity_2Call,
+    Burn,
+    [..RemoveLiquidity],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: remove_liquidity_2Call,
+    log: CurveStableSwapNgPlainPoolRemove_liquidity_2CallLogs,
+    db: &DB
+    | {
+        let log = log.remove_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedBurn {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: call_data._receiver,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::remove_liquidity_one_coin_0Call,
+    Burn,
+    [..RemoveLiquidityOne],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgPlainPoolRemove_liquidity_one_coin_0CallLogs, db: &DB| {
+        let log = log.remove_liquidity_one_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let coin = pool_coin(&details, log.token_id as usize)?;
+        let token = db.try_fetch_token_info(coin)?;
+        let amount = log.coin_amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBurn {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            token: vec![token],
+            amount: vec![amount],
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::remove_liquidity_one_coin_1Call,
+    Burn,
+    [..RemoveLiquidityOne],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: remove_liquidity_one_coin_1Call,
+    log: CurveStableSwapNgPlainPoolRemove_liquidity_one_coin_1CallLogs,
+    db: &DB
+    | {
+        let log = log.remove_liquidity_one_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let coin = pool_coin(&details, log.token_id as usize)?;
+        let token = db.try_fetch_token_info(coin)?;
+        let amount = log.coin_amount.to_scaled_rational(token.decimals);
+
+        Ok(NormalizedBurn {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: call_data._receiver,
+            token: vec![token],
+            amount: vec![amount],
+        })
+    }
+);
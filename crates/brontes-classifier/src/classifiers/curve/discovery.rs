@@ -28,6 +28,13 @@ curve_discovery_impl!(
     (2, 3)
 );
 
+curve_discovery_impl!(
+    CurveStableSwapNg,
+    crate::CurveStableSwapNgFactory,
+    0x6a8cbed756804b16e05e741edabd5cb544ae21bf,
+    (1, 1)
+);
+
 discovery_impl!(
     CurveCryptoSwapDiscovery,
     crate::CurveCryptoSwapFactory::deploy_poolCall,
@@ -38,6 +45,7 @@ discovery_impl!(
             protocol: Protocol::CurveCryptoSwapPool,
             pool_address: deployed_address,
             tokens: call_data._coins.to_vec(),
+            pool_id: None,
         }]
     }
 );
@@ -58,6 +66,7 @@ discovery_impl!(
             protocol: Protocol::CurveTriCryptoPool,
             pool_address: deployed_address,
             tokens: call_data._coins.to_vec(),
+            pool_id: None,
         }]
     }
 );
@@ -93,15 +102,21 @@ async fn query_base_pool<T: TracingProvider>(tracer: &Arc<T>, base_pool: &Addres
     result
 }
 
-async fn parse_plain_pool<const N: usize>(
+async fn parse_plain_pool(
     protocol: Protocol,
     deployed_address: Address,
     trace_index: u64,
-    tokens: [Address; N],
+    tokens: impl IntoIterator<Item = Address>,
 ) -> Vec<NormalizedNewPool> {
     let tokens = tokens.into_iter().filter(|t| t != &Address::ZERO).collect();
 
-    vec![NormalizedNewPool { pool_address: deployed_address, trace_index, protocol, tokens }]
+    vec![NormalizedNewPool {
+        pool_address: deployed_address,
+        trace_index,
+        protocol,
+        tokens,
+        pool_id: None,
+    }]
 }
 
 async fn parse_meta_pool<T: TracingProvider>(
@@ -113,17 +128,31 @@ async fn parse_meta_pool<T: TracingProvider>(
     tracer: Arc<T>,
 ) -> Vec<NormalizedNewPool> {
     let mut tokens = query_base_pool(&tracer, &base_pool).await;
+    // a metapool is only usable with the tokens of its base pool
+    if tokens.is_empty() {
+        tracing::debug!(?deployed_address, ?base_pool, "failed to query the base pool tokens");
+        return vec![]
+    }
     tokens.push(meta_token);
 
-    vec![NormalizedNewPool { pool_address: deployed_address, trace_index, protocol, tokens }]
+    vec![NormalizedNewPool {
+        pool_address: deployed_address,
+        trace_index,
+        protocol,
+        tokens,
+        pool_id: None,
+    }]
 }
 
 #[cfg(test)]
 mod tests {
+    use std::sync::Arc;
+
     use alloy_primitives::{hex, Address, FixedBytes, B256};
+    use brontes_core::offline_provider::OfflineProvider;
     use brontes_types::{normalized_actions::pool::NormalizedNewPool, Protocol};
 
-    use super::query_base_pool;
+    use super::{parse_meta_pool, query_base_pool};
     use crate::test_utils::ClassifierTestUtils;
 
     async fn verify_discovery(
@@ -134,7 +163,8 @@ mod tests {
     ) {
         let utils = ClassifierTestUtils::new().await;
 
-        let eq_create = NormalizedNewPool { trace_index: 1, protocol, pool_address, tokens };
+        let eq_create =
+            NormalizedNewPool { trace_index: 1, protocol, pool_address, tokens, pool_id: None };
 
         utils
             .test_discovery_classification(tx, pool_address, |mut pool| {
@@ -224,6 +254,21 @@ mod tests {
         );
     }
 
+    #[brontes_macros::test]
+    async fn test_meta_pool_without_base_pool_tokens() {
+        let pools = parse_meta_pool(
+            Protocol::CurveV1MetaPool,
+            Address::new(hex!("5a6a4d54456819380173272a5e8e9b9904bdf41b")),
+            Address::new(hex!("bEbc44782C7dB0a1A60Cb6fe97d0b483032FF1C7")),
+            Address::new(hex!("99d8a9c45b2eca8864373a26d1459e3dff1e17f3")),
+            1,
+            Arc::new(OfflineProvider),
+        )
+        .await;
+
+        assert!(pools.is_empty());
+    }
+
     // No base_pools using `coins(U256)` method found,
     // but no good way to find them.
 
@@ -288,6 +333,7 @@ mod tests {
                 hex!("81cb62d2cd9261f63a1ae96df715748dcbc97d46").into(),
                 hex!("dac17f958d2ee523a2206206994597c13d831ec7").into(),
             ],
+            pool_id:      None,
         };
 
         utils
@@ -321,6 +367,7 @@ mod tests {
                 hex!("b53ecF1345caBeE6eA1a65100Ebb153cEbcac40f").into(),
                 hex!("f3b9569F82B18aEf890De263B84189bd33EBe452").into(),
             ],
+            pool_id:      None,
         };
 
         utils
@@ -0,0 +1,132 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedMint, structured_trace::CallInfo};
+
+use crate::curve::utils::liquidity_amounts;
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::add_liquidity_0Call,
+    Mint,
+    [..AddLiquidity],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgPlainPoolAdd_liquidity_0CallLogs, db: &DB| {
+        let log = log.add_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedMint {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::add_liquidity_1Call,
+    Mint,
+    [..AddLiquidity],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: add_liquidity_1Call,
+    log: CurveStableSwapNgPlainPoolAdd_liquidity_1CallLogs,
+    db: &DB
+    | {
+        let log = log.add_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedMint {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: call_data._receiver,
+            token,
+            amount,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::Action,
+        ToScaledRational,
+    };
+
+    use super::*;
+    use crate::CurveStableSwapNgPlainPool::{add_liquidity_0Call, AddLiquidity};
+
+    #[brontes_macros::test]
+    async fn test_curve_stable_ng_plain_pool_add_liquidity0() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0x51);
+        let provider = Address::repeat_byte(0x52);
+
+        let usdc = TokenInfoWithAddress {
+            address: Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        };
+        let usdt = TokenInfoWithAddress {
+            address: Address::new(hex!("dAC17F958D2ee523a2206206994597C13D831ec7")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDT".to_string() },
+        };
+        classifier_utils.ensure_token(usdc.clone());
+        classifier_utils.ensure_token(usdt.clone());
+        classifier_utils.ensure_protocol(
+            Protocol::CurveStableSwapNgPlainPool,
+            pool,
+            usdc.address,
+            Some(usdt.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let amounts = vec![U256::from(1_000_000u64), U256::from(2_000_000u64)];
+        let minted = U256::from(3_000_000_000_000_000_000u128);
+        let input =
+            add_liquidity_0Call { _amounts: amounts.clone(), _min_mint_amount: U256::ZERO }
+                .abi_encode();
+        let output = add_liquidity_0Call::abi_encode_returns(&(minted,));
+        let add_liquidity = AddLiquidity {
+            provider,
+            token_amounts: amounts.clone(),
+            fees: vec![U256::ZERO, U256::ZERO],
+            invariant: U256::ZERO,
+            token_supply: minted,
+        };
+        let logs = vec![Log { address: pool, data: add_liquidity.encode_log_data() }];
+
+        let action = classifier_utils.classify_call(provider, pool, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Mint(NormalizedMint {
+                protocol: Protocol::CurveStableSwapNgPlainPool,
+                trace_index: 0,
+                pool,
+                from: provider,
+                recipient: provider,
+                token: vec![usdc, usdt],
+                amount: vec![amounts[0].to_scaled_rational(6), amounts[1].to_scaled_rational(6)],
+            }))
+        );
+    }
+}
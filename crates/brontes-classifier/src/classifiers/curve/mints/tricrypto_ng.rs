@@ -0,0 +1,160 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedMint, structured_trace::CallInfo};
+
+use crate::curve::utils::liquidity_amounts;
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNgPool::add_liquidity_0Call,
+    Mint,
+    [..AddLiquidity],
+    logs: true,
+    |info: CallInfo, log: CurveTriCryptoPoolAdd_liquidity_0CallLogs, db: &DB| {
+        let log = log.add_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedMint {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNgPool::add_liquidity_1Call,
+    Mint,
+    [..AddLiquidity],
+    logs: true,
+    |info: CallInfo, log: CurveTriCryptoPoolAdd_liquidity_1CallLogs, db: &DB| {
+        let log = log.add_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedMint {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNgPool::add_liquidity_2Call,
+    Mint,
+    [..AddLiquidity],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: add_liquidity_2Call,
+    log: CurveTriCryptoPoolAdd_liquidity_2CallLogs,
+    db: &DB
+    | {
+        let log = log.add_liquidity_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let (token, amount) = liquidity_amounts(&details, &log.token_amounts, db)?;
+
+        Ok(NormalizedMint {
+            protocol: details.protocol,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            from: info.msg_sender,
+            recipient: call_data.receiver,
+            token,
+            amount,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::Action,
+        ToScaledRational,
+    };
+
+    use super::*;
+    use crate::CurveTriCryptoNgPool::{add_liquidity_0Call, AddLiquidity};
+
+    #[brontes_macros::test]
+    async fn test_curve_tricrypto_ng_add_liquidity0() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0x53);
+        let provider = Address::repeat_byte(0x54);
+
+        let usdt = TokenInfoWithAddress {
+            address: Address::new(hex!("dAC17F958D2ee523a2206206994597C13D831ec7")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDT".to_string() },
+        };
+        let wbtc = TokenInfoWithAddress {
+            address: Address::new(hex!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599")),
+            inner:   TokenInfo { decimals: 8, symbol: "WBTC".to_string() },
+        };
+        let weth = TokenInfoWithAddress {
+            address: Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            inner:   TokenInfo { decimals: 18, symbol: "WETH".to_string() },
+        };
+        classifier_utils.ensure_token(usdt.clone());
+        classifier_utils.ensure_token(wbtc.clone());
+        classifier_utils.ensure_token(weth.clone());
+        classifier_utils.ensure_protocol(
+            Protocol::CurveTriCryptoPool,
+            pool,
+            usdt.address,
+            Some(wbtc.address),
+            Some(weth.address),
+            None,
+            None,
+            None,
+        );
+
+        // wbtc isn't added, so it isn't part of the mint
+        let amounts = [U256::from(1_000_000u64), U256::ZERO, U256::from(10u64.pow(18))];
+        let minted = U256::from(10u64.pow(18));
+        let input = add_liquidity_0Call { amounts, min_mint_amount: U256::ZERO }.abi_encode();
+        let output = add_liquidity_0Call::abi_encode_returns(&(minted,));
+        let add_liquidity = AddLiquidity {
+            provider,
+            token_amounts: amounts,
+            fee: U256::ZERO,
+            token_supply: minted,
+            packed_price_scale: U256::ZERO,
+        };
+        let logs = vec![Log { address: pool, data: add_liquidity.encode_log_data() }];
+
+        let action = classifier_utils.classify_call(provider, pool, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Mint(NormalizedMint {
+                protocol: Protocol::CurveTriCryptoPool,
+                trace_index: 0,
+                pool,
+                from: provider,
+                recipient: provider,
+                token: vec![usdt, weth],
+                amount: vec![amounts[0].to_scaled_rational(6), amounts[2].to_scaled_rational(18)],
+            }))
+        );
+    }
+}
@@ -11,3 +11,5 @@ pub use mints::*;
 
 pub(crate) mod burns;
 pub use burns::*;
+
+pub(crate) mod utils;
@@ -9,3 +9,9 @@ pub use v2_meta::*;
 
 mod v2_plain;
 pub use v2_plain::*;
+
+mod stable_ng;
+pub use stable_ng::*;
+
+mod tricrypto_ng;
+pub use tricrypto_ng::*;
@@ -0,0 +1,371 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::structured_trace::CallInfo;
+
+use crate::curve::utils::{curve_swap, meta_pool_coin, pool_coin, underlying_coin};
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::exchange_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgPlainPoolExchange_0CallLogs, db: &DB| {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id as usize)?;
+        let token_out = pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::exchange_1Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_1Call,
+    log: CurveStableSwapNgPlainPoolExchange_1CallLogs,
+    db: &DB
+    | {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id as usize)?;
+        let token_out = pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            call_data._receiver,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::exchange_received_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgPlainPoolExchange_received_0CallLogs, db: &DB| {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id as usize)?;
+        let token_out = pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgPlainPool,
+    crate::CurveStableSwapNgPlainPool::exchange_received_1Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_received_1Call,
+    log: CurveStableSwapNgPlainPoolExchange_received_1CallLogs,
+    db: &DB
+    | {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id as usize)?;
+        let token_out = pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            call_data._receiver,
+            db,
+        )
+    }
+);
+
+// metapools swap their own coin against the lp token of the base pool, or
+// against the coins of the base pool with `exchange_underlying`
+
+action_impl!(
+    Protocol::CurveStableSwapNgMetaPool,
+    crate::CurveStableSwapNgMetaPool::exchange_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgMetaPoolExchange_0CallLogs, db: &DB| {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = meta_pool_coin(&details, log.sold_id as usize)?;
+        let token_out = meta_pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgMetaPool,
+    crate::CurveStableSwapNgMetaPool::exchange_1Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_1Call,
+    log: CurveStableSwapNgMetaPoolExchange_1CallLogs,
+    db: &DB
+    | {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = meta_pool_coin(&details, log.sold_id as usize)?;
+        let token_out = meta_pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            call_data._receiver,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgMetaPool,
+    crate::CurveStableSwapNgMetaPool::exchange_received_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgMetaPoolExchange_received_0CallLogs, db: &DB| {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = meta_pool_coin(&details, log.sold_id as usize)?;
+        let token_out = meta_pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgMetaPool,
+    crate::CurveStableSwapNgMetaPool::exchange_received_1Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_received_1Call,
+    log: CurveStableSwapNgMetaPoolExchange_received_1CallLogs,
+    db: &DB
+    | {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = meta_pool_coin(&details, log.sold_id as usize)?;
+        let token_out = meta_pool_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            call_data._receiver,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgMetaPool,
+    crate::CurveStableSwapNgMetaPool::exchange_underlying_0Call,
+    Swap,
+    [..TokenExchangeUnderlying],
+    logs: true,
+    |info: CallInfo, log: CurveStableSwapNgMetaPoolExchange_underlying_0CallLogs, db: &DB| {
+        let log = log.token_exchange_underlying_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = underlying_coin(&details, log.sold_id as usize)?;
+        let token_out = underlying_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveStableSwapNgMetaPool,
+    crate::CurveStableSwapNgMetaPool::exchange_underlying_1Call,
+    Swap,
+    [..TokenExchangeUnderlying],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_underlying_1Call,
+    log: CurveStableSwapNgMetaPoolExchange_underlying_1CallLogs,
+    db: &DB
+    | {
+        let log = log.token_exchange_underlying_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = underlying_coin(&details, log.sold_id as usize)?;
+        let token_out = underlying_coin(&details, log.bought_id as usize)?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            call_data._receiver,
+            db,
+        )
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::{Action, NormalizedSwap},
+        ToScaledRational,
+    };
+
+    use super::*;
+    use crate::CurveStableSwapNgPlainPool::{exchange_0Call, TokenExchange};
+
+    #[brontes_macros::test]
+    async fn test_curve_stable_ng_plain_pool_exchange0() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0x51);
+        let trader = Address::repeat_byte(0x52);
+
+        let usdc = TokenInfoWithAddress {
+            address: Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        };
+        let usdt = TokenInfoWithAddress {
+            address: Address::new(hex!("dAC17F958D2ee523a2206206994597C13D831ec7")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDT".to_string() },
+        };
+        classifier_utils.ensure_token(usdc.clone());
+        classifier_utils.ensure_token(usdt.clone());
+        classifier_utils.ensure_protocol(
+            Protocol::CurveStableSwapNgPlainPool,
+            pool,
+            usdc.address,
+            Some(usdt.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        let (sold, bought) = (U256::from(1_000_000_000u64), U256::from(999_500_000u64));
+        let input = exchange_0Call { i: 0, j: 1, _dx: sold, _min_dy: U256::ZERO }.abi_encode();
+        let output = exchange_0Call::abi_encode_returns(&(bought,));
+        let exchange = TokenExchange {
+            buyer:         trader,
+            sold_id:       0,
+            tokens_sold:   sold,
+            bought_id:     1,
+            tokens_bought: bought,
+        };
+        let logs = vec![Log { address: pool, data: exchange.encode_log_data() }];
+
+        let action = classifier_utils.classify_call(trader, pool, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Swap(NormalizedSwap {
+                protocol: Protocol::CurveStableSwapNgPlainPool,
+                trace_index: 0,
+                from: trader,
+                recipient: trader,
+                pool,
+                token_in: usdc,
+                amount_in: sold.to_scaled_rational(6),
+                token_out: usdt,
+                amount_out: bought.to_scaled_rational(6),
+                msg_value: U256::ZERO,
+            }))
+        );
+    }
+}
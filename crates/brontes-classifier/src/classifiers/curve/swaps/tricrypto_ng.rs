@@ -0,0 +1,176 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::structured_trace::CallInfo;
+
+use crate::curve::utils::{curve_swap, pool_coin};
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNgPool::exchange_0Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo, log: CurveTriCryptoPoolExchange_0CallLogs, db: &DB| {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id.to::<usize>())?;
+        let token_out = pool_coin(&details, log.bought_id.to::<usize>())?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNgPool::exchange_1Call,
+    Swap,
+    [..TokenExchange],
+    logs: true,
+    |info: CallInfo, log: CurveTriCryptoPoolExchange_1CallLogs, db: &DB| {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id.to::<usize>())?;
+        let token_out = pool_coin(&details, log.bought_id.to::<usize>())?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            info.msg_sender,
+            db,
+        )
+    }
+);
+
+action_impl!(
+    Protocol::CurveTriCryptoPool,
+    crate::CurveTriCryptoNgPool::exchange_2Call,
+    Swap,
+    [..TokenExchange],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: exchange_2Call,
+    log: CurveTriCryptoPoolExchange_2CallLogs,
+    db: &DB
+    | {
+        let log = log.token_exchange_field?;
+        let details = db.get_protocol_details(info.target_address)?;
+
+        let token_in = pool_coin(&details, log.sold_id.to::<usize>())?;
+        let token_out = pool_coin(&details, log.bought_id.to::<usize>())?;
+
+        curve_swap(
+            &info,
+            &details,
+            token_in,
+            log.tokens_sold,
+            token_out,
+            log.tokens_bought,
+            call_data.receiver,
+            db,
+        )
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::{Action, NormalizedSwap},
+        ToScaledRational,
+    };
+
+    use super::*;
+    use crate::CurveTriCryptoNgPool::{exchange_0Call, TokenExchange};
+
+    #[brontes_macros::test]
+    async fn test_curve_tricrypto_ng_exchange0() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let pool = Address::repeat_byte(0x53);
+        let trader = Address::repeat_byte(0x54);
+
+        let usdt = TokenInfoWithAddress {
+            address: Address::new(hex!("dAC17F958D2ee523a2206206994597C13D831ec7")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDT".to_string() },
+        };
+        let wbtc = TokenInfoWithAddress {
+            address: Address::new(hex!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599")),
+            inner:   TokenInfo { decimals: 8, symbol: "WBTC".to_string() },
+        };
+        let weth = TokenInfoWithAddress {
+            address: Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            inner:   TokenInfo { decimals: 18, symbol: "WETH".to_string() },
+        };
+        classifier_utils.ensure_token(usdt.clone());
+        classifier_utils.ensure_token(wbtc.clone());
+        classifier_utils.ensure_token(weth.clone());
+        classifier_utils.ensure_protocol(
+            Protocol::CurveTriCryptoPool,
+            pool,
+            usdt.address,
+            Some(wbtc.address),
+            Some(weth.address),
+            None,
+            None,
+            None,
+        );
+
+        let sold = U256::from(2_000_000_000u64);
+        let bought = U256::from(1_000_000_000_000_000_000u128);
+        let input = exchange_0Call {
+            i:      U256::ZERO,
+            j:      U256::from(2),
+            dx:     sold,
+            min_dy: U256::ZERO,
+        }
+        .abi_encode();
+        let output = exchange_0Call::abi_encode_returns(&(bought,));
+        let exchange = TokenExchange {
+            buyer:              trader,
+            sold_id:            U256::ZERO,
+            tokens_sold:        sold,
+            bought_id:          U256::from(2),
+            tokens_bought:      bought,
+            fee:                U256::ZERO,
+            packed_price_scale: U256::ZERO,
+        };
+        let logs = vec![Log { address: pool, data: exchange.encode_log_data() }];
+
+        let action = classifier_utils.classify_call(trader, pool, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Swap(NormalizedSwap {
+                protocol: Protocol::CurveTriCryptoPool,
+                trace_index: 0,
+                from: trader,
+                recipient: trader,
+                pool,
+                token_in: usdt,
+                amount_in: sold.to_scaled_rational(6),
+                token_out: weth,
+                amount_out: bought.to_scaled_rational(18),
+                msg_value: U256::ZERO,
+            }))
+        );
+    }
+}
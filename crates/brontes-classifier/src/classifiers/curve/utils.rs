@@ -0,0 +1,137 @@
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_types::{
+    db::{address_to_protocol_info::ProtocolInfo, token_info::TokenInfoWithAddress},
+    normalized_actions::NormalizedSwap,
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+use malachite::Rational;
+
+/// Returns the coin at `index`, for pools that were discovered with their
+/// coins in the same order as the pool indexes them
+pub(crate) fn pool_coin(details: &ProtocolInfo, index: usize) -> eyre::Result<Address> {
+    details
+        .clone()
+        .into_iter()
+        .nth(index)
+        .ok_or_else(|| eyre::eyre!("Expected coin {index} for {}, found None", details.protocol))
+}
+
+/// Stableswap-ng metapools index their own coin first, followed by the coins of
+/// the base pool. Discovery stores the base pool coins first and the metapool
+/// coin last.
+pub(crate) fn underlying_coin(details: &ProtocolInfo, index: usize) -> eyre::Result<Address> {
+    let coins = details.clone().into_iter().collect::<Vec<_>>();
+    let Some((meta_coin, base_coins)) = coins.split_last() else {
+        return Err(eyre::eyre!("Expected coins for {}, found None", details.protocol))
+    };
+
+    match index {
+        0 => Ok(*meta_coin),
+        i => base_coins
+            .get(i - 1)
+            .copied()
+            .ok_or_else(|| eyre::eyre!("Expected underlying coin {i}, found None")),
+    }
+}
+
+/// Stableswap-ng metapools hold their own coin and the lp token of the base
+/// pool
+pub(crate) fn meta_pool_coin(details: &ProtocolInfo, index: usize) -> eyre::Result<Address> {
+    match index {
+        0 => underlying_coin(details, 0),
+        1 => details
+            .curve_lp_token
+            .ok_or(eyre::eyre!("Expected curve_lp_token for coin 1, found None")),
+        i => Err(eyre::eyre!("Expected coin index 0 or 1 for metapool, found {i}")),
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn curve_swap<DB: LibmdbxReader>(
+    info: &CallInfo,
+    details: &ProtocolInfo,
+    token_in: Address,
+    amount_in: U256,
+    token_out: Address,
+    amount_out: U256,
+    recipient: Address,
+    db: &DB,
+) -> eyre::Result<NormalizedSwap> {
+    let token_in = db.try_fetch_token_info(token_in)?;
+    let token_out = db.try_fetch_token_info(token_out)?;
+
+    Ok(NormalizedSwap {
+        protocol: details.protocol,
+        pool: info.target_address,
+        trace_index: info.trace_idx,
+        from: info.msg_sender,
+        recipient,
+        amount_in: amount_in.to_scaled_rational(token_in.decimals),
+        amount_out: amount_out.to_scaled_rational(token_out.decimals),
+        token_in,
+        token_out,
+        msg_value: info.msg_value,
+    })
+}
+
+/// Pairs the coins of the pool with the amounts of a liquidity event, skipping
+/// the coins that weren't added or removed
+pub(crate) fn liquidity_amounts<DB: LibmdbxReader>(
+    details: &ProtocolInfo,
+    amounts: &[U256],
+    db: &DB,
+) -> eyre::Result<(Vec<TokenInfoWithAddress>, Vec<Rational>)> {
+    let mut tokens = Vec::new();
+    let mut token_amounts = Vec::new();
+
+    for (coin, amount) in details.clone().into_iter().zip(amounts) {
+        if amount.is_zero() {
+            continue
+        }
+
+        let token = db.try_fetch_token_info(coin)?;
+        token_amounts.push(amount.to_scaled_rational(token.decimals));
+        tokens.push(token);
+    }
+
+    Ok((tokens, token_amounts))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+    use brontes_types::Protocol;
+
+    use super::*;
+
+    #[test]
+    fn test_ng_metapool_coin_order() {
+        let dai = Address::new(hex!("6b175474e89094c44da98b954eedeac495271d0f"));
+        let usdc = Address::new(hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48"));
+        let usdt = Address::new(hex!("dac17f958d2ee523a2206206994597c13d831ec7"));
+        let three_crv = Address::new(hex!("6c3f90f043a72fa612cbac8115ee7e52bde6e490"));
+        let meta_coin = Address::new(hex!("99d8a9c45b2eca8864373a26d1459e3dff1e17f3"));
+
+        // discovered with the 3pool coins first and the metapool coin last
+        let details = ProtocolInfo {
+            protocol: Protocol::CurveStableSwapNgMetaPool,
+            token0: dai,
+            token1: usdc,
+            token2: Some(usdt),
+            token3: Some(meta_coin),
+            curve_lp_token: Some(three_crv),
+            ..Default::default()
+        };
+
+        assert_eq!(underlying_coin(&details, 0).unwrap(), meta_coin);
+        assert_eq!(underlying_coin(&details, 1).unwrap(), dai);
+        assert_eq!(underlying_coin(&details, 3).unwrap(), usdt);
+        assert!(underlying_coin(&details, 4).is_err());
+
+        assert_eq!(meta_pool_coin(&details, 0).unwrap(), meta_coin);
+        assert_eq!(meta_pool_coin(&details, 1).unwrap(), three_crv);
+        assert!(meta_pool_coin(&details, 2).is_err());
+    }
+}
@@ -16,6 +16,7 @@ action_impl!(
             protocol: Protocol::Dodo,
             pool_address: logs.dvm,
             tokens: vec![logs.baseToken, logs.quoteToken],
+            pool_id: None,
         })
     }
 );
@@ -34,6 +35,7 @@ action_impl!(
             protocol: Protocol::Dodo,
             pool_address: logs.DSP,
             tokens: vec![logs.baseToken, logs.quoteToken],
+            pool_id: None,
         })
     }
 );
@@ -55,6 +57,7 @@ action_impl!(
             protocol: Protocol::Dodo,
             pool_address: logs.dpp,
             tokens: vec![base_token, quote_token],
+            pool_id: None,
         })
     }
 );
@@ -81,6 +84,7 @@ mod tests {
                 Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
                 Address::new(hex!("9aFa9999e45484Adf5d8EED8D9Dfe0693BACd838")),
             ],
+            pool_id:      None,
         });
 
         classifier_utils
@@ -108,6 +112,7 @@ mod tests {
                 Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
                 Address::new(hex!("99ea4dB9EE77ACD40B119BD1dC4E33e1C070b80d")),
             ],
+            pool_id:      None,
         });
 
         classifier_utils
@@ -135,6 +140,7 @@ mod tests {
                 Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
                 Address::new(hex!("9d71CE49ab8A0E6D2a1e7BFB89374C9392FD6804")),
             ],
+            pool_id:      None,
         });
 
         classifier_utils
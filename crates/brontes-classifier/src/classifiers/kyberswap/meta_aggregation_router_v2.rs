@@ -0,0 +1,103 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::KyberSwapMetaAggregator,
+    crate::KyberSwapMetaAggregationRouterV2::swapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::KyberSwapMetaAggregator,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: call_data.execution.desc.dstReceiver,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::KyberSwapMetaAggregator,
+    crate::KyberSwapMetaAggregationRouterV2::swapSimpleModeCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapSimpleModeCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::KyberSwapMetaAggregator,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: call_data.desc.dstReceiver,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Bytes, U256};
+    use alloy_sol_types::{SolCall, SolValue};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::normalized_actions::Action;
+
+    use super::*;
+    use crate::KyberSwapMetaAggregationRouterV2::swapCall;
+
+    #[brontes_macros::test]
+    async fn test_kyberswap_swap() {
+        let utils = ClassifierTestUtils::new().await;
+        let router = Address::repeat_byte(0x6d);
+        let (trader, receiver) = (Address::repeat_byte(0x6e), Address::repeat_byte(0x6f));
+        utils.ensure_protocol(
+            Protocol::KyberSwapMetaAggregator,
+            router,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        // the swap structs are declared in a library of the router abi, the
+        // calldata is encoded from tuples of their fields
+        let desc = (
+            Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            Vec::<Address>::new(),
+            Vec::<U256>::new(),
+            Vec::<Address>::new(),
+            Vec::<U256>::new(),
+            receiver,
+            U256::from(2_500_000_000u64),
+            U256::from(10u64.pow(18)),
+            U256::ZERO,
+            Bytes::new(),
+        );
+        let execution =
+            (Address::repeat_byte(0x70), Address::ZERO, Bytes::new(), desc, Bytes::new());
+        let input = [swapCall::SELECTOR.as_slice(), &(execution,).abi_encode_params()].concat();
+
+        let action = utils.classify_call(trader, router, input, vec![], vec![]);
+
+        assert_eq!(
+            action,
+            Some(Action::Aggregator(NormalizedAggregator {
+                protocol:      Protocol::KyberSwapMetaAggregator,
+                trace_index:   0,
+                from:          trader,
+                to:            router,
+                recipient:     receiver,
+                child_actions: vec![],
+                msg_value:     U256::ZERO,
+            }))
+        );
+    }
+}
@@ -0,0 +1,31 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedWrap,
+    structured_trace::CallInfo, ToScaledRational,
+};
+
+// stETH rebases, the balance minted for a submit is the eth deposited while
+// the returned value is the amount of shares
+action_impl!(
+    Protocol::LidoStEth,
+    crate::LidoStEth::submitCall,
+    Wrap,
+    [],
+    |info: CallInfo, db_tx: &DB| {
+        let steth = db_tx.try_fetch_token_info(info.target_address)?;
+        let eth = TokenInfoWithAddress::native_eth();
+
+        Ok(NormalizedWrap {
+            protocol:    Protocol::LidoStEth,
+            trace_index: info.trace_idx,
+            from:        info.msg_sender,
+            recipient:   info.msg_sender,
+            amount_in:   info.msg_value.to_scaled_rational(eth.decimals),
+            amount_out:  info.msg_value.to_scaled_rational(steth.decimals),
+            underlying:  eth,
+            wrapped:     steth,
+            msg_value:   info.msg_value,
+        })
+    }
+);
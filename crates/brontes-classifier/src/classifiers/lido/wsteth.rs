@@ -0,0 +1,66 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    constants::STETH_ADDRESS,
+    normalized_actions::{NormalizedUnwrap, NormalizedWrap},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+action_impl!(
+    Protocol::LidoWstEth,
+    crate::LidoWstEth::wrapCall,
+    Wrap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: wrapCall,
+    return_data: wrapReturn,
+    db_tx: &DB| {
+        let steth = db_tx.try_fetch_token_info(STETH_ADDRESS)?;
+        let wsteth = db_tx.try_fetch_token_info(info.target_address)?;
+
+        Ok(NormalizedWrap {
+            protocol: Protocol::LidoWstEth,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            amount_in: call_data._stETHAmount.to_scaled_rational(steth.decimals),
+            amount_out: return_data._0.to_scaled_rational(wsteth.decimals),
+            underlying: steth,
+            wrapped: wsteth,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::LidoWstEth,
+    crate::LidoWstEth::unwrapCall,
+    Unwrap,
+    [],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: unwrapCall,
+    return_data: unwrapReturn,
+    db_tx: &DB| {
+        let steth = db_tx.try_fetch_token_info(STETH_ADDRESS)?;
+        let wsteth = db_tx.try_fetch_token_info(info.target_address)?;
+
+        Ok(NormalizedUnwrap {
+            protocol: Protocol::LidoWstEth,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            amount_in: call_data._wstETHAmount.to_scaled_rational(wsteth.decimals),
+            amount_out: return_data._0.to_scaled_rational(steth.decimals),
+            wrapped: wsteth,
+            underlying: steth,
+            msg_value: info.msg_value,
+        })
+    }
+);
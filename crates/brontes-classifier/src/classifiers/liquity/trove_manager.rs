@@ -0,0 +1,234 @@
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{Action, LiquidationKind, NormalizedAggregator, NormalizedLiquidation},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use crate::LiquityTroveManager;
+
+action_impl!(
+    Protocol::LiquityV1,
+    crate::LiquityTroveManager::liquidateCall,
+    Action,
+    [..TroveLiquidated*],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: LiquityV1LiquidateCallLogs,
+    db_tx: &DB| {
+        trove_liquidations(info, log_data.trove_liquidated_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::LiquityV1,
+    crate::LiquityTroveManager::batchLiquidateTrovesCall,
+    Action,
+    [..TroveLiquidated*],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: LiquityV1BatchLiquidateTrovesCallLogs,
+    db_tx: &DB| {
+        trove_liquidations(info, log_data.trove_liquidated_field?, db_tx)
+    }
+);
+
+action_impl!(
+    Protocol::LiquityV1,
+    crate::LiquityTroveManager::liquidateTrovesCall,
+    Action,
+    [..TroveLiquidated*],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: LiquityV1LiquidateTrovesCallLogs,
+    db_tx: &DB| {
+        trove_liquidations(info, log_data.trove_liquidated_field?, db_tx)
+    }
+);
+
+/// The debt of liquidated troves is absorbed by the stability pool or
+/// redistributed to the other troves, together with their collateral. The
+/// liquidator is only paid the gas compensation, which it receives through
+/// regular transfers.
+///
+/// A call can liquidate many troves, each trove gives a liquidation and these
+/// are grouped under the call when there are several.
+fn trove_liquidations<DB: LibmdbxReader>(
+    info: CallInfo,
+    troves: Vec<LiquityTroveManager::TroveLiquidated>,
+    db: &DB,
+) -> eyre::Result<Action> {
+    let lusd = db.try_fetch_token_info(db.get_protocol_details(info.target_address)?.token0)?;
+    let eth = TokenInfoWithAddress::native_eth();
+
+    let mut liquidations = troves
+        .into_iter()
+        .map(|trove| {
+            Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::LiquityV1,
+                trace_index:           info.trace_idx,
+                pool:                  info.target_address,
+                liquidator:            info.from_address,
+                debtor:                trove._borrower,
+                covered_debt:          trove._debt.to_scaled_rational(lusd.decimals),
+                liquidated_collateral: trove._coll.to_scaled_rational(eth.decimals),
+                collateral_asset:      eth.clone(),
+                debt_asset:            lusd.clone(),
+                msg_value:             info.msg_value,
+                kind:                  LiquidationKind::Absorbed,
+                auction_price:         None,
+                oracle_price:          None,
+            })
+        })
+        .collect::<Vec<_>>();
+
+    match liquidations.len() {
+        0 => Err(eyre::eyre!("liquidation call didn't liquidate any trove")),
+        1 => Ok(liquidations.remove(0)),
+        _ => Ok(Action::Aggregator(NormalizedAggregator {
+            protocol:      Protocol::LiquityV1,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.from_address,
+            child_actions: liquidations,
+            msg_value:     info.msg_value,
+        })),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{constants::LUSD_ADDRESS, db::token_info::TokenInfo};
+
+    use super::*;
+    use crate::LiquityTroveManager::{batchLiquidateTrovesCall, liquidateCall, TroveLiquidated};
+
+    struct TroveManagerSetup {
+        utils:         ClassifierTestUtils,
+        trove_manager: Address,
+        lusd:          TokenInfoWithAddress,
+    }
+
+    async fn setup() -> TroveManagerSetup {
+        let utils = ClassifierTestUtils::new().await;
+        let trove_manager = Address::repeat_byte(0x65);
+
+        let lusd = TokenInfoWithAddress {
+            address: LUSD_ADDRESS,
+            inner:   TokenInfo { decimals: 18, symbol: "LUSD".to_string() },
+        };
+        utils.ensure_token(lusd.clone());
+        utils.ensure_protocol(
+            Protocol::LiquityV1,
+            trove_manager,
+            lusd.address,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        TroveManagerSetup { utils, trove_manager, lusd }
+    }
+
+    fn trove_liquidated(trove_manager: Address, borrower: Address, debt: U256, coll: U256) -> Log {
+        Log {
+            address: trove_manager,
+            data:    TroveLiquidated {
+                _borrower:  borrower,
+                _debt:      debt,
+                _coll:      coll,
+                _operation: 0,
+            }
+            .encode_log_data(),
+        }
+    }
+
+    fn liquidation(
+        trove_manager: Address,
+        liquidator: Address,
+        borrower: Address,
+        lusd: &TokenInfoWithAddress,
+        debt: U256,
+        coll: U256,
+    ) -> Action {
+        Action::Liquidation(NormalizedLiquidation {
+            protocol: Protocol::LiquityV1,
+            trace_index: 0,
+            pool: trove_manager,
+            liquidator,
+            debtor: borrower,
+            covered_debt: debt.to_scaled_rational(18),
+            liquidated_collateral: coll.to_scaled_rational(18),
+            collateral_asset: TokenInfoWithAddress::native_eth(),
+            debt_asset: lusd.clone(),
+            msg_value: U256::ZERO,
+            kind: LiquidationKind::Absorbed,
+            auction_price: None,
+            oracle_price: None,
+        })
+    }
+
+    #[brontes_macros::test]
+    async fn test_liquity_liquidate() {
+        let TroveManagerSetup { utils, trove_manager, lusd } = setup().await;
+        let (liquidator, borrower) = (Address::repeat_byte(0x66), Address::repeat_byte(0x67));
+
+        let (debt, coll) =
+            (U256::from(20_000u64) * U256::from(10u64.pow(18)), U256::from(10u64.pow(19)));
+        let input = liquidateCall { _borrower: borrower }.abi_encode();
+        let logs = vec![trove_liquidated(trove_manager, borrower, debt, coll)];
+
+        let action = utils.classify_call(liquidator, trove_manager, input, vec![], logs);
+
+        assert_eq!(
+            action,
+            Some(liquidation(trove_manager, liquidator, borrower, &lusd, debt, coll))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_liquity_batch_liquidate() {
+        let TroveManagerSetup { utils, trove_manager, lusd } = setup().await;
+        let liquidator = Address::repeat_byte(0x66);
+        let (alice, bob) = (Address::repeat_byte(0x67), Address::repeat_byte(0x68));
+
+        let (alice_debt, alice_coll) = (U256::from(2_000u64), U256::from(1u64));
+        let (bob_debt, bob_coll) = (U256::from(5_000u64), U256::from(3u64));
+        let input = batchLiquidateTrovesCall { _troveArray: vec![alice, bob] }.abi_encode();
+        let logs = vec![
+            trove_liquidated(trove_manager, alice, alice_debt, alice_coll),
+            trove_liquidated(trove_manager, bob, bob_debt, bob_coll),
+        ];
+
+        let action = utils.classify_call(liquidator, trove_manager, input, vec![], logs);
+
+        // each trove liquidated by the call gives its own liquidation
+        assert_eq!(
+            action,
+            Some(Action::Aggregator(NormalizedAggregator {
+                protocol:      Protocol::LiquityV1,
+                trace_index:   0,
+                from:          liquidator,
+                to:            trove_manager,
+                recipient:     liquidator,
+                child_actions: vec![
+                    liquidation(trove_manager, liquidator, alice, &lusd, alice_debt, alice_coll),
+                    liquidation(trove_manager, liquidator, bob, &lusd, bob_debt, bob_coll),
+                ],
+                msg_value:     U256::ZERO,
+            }))
+        );
+    }
+}
@@ -0,0 +1,61 @@
+use alloy_primitives::{hex, Address, FixedBytes};
+use brontes_macros::discovery_impl;
+use brontes_pricing::make_call_request;
+use brontes_types::{
+    constants::DAI_ADDRESS, normalized_actions::pool::NormalizedNewPool, Protocol,
+};
+
+/// Lists the gem of every collateral type of the vat
+const ILK_REGISTRY: Address = Address::new(hex!("5a464c28d19848f44199d003bef5ecc87d090f87"));
+
+alloy_sol_types::sol!(
+    function gem(bytes32 ilk) external view returns (address);
+);
+
+// Clippers aren't deployed by a factory, a clipper is live once the dog is
+// filed with it as the clip of its collateral type.
+discovery_impl!(
+    MakerDogDiscovery,
+    crate::MakerDog::fileCall,
+    0x135954d155898d42c90d2a57824c690e0c7bef1b,
+    |_, trace_index: u64, call_data: fileCall, tracer| async move {
+        if !is_clip(&call_data.what) {
+            return vec![]
+        }
+
+        let Ok(gem) =
+            make_call_request(gemCall { ilk: call_data.ilk }, &tracer, ILK_REGISTRY, None)
+                .await
+                .map(|res| res._0)
+        else {
+            return vec![]
+        };
+
+        vec![NormalizedNewPool {
+            trace_index,
+            protocol: Protocol::MakerClipper,
+            pool_address: call_data.clip,
+            tokens: vec![DAI_ADDRESS, gem],
+            pool_id: None,
+        }]
+    }
+);
+
+/// The parameter names filed on the dog are right padded strings
+fn is_clip(what: &FixedBytes<32>) -> bool {
+    what.starts_with(b"clip") && what[4..].iter().all(|byte| *byte == 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::FixedBytes;
+
+    use super::is_clip;
+
+    #[test]
+    fn test_is_clip() {
+        assert!(is_clip(&FixedBytes::right_padding_from(b"clip")));
+        assert!(!is_clip(&FixedBytes::right_padding_from(b"chop")));
+        assert!(!is_clip(&FixedBytes::right_padding_from(b"clipper")));
+    }
+}
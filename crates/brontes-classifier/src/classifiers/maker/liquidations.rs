@@ -0,0 +1,319 @@
+use alloy_primitives::{hex, Address};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_types::{
+    constants::DAI_ADDRESS,
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{LiquidationKind, NormalizedLiquidation, NormalizedTransfer},
+    structured_trace::CallInfo,
+    Protocol, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+
+/// Decimals of the fixed point numbers used by the vat
+const WAD: u8 = 18;
+const RAY: u8 = 27;
+const RAD: u8 = 45;
+
+/// The vow backs the dai sucked from the vat, such as keeper incentives
+const MAKER_VOW: Address = Address::new(hex!("a950524441892a31ebddf91d3ceefa04bf454466"));
+
+// Barking a vault starts a collateral auction on the clipper of its ilk. The
+// whole debt and collateral of the vault are moved into the auction, the oracle
+// price the auction starts from is read when the clipper is kicked.
+action_impl!(
+    Protocol::MakerDog,
+    crate::MakerDog::barkCall,
+    Liquidation,
+    [..Bark],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: barkCall,
+    log_data: MakerDogBarkCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.bark_field?;
+        let (dai, collateral_asset) = clipper_tokens(logs.clip, db_tx)?;
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MakerDog,
+            trace_index: info.trace_idx,
+            pool: logs.clip,
+            liquidator: call_data.kpr,
+            debtor: call_data.urn,
+            collateral_asset,
+            debt_asset: dai,
+            covered_debt: logs.due.to_scaled_rational(RAD),
+            liquidated_collateral: logs.ink.to_scaled_rational(WAD),
+            msg_value: info.msg_value,
+            kind: LiquidationKind::Absorbed,
+            auction_price: None,
+            oracle_price: None,
+        })
+    }
+);
+
+// Taking from an auction buys collateral at the current auction price. The
+// collateral bought isn't logged, it is the dai owed divided by the price.
+action_impl!(
+    Protocol::MakerClipper,
+    crate::MakerClipper::takeCall,
+    Liquidation,
+    [..Take],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: takeCall,
+    log_data: MakerClipperTakeCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.take_field?;
+        let (dai, collateral_asset) = clipper_tokens(info.target_address, db_tx)?;
+
+        let covered_debt = logs.owe.to_scaled_rational(RAD);
+        let liquidated_collateral = &covered_debt / logs.price.to_scaled_rational(RAY);
+
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MakerClipper,
+            trace_index: info.trace_idx,
+            pool: info.target_address,
+            liquidator: call_data.who,
+            debtor: logs.usr,
+            collateral_asset,
+            debt_asset: dai,
+            covered_debt,
+            liquidated_collateral,
+            msg_value: info.msg_value,
+            kind: LiquidationKind::Repaid,
+            auction_price: Some(logs.price.to_scaled_rational(RAY)),
+            oracle_price: None,
+        })
+    }
+);
+
+// Kicking an auction pays the keeper that barked the vault a flat tip plus a
+// share of the debt, as dai sucked from the vow into the vat.
+action_impl!(
+    Protocol::MakerClipper,
+    crate::MakerClipper::kickCall,
+    Transfer,
+    [..Kick],
+    logs: true,
+    |
+    info: CallInfo,
+    log_data: MakerClipperKickCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.kick_field?;
+
+        Ok(NormalizedTransfer {
+            trace_index: info.trace_idx,
+            from: MAKER_VOW,
+            to: logs.kpr,
+            token: db_tx.try_fetch_token_info(DAI_ADDRESS)?,
+            amount: logs.coin.to_scaled_rational(RAD),
+            fee: Rational::ZERO,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// Clippers are configured with dai as their first token and the collateral of
+/// their ilk as the second
+fn clipper_tokens<DB: LibmdbxReader>(
+    clipper: Address,
+    db: &DB,
+) -> eyre::Result<(TokenInfoWithAddress, TokenInfoWithAddress)> {
+    let details = db.get_protocol_details(clipper)?;
+
+    Ok((db.try_fetch_token_info(details.token0)?, db.try_fetch_token_info(details.token1)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{FixedBytes, Log, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{db::token_info::TokenInfo, normalized_actions::Action};
+
+    use super::*;
+    use crate::{
+        MakerClipper::{kickCall, takeCall, Kick, Take},
+        MakerDog::{barkCall, Bark},
+    };
+
+    struct MakerSetup {
+        utils:   ClassifierTestUtils,
+        dog:     Address,
+        clipper: Address,
+        dai:     TokenInfoWithAddress,
+        weth:    TokenInfoWithAddress,
+    }
+
+    async fn setup() -> MakerSetup {
+        let utils = ClassifierTestUtils::new().await;
+        let (dog, clipper) = (Address::repeat_byte(0x51), Address::repeat_byte(0x52));
+
+        let dai = TokenInfoWithAddress {
+            address: DAI_ADDRESS,
+            inner:   TokenInfo { decimals: 18, symbol: "DAI".to_string() },
+        };
+        let weth = TokenInfoWithAddress {
+            address: Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            inner:   TokenInfo { decimals: 18, symbol: "WETH".to_string() },
+        };
+        utils.ensure_token(dai.clone());
+        utils.ensure_token(weth.clone());
+        utils.ensure_protocol(Protocol::MakerDog, dog, Address::ZERO, None, None, None, None, None);
+        utils.ensure_protocol(
+            Protocol::MakerClipper,
+            clipper,
+            dai.address,
+            Some(weth.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        MakerSetup { utils, dog, clipper, dai, weth }
+    }
+
+    fn scaled(amount: u64, decimals: u8) -> U256 {
+        U256::from(amount) * U256::from(10).pow(U256::from(decimals))
+    }
+
+    #[brontes_macros::test]
+    async fn test_maker_bark() {
+        let MakerSetup { utils, dog, clipper, dai, weth } = setup().await;
+        let (keeper, urn) = (Address::repeat_byte(0x53), Address::repeat_byte(0x54));
+        let ilk = FixedBytes::repeat_byte(0x55);
+
+        let input = barkCall { ilk, urn, kpr: keeper }.abi_encode();
+        let output = barkCall::abi_encode_returns(&(U256::from(7),));
+        let logs = vec![Log {
+            address: dog,
+            data:    Bark {
+                ilk,
+                urn,
+                ink: scaled(10, WAD),
+                art: scaled(20_000, WAD),
+                due: scaled(21_000, RAD),
+                clip: clipper,
+                id: U256::from(7),
+            }
+            .encode_log_data(),
+        }];
+
+        let action = utils.classify_call(keeper, dog, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::MakerDog,
+                trace_index:           0,
+                pool:                  clipper,
+                liquidator:            keeper,
+                debtor:                urn,
+                collateral_asset:      weth,
+                debt_asset:            dai,
+                covered_debt:          Rational::from(21_000),
+                liquidated_collateral: Rational::from(10),
+                msg_value:             U256::ZERO,
+                kind:                  LiquidationKind::Absorbed,
+                auction_price:         None,
+                oracle_price:          None,
+            }))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_maker_take() {
+        let MakerSetup { utils, clipper, dai, weth, .. } = setup().await;
+        let (taker, urn) = (Address::repeat_byte(0x56), Address::repeat_byte(0x54));
+
+        let input = takeCall {
+            id:   U256::from(7),
+            amt:  scaled(4, WAD),
+            max:  scaled(2_600, RAY),
+            who:  taker,
+            data: Default::default(),
+        }
+        .abi_encode();
+        let logs = vec![Log {
+            address: clipper,
+            data:    Take {
+                id:    U256::from(7),
+                max:   scaled(2_600, RAY),
+                price: scaled(2_500, RAY),
+                owe:   scaled(10_000, RAD),
+                tab:   scaled(11_000, RAD),
+                lot:   scaled(6, WAD),
+                usr:   urn,
+            }
+            .encode_log_data(),
+        }];
+
+        let action = utils.classify_call(taker, clipper, input, vec![], logs);
+
+        // 10,000 dai owed at an auction price of 2,500 buys 4 weth
+        assert_eq!(
+            action,
+            Some(Action::Liquidation(NormalizedLiquidation {
+                protocol:              Protocol::MakerClipper,
+                trace_index:           0,
+                pool:                  clipper,
+                liquidator:            taker,
+                debtor:                urn,
+                collateral_asset:      weth,
+                debt_asset:            dai,
+                covered_debt:          Rational::from(10_000),
+                liquidated_collateral: Rational::from(4),
+                msg_value:             U256::ZERO,
+                kind:                  LiquidationKind::Repaid,
+                auction_price:         Some(Rational::from(2_500)),
+                oracle_price:          None,
+            }))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_maker_kick_incentive() {
+        let MakerSetup { utils, dog, clipper, dai, .. } = setup().await;
+        let (keeper, urn) = (Address::repeat_byte(0x53), Address::repeat_byte(0x54));
+
+        let input =
+            kickCall { tab: scaled(21_000, RAD), lot: scaled(10, WAD), usr: urn, kpr: keeper }
+                .abi_encode();
+        let output = kickCall::abi_encode_returns(&(U256::from(7),));
+        let logs = vec![Log {
+            address: clipper,
+            data:    Kick {
+                id:   U256::from(7),
+                top:  scaled(2_600, RAY),
+                tab:  scaled(21_000, RAD),
+                lot:  scaled(10, WAD),
+                usr:  urn,
+                kpr:  keeper,
+                coin: scaled(300, RAD),
+            }
+            .encode_log_data(),
+        }];
+
+        // the dog kicks the clipper when a vault is barked
+        let action = utils.classify_call(dog, clipper, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Transfer(NormalizedTransfer {
+                trace_index: 0,
+                from:        MAKER_VOW,
+                to:          keeper,
+                token:       dai,
+                amount:      Rational::from(300),
+                fee:         Rational::ZERO,
+                msg_value:   U256::ZERO,
+            }))
+        );
+    }
+}
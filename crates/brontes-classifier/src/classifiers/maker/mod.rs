@@ -6,3 +6,11 @@ pub use psm::*;
 mod dss_flash;
 
 pub use dss_flash::*;
+
+mod liquidations;
+
+pub use liquidations::*;
+
+mod discovery;
+
+pub use discovery::*;
@@ -1,6 +1,8 @@
 use brontes_macros::{action_dispatch, discovery_dispatch};
 use futures::StreamExt;
 pub mod erc20;
+pub mod nft;
+mod pool_id;
 
 pub mod uniswap;
 pub use uniswap::*;
@@ -41,6 +43,39 @@ pub use clipper::*;
 pub mod dodo;
 pub use dodo::*;
 
+pub mod morpho;
+pub use morpho::*;
+
+pub mod liquity;
+pub use liquity::*;
+
+pub mod paraswap;
+pub use paraswap::*;
+
+pub mod kyberswap;
+pub use kyberswap::*;
+
+pub mod odos;
+pub use odos::*;
+
+pub mod seaport;
+pub use seaport::*;
+
+pub mod blur;
+pub use blur::*;
+
+pub mod sudoswap;
+pub use sudoswap::*;
+
+pub mod weth;
+pub use weth::*;
+
+pub mod lido;
+pub use lido::*;
+
+pub mod rocket_pool;
+pub use rocket_pool::*;
+
 discovery_dispatch!(
     DiscoveryClassifier,
     SushiSwapV2Discovery,
@@ -62,10 +97,19 @@ discovery_dispatch!(
     CurvecrvUSDMetaDiscovery1,
     CurveCryptoSwapDiscovery,
     CurveTriCryptoDiscovery,
+    CurveStableSwapNgMetaDiscovery,
+    CurveStableSwapNgPlainDiscovery,
     BalancerV1CoreDiscovery,
-    BalancerV1SmartPoolDiscovery
+    BalancerV1SmartPoolDiscovery,
+    SudoswapV2ETHDiscovery,
+    SudoswapV2ERC20Discovery,
+    ZeroXSettlerDiscovery
 );
 
+// contracts that are registered on an existing contract instead of being
+// deployed by a factory, dispatched on the registering call
+discovery_dispatch!(RegistrationDiscoveryClassifier, MakerDogDiscovery, BalancerV3VaultDiscovery);
+
 action_dispatch!(
     ProtocolClassifier,
     UniswapV2SwapCall,
@@ -81,6 +125,11 @@ action_dispatch!(
     UniswapV3MintCall,
     UniswapV3BurnCall,
     UniswapV3CollectCall,
+    UniswapV4InitializeCall,
+    UniswapV4SwapCall,
+    UniswapV4ModifyLiquidityCall,
+    UniswapV4DonateCall,
+    UniswapV4UnlockCall,
     SushiSwapV3SwapCall,
     SushiSwapV3MintCall,
     SushiSwapV3BurnCall,
@@ -142,14 +191,64 @@ action_dispatch!(
     CurveV2PlainPoolImplRemove_liquidity_imbalance_1Call,
     CurveV2PlainPoolImplRemove_liquidity_one_coin_0Call,
     CurveV2PlainPoolImplRemove_liquidity_one_coin_1Call,
+    CurveStableSwapNgPlainPoolExchange_0Call,
+    CurveStableSwapNgPlainPoolExchange_1Call,
+    CurveStableSwapNgPlainPoolExchange_received_0Call,
+    CurveStableSwapNgPlainPoolExchange_received_1Call,
+    CurveStableSwapNgMetaPoolExchange_0Call,
+    CurveStableSwapNgMetaPoolExchange_1Call,
+    CurveStableSwapNgMetaPoolExchange_received_0Call,
+    CurveStableSwapNgMetaPoolExchange_received_1Call,
+    CurveStableSwapNgMetaPoolExchange_underlying_0Call,
+    CurveStableSwapNgMetaPoolExchange_underlying_1Call,
+    CurveTriCryptoPoolExchange_0Call,
+    CurveTriCryptoPoolExchange_1Call,
+    CurveTriCryptoPoolExchange_2Call,
+    CurveStableSwapNgPlainPoolAdd_liquidity_0Call,
+    CurveStableSwapNgPlainPoolAdd_liquidity_1Call,
+    CurveTriCryptoPoolAdd_liquidity_0Call,
+    CurveTriCryptoPoolAdd_liquidity_1Call,
+    CurveTriCryptoPoolAdd_liquidity_2Call,
+    CurveStableSwapNgPlainPoolRemove_liquidity_0Call,
+    CurveStableSwapNgPlainPoolRemove_liquidity_1Call,
+    CurveStableSwapNgPlainPoolRemove_liquidity_2Call,
+    CurveStableSwapNgPlainPoolRemove_liquidity_one_coin_0Call,
+    CurveStableSwapNgPlainPoolRemove_liquidity_one_coin_1Call,
+    CurveTriCryptoPoolRemove_liquidity_0Call,
+    CurveTriCryptoPoolRemove_liquidity_1Call,
+    CurveTriCryptoPoolRemove_liquidity_2Call,
+    CurveTriCryptoPoolRemove_liquidity_one_coin_0Call,
+    CurveTriCryptoPoolRemove_liquidity_one_coin_1Call,
+    CurveTriCryptoPoolRemove_liquidity_one_coin_2Call,
     MakerPSMBuyGemCall,
     MakerPSMSellGemCall,
     MakerDssFlashFlashLoanCall,
+    MakerDogBarkCall,
+    MakerClipperTakeCall,
+    MakerClipperKickCall,
+    MorphoBlueCreateMarketCall,
+    MorphoBlueLiquidateCall,
+    MorphoBlueFlashLoanCall,
+    LiquityV1LiquidateCall,
+    LiquityV1BatchLiquidateTrovesCall,
+    LiquityV1LiquidateTrovesCall,
     AaveV2LiquidationCallCall,
     AaveV3LiquidationCallCall,
     AaveV2FlashLoanCall,
     AaveV3FlashLoanCall,
     AaveV3FlashLoanSimpleCall,
+    AaveV2BorrowCall,
+    AaveV2RepayCall,
+    AaveV2DepositCall,
+    AaveV2WithdrawCall,
+    AaveV3BorrowCall,
+    AaveV3RepayCall,
+    AaveV3RepayWithPermitCall,
+    AaveV3RepayWithATokensCall,
+    AaveV3SupplyCall,
+    AaveV3SupplyWithPermitCall,
+    AaveV3DepositCall,
+    AaveV3WithdrawCall,
     BalancerV1SwapExactAmountInCall,
     BalancerV1SwapExactAmountOutCall,
     BalancerV1BindCall,
@@ -159,9 +258,22 @@ action_dispatch!(
     BalancerV2JoinPoolCall,
     BalancerV2ExitPoolCall,
     BalancerV2RegisterTokensCall,
+    BalancerV3SwapCall,
+    BalancerV3AddLiquidityCall,
+    BalancerV3RemoveLiquidityCall,
+    BalancerV3UnlockCall,
     CompoundV2LiquidateBorrowCall,
+    CompoundV2BorrowCall,
+    CompoundV2RepayBorrowCall,
+    CompoundV2RepayBorrowBehalfCall,
+    CompoundV2MintCall,
+    CompoundV2RedeemCall,
+    CompoundV2RedeemUnderlyingCall,
     CompoundV2Initialize_0Call,
     CompoundV2Initialize_1Call,
+    CompoundV3AbsorbCall,
+    CompoundV3BuyCollateralCall,
+    CompoundV3SetConfigurationCall,
     OneInchV5SwapCall,
     OneInchV5ClipperSwapCall,
     OneInchV5ClipperSwapToCall,
@@ -171,6 +283,18 @@ action_dispatch!(
     OneInchV5UniswapV3SwapToCall,
     OneInchV5UniswapV3SwapToWithPermitCall,
     OneInchFusionSettleOrdersCall,
+    ParaswapV6SwapExactAmountInCall,
+    ParaswapV6SwapExactAmountOutCall,
+    ParaswapV6SwapExactAmountInOnUniswapV2Call,
+    ParaswapV6SwapExactAmountOutOnUniswapV2Call,
+    ParaswapV6SwapExactAmountInOnUniswapV3Call,
+    ParaswapV6SwapExactAmountOutOnUniswapV3Call,
+    KyberSwapMetaAggregatorSwapCall,
+    KyberSwapMetaAggregatorSwapSimpleModeCall,
+    OdosV2SwapCall,
+    OdosV2SwapMultiCall,
+    OdosV2SwapCompactCall,
+    OdosV2SwapMultiCompactCall,
     ClipperExchangeSwapCall,
     ClipperExchangeSellEthForTokenCall,
     ClipperExchangeSellTokenForEthCall,
@@ -201,6 +325,8 @@ action_dispatch!(
     ZeroXFillRfqOrderCall,
     ZeroXFillOrKillLimitOrderCall,
     ZeroXFillOrKillRfqOrderCall,
+    ZeroXSettlerExecuteCall,
+    ZeroXSettlerExecuteMetaTxnCall,
     DodoCreateDODOVendingMachineCall,
     DodoCreateDODOStablePoolCall,
     DodoInitDODOPrivatePoolCall,
@@ -208,5 +334,24 @@ action_dispatch!(
     DodoSellSharesCall,
     DodoSellBaseCall,
     DodoSellQuoteCall,
-    DodoFlashLoanCall
+    DodoFlashLoanCall,
+    SeaportV1_5FulfillBasicOrderCall,
+    SeaportV1_5FulfillBasicOrder_efficient_6GL6ycCall,
+    SeaportV1_5FulfillOrderCall,
+    SeaportV1_5FulfillAdvancedOrderCall,
+    SeaportV1_6FulfillBasicOrderCall,
+    SeaportV1_6FulfillBasicOrder_efficient_6GL6ycCall,
+    SeaportV1_6FulfillOrderCall,
+    SeaportV1_6FulfillAdvancedOrderCall,
+    BlurExecuteCall,
+    BlurBulkExecuteCall,
+    SudoswapV2SwapTokenForSpecificNFTsCall,
+    SudoswapV2SwapNFTsForTokenCall,
+    WethDepositCall,
+    WethWithdrawCall,
+    LidoStEthSubmitCall,
+    LidoWstEthWrapCall,
+    LidoWstEthUnwrapCall,
+    RocketPoolREthMintCall,
+    RocketPoolREthBurnCall
 );
@@ -0,0 +1,238 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::{
+        LiquidationKind, NormalizedFlashLoan, NormalizedLiquidation, NormalizedNewPool,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use crate::pool_id::{new_pool_address, pool_id_to_address};
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::createMarketCall,
+    NewPool,
+    [..CreateMarket],
+    logs: true,
+    |info: CallInfo, log_data: MorphoBlueCreateMarketCallLogs, db: &DB| {
+        let logs = log_data.create_market_field?;
+
+        // markets are identified by the hash of their market params
+        Ok(NormalizedNewPool {
+            trace_index: info.trace_idx,
+            protocol: Protocol::MorphoBlue,
+            pool_address: new_pool_address(logs.id, db),
+            tokens: vec![logs.marketParams.loanToken, logs.marketParams.collateralToken],
+            pool_id: Some(logs.id),
+        })
+    }
+);
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::liquidateCall,
+    Liquidation,
+    [..Liquidate],
+    call_data: true,
+    logs: true,
+    |
+    info: CallInfo,
+    call_data: liquidateCall,
+    log_data: MorphoBlueLiquidateCallLogs,
+    db_tx: &DB| {
+        let logs = log_data.liquidate_field?;
+        let debt_asset = db_tx.try_fetch_token_info(call_data.marketParams.loanToken)?;
+        let collateral_asset =
+            db_tx.try_fetch_token_info(call_data.marketParams.collateralToken)?;
+
+        // the seized collateral is sent to the caller, the repaid debt is pulled
+        // from it once the liquidation callback returns
+        Ok(NormalizedLiquidation {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            pool: pool_id_to_address(logs.id, db_tx),
+            liquidator: info.from_address,
+            debtor: call_data.borrower,
+            covered_debt: logs.repaidAssets.to_scaled_rational(debt_asset.decimals),
+            liquidated_collateral: logs
+                .seizedAssets
+                .to_scaled_rational(collateral_asset.decimals),
+            collateral_asset,
+            debt_asset,
+            msg_value: info.msg_value,
+            kind: LiquidationKind::Repaid,
+            auction_price: None,
+            oracle_price: None,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::MorphoBlue,
+    crate::MorphoBlue::flashLoanCall,
+    FlashLoan,
+    [..FlashLoan],
+    call_data: true,
+    |info: CallInfo, call_data: flashLoanCall, db_tx: &DB| {
+        let token = db_tx.try_fetch_token_info(call_data.token)?;
+        let amount = call_data.assets.to_scaled_rational(token.decimals);
+
+        // morpho flash loans are free and always call back the caller
+        Ok(NormalizedFlashLoan {
+            protocol: Protocol::MorphoBlue,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            pool: info.target_address,
+            receiver_contract: info.from_address,
+            assets: vec![token],
+            amounts: vec![amount],
+            aave_mode: None,
+            child_actions: vec![],
+            repayments: vec![],
+            fees_paid: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Log, B256, U256};
+    use alloy_sol_types::{SolCall, SolEvent};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::Action,
+    };
+
+    use super::*;
+    use crate::MorphoBlue::{flashLoanCall, liquidateCall, Liquidate, MarketParams};
+
+    struct MorphoSetup {
+        utils:  ClassifierTestUtils,
+        morpho: Address,
+        usdc:   TokenInfoWithAddress,
+        wbtc:   TokenInfoWithAddress,
+    }
+
+    async fn setup() -> MorphoSetup {
+        let utils = ClassifierTestUtils::new().await;
+        let morpho = Address::repeat_byte(0x57);
+
+        let usdc = TokenInfoWithAddress {
+            address: Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            inner:   TokenInfo { decimals: 6, symbol: "USDC".to_string() },
+        };
+        let wbtc = TokenInfoWithAddress {
+            address: Address::new(hex!("2260FAC5E5542a773Aa44fBCfeDf7C193bc2C599")),
+            inner:   TokenInfo { decimals: 8, symbol: "WBTC".to_string() },
+        };
+        utils.ensure_token(usdc.clone());
+        utils.ensure_token(wbtc.clone());
+        utils.ensure_protocol(
+            Protocol::MorphoBlue,
+            morpho,
+            usdc.address,
+            Some(wbtc.address),
+            None,
+            None,
+            None,
+            None,
+        );
+
+        MorphoSetup { utils, morpho, usdc, wbtc }
+    }
+
+    #[brontes_macros::test]
+    async fn test_morpho_blue_liquidate() {
+        let MorphoSetup { utils, morpho, usdc, wbtc } = setup().await;
+        let (liquidator, borrower) = (Address::repeat_byte(0x58), Address::repeat_byte(0x59));
+        // markets without a stored pool address are tracked under the first 20
+        // bytes of their id
+        let id = B256::repeat_byte(0x5a);
+
+        let (repaid, seized) = (U256::from(60_000_000_000u64), U256::from(100_000_000u64));
+        let input = liquidateCall {
+            marketParams: MarketParams {
+                loanToken:       usdc.address,
+                collateralToken: wbtc.address,
+                oracle:          Address::repeat_byte(0x5b),
+                irm:             Address::repeat_byte(0x5c),
+                lltv:            U256::from(860_000_000_000_000_000u64),
+            },
+            borrower,
+            seizedAssets: seized,
+            repaidShares: U256::ZERO,
+            data: Default::default(),
+        }
+        .abi_encode();
+        let output = liquidateCall::abi_encode_returns(&(seized, repaid));
+        let logs = vec![Log {
+            address: morpho,
+            data:    Liquidate {
+                id,
+                caller: liquidator,
+                borrower,
+                repaidAssets: repaid,
+                repaidShares: repaid,
+                seizedAssets: seized,
+                badDebtAssets: U256::ZERO,
+                badDebtShares: U256::ZERO,
+            }
+            .encode_log_data(),
+        }];
+
+        let action = utils.classify_call(liquidator, morpho, input, output, logs);
+
+        assert_eq!(
+            action,
+            Some(Action::Liquidation(NormalizedLiquidation {
+                protocol: Protocol::MorphoBlue,
+                trace_index: 0,
+                pool: Address::repeat_byte(0x5a),
+                liquidator,
+                debtor: borrower,
+                collateral_asset: wbtc,
+                debt_asset: usdc,
+                covered_debt: repaid.to_scaled_rational(6),
+                liquidated_collateral: seized.to_scaled_rational(8),
+                msg_value: U256::ZERO,
+                kind: LiquidationKind::Repaid,
+                auction_price: None,
+                oracle_price: None,
+            }))
+        );
+    }
+
+    #[brontes_macros::test]
+    async fn test_morpho_blue_flash_loan() {
+        let MorphoSetup { utils, morpho, usdc, .. } = setup().await;
+        let borrower = Address::repeat_byte(0x5d);
+
+        let assets = U256::from(1_000_000_000_000u64);
+        let input =
+            flashLoanCall { token: usdc.address, assets, data: Default::default() }.abi_encode();
+
+        let action = utils.classify_call(borrower, morpho, input, vec![], vec![]);
+
+        assert_eq!(
+            action,
+            Some(Action::FlashLoan(NormalizedFlashLoan {
+                protocol:          Protocol::MorphoBlue,
+                trace_index:       0,
+                from:              borrower,
+                pool:              morpho,
+                receiver_contract: borrower,
+                assets:            vec![usdc],
+                amounts:           vec![assets.to_scaled_rational(6)],
+                aave_mode:         None,
+                child_actions:     vec![],
+                repayments:        vec![],
+                fees_paid:         vec![],
+                msg_value:         U256::ZERO,
+            }))
+        );
+    }
+}
@@ -0,0 +1,147 @@
+use alloy_primitives::{Address, Log};
+use alloy_sol_types::SolEvent;
+use brontes_types::{
+    normalized_actions::NormalizedNftTransfer,
+    structured_trace::{TraceActions, TransactionTraceWithLogs},
+};
+use reth_primitives::U256;
+
+alloy_sol_macro::sol!(
+    event Transfer(address indexed from, address indexed to, uint256 indexed tokenId);
+    event TransferSingle(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256 id,
+        uint256 value
+    );
+    event TransferBatch(
+        address indexed operator,
+        address indexed from,
+        address indexed to,
+        uint256[] ids,
+        uint256[] values
+    );
+);
+
+/// Decodes the ERC721 and ERC1155 transfers emitted by the called contract.
+/// ERC721 transfers share their event signature with ERC20 transfers, they are
+/// told apart by the token id being indexed. Contracts that also emit ERC20
+/// transfers for the call, such as ERC404 tokens, are left to the ERC20
+/// classification.
+pub fn try_decode_nft_transfers(
+    idx: u64,
+    trace: &TransactionTraceWithLogs,
+) -> Vec<NormalizedNftTransfer> {
+    decode_nft_transfers(idx, trace.get_to_address(), &trace.logs)
+}
+
+fn decode_nft_transfers(idx: u64, collection: Address, logs: &[Log]) -> Vec<NormalizedNftTransfer> {
+    let logs = logs.iter().filter(|log| log.address == collection);
+    if logs.clone().any(is_erc20_transfer) {
+        return vec![]
+    }
+
+    logs.flat_map(|log| decode_nft_transfer(idx, log)).collect()
+}
+
+fn is_erc20_transfer(log: &&Log) -> bool {
+    log.topics().len() == 3 && log.topics()[0] == Transfer::SIGNATURE_HASH
+}
+
+fn decode_nft_transfer(idx: u64, log: &Log) -> Vec<NormalizedNftTransfer> {
+    let transfer = |token_id: U256, amount: U256, from, to| NormalizedNftTransfer {
+        trace_index: idx,
+        collection: log.address,
+        token_id,
+        amount,
+        from,
+        to,
+    };
+
+    if log.topics().len() == 4 {
+        if let Ok(t) = Transfer::decode_log_data(&log.data, true) {
+            return vec![transfer(t.tokenId, U256::from(1), t.from, t.to)]
+        }
+    }
+
+    if let Ok(t) = TransferSingle::decode_log_data(&log.data, true) {
+        return vec![transfer(t.id, t.value, t.from, t.to)]
+    }
+
+    if let Ok(t) = TransferBatch::decode_log_data(&log.data, true) {
+        return t
+            .ids
+            .into_iter()
+            .zip(t.values)
+            .map(|(id, value)| transfer(id, value, t.from, t.to))
+            .collect()
+    }
+
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::B256;
+
+    use super::*;
+
+    #[test]
+    fn test_erc20_transfer_is_not_nft() {
+        let from = Address::with_last_byte(1);
+        let to = Address::with_last_byte(2);
+        let token = Address::with_last_byte(3);
+
+        let erc20 = Log::new_unchecked(
+            token,
+            vec![Transfer::SIGNATURE_HASH, from.into_word(), to.into_word()],
+            U256::from(100).to_be_bytes_vec().into(),
+        );
+        assert!(decode_nft_transfer(0, &erc20).is_empty());
+
+        let erc721 = Log::new_unchecked(
+            token,
+            vec![
+                Transfer::SIGNATURE_HASH,
+                from.into_word(),
+                to.into_word(),
+                B256::from(U256::from(7)),
+            ],
+            Default::default(),
+        );
+        let transfers = decode_nft_transfer(0, &erc721);
+
+        assert_eq!(transfers.len(), 1);
+        assert_eq!(transfers[0].collection, token);
+        assert_eq!(transfers[0].token_id, U256::from(7));
+        assert_eq!(transfers[0].amount, U256::from(1));
+        assert_eq!((transfers[0].from, transfers[0].to), (from, to));
+    }
+
+    #[test]
+    fn test_erc404_transfer_is_left_to_erc20() {
+        let from = Address::with_last_byte(1);
+        let to = Address::with_last_byte(2);
+        let token = Address::with_last_byte(3);
+
+        let erc20 = Log::new_unchecked(
+            token,
+            vec![Transfer::SIGNATURE_HASH, from.into_word(), to.into_word()],
+            U256::from(100).to_be_bytes_vec().into(),
+        );
+        let erc721 = Log::new_unchecked(
+            token,
+            vec![
+                Transfer::SIGNATURE_HASH,
+                from.into_word(),
+                to.into_word(),
+                B256::from(U256::from(7)),
+            ],
+            Default::default(),
+        );
+
+        assert!(decode_nft_transfers(0, token, &[erc20, erc721.clone()]).is_empty());
+        assert_eq!(decode_nft_transfers(0, token, &[erc721]).len(), 1);
+    }
+}
@@ -0,0 +1,165 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: call_data.tokenInfo.outputReceiver,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// the outputs can go to different receivers, the first one is used as the
+// recipient of the trade
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapMultiCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapMultiCall, _| {
+        let recipient = call_data
+            .outputs
+            .first()
+            .map(|output| output.receiver)
+            .unwrap_or(info.msg_sender);
+
+        Ok(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+// the compact variants pack their arguments into custom calldata that isn't
+// decoded, the router is used as the recipient until the recipient is resolved
+// from the outputs it pays out when grouping the trade
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapCompactCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _| {
+        Ok(NormalizedAggregator {
+            protocol:      Protocol::OdosV2,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.target_address,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::OdosV2,
+    crate::OdosRouterV2::swapMultiCompactCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _| {
+        Ok(NormalizedAggregator {
+            protocol:      Protocol::OdosV2,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.target_address,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, Address, Bytes, U256};
+    use alloy_sol_types::{SolCall, SolValue};
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::normalized_actions::Action;
+
+    use super::*;
+    use crate::OdosRouterV2::{swapCall, swapCompactCall};
+
+    async fn setup() -> (ClassifierTestUtils, Address) {
+        let utils = ClassifierTestUtils::new().await;
+        let router = Address::repeat_byte(0x71);
+        utils.ensure_protocol(
+            Protocol::OdosV2,
+            router,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        (utils, router)
+    }
+
+    fn aggregator(router: Address, from: Address, recipient: Address) -> Action {
+        Action::Aggregator(NormalizedAggregator {
+            protocol: Protocol::OdosV2,
+            trace_index: 0,
+            from,
+            to: router,
+            recipient,
+            child_actions: vec![],
+            msg_value: U256::ZERO,
+        })
+    }
+
+    #[brontes_macros::test]
+    async fn test_odos_v2_swap() {
+        let (utils, router) = setup().await;
+        let (trader, receiver) = (Address::repeat_byte(0x72), Address::repeat_byte(0x73));
+
+        // the calldata is encoded from a tuple of the fields of the swap token info
+        let token_info = (
+            Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48")),
+            U256::from(2_500_000_000u64),
+            Address::repeat_byte(0x74),
+            Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2")),
+            U256::from(10u64.pow(18)),
+            U256::from(99 * 10u64.pow(16)),
+            receiver,
+        );
+        let args = (token_info, Bytes::new(), Address::repeat_byte(0x74), 0u32);
+        let input = [swapCall::SELECTOR.as_slice(), &args.abi_encode_params()].concat();
+
+        let action = utils.classify_call(trader, router, input, vec![], vec![]);
+
+        assert_eq!(action, Some(aggregator(router, trader, receiver)));
+    }
+
+    #[brontes_macros::test]
+    async fn test_odos_v2_swap_compact() {
+        let (utils, router) = setup().await;
+        let trader = Address::repeat_byte(0x72);
+
+        // the packed arguments follow the selector
+        let input = [swapCompactCall::SELECTOR.as_slice(), &[0x01; 24]].concat();
+
+        let action = utils.classify_call(trader, router, input, vec![], vec![]);
+
+        // the recipient is only resolved once the payouts of the router are grouped
+        assert_eq!(action, Some(aggregator(router, trader, router)));
+    }
+}
@@ -19,6 +19,7 @@ discovery_impl!(
             trace_index,
             protocol: Protocol::PancakeSwapV3,
             tokens: vec![token_a, token_b],
+            pool_id: None,
         }]
     }
 );
@@ -40,6 +41,7 @@ discovery_impl!(
             trace_index,
             protocol: Protocol::PancakeSwapV2,
             tokens: vec![token_a, token_b],
+            pool_id: None,
         }]
     }
 );
@@ -67,6 +69,7 @@ pub mod test {
                 Address::new(hex!("186eF81fd8E77EEC8BfFC3039e7eC41D5FC0b457")),
                 TokenInfoWithAddress::usdt().address,
             ],
+            pool_id:      None,
         };
 
         utils
@@ -0,0 +1,226 @@
+use alloy_primitives::Address;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{normalized_actions::NormalizedAggregator, structured_trace::CallInfo};
+
+action_impl!(
+    Protocol::ParaswapV6,
+    crate::ParaswapAugustusV6::swapExactAmountInCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapExactAmountInCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: beneficiary_or_sender(call_data.swapData.beneficiary, &info),
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV6,
+    crate::ParaswapAugustusV6::swapExactAmountOutCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapExactAmountOutCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: beneficiary_or_sender(call_data.swapData.beneficiary, &info),
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV6,
+    crate::ParaswapAugustusV6::swapExactAmountInOnUniswapV2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapExactAmountInOnUniswapV2Call, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: beneficiary_or_sender(call_data.uniData.beneficiary, &info),
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV6,
+    crate::ParaswapAugustusV6::swapExactAmountOutOnUniswapV2Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapExactAmountOutOnUniswapV2Call, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: beneficiary_or_sender(call_data.uniData.beneficiary, &info),
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV6,
+    crate::ParaswapAugustusV6::swapExactAmountInOnUniswapV3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapExactAmountInOnUniswapV3Call, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: beneficiary_or_sender(call_data.uniData.beneficiary, &info),
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ParaswapV6,
+    crate::ParaswapAugustusV6::swapExactAmountOutOnUniswapV3Call,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: swapExactAmountOutOnUniswapV3Call, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: beneficiary_or_sender(call_data.uniData.beneficiary, &info),
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+/// Augustus sends the output to the caller when no beneficiary is set
+fn beneficiary_or_sender(beneficiary: Address, info: &CallInfo) -> Address {
+    if beneficiary.is_zero() {
+        info.msg_sender
+    } else {
+        beneficiary
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, B256, U256};
+    use alloy_sol_types::SolCall;
+    use brontes_classifier::test_utils::ClassifierTestUtils;
+    use brontes_types::normalized_actions::Action;
+
+    use super::*;
+    use crate::ParaswapAugustusV6::{
+        swapExactAmountInCall, swapExactAmountInOnUniswapV2Call, GenericData, UniswapV2Data,
+    };
+
+    const USDC: Address = Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
+    const WETH: Address = Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
+
+    async fn setup() -> (ClassifierTestUtils, Address) {
+        let utils = ClassifierTestUtils::new().await;
+        let augustus = Address::repeat_byte(0x69);
+        utils.ensure_protocol(
+            Protocol::ParaswapV6,
+            augustus,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        (utils, augustus)
+    }
+
+    fn aggregator(augustus: Address, from: Address, recipient: Address) -> Action {
+        Action::Aggregator(NormalizedAggregator {
+            protocol: Protocol::ParaswapV6,
+            trace_index: 0,
+            from,
+            to: augustus,
+            recipient,
+            child_actions: vec![],
+            msg_value: U256::ZERO,
+        })
+    }
+
+    #[brontes_macros::test]
+    async fn test_paraswap_v6_swap_exact_amount_in() {
+        let (utils, augustus) = setup().await;
+        let trader = Address::repeat_byte(0x6a);
+
+        let input = swapExactAmountInCall {
+            executor:      Address::repeat_byte(0x6b),
+            swapData:      GenericData {
+                srcToken:     USDC,
+                destToken:    WETH,
+                fromAmount:   U256::from(2_500_000_000u64),
+                toAmount:     U256::from(10u64.pow(18)),
+                quotedAmount: U256::from(10u64.pow(18)),
+                metadata:     B256::ZERO,
+                beneficiary:  Address::ZERO,
+            },
+            partnerAndFee: U256::ZERO,
+            permit:        Default::default(),
+            executorData:  Default::default(),
+        }
+        .abi_encode();
+
+        let action = utils.classify_call(trader, augustus, input, vec![], vec![]);
+
+        // without a beneficiary the output goes to the caller
+        assert_eq!(action, Some(aggregator(augustus, trader, trader)));
+    }
+
+    #[brontes_macros::test]
+    async fn test_paraswap_v6_swap_exact_amount_in_on_uniswap_v2() {
+        let (utils, augustus) = setup().await;
+        let (trader, beneficiary) = (Address::repeat_byte(0x6a), Address::repeat_byte(0x6c));
+
+        let input = swapExactAmountInOnUniswapV2Call {
+            uniData:       UniswapV2Data {
+                srcToken: USDC,
+                destToken: WETH,
+                fromAmount: U256::from(2_500_000_000u64),
+                toAmount: U256::from(10u64.pow(18)),
+                quotedAmount: U256::from(10u64.pow(18)),
+                metadata: B256::ZERO,
+                beneficiary,
+                pools: Default::default(),
+            },
+            partnerAndFee: U256::ZERO,
+            permit:        Default::default(),
+        }
+        .abi_encode();
+
+        let action = utils.classify_call(trader, augustus, input, vec![], vec![]);
+
+        assert_eq!(action, Some(aggregator(augustus, trader, beneficiary)));
+    }
+}
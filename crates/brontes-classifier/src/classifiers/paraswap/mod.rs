@@ -0,0 +1,3 @@
+mod augustus_v6;
+
+pub use augustus_v6::*;
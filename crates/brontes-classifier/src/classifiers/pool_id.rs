@@ -0,0 +1,30 @@
+use alloy_primitives::{keccak256, Address, B256};
+use brontes_database::libmdbx::LibmdbxReader;
+
+/// Pools such as the uniswap v4 pools and the morpho blue markets don't have an
+/// address, they are identified by a 32 byte id. Actions need an address for
+/// the pool, so each pool is given the first 20 bytes of its id, or of the
+/// hash of it when these are already taken by another pool. The address given
+/// to each pool id is stored when the pool is created.
+pub(crate) fn new_pool_address<DB: LibmdbxReader>(pool_id: B256, db: &DB) -> Address {
+    if let Ok(address) = db.try_fetch_uniswap_v4_pool(pool_id) {
+        return address
+    }
+
+    let mut candidate = pool_id;
+    loop {
+        let address = Address::from_slice(&candidate[0..20]);
+        if db.get_protocol_details(address).is_err() {
+            return address
+        }
+        candidate = keccak256(candidate);
+    }
+}
+
+/// The address the pool of `pool_id` is tracked under, see
+/// [`new_pool_address`]. Pools created before the pool ids were stored are
+/// tracked under the first 20 bytes of their id.
+pub(crate) fn pool_id_to_address<DB: LibmdbxReader>(pool_id: B256, db: &DB) -> Address {
+    db.try_fetch_uniswap_v4_pool(pool_id)
+        .unwrap_or_else(|_| Address::from_slice(&pool_id[0..20]))
+}
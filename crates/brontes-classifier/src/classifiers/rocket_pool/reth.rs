@@ -0,0 +1,61 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedUnwrap, NormalizedWrap},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+// rETH is minted by the deposit pool, which holds the deposited eth. The
+// deposit pool gets upgraded to new addresses, so the mint on the token is
+// classified instead of the deposit
+action_impl!(
+    Protocol::RocketPoolREth,
+    crate::RocketPoolREth::mintCall,
+    Wrap,
+    [..TokensMinted],
+    logs: true,
+    |info: CallInfo, log_data: RocketPoolREthMintCallLogs, db_tx: &DB| {
+        let logs = log_data.tokens_minted_field?;
+        let reth = db_tx.try_fetch_token_info(info.target_address)?;
+        let eth = TokenInfoWithAddress::native_eth();
+
+        Ok(NormalizedWrap {
+            protocol: Protocol::RocketPoolREth,
+            trace_index: info.trace_idx,
+            from: logs.to,
+            recipient: logs.to,
+            amount_in: logs.ethAmount.to_scaled_rational(eth.decimals),
+            amount_out: logs.amount.to_scaled_rational(reth.decimals),
+            underlying: eth,
+            wrapped: reth,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::RocketPoolREth,
+    crate::RocketPoolREth::burnCall,
+    Unwrap,
+    [..TokensBurned],
+    logs: true,
+    |info: CallInfo, log_data: RocketPoolREthBurnCallLogs, db_tx: &DB| {
+        let logs = log_data.tokens_burned_field?;
+        let reth = db_tx.try_fetch_token_info(info.target_address)?;
+        let eth = TokenInfoWithAddress::native_eth();
+
+        Ok(NormalizedUnwrap {
+            protocol: Protocol::RocketPoolREth,
+            trace_index: info.trace_idx,
+            from: logs.from,
+            recipient: logs.from,
+            amount_in: logs.amount.to_scaled_rational(reth.decimals),
+            amount_out: logs.ethAmount.to_scaled_rational(eth.decimals),
+            wrapped: reth,
+            underlying: eth,
+            msg_value: info.msg_value,
+        })
+    }
+);
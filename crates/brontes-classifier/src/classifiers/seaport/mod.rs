@@ -0,0 +1,6 @@
+mod seaport_v1_5;
+mod seaport_v1_6;
+mod utils;
+
+pub use seaport_v1_5::*;
+pub use seaport_v1_6::*;
@@ -0,0 +1,53 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::structured_trace::CallInfo;
+
+use super::utils::order_fulfilled_trade;
+
+action_impl!(
+    Protocol::SeaportV1_6,
+    crate::Seaport::fulfillBasicOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportV1_6FulfillBasicOrderCallLogs, db: &DB| {
+        let order = log_data.order_fulfilled_field?;
+        order_fulfilled_trade(Protocol::SeaportV1_6, &info, order, db)
+    }
+);
+
+action_impl!(
+    Protocol::SeaportV1_6,
+    crate::Seaport::fulfillBasicOrder_efficient_6GL6ycCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportV1_6FulfillBasicOrder_efficient_6GL6ycCallLogs, db: &DB| {
+        let order = log_data.order_fulfilled_field?;
+        order_fulfilled_trade(Protocol::SeaportV1_6, &info, order, db)
+    }
+);
+
+action_impl!(
+    Protocol::SeaportV1_6,
+    crate::Seaport::fulfillOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportV1_6FulfillOrderCallLogs, db: &DB| {
+        let order = log_data.order_fulfilled_field?;
+        order_fulfilled_trade(Protocol::SeaportV1_6, &info, order, db)
+    }
+);
+
+action_impl!(
+    Protocol::SeaportV1_6,
+    crate::Seaport::fulfillAdvancedOrderCall,
+    NftTrade,
+    [..OrderFulfilled],
+    logs: true,
+    |info: CallInfo, log_data: SeaportV1_6FulfillAdvancedOrderCallLogs, db: &DB| {
+        let order = log_data.order_fulfilled_field?;
+        order_fulfilled_trade(Protocol::SeaportV1_6, &info, order, db)
+    }
+);
@@ -0,0 +1,111 @@
+use alloy_primitives::{Address, U256};
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress, normalized_actions::NormalizedNftTrade,
+    structured_trace::CallInfo, ToScaledRational,
+};
+
+use crate::Seaport::OrderFulfilled;
+
+const NATIVE: u8 = 0;
+const ERC721: u8 = 2;
+const ERC1155: u8 = 3;
+
+/// Criteria based items are resolved to their token ids by the time the order
+/// is fulfilled, so only the plain ERC721 and ERC1155 item types show up in
+/// the event
+fn is_nft(item_type: u8) -> bool {
+    item_type == ERC721 || item_type == ERC1155
+}
+
+/// Builds the trade from the `OrderFulfilled` event. The recipient of the event
+/// receives the offer items, so when the offer is an NFT (a listing) the
+/// recipient is the buyer, and when the offer is a payment (a bid) the
+/// recipient is the seller.
+pub(crate) fn order_fulfilled_trade<DB: LibmdbxReader>(
+    protocol: Protocol,
+    info: &CallInfo,
+    order: OrderFulfilled,
+    db: &DB,
+) -> eyre::Result<NormalizedNftTrade> {
+    let is_listing = order.offer.iter().any(|item| is_nft(item.itemType));
+
+    let (seller, buyer, nfts, payments) = if is_listing {
+        let nfts = order
+            .offer
+            .iter()
+            .filter(|item| is_nft(item.itemType))
+            .map(|item| (item.token, item.identifier, item.amount))
+            .collect::<Vec<_>>();
+        let payments = order
+            .consideration
+            .iter()
+            .filter(|item| !is_nft(item.itemType))
+            .map(|item| (item.itemType, item.token, item.amount))
+            .collect::<Vec<_>>();
+
+        (order.offerer, order.recipient, nfts, payments)
+    } else {
+        let nfts = order
+            .consideration
+            .iter()
+            .filter(|item| is_nft(item.itemType))
+            .map(|item| (item.token, item.identifier, item.amount))
+            .collect::<Vec<_>>();
+        let payments = order
+            .offer
+            .iter()
+            .map(|item| (item.itemType, item.token, item.amount))
+            .collect::<Vec<_>>();
+
+        (order.recipient, order.offerer, nfts, payments)
+    };
+
+    let Some(&(collection, ..)) = nfts.first() else {
+        return Err(eyre::eyre!("Seaport order {} didn't trade an nft", order.orderHash))
+    };
+    let (token_ids, amounts) = nfts
+        .into_iter()
+        .filter(|(token, ..)| *token == collection)
+        .map(|(_, id, amount)| (id, amount))
+        .unzip();
+
+    let (payment_token, price) = match payments.first() {
+        Some(&(item_type, token, _)) => {
+            let paid = payments
+                .iter()
+                .filter(|(_, payment, _)| *payment == token)
+                .fold(U256::ZERO, |acc, (.., amount)| acc + *amount);
+
+            (payment_token_info(item_type, token, db)?, paid)
+        }
+        None => (TokenInfoWithAddress::native_eth(), U256::ZERO),
+    };
+
+    Ok(NormalizedNftTrade {
+        protocol,
+        trace_index: info.trace_idx,
+        marketplace: info.target_address,
+        seller,
+        buyer,
+        collection,
+        token_ids,
+        amounts,
+        price: price.to_scaled_rational(payment_token.decimals),
+        payment_token,
+        msg_value: info.msg_value,
+    })
+}
+
+fn payment_token_info<DB: LibmdbxReader>(
+    item_type: u8,
+    token: Address,
+    db: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    if item_type == NATIVE {
+        return Ok(TokenInfoWithAddress::native_eth())
+    }
+
+    db.try_fetch_token_info(token)
+}
@@ -0,0 +1,36 @@
+use alloy_primitives::Address;
+use brontes_macros::discovery_impl;
+use brontes_pricing::Protocol;
+use brontes_types::constants::WETH_ADDRESS;
+
+// Pairs hold the nft collection as token0 and the token they are paid in as
+// token1. Eth pairs are priced as weth.
+discovery_impl!(
+    SudoswapV2ETHDiscovery,
+    crate::SudoswapV2PairFactory::createPairERC721ETHCall,
+    0xA020d57aB0448Ef74115c112D18a9C231CC86000,
+    |pair: Address, trace_index: u64, call_data: createPairERC721ETHCall, _| async move {
+        vec![NormalizedNewPool {
+            pool_address: pair,
+            trace_index,
+            protocol: Protocol::SudoswapV2,
+            tokens: vec![call_data._nft, WETH_ADDRESS],
+            pool_id: None,
+        }]
+    }
+);
+
+discovery_impl!(
+    SudoswapV2ERC20Discovery,
+    crate::SudoswapV2PairFactory::createPairERC721ERC20Call,
+    0xA020d57aB0448Ef74115c112D18a9C231CC86000,
+    |pair: Address, trace_index: u64, call_data: createPairERC721ERC20Call, _| async move {
+        vec![NormalizedNewPool {
+            pool_address: pair,
+            trace_index,
+            protocol: Protocol::SudoswapV2,
+            tokens: vec![call_data.params.nft, call_data.params.token],
+            pool_id: None,
+        }]
+    }
+);
@@ -0,0 +1,5 @@
+mod discovery;
+mod sudoswap_v2;
+
+pub use discovery::*;
+pub use sudoswap_v2::*;
@@ -0,0 +1,75 @@
+use alloy_primitives::U256;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    normalized_actions::NormalizedNftTrade, structured_trace::CallInfo, ToScaledRational,
+};
+
+action_impl!(
+    Protocol::SudoswapV2,
+    crate::SudoswapV2Pair::swapTokenForSpecificNFTsCall,
+    NftTrade,
+    [..SwapNFTOutPair],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: swapTokenForSpecificNFTsCall,
+    return_data: swapTokenForSpecificNFTsReturn,
+    db: &DB
+    | {
+        let details = db.get_protocol_details(info.target_address)?;
+        let payment_token = db.try_fetch_token_info(details.token1)?;
+        let amounts = vec![U256::from(1); call_data.nftIds.len()];
+
+        Ok(NormalizedNftTrade {
+            protocol: Protocol::SudoswapV2,
+            trace_index: info.trace_idx,
+            marketplace: info.target_address,
+            seller: info.target_address,
+            buyer: call_data.nftRecipient,
+            collection: details.token0,
+            token_ids: call_data.nftIds,
+            amounts,
+            price: return_data.inputAmount.to_scaled_rational(payment_token.decimals),
+            payment_token,
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::SudoswapV2,
+    crate::SudoswapV2Pair::swapNFTsForTokenCall,
+    NftTrade,
+    [..SwapNFTInPair],
+    call_data: true,
+    return_data: true,
+    |
+    info: CallInfo,
+    call_data: swapNFTsForTokenCall,
+    return_data: swapNFTsForTokenReturn,
+    db: &DB
+    | {
+        let details = db.get_protocol_details(info.target_address)?;
+        let payment_token = db.try_fetch_token_info(details.token1)?;
+        let amounts = vec![U256::from(1); call_data.nftIds.len()];
+
+        // the router transfers the nfts on behalf of the caller
+        let seller = if call_data.isRouter { call_data.routerCaller } else { info.msg_sender };
+
+        Ok(NormalizedNftTrade {
+            protocol: Protocol::SudoswapV2,
+            trace_index: info.trace_idx,
+            marketplace: info.target_address,
+            seller,
+            buyer: info.target_address,
+            collection: details.token0,
+            token_ids: call_data.nftIds,
+            amounts,
+            price: return_data.outputAmount.to_scaled_rational(payment_token.decimals),
+            payment_token,
+            msg_value: info.msg_value,
+        })
+    }
+);
@@ -18,6 +18,7 @@ discovery_impl!(
             trace_index,
             protocol: Protocol::SushiSwapV2,
             tokens: vec![token_a, token_b],
+            pool_id: None,
         }]
     }
 );
@@ -39,6 +40,7 @@ discovery_impl!(
             trace_index,
             protocol: Protocol::SushiSwapV3,
             tokens: vec![token_a, token_b],
+            pool_id: None,
         }]
     }
 );
@@ -64,6 +66,7 @@ mod tests {
                 hex!("189564397643D9e6173A002f1BA98da7d40a0FA6").into(),
                 hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").into(),
             ],
+            pool_id:      None,
         };
 
         utils
@@ -18,6 +18,7 @@ discovery_impl!(
             trace_index,
             protocol: Protocol::UniswapV2,
             tokens: vec![token_a, token_b],
+            pool_id: None,
         }]
     }
 );
@@ -39,6 +40,7 @@ discovery_impl!(
             trace_index,
             protocol: Protocol::UniswapV3,
             tokens: vec![token_a, token_b],
+            pool_id: None,
         }]
     }
 );
@@ -64,6 +66,7 @@ mod tests {
                 hex!("52c6889677E514BDD0f09E32003C15B33E88DccE").into(),
                 hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").into(),
             ],
+            pool_id:      None,
         };
 
         utils
@@ -96,6 +99,7 @@ mod tests {
                 hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").into(),
                 hex!("edB357b55BC2DA1882B629EaDD3DF06202092d69").into(),
             ],
+            pool_id:      None,
         };
 
         utils
@@ -0,0 +1,272 @@
+use alloy_primitives::{keccak256, Address, B256, I256};
+use alloy_sol_types::SolValue;
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    constants::WETH_ADDRESS,
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{
+        Action, NormalizedAggregator, NormalizedBurn, NormalizedCollect, NormalizedMint,
+        NormalizedNewPool, NormalizedSwap,
+    },
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+use crate::{
+    pool_id::{new_pool_address, pool_id_to_address},
+    UniswapV4PoolManager::PoolKey,
+};
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::initializeCall,
+    NewPool,
+    [..Initialize],
+    logs: true,
+    |info: CallInfo, log_data: UniswapV4InitializeCallLogs, db: &DB| {
+        let logs = log_data.initialize_field?;
+
+        Ok(NormalizedNewPool {
+            trace_index: info.trace_idx,
+            protocol: Protocol::UniswapV4,
+            pool_address: new_pool_address(logs.id, db),
+            tokens: vec![currency_address(logs.currency0), currency_address(logs.currency1)],
+            pool_id: Some(logs.id),
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::swapCall,
+    Swap,
+    [..Swap],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: swapCall, return_data: swapReturn, db: &DB| {
+        let (amount0, amount1) = split_balance_delta(return_data.swapDelta);
+        let t0_info = currency_info(call_data.key.currency0, db)?;
+        let t1_info = currency_info(call_data.key.currency1, db)?;
+
+        let (amount_in, amount_out, token_in, token_out) = if call_data.params.zeroForOne {
+            (
+                amount0.unsigned_abs().to_scaled_rational(t0_info.decimals),
+                amount1.unsigned_abs().to_scaled_rational(t1_info.decimals),
+                t0_info,
+                t1_info,
+            )
+        } else {
+            (
+                amount1.unsigned_abs().to_scaled_rational(t1_info.decimals),
+                amount0.unsigned_abs().to_scaled_rational(t0_info.decimals),
+                t1_info,
+                t0_info,
+            )
+        };
+
+        // the swapper and the recipient are only known once the deltas are
+        // settled, this is resolved when classifying the unlock call
+        Ok(NormalizedSwap {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: info.from_address,
+            pool: pool_key_to_address(&call_data.key, db),
+            token_in,
+            token_out,
+            amount_in,
+            amount_out,
+            msg_value: info.msg_value
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::modifyLiquidityCall,
+    Action,
+    [..ModifyLiquidity],
+    call_data: true,
+    return_data: true,
+    |info: CallInfo, call_data: modifyLiquidityCall, return_data: modifyLiquidityReturn, db: &DB| {
+        let (delta0, delta1) = split_balance_delta(return_data.callerDelta);
+        let (fees0, fees1) = split_balance_delta(return_data.feesAccrued);
+        let t0_info = currency_info(call_data.key.currency0, db)?;
+        let t1_info = currency_info(call_data.key.currency1, db)?;
+        let pool = pool_key_to_address(&call_data.key, db);
+
+        let (decimals0, decimals1) = (t0_info.decimals, t1_info.decimals);
+        let to_amounts = |amount0: i128, amount1: i128| {
+            vec![
+                amount0.unsigned_abs().to_scaled_rational(decimals0),
+                amount1.unsigned_abs().to_scaled_rational(decimals1),
+            ]
+        };
+
+        // the fees accrued by the position are credited to the caller whenever its
+        // liquidity is modified, so they are part of the caller delta of both mints
+        // and burns. They are left out of the liquidity that is added or removed.
+        let action = if call_data.params.liquidityDelta.is_positive() {
+            Action::Mint(NormalizedMint {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                amount: to_amounts(delta0 - fees0, delta1 - fees1),
+                token: vec![t0_info, t1_info],
+            })
+        } else if call_data.params.liquidityDelta.is_negative() {
+            Action::Burn(NormalizedBurn {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                amount: to_amounts(delta0 - fees0, delta1 - fees1),
+                token: vec![t0_info, t1_info],
+            })
+        } else {
+            Action::Collect(NormalizedCollect {
+                protocol: Protocol::UniswapV4,
+                trace_index: info.trace_idx,
+                from: info.from_address,
+                recipient: info.from_address,
+                pool,
+                amount: to_amounts(delta0, delta1),
+                token: vec![t0_info, t1_info],
+            })
+        };
+
+        Ok(action)
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::donateCall,
+    Mint,
+    [..Donate],
+    call_data: true,
+    |info: CallInfo, call_data: donateCall, db: &DB| {
+        let pool = pool_key_to_address(&call_data.key, db);
+        let mut token = Vec::new();
+        let mut amount = Vec::new();
+
+        for (currency, donated) in [
+            (call_data.key.currency0, call_data.amount0),
+            (call_data.key.currency1, call_data.amount1),
+        ] {
+            if donated.is_zero() {
+                continue
+            }
+
+            let info = currency_info(currency, db)?;
+            amount.push(donated.to_scaled_rational(info.decimals));
+            token.push(info);
+        }
+
+        Ok(NormalizedMint {
+            protocol: Protocol::UniswapV4,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            recipient: pool,
+            pool,
+            token,
+            amount,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::UniswapV4,
+    crate::UniswapV4PoolManager::unlockCall,
+    Aggregator,
+    [],
+    |info: CallInfo, _| {
+        Ok(NormalizedAggregator {
+            protocol:      Protocol::UniswapV4,
+            trace_index:   info.trace_idx,
+            from:          info.from_address,
+            to:            info.target_address,
+            recipient:     info.from_address,
+            child_actions: vec![],
+            msg_value:     info.msg_value,
+        })
+    }
+);
+
+/// The address the pool of `key` is tracked under
+fn pool_key_to_address<DB: LibmdbxReader>(key: &PoolKey, db: &DB) -> Address {
+    pool_id_to_address(pool_id(key), db)
+}
+
+fn pool_id(key: &PoolKey) -> B256 {
+    keccak256(key.abi_encode())
+}
+
+/// A `BalanceDelta` packs the delta of currency0 in the upper 128 bits and the
+/// delta of currency1 in the lower 128 bits. Negative deltas are owed by the
+/// caller to the pool manager.
+fn split_balance_delta(delta: I256) -> (i128, i128) {
+    let limbs = delta.into_limbs();
+    let amount0 = (((limbs[3] as u128) << 64) | limbs[2] as u128) as i128;
+    let amount1 = (((limbs[1] as u128) << 64) | limbs[0] as u128) as i128;
+
+    (amount0, amount1)
+}
+
+/// The zero address is used for native eth, which is priced as weth
+fn currency_address(currency: Address) -> Address {
+    if currency.is_zero() {
+        WETH_ADDRESS
+    } else {
+        currency
+    }
+}
+
+fn currency_info<DB: LibmdbxReader>(
+    currency: Address,
+    db: &DB,
+) -> eyre::Result<TokenInfoWithAddress> {
+    if currency.is_zero() {
+        return Ok(TokenInfoWithAddress::native_eth())
+    }
+
+    db.try_fetch_token_info(currency)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, U256};
+
+    use super::*;
+
+    #[test]
+    fn test_pool_id() {
+        // ETH / USDC 0.05%
+        let key = PoolKey {
+            currency0:   Address::ZERO,
+            currency1:   hex!("a0b86991c6218b36c1d19d4a2e9eb0ce3606eb48").into(),
+            fee:         500,
+            tickSpacing: 10,
+            hooks:       Address::ZERO,
+        };
+
+        assert_eq!(
+            Address::from_slice(&pool_id(&key)[0..20]),
+            Address::new(hex!("21c67e77068de97969ba93d4aab21826d33ca12b"))
+        );
+    }
+
+    #[test]
+    fn test_split_balance_delta() {
+        let amount0: i128 = -1_000_000;
+        let amount1: i128 = 2_500;
+        let packed = (U256::from(amount0 as u128) << 128) | U256::from(amount1 as u128);
+
+        assert_eq!(split_balance_delta(I256::from_raw(packed)), (amount0, amount1));
+    }
+}
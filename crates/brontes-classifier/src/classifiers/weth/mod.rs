@@ -0,0 +1,3 @@
+mod weth9;
+
+pub use weth9::*;
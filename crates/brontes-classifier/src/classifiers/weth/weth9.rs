@@ -0,0 +1,47 @@
+use brontes_macros::action_impl;
+use brontes_pricing::Protocol;
+use brontes_types::{
+    db::token_info::TokenInfoWithAddress,
+    normalized_actions::{NormalizedUnwrap, NormalizedWrap},
+    structured_trace::CallInfo,
+    ToScaledRational,
+};
+
+action_impl!(Protocol::Weth, crate::Weth9::depositCall, Wrap, [], |info: CallInfo, _| {
+    let amount = info.msg_value.to_scaled_rational(18);
+
+    Ok(NormalizedWrap {
+        protocol:    Protocol::Weth,
+        trace_index: info.trace_idx,
+        from:        info.msg_sender,
+        recipient:   info.msg_sender,
+        underlying:  TokenInfoWithAddress::native_eth(),
+        wrapped:     TokenInfoWithAddress::weth(),
+        amount_in:   amount.clone(),
+        amount_out:  amount,
+        msg_value:   info.msg_value,
+    })
+});
+
+action_impl!(
+    Protocol::Weth,
+    crate::Weth9::withdrawCall,
+    Unwrap,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: withdrawCall, _| {
+        let amount = call_data.wad.to_scaled_rational(18);
+
+        Ok(NormalizedUnwrap {
+            protocol: Protocol::Weth,
+            trace_index: info.trace_idx,
+            from: info.msg_sender,
+            recipient: info.msg_sender,
+            wrapped: TokenInfoWithAddress::weth(),
+            underlying: TokenInfoWithAddress::native_eth(),
+            amount_in: amount.clone(),
+            amount_out: amount,
+            msg_value: info.msg_value,
+        })
+    }
+);
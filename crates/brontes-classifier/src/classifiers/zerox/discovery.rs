@@ -0,0 +1,21 @@
+use alloy_primitives::Address;
+use brontes_macros::discovery_impl;
+use brontes_pricing::Protocol;
+
+// Every settler release is deployed through the settler deployer, the
+// deployment goes through a create3 proxy which is registered along with the
+// settler but never receives settlement calls.
+discovery_impl!(
+    ZeroXSettlerDiscovery,
+    crate::ZeroXSettlerDeployer::deployCall,
+    0x00000000000004533Fe15556B1E086BB1A72cEae,
+    |deployed_address: Address, trace_index: u64, _call_data: deployCall, _| async move {
+        vec![NormalizedNewPool {
+            trace_index,
+            protocol: Protocol::ZeroXSettler,
+            pool_address: deployed_address,
+            tokens: vec![],
+            pool_id: None,
+        }]
+    }
+);
@@ -1,3 +1,4 @@
+mod discovery;
 use alloy_primitives::U256;
 use brontes_macros::action_impl;
 use brontes_pricing::Protocol;
@@ -6,6 +7,7 @@ use brontes_types::{
     structured_trace::CallInfo,
     ToScaledRational,
 };
+pub use discovery::*;
 
 // Uniswap
 action_impl!(
@@ -600,11 +602,51 @@ action_impl!(
     }
 );
 
+// Settler
+action_impl!(
+    Protocol::ZeroXSettler,
+    crate::ZeroXSettler::executeCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: executeCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ZeroXSettler,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: call_data.slippage.recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
+action_impl!(
+    Protocol::ZeroXSettler,
+    crate::ZeroXSettler::executeMetaTxnCall,
+    Aggregator,
+    [],
+    call_data: true,
+    |info: CallInfo, call_data: executeMetaTxnCall, _| {
+        Ok(NormalizedAggregator {
+            protocol: Protocol::ZeroXSettler,
+            trace_index: info.trace_idx,
+            from: info.from_address,
+            to: info.target_address,
+            recipient: call_data.slippage.recipient,
+            child_actions: vec![],
+            msg_value: info.msg_value,
+        })
+    }
+);
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
-    use alloy_primitives::{hex, Address, B256};
+    use alloy_primitives::{hex, Address, Bytes, B256};
+    use alloy_sol_types::{SolCall, SolValue};
     use brontes_classifier::test_utils::ClassifierTestUtils;
     use brontes_types::{
         db::token_info::{TokenInfo, TokenInfoWithAddress},
@@ -1215,4 +1257,48 @@ mod tests {
             .await
             .unwrap()
     }
+
+    #[brontes_macros::test]
+    async fn test_zerox_settler_execute() {
+        let classifier_utils = ClassifierTestUtils::new().await;
+        let settler = Address::repeat_byte(0x75);
+        let (trader, recipient) = (Address::repeat_byte(0x76), Address::repeat_byte(0x77));
+        classifier_utils.ensure_protocol(
+            Protocol::ZeroXSettler,
+            settler,
+            Address::ZERO,
+            None,
+            None,
+            None,
+            None,
+            None,
+        );
+
+        // the allowed slippage is declared in a library of the settler abi, the
+        // calldata is encoded from a tuple of its fields
+        let slippage = (
+            recipient,
+            Address::from_str("0xC02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").unwrap(),
+            U256::from(10u64.pow(18)),
+        );
+        let args = (slippage, vec![Bytes::from(vec![0x01; 36])], B256::ZERO);
+        let input =
+            [crate::ZeroXSettler::executeCall::SELECTOR.as_slice(), &args.abi_encode_params()]
+                .concat();
+
+        let action = classifier_utils.classify_call(trader, settler, input, vec![], vec![]);
+
+        assert_eq!(
+            action,
+            Some(Action::Aggregator(NormalizedAggregator {
+                protocol: Protocol::ZeroXSettler,
+                trace_index: 0,
+                from: trader,
+                to: settler,
+                recipient,
+                child_actions: vec![],
+                msg_value: U256::ZERO,
+            }))
+        );
+    }
 }
@@ -9,8 +9,10 @@ use brontes_types::{
     structured_trace::{TraceActions, TransactionTraceWithLogs, TxTrace},
     traits::TracingProvider,
     tree::{root::NodeData, GasDetails, Node, Root},
+    FastHashMap, Protocol,
 };
 use futures::future::join_all;
+use parking_lot::Mutex;
 use reth_primitives::{Address, Header};
 use reth_rpc_types::trace::parity::{Action as TraceAction, CallType};
 use tracing::{error, trace};
@@ -23,21 +25,34 @@ use crate::{
 
 #[derive(Debug)]
 pub struct DiscoveryOnlyClassifier<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> {
-    libmdbx:  &'db DB,
-    provider: Arc<T>,
+    libmdbx:    &'db DB,
+    provider:   Arc<T>,
+    /// count of the pools that weren't in the db before, per protocol. Shared
+    /// between the clones so that a whole range can be reported on
+    discovered: Arc<Mutex<FastHashMap<Protocol, u64>>>,
 }
 
 impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Clone
     for DiscoveryOnlyClassifier<'db, T, DB>
 {
     fn clone(&self) -> Self {
-        Self { libmdbx: self.libmdbx, provider: self.provider.clone() }
+        Self {
+            libmdbx:    self.libmdbx,
+            provider:   self.provider.clone(),
+            discovered: self.discovered.clone(),
+        }
     }
 }
 
 impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassifier<'db, T, DB> {
     pub fn new(libmdbx: &'db DB, provider: Arc<T>) -> Self {
-        Self { libmdbx, provider }
+        Self { libmdbx, provider, discovered: Arc::default() }
+    }
+
+    /// The amount of newly discovered pools for each protocol since this
+    /// classifier was created
+    pub fn discovered_pools(&self) -> FastHashMap<Protocol, u64> {
+        self.discovered.lock().clone()
     }
 
     pub async fn run_discovery(&self, traces: Vec<TxTrace>, header: Header) {
@@ -62,6 +77,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                     let root_trace = trace.trace.remove(0);
                     let address = root_trace.get_from_addr();
                     let trace_idx = root_trace.trace_idx;
+                    let (to, calldata) = (root_trace.get_to_address(), root_trace.get_calldata());
 
                     self.process_classification(
                         header.number,
@@ -81,6 +97,8 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                         position: tx_idx,
                         head: node,
                         tx_hash: trace.tx_hash,
+                        to,
+                        calldata,
                         private: false,
                         total_msg_value_transfers: vec![],
                         gas_details: GasDetails {
@@ -229,11 +247,36 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                     error!(pool=?p.pool_address,"failed to update pool config");
                 }
             }
-        } else {
+        } else if !self.classify_registration(block, trace_index, &trace).await {
             self.classify_transfer(trace_index, &trace, block).await
         }
     }
 
+    /// Discovers the contracts registered by the call, returning whether it
+    /// registered any
+    async fn classify_registration(
+        &self,
+        block: u64,
+        trace_index: u64,
+        trace: &TransactionTraceWithLogs,
+    ) -> bool {
+        let registered = RegistrationDiscoveryClassifier::default()
+            .dispatch(
+                self.provider.clone(),
+                vec![(trace.get_to_address(), trace.get_calldata())],
+                trace.get_to_address(),
+                trace_index,
+            )
+            .await;
+        let found = !registered.is_empty();
+
+        for pool in registered {
+            self.insert_new_pool(block, pool).await;
+        }
+
+        found
+    }
+
     async fn classify_transfer(
         &self,
         trace_idx: u64,
@@ -319,6 +362,19 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
     }
 
     async fn insert_new_pool(&self, block: u64, pool: NormalizedNewPool) {
+        let is_new = !self.contains_pool(pool.pool_address);
+
+        if let Some(pool_id) = pool.pool_id {
+            if self
+                .libmdbx
+                .insert_uniswap_v4_pool(pool_id, pool.pool_address)
+                .await
+                .is_err()
+            {
+                error!(?pool_id, "failed to insert the address of the pool id into libmdbx");
+            }
+        }
+
         if self
             .libmdbx
             .insert_pool(block, pool.pool_address, &pool.tokens, None, pool.protocol)
@@ -334,6 +390,10 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> DiscoveryOnlyClassif
                 pool.protocol,
                 pool.pool_address
             );
+
+            if is_new {
+                *self.discovered.lock().entry(pool.protocol).or_default() += 1;
+            }
         }
     }
 }
@@ -359,3 +419,32 @@ pub struct TxTreeResult {
     pub further_classification_requests: Option<(usize, Vec<MultiFrameRequest>)>,
     pub root: Root<Action>,
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use alloy_primitives::{hex, Address, B256};
+    use brontes_core::{offline_provider::OfflineProvider, TxTracesWithHeaderAnd};
+    use brontes_database::libmdbx::LibmdbxReader;
+    use brontes_types::Protocol;
+
+    use super::DiscoveryOnlyClassifier;
+    use crate::test_utils::ClassifierTestUtils;
+
+    #[brontes_macros::test]
+    async fn test_discovery_without_node() {
+        let utils = ClassifierTestUtils::new().await;
+        let tx =
+            B256::new(hex!("f5b9b2c23fa3ddf58c31a9377d37439740913f526910cca947c0a3e4bb9bb1d7"));
+        let TxTracesWithHeaderAnd { trace, header, .. } =
+            utils.get_tx_trace_with_header(tx).await.unwrap();
+
+        // the factory call is enough to discover the pool
+        let classifier = DiscoveryOnlyClassifier::new(utils.libmdbx, Arc::new(OfflineProvider));
+        classifier.run_discovery(vec![trace], header).await;
+
+        let pool = Address::new(hex!("1FA0d58e663017cdd80B87fd24C46818364fc9B6"));
+        assert_eq!(utils.libmdbx.get_protocol(pool).unwrap(), Protocol::BalancerV1);
+    }
+}
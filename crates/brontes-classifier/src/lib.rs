@@ -33,6 +33,7 @@ pub use classifiers::*;
 sol!(UniswapV2, "./classifier-abis/UniswapV2.json");
 sol!(SushiSwapV2, "./classifier-abis/SushiSwapV2.json");
 sol!(UniswapV3, "./classifier-abis/UniswapV3.json");
+sol!(UniswapV4PoolManager, "./classifier-abis/UniswapV4PoolManager.json");
 sol!(SushiSwapV3, "./classifier-abis/SushiSwapV3.json");
 sol!(PancakeSwapV2, "./classifier-abis/PancakeSwapV2.json");
 sol!(PancakeSwapV3, "./classifier-abis/PancakeSwapV3.json");
@@ -45,16 +46,28 @@ sol!(CurveV2MetapoolImpl, "./classifier-abis/CurveV2MetapoolImpl.json");
 sol!(CurveV2PlainImpl, "./classifier-abis/CurveV2PlainImpl.json");
 sol!(CurvecrvUSDPlainImpl, "./classifier-abis/CurvecrvUSDPlainImpl.json");
 sol!(CurveCryptoSwap, "./classifier-abis/CurveCryptoSwap.json");
+sol!(CurveStableSwapNgPlainPool, "./classifier-abis/CurveStableSwapNgPlainPool.json");
+sol!(CurveStableSwapNgMetaPool, "./classifier-abis/CurveStableSwapNgMetaPool.json");
+sol!(CurveTriCryptoNgPool, "./classifier-abis/CurveTriCryptoNgPool.json");
 sol!(BalancerV1, "./classifier-abis/balancer/BalancerV1Pool.json");
 sol!(BalancerV2Vault, "./classifier-abis/balancer/BalancerV2Vault.json");
+sol!(BalancerV3Vault, "./classifier-abis/balancer/BalancerV3Vault.json");
 sol!(AaveV2, "./classifier-abis/AaveV2Pool.json");
 sol!(AaveV3, "./classifier-abis/AaveV3Pool.json");
 sol!(UniswapX, "./classifier-abis/UniswapXExclusiveDutchOrderReactor.json");
 sol!(MakerPSM, "./classifier-abis/maker/MakerPSM.json");
 sol!(MakerDssFlash, "./classifier-abis/maker/MakerDssFlash.json");
+sol!(MakerDog, "./classifier-abis/maker/MakerDog.json");
+sol!(MakerClipper, "./classifier-abis/maker/MakerClipper.json");
 sol!(CompoundV2CToken, "./classifier-abis/CompoundV2CToken.json");
+sol!(CompoundV3Comet, "./classifier-abis/CompoundV3Comet.json");
+sol!(MorphoBlue, "./classifier-abis/MorphoBlue.json");
+sol!(LiquityTroveManager, "./classifier-abis/LiquityTroveManager.json");
 sol!(OneInchAggregationRouterV5, "./classifier-abis/OneInchAggregationRouterV5.json");
 sol!(OneInchFusionSettlement, "./classifier-abis/OneInchFusionSettlement.json");
+sol!(ParaswapAugustusV6, "./classifier-abis/ParaswapAugustusV6.json");
+sol!(KyberSwapMetaAggregationRouterV2, "./classifier-abis/KyberSwapMetaAggregationRouterV2.json");
+sol!(OdosRouterV2, "./classifier-abis/OdosRouterV2.json");
 sol!(ClipperExchange, "./classifier-abis/ClipperExchange.json");
 sol!(CowswapGPv2Settlement, "./classifier-abis/cowswap/GPv2Settlement.json");
 sol!(ZeroXUniswapFeaure, "./classifier-abis/zero-x/ZeroXUniswapFeature.json");
@@ -64,8 +77,17 @@ sol!(ZeroXPancakeSwapFeature, "./classifier-abis/zero-x/ZeroXPancakeSwapFeature.
 sol!(ZeroXOtcOrdersFeature, "./classifier-abis/zero-x/ZeroXOtcOrdersFeature.json");
 sol!(ZeroXLiquidityProviderFeature, "./classifier-abis/zero-x/ZeroXLiquidityProviderFeature.json");
 sol!(ZeroXInterface, "./classifier-abis/zero-x/ZeroXInterface.json");
+sol!(ZeroXSettler, "./classifier-abis/zero-x/ZeroXSettler.json");
+sol!(ZeroXSettlerDeployer, "./classifier-abis/zero-x/ZeroXSettlerDeployer.json");
 sol!(DodoDPPPool, "./classifier-abis/dodo/DPPPool.json");
 sol!(DodoDSPPool, "./classifier-abis/dodo/DSPPool.json");
+sol!(Seaport, "./classifier-abis/nft/Seaport.json");
+sol!(BlurExchange, "./classifier-abis/nft/BlurExchange.json");
+sol!(SudoswapV2Pair, "./classifier-abis/nft/SudoswapV2Pair.json");
+sol!(Weth9, "./classifier-abis/WETH9.json");
+sol!(LidoStEth, "./classifier-abis/LidoStEth.json");
+sol!(LidoWstEth, "./classifier-abis/LidoWstEth.json");
+sol!(RocketPoolREth, "./classifier-abis/RocketPoolREth.json");
 
 // Discovery
 sol!(UniswapV2Factory, "./classifier-abis/UniswapV2Factory.json");
@@ -75,14 +97,17 @@ sol!(CurveV2MetapoolFactory, "./classifier-abis/CurveMetapoolFactoryV2.json");
 sol!(CurvecrvUSDFactory, "./classifier-abis/CurveCRVUSDFactory.json");
 sol!(CurveCryptoSwapFactory, "./classifier-abis/CurveCryptoSwapFactory.json");
 sol!(CurveTriCryptoFactory, "./classifier-abis/CurveTriCryptoFactory.json");
+sol!(CurveStableSwapNgFactory, "./classifier-abis/CurveStableSwapNgFactory.json");
 sol!(PancakeSwapV3PoolDeployer, "./classifier-abis/PancakeSwapV3PoolDeployer.json");
 sol!(CompoundV2Comptroller, "./classifier-abis/CompoundV2Comptroller.json");
 sol!(CErc20Delegate, "./classifier-abis/CErc20Delegate.json");
+sol!(CompoundV3Configurator, "./classifier-abis/CompoundV3Configurator.json");
 sol!(BalancerV1CorePoolFactory, "./classifier-abis/balancer/BalancerV1Factory.json");
 sol!(BalancerV1SmartPoolFactory, "./classifier-abis/balancer/BalancerV1CrpFactory.json");
 sol!(DodoDVMFactory, "./classifier-abis/dodo/DVMFactory.json");
 sol!(DodoDPPFactory, "./classifier-abis/dodo/DPPFactory.json");
 sol!(DodoDSPFactory, "./classifier-abis/dodo/DSPFactory.json");
+sol!(SudoswapV2PairFactory, "./classifier-abis/nft/SudoswapV2PairFactory.json");
 
 // Balancer Pool Interfaces
 sol! {
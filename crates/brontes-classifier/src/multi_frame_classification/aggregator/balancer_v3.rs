@@ -0,0 +1,38 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use super::unlock::settle_unlock;
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+/// The vault doesn't handle native eth, it is wrapped by the routers
+pub struct BalancerV3;
+
+impl MultiCallFrameClassifier for BalancerV3 {
+    const KEY: [u8; 2] = [Protocol::BalancerV3 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_actions([
+                Action::is_swap,
+                Action::is_mint,
+                Action::is_burn,
+                Action::is_transfer,
+            ]),
+            parse_fn:            Box::new(parse_unlock),
+        })
+    }
+}
+
+fn parse_unlock(
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    settle_unlock(Protocol::BalancerV3, this_action, child_nodes)
+}
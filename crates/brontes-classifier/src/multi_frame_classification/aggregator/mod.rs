@@ -1,4 +1,40 @@
+use brontes_types::normalized_actions::{Action, NodeDataIndex};
+
 pub mod one_inch;
 pub use one_inch::*;
 pub mod zero_x;
 pub use zero_x::*;
+pub mod uniswap_v4;
+pub use uniswap_v4::*;
+pub mod balancer_v3;
+pub use balancer_v3::*;
+pub mod paraswap;
+pub use paraswap::*;
+pub mod kyberswap;
+pub use kyberswap::*;
+pub mod odos;
+pub use odos::*;
+mod unlock;
+
+/// Groups the swaps and transfers executed by a router under the router call
+fn parse_router_actions(
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this_action.try_aggregator_mut().unwrap();
+    let mut prune_nodes = Vec::new();
+
+    for (trace_index, action) in child_nodes {
+        match action {
+            Action::Swap(_)
+            | Action::SwapWithFee(_)
+            | Action::Transfer(_)
+            | Action::EthTransfer(_) => {
+                this.child_actions.push(action.clone());
+                prune_nodes.push(trace_index);
+            }
+            _ => {}
+        }
+    }
+    prune_nodes
+}
@@ -0,0 +1,139 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use super::parse_router_actions;
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct OdosAgg;
+
+impl MultiCallFrameClassifier for OdosAgg {
+    const KEY: [u8; 2] = [Protocol::OdosV2 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_actions([
+                Action::is_swap,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+            ]),
+            parse_fn:            Box::new(parse_odos_actions),
+        })
+    }
+}
+
+/// Compact swaps don't decode their receiver, it is the account the router
+/// pays the last output to
+fn parse_odos_actions(
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let prune_nodes = parse_router_actions(this_action, child_nodes);
+    let this = this_action.try_aggregator_mut().unwrap();
+
+    if this.recipient == this.to {
+        let router = this.to;
+        if let Some(recipient) = this
+            .child_actions
+            .iter()
+            .rev()
+            .find_map(|action| match action {
+                Action::Transfer(transfer) if transfer.from == router => Some(transfer.to),
+                Action::EthTransfer(transfer) if transfer.from == router => Some(transfer.to),
+                _ => None,
+            })
+        {
+            this.recipient = recipient;
+        }
+    }
+
+    prune_nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, U256};
+    use brontes_types::{
+        normalized_actions::{NormalizedAggregator, NormalizedEthTransfer, NormalizedTransfer},
+        Protocol,
+    };
+
+    use super::*;
+
+    fn node(trace_index: u64) -> NodeDataIndex {
+        NodeDataIndex { trace_index, data_idx: 0, multi_data_idx: 0 }
+    }
+
+    fn compact_swap(router: Address) -> Action {
+        Action::Aggregator(NormalizedAggregator {
+            protocol:      Protocol::OdosV2,
+            trace_index:   0,
+            from:          Address::ZERO,
+            to:            router,
+            recipient:     router,
+            child_actions: vec![],
+            msg_value:     U256::ZERO,
+        })
+    }
+
+    #[test]
+    fn test_compact_recipient_is_last_payout() {
+        let router = Address::with_last_byte(1);
+        let pool = Address::with_last_byte(2);
+        let receiver = Address::with_last_byte(3);
+
+        let mut this = compact_swap(router);
+        let pruned = parse_odos_actions(
+            &mut this,
+            vec![
+                (
+                    node(1),
+                    Action::Transfer(NormalizedTransfer {
+                        from: router,
+                        to: pool,
+                        ..Default::default()
+                    }),
+                ),
+                (
+                    node(2),
+                    Action::EthTransfer(NormalizedEthTransfer {
+                        from: router,
+                        to: receiver,
+                        ..Default::default()
+                    }),
+                ),
+            ],
+        );
+
+        assert_eq!(pruned.len(), 2);
+        assert_eq!(this.try_aggregator_mut().unwrap().recipient, receiver);
+    }
+
+    #[test]
+    fn test_decoded_recipient_is_kept() {
+        let router = Address::with_last_byte(1);
+        let receiver = Address::with_last_byte(3);
+
+        let mut this = compact_swap(router);
+        this.try_aggregator_mut().unwrap().recipient = receiver;
+        parse_odos_actions(
+            &mut this,
+            vec![(
+                node(1),
+                Action::Transfer(NormalizedTransfer {
+                    from: router,
+                    to: Address::with_last_byte(4),
+                    ..Default::default()
+                }),
+            )],
+        );
+
+        assert_eq!(this.try_aggregator_mut().unwrap().recipient, receiver);
+    }
+}
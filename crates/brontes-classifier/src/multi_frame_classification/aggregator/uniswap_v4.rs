@@ -0,0 +1,45 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use super::unlock::settle_unlock;
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct UniswapV4;
+
+impl MultiCallFrameClassifier for UniswapV4 {
+    const KEY: [u8; 2] = [Protocol::UniswapV4 as u8, MultiFrameAction::Aggregator as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_actions([
+                Action::is_swap,
+                Action::is_mint,
+                Action::is_burn,
+                Action::is_collect,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+                is_settle_with_value,
+            ]),
+            parse_fn:            Box::new(parse_unlock),
+        })
+    }
+}
+
+/// native eth is settled by sending it with the `settle` call
+fn is_settle_with_value(action: &Action) -> bool {
+    action.is_unclassified() && action.get_msg_value_not_eth_transfer().is_some()
+}
+
+fn parse_unlock(
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    settle_unlock(Protocol::UniswapV4, this_action, child_nodes)
+}
@@ -0,0 +1,243 @@
+use alloy_primitives::Address;
+use brontes_types::{
+    constants::WETH_ADDRESS,
+    normalized_actions::{Action, NodeDataIndex},
+    FastHashMap, FastHashSet, Protocol,
+};
+
+/// Everything that happens on the pool manager during the unlock callback is
+/// only settled once the callback is done. The actions of `protocol` only know
+/// the deltas they created, so the transfers settling these deltas are used to
+/// find who paid for them and who received their output. These transfers are
+/// then dropped, as the actions already account for them.
+pub(super) fn settle_unlock(
+    protocol: Protocol,
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this_action.try_aggregator_mut().unwrap();
+    let pool_manager = this.to;
+
+    let currencies = child_nodes
+        .iter()
+        .filter(|(_, action)| action.get_protocol() == protocol)
+        .flat_map(|(_, action)| match action {
+            Action::Swap(s) => vec![s.token_in.address, s.token_out.address],
+            Action::Mint(m) => m.token.iter().map(|t| t.address).collect(),
+            Action::Burn(b) => b.token.iter().map(|t| t.address).collect(),
+            Action::Collect(c) => c.token.iter().map(|t| t.address).collect(),
+            _ => vec![],
+        })
+        .collect::<FastHashSet<_>>();
+
+    let mut payers: FastHashMap<Address, Address> = FastHashMap::default();
+    let mut takers: FastHashMap<Address, Address> = FastHashMap::default();
+    let mut prune_nodes = Vec::new();
+    let mut actions = Vec::new();
+
+    for (index, action) in child_nodes {
+        prune_nodes.push(index);
+        match &action {
+            Action::Transfer(t)
+                if t.to == pool_manager && currencies.contains(&t.token.address) =>
+            {
+                payers.entry(t.token.address).or_insert(t.from);
+            }
+            Action::Transfer(t)
+                if t.from == pool_manager && currencies.contains(&t.token.address) =>
+            {
+                takers.entry(t.token.address).or_insert(t.to);
+            }
+            // native eth is taken through a plain eth transfer and is classified with the
+            // weth address
+            Action::EthTransfer(et)
+                if et.from == pool_manager && currencies.contains(&WETH_ADDRESS) =>
+            {
+                takers.entry(WETH_ADDRESS).or_insert(et.to);
+            }
+            // and paid by sending it along with the unclassified settle call
+            Action::Unclassified(_) => match action.get_msg_value_not_eth_transfer() {
+                Some(et) if et.to == pool_manager && currencies.contains(&WETH_ADDRESS) => {
+                    payers.entry(WETH_ADDRESS).or_insert(et.from);
+                }
+                _ => actions.push(action),
+            },
+            _ => actions.push(action),
+        }
+    }
+
+    for action in &mut actions {
+        if action.get_protocol() != protocol {
+            continue
+        }
+
+        match action {
+            Action::Swap(s) => {
+                if let Some(payer) = payers.get(&s.token_in.address) {
+                    s.from = *payer;
+                }
+                if let Some(taker) = takers.get(&s.token_out.address) {
+                    s.recipient = *taker;
+                }
+            }
+            Action::Mint(m) => {
+                if let Some(payer) = m.token.iter().find_map(|t| payers.get(&t.address)) {
+                    m.from = *payer;
+                }
+            }
+            Action::Burn(b) => {
+                if let Some(taker) = b.token.iter().find_map(|t| takers.get(&t.address)) {
+                    b.recipient = *taker;
+                }
+            }
+            Action::Collect(c) => {
+                if let Some(taker) = c.token.iter().find_map(|t| takers.get(&t.address)) {
+                    c.recipient = *taker;
+                }
+            }
+            _ => {}
+        }
+    }
+
+    this.child_actions.extend(actions);
+
+    prune_nodes
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{hex, U256};
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::{NormalizedAggregator, NormalizedSwap, NormalizedTransfer},
+        structured_trace::TransactionTraceWithLogs,
+    };
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallType, TransactionTrace,
+    };
+
+    use super::*;
+
+    const POOL_MANAGER: Address = Address::new(hex!("000000000004444c5dc75cB358380D2e3dE08A90"));
+    const USDC: Address = Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
+    const ROUTER: Address = Address::with_last_byte(1);
+    const USER: Address = Address::with_last_byte(2);
+
+    fn index(trace_index: u64) -> NodeDataIndex {
+        NodeDataIndex { trace_index, data_idx: 0, multi_data_idx: 0 }
+    }
+
+    fn unlock() -> Action {
+        Action::Aggregator(NormalizedAggregator {
+            protocol:      Protocol::UniswapV4,
+            trace_index:   0,
+            from:          ROUTER,
+            to:            POOL_MANAGER,
+            recipient:     ROUTER,
+            child_actions: vec![],
+            msg_value:     U256::ZERO,
+        })
+    }
+
+    fn swap(token_in: Address, token_out: Address) -> Action {
+        Action::Swap(NormalizedSwap {
+            protocol: Protocol::UniswapV4,
+            trace_index: 1,
+            from: POOL_MANAGER,
+            recipient: POOL_MANAGER,
+            pool: Address::with_last_byte(3),
+            token_in: TokenInfoWithAddress { address: token_in, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: token_out, ..Default::default() },
+            ..Default::default()
+        })
+    }
+
+    fn transfer(token: Address, from: Address, to: Address) -> Action {
+        Action::Transfer(NormalizedTransfer {
+            from,
+            to,
+            token: TokenInfoWithAddress { address: token, ..Default::default() },
+            ..Default::default()
+        })
+    }
+
+    fn settle(from: Address, value: u64) -> Action {
+        Action::Unclassified(TransactionTraceWithLogs {
+            trace:        TransactionTrace {
+                action:        TraceAction::Call(CallAction {
+                    from,
+                    to: POOL_MANAGER,
+                    value: U256::from(value),
+                    gas: Default::default(),
+                    input: Default::default(),
+                    call_type: CallType::Call,
+                }),
+                error:         None,
+                result:        None,
+                subtraces:     0,
+                trace_address: Vec::new(),
+            },
+            logs:         vec![],
+            msg_sender:   from,
+            trace_idx:    2,
+            decoded_data: None,
+        })
+    }
+
+    fn settled_swap(child_nodes: Vec<(NodeDataIndex, Action)>) -> (NormalizedSwap, usize) {
+        let mut this = unlock();
+        let pruned = settle_unlock(Protocol::UniswapV4, &mut this, child_nodes);
+
+        let Action::Aggregator(agg) = this else { unreachable!() };
+        let [Action::Swap(swap)] = agg.child_actions.as_slice() else {
+            panic!("expected only the swap, got {:?}", agg.child_actions)
+        };
+
+        (swap.clone(), pruned.len())
+    }
+
+    #[test]
+    fn test_settle_erc20() {
+        let (swap, pruned) = settled_swap(vec![
+            (index(1), swap(USDC, WETH_ADDRESS)),
+            (index(2), transfer(USDC, ROUTER, POOL_MANAGER)),
+            (index(3), transfer(WETH_ADDRESS, POOL_MANAGER, USER)),
+        ]);
+
+        assert_eq!(swap.from, ROUTER);
+        assert_eq!(swap.recipient, USER);
+        assert_eq!(pruned, 3);
+    }
+
+    #[test]
+    fn test_settle_native_eth() {
+        let (swap, pruned) = settled_swap(vec![
+            (index(1), swap(WETH_ADDRESS, USDC)),
+            (index(2), settle(ROUTER, 1_000)),
+            (index(3), transfer(USDC, POOL_MANAGER, USER)),
+        ]);
+
+        assert_eq!(swap.from, ROUTER);
+        assert_eq!(swap.recipient, USER);
+        assert_eq!(pruned, 3);
+    }
+
+    #[test]
+    fn test_settle_ignores_other_calls_with_value() {
+        let mut this = unlock();
+        let other_call = settle(ROUTER, 1_000);
+        let pruned = settle_unlock(
+            Protocol::UniswapV4,
+            &mut this,
+            vec![
+                (index(1), swap(USDC, Address::with_last_byte(4))),
+                (index(2), other_call.clone()),
+            ],
+        );
+
+        let Action::Aggregator(agg) = this else { unreachable!() };
+        assert_eq!(agg.child_actions.len(), 2);
+        assert_eq!(agg.child_actions[1], other_call);
+        assert_eq!(pruned.len(), 2);
+    }
+}
@@ -1,6 +1,6 @@
 use brontes_types::{
     normalized_actions::{
-        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest,
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
     },
     Protocol, TreeSearchBuilder,
 };
@@ -23,43 +23,119 @@ impl MultiCallFrameClassifier for BalancerV2 {
                 Action::is_transfer,
                 Action::is_eth_transfer,
             ]),
-            parse_fn:            Box::new(|this_action, child_nodes| {
-                let this = this_action.try_flash_loan_mut().unwrap();
-                let mut nodes_to_prune = Vec::new();
-                let mut repay_transfers = Vec::new();
-
-                for (index, action) in child_nodes.into_iter() {
-                    match &action {
-                        Action::Swap(_) | Action::SwapWithFee(_) | Action::EthTransfer(_) => {
-                            this.child_actions.push(action);
-                            nodes_to_prune.push(index);
-                        }
-                        Action::Transfer(t) => {
-                            if t.from == this.receiver_contract && this.pool == t.to {
-                                if let Some(i) = this.assets.iter().position(|x| *x == t.token) {
-                                    if t.amount >= this.amounts[i] {
-                                        repay_transfers.push(t.clone());
-                                        nodes_to_prune.push(index);
-                                        continue
-                                    }
-                                }
-                            }
-                            this.child_actions.push(action);
+            parse_fn:            Box::new(parse_fee_free_flash_loan),
+        })
+    }
+}
+
+/// Nests the child actions of a flash loan that charges no fee, the loan is
+/// repaid by transferring at least the borrowed amount back to the pool
+pub(crate) fn parse_fee_free_flash_loan(
+    this_action: &mut Action,
+    child_nodes: Vec<(NodeDataIndex, Action)>,
+) -> Vec<NodeDataIndex> {
+    let this = this_action.try_flash_loan_mut().unwrap();
+    let mut nodes_to_prune = Vec::new();
+    let mut repay_transfers = Vec::new();
+
+    for (index, action) in child_nodes.into_iter() {
+        match &action {
+            Action::Swap(_) | Action::SwapWithFee(_) | Action::EthTransfer(_) => {
+                this.child_actions.push(action);
+                nodes_to_prune.push(index);
+            }
+            Action::Transfer(t) => {
+                if t.from == this.receiver_contract && this.pool == t.to {
+                    if let Some(i) = this.assets.iter().position(|x| *x == t.token) {
+                        if t.amount >= this.amounts[i] {
+                            repay_transfers.push(t.clone());
                             nodes_to_prune.push(index);
-                        }
-                        _ => {
-                            warn!("Balancer V2 flashloan, unknown call");
                             continue
                         }
                     }
                 }
+                this.child_actions.push(action);
+                nodes_to_prune.push(index);
+            }
+            _ => {
+                warn!("{} flashloan, unknown call", this.protocol);
+                continue
+            }
+        }
+    }
+
+    // no fee
+    this.fees_paid = vec![];
+    this.repayments = repay_transfers;
+
+    nodes_to_prune
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Address, U256};
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::{NormalizedFlashLoan, NormalizedSwap, NormalizedTransfer},
+    };
+    use malachite::Rational;
+
+    use super::*;
 
-                // no fee
-                this.fees_paid = vec![];
-                this.repayments = repay_transfers;
+    const MORPHO: Address = Address::with_last_byte(1);
+    const RECEIVER: Address = Address::with_last_byte(2);
+    const USDC: Address = Address::with_last_byte(3);
 
-                nodes_to_prune
-            }),
+    fn index(trace_index: u64) -> NodeDataIndex {
+        NodeDataIndex { trace_index, data_idx: 0, multi_data_idx: 0 }
+    }
+
+    fn usdc() -> TokenInfoWithAddress {
+        TokenInfoWithAddress { address: USDC, ..Default::default() }
+    }
+
+    fn transfer(trace_index: u64, from: Address, to: Address, amount: u64) -> Action {
+        Action::Transfer(NormalizedTransfer {
+            trace_index,
+            from,
+            to,
+            token: usdc(),
+            amount: Rational::from(amount),
+            ..Default::default()
         })
     }
+
+    #[test]
+    fn test_fee_free_flash_loan() {
+        let mut this = Action::FlashLoan(NormalizedFlashLoan {
+            protocol:          Protocol::MorphoBlue,
+            trace_index:       0,
+            from:              RECEIVER,
+            pool:              MORPHO,
+            receiver_contract: RECEIVER,
+            assets:            vec![usdc()],
+            amounts:           vec![Rational::from(1_000)],
+            aave_mode:         None,
+            child_actions:     vec![],
+            repayments:        vec![],
+            fees_paid:         vec![],
+            msg_value:         U256::ZERO,
+        });
+
+        let pruned = parse_fee_free_flash_loan(
+            &mut this,
+            vec![
+                (index(1), transfer(1, MORPHO, RECEIVER, 1_000)),
+                (index(2), Action::Swap(NormalizedSwap { trace_index: 2, ..Default::default() })),
+                (index(3), transfer(3, RECEIVER, MORPHO, 1_000)),
+            ],
+        );
+
+        let Action::FlashLoan(loan) = this else { unreachable!() };
+        assert_eq!(pruned.len(), 3);
+        assert_eq!(loan.child_actions.len(), 2);
+        assert_eq!(loan.repayments.len(), 1);
+        assert_eq!(loan.repayments[0].trace_index, 3);
+        assert!(loan.fees_paid.is_empty());
+    }
 }
@@ -0,0 +1,30 @@
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest,
+    },
+    Protocol, TreeSearchBuilder,
+};
+
+use super::balancer::parse_fee_free_flash_loan;
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+pub struct MorphoBlue;
+
+impl MultiCallFrameClassifier for MorphoBlue {
+    const KEY: [u8; 2] = [Protocol::MorphoBlue as u8, MultiFrameAction::FlashLoan as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        // the loan is pulled back from the receiver once the callback returns
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_actions([
+                Action::is_swap,
+                Action::is_transfer,
+                Action::is_eth_transfer,
+            ]),
+            parse_fn:            Box::new(parse_fee_free_flash_loan),
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use alloy_primitives::U256;
+use alloy_sol_types::SolCall;
+use brontes_types::{
+    normalized_actions::{
+        Action, MultiCallFrameClassification, MultiFrameAction, MultiFrameRequest, NodeDataIndex,
+    },
+    structured_trace::TraceActions,
+    Protocol, ToScaledRational, TreeSearchBuilder,
+};
+
+use crate::multi_frame_classification::MultiCallFrameClassifier;
+
+/// Decimals of the oracle value and of the par price of dai
+const WAD: u8 = 18;
+const RAY: u8 = 27;
+
+alloy_sol_types::sol!(
+    function peek() external view returns (bytes32, bool);
+    function par() external view returns (uint256);
+);
+
+pub struct MakerDog;
+
+impl MultiCallFrameClassifier for MakerDog {
+    const KEY: [u8; 2] = [Protocol::MakerDog as u8, MultiFrameAction::Liquidation as u8];
+
+    fn create_classifier(
+        request: MultiFrameRequest,
+    ) -> Option<MultiCallFrameClassification<Action>> {
+        Some(MultiCallFrameClassification {
+            trace_index:         request.trace_idx,
+            tree_search_builder: TreeSearchBuilder::new().with_action(Action::is_static_call),
+            parse_fn:            Box::new(parse_bark),
+        })
+    }
+}
+
+/// When kicked, the clipper prices the collateral at the value it peeks on the
+/// oracle of the ilk divided by the par price of dai, both read with static
+/// calls under the bark
+fn parse_bark(this: &mut Action, child_nodes: Vec<(NodeDataIndex, Action)>) -> Vec<NodeDataIndex> {
+    let this = this.try_liquidation_mut().unwrap();
+
+    let mut peeked = None;
+    let mut par = None;
+    for (_, action) in child_nodes {
+        let Action::Unclassified(trace) = action else { continue };
+        let calldata = trace.get_calldata();
+
+        if calldata.starts_with(&peekCall::SELECTOR) {
+            peeked = peekCall::abi_decode_returns(&trace.get_return_calldata(), false)
+                .ok()
+                .map(|res| U256::from_be_bytes(res._0.0));
+        } else if calldata.starts_with(&parCall::SELECTOR) {
+            par = parCall::abi_decode_returns(&trace.get_return_calldata(), false)
+                .ok()
+                .map(|res| res._0);
+        }
+    }
+
+    if let (Some(peeked), Some(par)) = (peeked, par.filter(|par| !par.is_zero())) {
+        this.oracle_price = Some(peeked.to_scaled_rational(WAD) / par.to_scaled_rational(RAY));
+    }
+
+    vec![]
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Bytes, FixedBytes, U64};
+    use brontes_types::{
+        normalized_actions::{LiquidationKind, NormalizedLiquidation},
+        structured_trace::TransactionTraceWithLogs,
+        Protocol,
+    };
+    use malachite::Rational;
+    use reth_primitives::Address;
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, CallAction, CallOutput, CallType, TraceOutput, TransactionTrace,
+    };
+
+    use super::*;
+
+    fn static_call(trace_idx: u64, input: Vec<u8>, output: Vec<u8>) -> (NodeDataIndex, Action) {
+        let trace = TransactionTraceWithLogs {
+            trace: TransactionTrace {
+                action:        TraceAction::Call(CallAction {
+                    from:      Address::with_last_byte(1),
+                    to:        Address::with_last_byte(2),
+                    value:     U256::ZERO,
+                    gas:       Default::default(),
+                    input:     Bytes::from(input),
+                    call_type: CallType::StaticCall,
+                }),
+                error:         None,
+                result:        Some(TraceOutput::Call(CallOutput {
+                    gas_used: U64::ZERO,
+                    output:   Bytes::from(output),
+                })),
+                subtraces:     0,
+                trace_address: Vec::new(),
+            },
+            logs: vec![],
+            msg_sender: Address::with_last_byte(1),
+            trace_idx,
+            decoded_data: None,
+        };
+
+        (
+            NodeDataIndex { trace_index: trace_idx, data_idx: 0, multi_data_idx: 0 },
+            Action::Unclassified(trace),
+        )
+    }
+
+    fn bark() -> Action {
+        Action::Liquidation(NormalizedLiquidation {
+            protocol: Protocol::MakerDog,
+            kind: LiquidationKind::Absorbed,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_bark_oracle_price() {
+        // the oracle values the collateral at 2500 usd and dai is at a par of 1.25 usd
+        let peeked = FixedBytes::from(U256::from(2_500u64) * U256::from(10u64).pow(U256::from(18)));
+        let par = U256::from(125u64) * U256::from(10u64).pow(U256::from(25));
+
+        let mut this = bark();
+        let pruned = parse_bark(
+            &mut this,
+            vec![
+                static_call(
+                    2,
+                    peekCall {}.abi_encode(),
+                    peekCall::abi_encode_returns(&(peeked, true)),
+                ),
+                static_call(3, parCall {}.abi_encode(), parCall::abi_encode_returns(&(par,))),
+            ],
+        );
+
+        assert!(pruned.is_empty());
+        assert_eq!(this.force_liquidation().oracle_price, Some(Rational::from(2_000)));
+    }
+
+    #[test]
+    fn test_bark_without_oracle_reads() {
+        let mut this = bark();
+        parse_bark(&mut this, vec![]);
+
+        assert_eq!(this.force_liquidation().oracle_price, None);
+    }
+}
@@ -9,12 +9,15 @@ pub mod batch;
 pub mod flash_loan;
 pub mod liquidations;
 
-use aggregator::{OneInchAggregator, OneInchFusion, ZeroXAgg};
+use aggregator::{
+    BalancerV3, KyberSwapAgg, OdosAgg, OneInchAggregator, OneInchFusion, ParaswapAgg, UniswapV4,
+    ZeroXAgg, ZeroXSettlerAgg,
+};
 use batch::{Cowswap, UniswapX, ZeroXBatch};
 use brontes_types::normalized_actions::{Action, MultiCallFrameClassification, MultiFrameRequest};
-use flash_loan::{BalancerV2, MakerDss};
+use flash_loan::{BalancerV2, MakerDss, MorphoBlue};
 use itertools::Itertools;
-use liquidations::{AaveV2, AaveV3};
+use liquidations::{AaveV2, AaveV3, MakerDog};
 use tracing::debug;
 
 use self::flash_loan::Dodo;
@@ -44,10 +47,18 @@ pub fn parse_multi_frame_requests(
             BalancerV2::KEY => BalancerV2::create_classifier(request),
             AaveV2::KEY => AaveV2::create_classifier(request),
             AaveV3::KEY => AaveV3::create_classifier(request),
+            MakerDog::KEY => MakerDog::create_classifier(request),
             ZeroXAgg::KEY => ZeroXAgg::create_classifier(request),
+            ZeroXSettlerAgg::KEY => ZeroXSettlerAgg::create_classifier(request),
+            ParaswapAgg::KEY => ParaswapAgg::create_classifier(request),
+            KyberSwapAgg::KEY => KyberSwapAgg::create_classifier(request),
+            OdosAgg::KEY => OdosAgg::create_classifier(request),
             ZeroXBatch::KEY => ZeroXBatch::create_classifier(request),
             MakerDss::KEY => MakerDss::create_classifier(request),
+            MorphoBlue::KEY => MorphoBlue::create_classifier(request),
             Dodo::KEY => Dodo::create_classifier(request),
+            UniswapV4::KEY => UniswapV4::create_classifier(request),
+            BalancerV3::KEY => BalancerV3::create_classifier(request),
             _ => {
                 debug!(?request, "no multi frame classification impl for this request");
                 None
@@ -6,7 +6,7 @@ use std::{
     },
 };
 
-use alloy_primitives::{Address, TxHash};
+use alloy_primitives::{Address, Bytes, Log, TxHash, U256, U64};
 use brontes_core::{
     decoding::TracingProvider, BlockTracesWithHeaderAnd, TraceLoader, TraceLoaderError,
     TxTracesWithHeaderAnd,
@@ -24,12 +24,15 @@ use brontes_types::{
         address_to_protocol_info::ProtocolInfo, dex::DexQuotes, token_info::TokenInfoWithAddress,
     },
     normalized_actions::{pool::NormalizedNewPool, NormalizedTransfer},
-    structured_trace::TraceActions,
+    structured_trace::{TraceActions, TransactionTraceWithLogs},
     tree::BlockTree,
     BrontesTaskManager, FastHashMap, TreeCollector, TreeSearchBuilder, UnboundedYapperReceiver,
 };
 use futures::{future::join_all, StreamExt};
 use reth_db::DatabaseError;
+use reth_rpc_types::trace::parity::{
+    Action as TraceAction, CallAction, CallOutput, CallType, TraceOutput, TransactionTrace,
+};
 use serde_json::Value;
 use thiserror::Error;
 use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
@@ -605,6 +608,46 @@ impl ClassifierTestUtils {
         Ok(())
     }
 
+    /// Classifies a single call frame that is built by the test instead of
+    /// loaded from a recorded transaction. The called contract has to be
+    /// added with [`Self::ensure_protocol`] first
+    pub fn classify_call(
+        &self,
+        from: Address,
+        to: Address,
+        input: Vec<u8>,
+        output: Vec<u8>,
+        logs: Vec<Log>,
+    ) -> Option<Action> {
+        let trace = TransactionTraceWithLogs {
+            trace: TransactionTrace {
+                action:        TraceAction::Call(CallAction {
+                    from,
+                    to,
+                    value: U256::ZERO,
+                    gas: Default::default(),
+                    input: Bytes::from(input),
+                    call_type: CallType::Call,
+                }),
+                error:         None,
+                result:        Some(TraceOutput::Call(CallOutput {
+                    gas_used: U64::ZERO,
+                    output:   Bytes::from(output),
+                })),
+                subtraces:     0,
+                trace_address: Vec::new(),
+            },
+            logs,
+            msg_sender: from,
+            trace_idx: 0,
+            decoded_data: None,
+        };
+
+        ProtocolClassifier::default()
+            .dispatch(trace.get_callframe_info(), self.trace_loader.libmdbx, 0, 0)
+            .map(|(_, action)| action)
+    }
+
     pub fn ensure_protocol(
         &self,
         protocol: Protocol,
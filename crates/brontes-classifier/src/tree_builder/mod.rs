@@ -33,7 +33,7 @@ use tracing::{error, trace};
 use tree_pruning::{account_for_tax_tokens, remove_possible_transfer_double_counts};
 use utils::{decode_transfer, get_coinbase_transfer};
 
-use self::erc20::try_decode_transfer;
+use self::{erc20::try_decode_transfer, nft::try_decode_nft_transfers};
 use crate::{
     classifiers::*, multi_frame_classification::parse_multi_frame_requests, ActionCollection,
     FactoryDiscoveryDispatch,
@@ -152,6 +152,7 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
 
                     let address = root_trace.get_from_addr();
                     let trace_idx = root_trace.trace_idx;
+                    let (to, calldata) = (root_trace.get_to_address(), root_trace.get_calldata());
 
                     let classification = self
                         .process_classification(
@@ -178,6 +179,8 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
                         position: tx_idx,
                         head: node,
                         tx_hash: trace.tx_hash,
+                        to,
+                        calldata,
                         private: false,
                         total_msg_value_transfers,
                         gas_details: GasDetails {
@@ -405,6 +408,12 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
             }
 
             (vec![results.0], vec![results.1])
+        } else if let Some(registered) =
+            self.classify_registration(block, trace_index, &trace).await
+        {
+            return registered
+        } else if let Some(transfers) = self.classify_nft_transfers(trace_index, &trace) {
+            return transfers
         } else if let Some(transfer) = self
             .classify_transfer(tx_idx, trace_index, &trace, block)
             .await
@@ -420,6 +429,35 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         }
     }
 
+    /// Contracts registered on an existing contract, such as the clipper of a
+    /// maker collateral type filed on the dog, are discovered from the call
+    /// that registers them
+    async fn classify_registration(
+        &self,
+        block: u64,
+        trace_index: u64,
+        trace: &TransactionTraceWithLogs,
+    ) -> Option<(Vec<DexPriceMsg>, Vec<Action>)> {
+        let registered = RegistrationDiscoveryClassifier::default()
+            .dispatch(
+                self.provider.clone(),
+                vec![(trace.get_to_address(), trace.get_calldata())],
+                trace.get_to_address(),
+                trace_index,
+            )
+            .await;
+
+        if registered.is_empty() {
+            return None
+        }
+
+        for pool in &registered {
+            self.insert_new_pool(block, pool).await;
+        }
+
+        Some((vec![], registered.into_iter().map(Action::NewPool).collect()))
+    }
+
     async fn classify_transfer(
         &self,
         tx_idx: u64,
@@ -525,6 +563,40 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
         }
     }
 
+    /// ERC721 `transferFrom` shares its selector with the ERC20 one, so nft
+    /// transfers are classified from their logs before trying to decode the
+    /// call as an erc20 transfer
+    fn classify_nft_transfers(
+        &self,
+        trace_idx: u64,
+        trace: &TransactionTraceWithLogs,
+    ) -> Option<(Vec<DexPriceMsg>, Vec<Action>)> {
+        if trace.is_delegate_call() {
+            return None
+        };
+
+        let mut result = try_decode_nft_transfers(trace_idx, trace)
+            .into_iter()
+            .map(Action::NftTransfer)
+            .collect_vec();
+
+        if result.is_empty() {
+            return None
+        }
+
+        if trace.get_msg_value() != U256::ZERO {
+            result.push(Action::EthTransfer(NormalizedEthTransfer {
+                coinbase_transfer: false,
+                trace_index:       trace_idx,
+                to:                trace.get_to_address(),
+                from:              trace.get_from_addr(),
+                value:             trace.get_msg_value(),
+            }));
+        }
+
+        Some((vec![], result))
+    }
+
     fn classify_eth_transfer(
         &self,
         trace: &TransactionTraceWithLogs,
@@ -635,6 +707,17 @@ impl<'db, T: TracingProvider, DB: LibmdbxReader + DBWriter> Classifier<'db, T, D
     }
 
     async fn insert_new_pool(&self, block: u64, pool: &NormalizedNewPool) {
+        if let Some(pool_id) = pool.pool_id {
+            if self
+                .libmdbx
+                .insert_uniswap_v4_pool(pool_id, pool.pool_address)
+                .await
+                .is_err()
+            {
+                error!(?pool_id, "failed to insert the address of the pool id into libmdbx");
+            }
+        }
+
         if self
             .libmdbx
             .insert_pool(block, pool.pool_address, &pool.tokens, None, pool.protocol)
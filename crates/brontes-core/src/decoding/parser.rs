@@ -60,6 +60,24 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
         Some((traces, self.tracer.header_by_number(block_num).await.ok()??))
     }
 
+    /// Loads the traces of a block along with the block info stored for it,
+    /// without going to the node. The header only carries the number and the
+    /// timestamp of the block.
+    pub fn load_block_from_libmdbx(
+        &self,
+        block_num: u64,
+    ) -> Option<(BlockHash, Vec<TxTrace>, Header)> {
+        let mut traces = self.libmdbx.load_trace(block_num).ok()?;
+        traces.sort_by(|a, b| a.tx_index.cmp(&b.tx_index));
+        traces.dedup_by(|a, b| a.tx_index.eq(&b.tx_index));
+
+        let info = self.libmdbx.get_block_info(block_num).ok()?;
+        let header =
+            Header { number: block_num, timestamp: info.block_timestamp, ..Default::default() };
+
+        Some((BlockHash::from(info.block_hash.to_be_bytes::<32>()), traces, header))
+    }
+
     pub async fn trace_clickhouse_block(self, block_num: u64) {
         let parity_trace = self.trace_block(block_num).await;
         let receipts = self.get_receipts(block_num).await;
@@ -98,16 +116,10 @@ impl<T: TracingProvider, DB: LibmdbxReader + DBWriter> TraceParser<T, DB> {
     /// executes the tracing of a given block
     #[allow(unreachable_code)]
     pub async fn execute_block(self, block_num: u64) -> Option<(BlockHash, Vec<TxTrace>, Header)> {
-        if let Some(res) = self.load_block_from_db(block_num).await {
-            tracing::debug!(%block_num, traces_in_block= res.0.len(),"loaded trace for db");
+        if let Some(res) = self.load_block_from_libmdbx(block_num) {
+            tracing::debug!(%block_num, traces_in_block= res.1.len(),"loaded trace for db");
 
-            let block_hash = self.tracer.block_hash_for_id(block_num).await.ok()?;
-
-            if block_hash.is_none() {
-                error!(%block_num, "failed to get block hash for block");
-            }
-
-            return block_hash.map(|b| (b, res.0, res.1))
+            return Some(res)
         }
         #[cfg(not(feature = "local-reth"))]
         {
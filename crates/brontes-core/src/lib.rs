@@ -5,6 +5,7 @@ pub mod executor;
 #[cfg(not(feature = "local-reth"))]
 pub mod local_provider;
 pub mod missing_token_info;
+pub mod offline_provider;
 
 #[cfg(feature = "tests")]
 pub mod test_utils;
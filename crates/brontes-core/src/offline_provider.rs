@@ -0,0 +1,78 @@
+use alloy_rpc_types::AnyReceiptEnvelope;
+use brontes_types::{structured_trace::TxTrace, traits::TracingProvider};
+use reth_primitives::{
+    Address, BlockId, BlockNumber, BlockNumberOrTag, Bytecode, Bytes, Header, StorageValue, TxHash,
+    B256,
+};
+use reth_rpc_types::{
+    state::StateOverride, BlockOverrides, Log, TransactionReceipt, TransactionRequest,
+};
+
+/// A provider for the commands that only run on the data stored in libmdbx.
+/// Every request to the node fails, the work that depends on the chain state,
+/// such as the discovery of pools whose tokens have to be queried, is skipped.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct OfflineProvider;
+
+fn offline<T>() -> eyre::Result<T> {
+    Err(eyre::eyre!("no node is available to the offline provider"))
+}
+
+#[async_trait::async_trait]
+impl TracingProvider for OfflineProvider {
+    async fn eth_call(
+        &self,
+        _: TransactionRequest,
+        _: Option<BlockId>,
+        _: Option<StateOverride>,
+        _: Option<Box<BlockOverrides>>,
+    ) -> eyre::Result<Bytes> {
+        offline()
+    }
+
+    async fn block_hash_for_id(&self, _: u64) -> eyre::Result<Option<B256>> {
+        offline()
+    }
+
+    #[cfg(feature = "local-reth")]
+    fn best_block_number(&self) -> eyre::Result<u64> {
+        offline()
+    }
+
+    #[cfg(not(feature = "local-reth"))]
+    async fn best_block_number(&self) -> eyre::Result<u64> {
+        offline()
+    }
+
+    async fn replay_block_transactions(&self, _: BlockId) -> eyre::Result<Option<Vec<TxTrace>>> {
+        offline()
+    }
+
+    async fn block_receipts(
+        &self,
+        _: BlockNumberOrTag,
+    ) -> eyre::Result<Option<Vec<TransactionReceipt<AnyReceiptEnvelope<Log>>>>> {
+        offline()
+    }
+
+    async fn header_by_number(&self, _: BlockNumber) -> eyre::Result<Option<Header>> {
+        offline()
+    }
+
+    async fn block_and_tx_index(&self, _: TxHash) -> eyre::Result<(u64, usize)> {
+        offline()
+    }
+
+    async fn get_storage(
+        &self,
+        _: Option<u64>,
+        _: Address,
+        _: B256,
+    ) -> eyre::Result<Option<StorageValue>> {
+        offline()
+    }
+
+    async fn get_bytecode(&self, _: Option<u64>, _: Address) -> eyre::Result<Option<Bytecode>> {
+        offline()
+    }
+}
@@ -23,7 +23,7 @@ use brontes_types::{
         searcher::SearcherInfo,
         token_info::{TokenInfo, TokenInfoWithAddress},
     },
-    mev::{Bundle, BundleData, MevBlock},
+    mev::{Bundle, BundleData, DeduplicationStats, MevBlock},
     normalized_actions::Action,
     structured_trace::TxTrace,
     BlockTree, Protocol,
@@ -193,6 +193,18 @@ impl Clickhouse {
                     BundleData::Liquidation(s) => {
                         tx.send(vec![(s, self.tip, self.run_id).into()])?
                     }
+                    BundleData::OracleBackrun(s) => {
+                        tx.send(vec![(s, self.tip, self.run_id).into()])?
+                    }
+                    BundleData::Snipe(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
+                    BundleData::GeneralizedFrontrun(s) => {
+                        tx.send(vec![(s, self.tip, self.run_id).into()])?
+                    }
+                    BundleData::IntentBackrun(s) => {
+                        tx.send(vec![(s, self.tip, self.run_id).into()])?
+                    }
+                    BundleData::NftArb(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
+                    BundleData::Custom(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
                     BundleData::Unknown(s) => tx.send(vec![(s, self.tip, self.run_id).into()])?,
                 };
 
@@ -286,6 +298,24 @@ impl Clickhouse {
         Ok(())
     }
 
+    pub async fn dedup_stats(&self, dedup_stats: DeduplicationStats) -> eyre::Result<()> {
+        if let Some(tx) = self.buffered_insert_tx.as_ref() {
+            let suppressed = dedup_stats
+                .suppressed
+                .iter()
+                .cloned()
+                .map(|bundle| (bundle, self.tip, self.run_id).into())
+                .collect::<Vec<_>>();
+
+            tx.send(vec![(dedup_stats, self.tip, self.run_id).into()])?;
+            if !suppressed.is_empty() {
+                tx.send(suppressed)?;
+            }
+        };
+
+        Ok(())
+    }
+
     pub async fn save_traces(&self, _block: u64, _traces: Vec<TxTrace>) -> eyre::Result<()> {
         Ok(())
     }
@@ -1018,15 +1048,17 @@ mod tests {
         db::{cex::CexExchange, dex::DexPrices, DbDataWithRunId},
         init_thread_pools,
         mev::{
-            ArbDetails, AtomicArb, BundleHeader, CexDex, CexDexQuote, JitLiquidity,
-            JitLiquiditySandwich, Liquidation, OptimisticTrade, PossibleMev, PossibleMevCollection,
-            Sandwich,
+            ArbDetails, AtomicArb, BundleHeader, CexDex, CexDexQuote, CustomMev,
+            GeneralizedFrontrun, IntentBackrun, JitLiquidity, JitLiquiditySandwich, Liquidation,
+            MevType, NftArb, OptimisticTrade, OracleBackrun, PossibleMev, PossibleMevCollection,
+            Sandwich, Snipe, SuppressedBundle,
         },
         normalized_actions::{
-            NormalizedBurn, NormalizedLiquidation, NormalizedMint, NormalizedSwap,
+            NormalizedBurn, NormalizedLiquidation, NormalizedMint, NormalizedNftTrade,
+            NormalizedSwap,
         },
         pair::Pair,
-        FastHashMap, GasDetails,
+        FastHashMap, GasDetails, Protocol,
     };
     use db_interfaces::{
         clickhouse::{dbms::ClickhouseDBMS, test_utils::ClickhouseTestClient},
@@ -1314,6 +1346,118 @@ mod tests {
             .unwrap();
     }
 
+    async fn oracle_backruns(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let swap = NormalizedSwap::default();
+        let liquidation = NormalizedLiquidation::default();
+        let gas_details = GasDetails::default();
+        let case0 = OracleBackrun {
+            swaps: vec![swap],
+            liquidations: vec![liquidation],
+            gas_details,
+            ..OracleBackrun::default()
+        };
+
+        db.insert_one::<MevOracle_Backruns>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn snipes(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let swap = NormalizedSwap::default();
+        let gas_details = GasDetails::default();
+        let case0 = Snipe { snipe_swaps: vec![swap], gas_details, ..Snipe::default() };
+
+        db.insert_one::<MevSnipes>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn generalized_frontruns(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let swap = NormalizedSwap::default();
+        let gas_details = GasDetails::default();
+        let case0 = GeneralizedFrontrun {
+            frontrun_swaps: vec![swap.clone()],
+            frontrun_gas_details: gas_details,
+            victim_swaps: vec![swap],
+            victim_gas_details: gas_details,
+            ..GeneralizedFrontrun::default()
+        };
+
+        db.insert_one::<MevGeneralized_Frontruns>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn intent_backruns(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let swap = NormalizedSwap::default();
+        let gas_details = GasDetails::default();
+        let case0 = IntentBackrun {
+            solver_swaps: vec![swap.clone()],
+            settlement_gas_details: gas_details,
+            backrun_swaps: vec![swap],
+            backrun_gas_details: gas_details,
+            ..IntentBackrun::default()
+        };
+
+        db.insert_one::<MevIntent_Backruns>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn nft_arbs(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let trade = NormalizedNftTrade::default();
+        let case0 = NftArb {
+            buys: vec![trade.clone()],
+            sells: vec![trade],
+            gas_details: GasDetails::default(),
+            ..NftArb::default()
+        };
+
+        db.insert_one::<MevNft_Arbs>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn custom_mev(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = CustomMev {
+            inspector: "Custom".to_string(),
+            tx_hashes: vec![Default::default()],
+            gas_details: vec![GasDetails::default()],
+            protocols: vec![Protocol::UniswapV2],
+            data: "{}".to_string(),
+            ..CustomMev::default()
+        };
+
+        db.insert_one::<MevCustom_Mev>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn suppressed_bundles(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = SuppressedBundle {
+            block_number:      0,
+            tx_hash:           Default::default(),
+            mev_type:          MevType::AtomicArb,
+            inspector:         None,
+            profit_usd:        0.0,
+            bribe_usd:         0.0,
+            dominant_tx_hash:  Default::default(),
+            dominant_mev_type: MevType::Sandwich,
+        };
+
+        db.insert_one::<MevSuppressed_Bundles>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
+    async fn dedup_stats(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
+        let case0 = DeduplicationStats::default();
+
+        db.insert_one::<MevDedup_Stats>(&DbDataWithRunId::new_with_run_id(case0, 0))
+            .await
+            .unwrap();
+    }
+
     async fn bundle_header(db: &ClickhouseTestClient<BrontesClickhouseTables>) {
         let case0 = BundleHeader::default();
 
@@ -1331,6 +1475,7 @@ mod tests {
             frontrun_swaps: vec![vec![swap0]],
             victim_swaps: vec![vec![swap1]],
             victim_swaps_gas_details: vec![gas_details],
+            victim_loss_usd: vec![Some(0.0)],
             backrun_swaps: vec![swap2],
             ..Sandwich::default()
         };
@@ -1402,6 +1547,14 @@ mod tests {
         sandwich(database).await;
         bundle_header(database).await;
         liquidations(database).await;
+        oracle_backruns(database).await;
+        snipes(database).await;
+        generalized_frontruns(database).await;
+        intent_backruns(database).await;
+        nft_arbs(database).await;
+        custom_mev(database).await;
+        suppressed_bundles(database).await;
+        dedup_stats(database).await;
         jit_sandwich(database).await;
         jit(database).await;
         cex_dex(database).await;
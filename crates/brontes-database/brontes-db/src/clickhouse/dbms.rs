@@ -20,6 +20,14 @@ clickhouse_dbms!(
         MevCex_Dex_Quotes,
         MevCex_Dex,
         MevLiquidations,
+        MevOracle_Backruns,
+        MevSnipes,
+        MevGeneralized_Frontruns,
+        MevIntent_Backruns,
+        MevNft_Arbs,
+        MevCustom_Mev,
+        MevSuppressed_Bundles,
+        MevDedup_Stats,
         MevJit_Sandwich,
         MevJit,
         MevSandwiches,
@@ -97,6 +105,62 @@ remote_clickhouse_table!(
     "crates/brontes-database/brontes-db/src/clickhouse/tables/"
 );
 
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Oracle_Backruns],
+    DbDataWithRunId<OracleBackrun>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Snipes],
+    DbDataWithRunId<Snipe>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Generalized_Frontruns],
+    DbDataWithRunId<GeneralizedFrontrun>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Intent_Backruns],
+    DbDataWithRunId<IntentBackrun>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Nft_Arbs],
+    DbDataWithRunId<NftArb>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Custom_Mev],
+    DbDataWithRunId<CustomMev>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Suppressed_Bundles],
+    DbDataWithRunId<SuppressedBundle>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
+remote_clickhouse_table!(
+    BrontesClickhouseTables,
+    [Mev, Dedup_Stats],
+    DbDataWithRunId<DeduplicationStats>,
+    "crates/brontes-database/brontes-db/src/clickhouse/tables/"
+);
+
 remote_clickhouse_table!(
     BrontesClickhouseTables,
     [Mev, Jit_Sandwich],
@@ -236,6 +300,14 @@ db_types!(
     (CexDex, MevCex_Dex, true),
     (CexDexQuote, MevCex_Dex_Quotes, true),
     (Liquidation, MevLiquidations, true),
+    (OracleBackrun, MevOracle_Backruns, true),
+    (Snipe, MevSnipes, true),
+    (GeneralizedFrontrun, MevGeneralized_Frontruns, true),
+    (IntentBackrun, MevIntent_Backruns, true),
+    (NftArb, MevNft_Arbs, true),
+    (CustomMev, MevCustom_Mev, true),
+    (SuppressedBundle, MevSuppressed_Bundles, true),
+    (DeduplicationStats, MevDedup_Stats, true),
     (JitLiquiditySandwich, MevJit_Sandwich, true),
     (JitLiquidity, MevJit, true),
     (Sandwich, MevSandwiches, true),
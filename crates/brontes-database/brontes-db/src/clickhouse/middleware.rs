@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use brontes_types::{
     db::{
         address_metadata::AddressMetadata,
@@ -8,13 +8,14 @@ use brontes_types::{
         block_analysis::BlockAnalysis,
         builder::BuilderInfo,
         dex::DexQuotes,
-        metadata::Metadata,
+        metadata::{BlockMetadataInner, Metadata},
         mev_block::MevBlockWithClassified,
+        pricing_checkpoint::PricingGraphCheckpoint,
         searcher::SearcherInfo,
         token_info::TokenInfoWithAddress,
         traits::{DBWriter, LibmdbxReader, ProtocolCreatedRange},
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, DeduplicationStats, MevBlock},
     normalized_actions::Action,
     pair::Pair,
     structured_trace::TxTrace,
@@ -166,6 +167,12 @@ impl<I: DBWriter + Send + Sync> DBWriter for ClickhouseMiddleware<I> {
 
         self.inner().save_traces(block, traces).await
     }
+
+    async fn write_dedup_stats(&self, dedup_stats: DeduplicationStats) -> eyre::Result<()> {
+        self.client.dedup_stats(dedup_stats.clone()).await?;
+
+        self.inner().write_dedup_stats(dedup_stats).await
+    }
 }
 
 impl<I: LibmdbxInit> LibmdbxInit for ClickhouseMiddleware<I> {
@@ -337,6 +344,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
         self.inner.get_metadata(block_num, quote_asset)
     }
 
+    fn get_block_info(&self, block_num: u64) -> eyre::Result<BlockMetadataInner> {
+        self.inner.get_block_info(block_num)
+    }
+
     fn try_fetch_address_metadata(
         &self,
         address: Address,
@@ -385,6 +396,31 @@ impl<I: LibmdbxInit> LibmdbxReader for ClickhouseMiddleware<I> {
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
+
+    fn try_fetch_pricing_checkpoint(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Option<(u64, PricingGraphCheckpoint)>> {
+        self.inner
+            .try_fetch_pricing_checkpoint(start_block, end_block)
+    }
+
+    fn first_block_without_mev(&self, start_block: u64, end_block: u64) -> eyre::Result<u64> {
+        self.inner.first_block_without_mev(start_block, end_block)
+    }
+
+    fn fetch_dedup_stats_range(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<DeduplicationStats>> {
+        self.inner.fetch_dedup_stats_range(start_block, end_block)
+    }
+
+    fn try_fetch_uniswap_v4_pool(&self, pool_id: B256) -> eyre::Result<Address> {
+        self.inner.try_fetch_uniswap_v4_pool(pool_id)
+    }
 }
 
 pub struct ReadOnlyMiddleware<I: DBWriter> {
@@ -494,6 +530,36 @@ impl<I: DBWriter + Send + Sync> DBWriter for ReadOnlyMiddleware<I> {
     async fn save_traces(&self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
         self.client.save_traces(block, traces.clone()).await
     }
+
+    /// checkpoints are only kept locally
+    async fn write_pricing_checkpoint(
+        &self,
+        block_number: u64,
+        checkpoint: PricingGraphCheckpoint,
+    ) -> eyre::Result<()> {
+        self.inner
+            .write_pricing_checkpoint(block_number, checkpoint)
+            .await
+    }
+
+    async fn prune_pricing_checkpoints(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<()> {
+        self.inner
+            .prune_pricing_checkpoints(start_block, end_block)
+            .await
+    }
+
+    async fn write_dedup_stats(&self, dedup_stats: DeduplicationStats) -> eyre::Result<()> {
+        self.client.dedup_stats(dedup_stats).await
+    }
+
+    /// the ids of v4 pools are only kept locally
+    async fn insert_uniswap_v4_pool(&self, pool_id: B256, address: Address) -> eyre::Result<()> {
+        self.inner.insert_uniswap_v4_pool(pool_id, address).await
+    }
 }
 
 impl<I: LibmdbxInit> LibmdbxInit for ReadOnlyMiddleware<I> {
@@ -659,6 +725,10 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
         self.inner.get_metadata(block_num, quote_asset)
     }
 
+    fn get_block_info(&self, block_num: u64) -> eyre::Result<BlockMetadataInner> {
+        self.inner.get_block_info(block_num)
+    }
+
     fn try_fetch_address_metadata(
         &self,
         address: Address,
@@ -707,4 +777,29 @@ impl<I: LibmdbxInit> LibmdbxReader for ReadOnlyMiddleware<I> {
     fn load_trace(&self, block_num: u64) -> eyre::Result<Vec<TxTrace>> {
         self.inner.load_trace(block_num)
     }
+
+    fn try_fetch_pricing_checkpoint(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Option<(u64, PricingGraphCheckpoint)>> {
+        self.inner
+            .try_fetch_pricing_checkpoint(start_block, end_block)
+    }
+
+    fn first_block_without_mev(&self, start_block: u64, end_block: u64) -> eyre::Result<u64> {
+        self.inner.first_block_without_mev(start_block, end_block)
+    }
+
+    fn fetch_dedup_stats_range(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<DeduplicationStats>> {
+        self.inner.fetch_dedup_stats_range(start_block, end_block)
+    }
+
+    fn try_fetch_uniswap_v4_pool(&self, pool_id: B256) -> eyre::Result<Address> {
+        self.inner.try_fetch_uniswap_v4_pool(pool_id)
+    }
 }
@@ -127,6 +127,14 @@ impl ClickhouseBuffered {
             (MevSandwiches, Sandwich),
             (MevAtomic_Arbs, AtomicArb),
             (MevLiquidations, Liquidation),
+            (MevOracle_Backruns, OracleBackrun),
+            (MevSnipes, Snipe),
+            (MevGeneralized_Frontruns, GeneralizedFrontrun),
+            (MevIntent_Backruns, IntentBackrun),
+            (MevNft_Arbs, NftArb),
+            (MevCustom_Mev, CustomMev),
+            (MevSuppressed_Bundles, SuppressedBundle),
+            (MevDedup_Stats, DeduplicationStats),
             (BrontesDex_Price_Mapping, DexQuotesWithBlockNumber),
             (BrontesToken_Info, TokenInfoWithAddress),
             (EthereumPools, ProtocolInfoClickhouse),
@@ -7,6 +7,7 @@ CREATE TABLE mev.bundle_header ON CLUSTER eth_cluster0
     `mev_contract` Nullable(String),
     `fund` String,
     `profit_usd` Float64,
+    `profit_usd_spread` Nullable(Float64),
     `bribe_usd` Float64,
     `mev_type` String,
     `no_pricing_calculated` Bool DEFAULT false,
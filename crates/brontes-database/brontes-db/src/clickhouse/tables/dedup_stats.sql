@@ -0,0 +1,12 @@
+CREATE TABLE mev.dedup_stats ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `inspected_bundles` UInt64,
+    `reported_bundles` UInt64,
+    `suppressed_bundles` UInt64,
+    `suppressed_profit_usd` Float64,
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/dedup_stats', '{replica}')
+PRIMARY KEY (`block_number`)
+ORDER BY (`block_number`)
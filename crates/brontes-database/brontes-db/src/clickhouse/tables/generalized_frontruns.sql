@@ -0,0 +1,47 @@
+CREATE TABLE mev.generalized_frontruns ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `frontrun_tx_hash` String,
+    `frontrun_tx_index` UInt64,
+    `frontrun_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `frontrun_gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `victim_tx_hash` String,
+    `victim_tx_index` UInt64,
+    `victim_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `victim_gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `victim_reverted` Bool,
+    `victim_profit_usd` Float64,
+    `shared_targets` Array(String),
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/generalized_frontruns', '{replica}')
+PRIMARY KEY (`block_number`,`frontrun_tx_hash`)
+ORDER BY (`block_number`,`frontrun_tx_hash`)
@@ -0,0 +1,48 @@
+CREATE TABLE mev.intent_backruns ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `settlement_tx_hash` String,
+    `settlement_tx_index` UInt64,
+    `settlement_protocol` String,
+    `solver` String,
+    `solver_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `filler_profit_usd` Float64,
+    `settlement_gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `backrun_tx_hash` String,
+    `backrun_tx_index` UInt64,
+    `backrun_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `backrun_profit_usd` Float64,
+    `backrun_gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/intent_backruns', '{replica}')
+PRIMARY KEY (`block_number`,`backrun_tx_hash`)
+ORDER BY (`block_number`,`backrun_tx_hash`)
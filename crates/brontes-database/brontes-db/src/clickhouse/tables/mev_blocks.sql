@@ -5,13 +5,20 @@ CREATE TABLE mev.mev_blocks ON CLUSTER eth_cluster0
     `mev_count` Nested (
         `mev_count` UInt64,
         `sandwich_count` UInt64,
+        `multi_block_sandwich_count` UInt64,
         `cex_dex_trade_count` UInt64,
         `cex_dex_quote_count` UInt64,
         `cex_dex_rfq_count` UInt64,
         `jit_count` UInt64,
         `jit_sandwich_count` UInt64,
         `atomic_backrun_count` UInt64,
-        `liquidation_count` UInt64
+        `liquidation_count` UInt64,
+        `oracle_backrun_count` UInt64,
+        `sniping_count` UInt64,
+        `generalized_frontrun_count` UInt64,
+        `intent_backrun_count` UInt64,
+        `custom_count` UInt64,
+        `nft_arb_count` UInt64
     ),
     `eth_price` Float64,
     `total_gas_used` UInt128,
@@ -0,0 +1,3 @@
+ALTER TABLE mev.liquidations ON CLUSTER eth_cluster0
+    ADD COLUMN IF NOT EXISTS `liquidations.auction_price` Array(Tuple(UInt256, UInt256)) AFTER `liquidations.liquidated_collateral`,
+    ADD COLUMN IF NOT EXISTS `liquidations.oracle_price` Array(Tuple(UInt256, UInt256)) AFTER `liquidations.auction_price`
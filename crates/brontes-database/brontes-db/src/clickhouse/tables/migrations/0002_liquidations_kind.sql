@@ -0,0 +1,2 @@
+ALTER TABLE mev.liquidations ON CLUSTER eth_cluster0
+    ADD COLUMN IF NOT EXISTS `liquidations.kind` Array(String) AFTER `liquidations.oracle_price`
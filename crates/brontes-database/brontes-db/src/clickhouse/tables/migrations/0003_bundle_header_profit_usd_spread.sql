@@ -0,0 +1,2 @@
+ALTER TABLE mev.bundle_header ON CLUSTER eth_cluster0
+    ADD COLUMN IF NOT EXISTS `profit_usd_spread` Nullable(Float64) AFTER `profit_usd`
@@ -0,0 +1,8 @@
+ALTER TABLE mev.mev_blocks ON CLUSTER eth_cluster0
+    ADD COLUMN IF NOT EXISTS `mev_count.multi_block_sandwich_count` Array(UInt64) AFTER `mev_count.sandwich_count`,
+    ADD COLUMN IF NOT EXISTS `mev_count.oracle_backrun_count` Array(UInt64) AFTER `mev_count.liquidation_count`,
+    ADD COLUMN IF NOT EXISTS `mev_count.sniping_count` Array(UInt64) AFTER `mev_count.oracle_backrun_count`,
+    ADD COLUMN IF NOT EXISTS `mev_count.generalized_frontrun_count` Array(UInt64) AFTER `mev_count.sniping_count`,
+    ADD COLUMN IF NOT EXISTS `mev_count.intent_backrun_count` Array(UInt64) AFTER `mev_count.generalized_frontrun_count`,
+    ADD COLUMN IF NOT EXISTS `mev_count.custom_count` Array(UInt64) AFTER `mev_count.intent_backrun_count`,
+    ADD COLUMN IF NOT EXISTS `mev_count.nft_arb_count` Array(UInt64) AFTER `mev_count.custom_count`
@@ -0,0 +1,2 @@
+ALTER TABLE mev.sandwiches ON CLUSTER eth_cluster0
+    ADD COLUMN IF NOT EXISTS `victim_loss_usd` Array(Nullable(Float64)) AFTER `victim_gas_details.effective_gas_price`
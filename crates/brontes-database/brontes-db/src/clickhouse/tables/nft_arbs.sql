@@ -0,0 +1,36 @@
+CREATE TABLE mev.nft_arbs ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `tx_hash` String,
+    `tx_index` UInt64,
+    `buys` Nested(
+        `trace_idx` UInt64,
+        `marketplace` String,
+        `seller` String,
+        `buyer` String,
+        `collection` String,
+        `token_ids` Array(String),
+        `payment_token` Tuple(String, String),
+        `price` Tuple(UInt256, UInt256)
+    ),
+    `sells` Nested(
+        `trace_idx` UInt64,
+        `marketplace` String,
+        `seller` String,
+        `buyer` String,
+        `collection` String,
+        `token_ids` Array(String),
+        `payment_token` Tuple(String, String),
+        `price` Tuple(UInt256, UInt256)
+    ),
+    `gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/nft_arbs', '{replica}')
+PRIMARY KEY (`block_number`,`tx_hash`)
+ORDER BY (`block_number`,`tx_hash`)
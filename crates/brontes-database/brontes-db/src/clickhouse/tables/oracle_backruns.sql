@@ -0,0 +1,41 @@
+CREATE TABLE mev.oracle_backruns ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `oracle_update_tx_hash` String,
+    `oracle_update_tx_index` UInt64,
+    `oracles` Array(String),
+    `backrun_tx_hash` String,
+    `backrun_tx_index` UInt64,
+    `latency` UInt64,
+    `liquidations` Nested(
+        `trace_idx` UInt64,
+        `pool` String,
+        `liquidator` String,
+        `debtor` String,
+        `collateral_asset` Tuple(String, String),
+        `debt_asset` Tuple(String, String),
+        `covered_debt` Tuple(UInt256, UInt256),
+        `liquidated_collateral` Tuple(UInt256, UInt256)
+    ),
+    `swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `liquidation_tx_hash` Nullable(String),
+    `gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/oracle_backruns', '{replica}')
+PRIMARY KEY (`block_number`,`backrun_tx_hash`)
+ORDER BY (`block_number`,`backrun_tx_hash`)
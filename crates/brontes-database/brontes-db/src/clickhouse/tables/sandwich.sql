@@ -38,6 +38,7 @@ CREATE TABLE mev.sandwiches ON CLUSTER eth_cluster0
         `gas_used` UInt128,
         `effective_gas_price` UInt128
     ),
+    `victim_loss_usd` Array(Nullable(Float64)),
     `backrun_tx_hash` String,
     `backrun_swaps` Nested(
         `tx_hash` String,
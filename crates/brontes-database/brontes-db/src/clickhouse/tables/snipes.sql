@@ -0,0 +1,28 @@
+CREATE TABLE mev.snipes ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `snipe_tx_hash` String,
+    `pools` Array(String),
+    `pool_creation_blocks` Array(UInt64),
+    `snipe_swaps` Nested(
+        `trace_idx` UInt64,
+        `from` String,
+        `recipient` String,
+        `pool` String,
+        `token_in` Tuple(String, String),
+        `token_out` Tuple(String, String),
+        `amount_in` Tuple(UInt256, UInt256),
+        `amount_out` Tuple(UInt256, UInt256)
+    ),
+    `markout_block` UInt64,
+    `gas_details` Tuple(
+        `coinbase_transfer` Nullable(UInt128),
+        `priority_fee` UInt128,
+        `gas_used` UInt128,
+        `effective_gas_price` UInt128
+    ),
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/snipes', '{replica}')
+PRIMARY KEY (`block_number`,`snipe_tx_hash`)
+ORDER BY (`block_number`,`snipe_tx_hash`)
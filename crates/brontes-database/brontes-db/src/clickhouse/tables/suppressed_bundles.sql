@@ -0,0 +1,15 @@
+CREATE TABLE mev.suppressed_bundles ON CLUSTER eth_cluster0
+(
+    `block_number` UInt64,
+    `tx_hash` String,
+    `mev_type` String,
+    `inspector` Nullable(String),
+    `profit_usd` Float64,
+    `bribe_usd` Float64,
+    `dominant_tx_hash` String,
+    `dominant_mev_type` String,
+    `run_id` UInt64
+)
+ENGINE = ReplicatedMergeTree('/clickhouse/eth_cluster0/tables/all/mev/suppressed_bundles', '{replica}')
+PRIMARY KEY (`block_number`, `tx_hash`)
+ORDER BY (`block_number`, `tx_hash`)
@@ -39,7 +39,7 @@ pub fn merge_libmdbx_dbs(
                 CexPrice,
                 CexTrades,
                 BlockInfo,
-                MevBlocks,
+                MevBlocksV2,
                 InitializedState,
                 PoolCreationBlocks,
                 TxTraces,
@@ -49,7 +49,10 @@ pub fn merge_libmdbx_dbs(
                 Builder,
                 AddressToProtocolInfo,
                 TokenDecimals,
-                DexPrice
+                DexPrice,
+                PricingCheckpoints,
+                DedupStats,
+                UniswapV4Pools
                 );
                 total_progress_bar.inc(1);
 
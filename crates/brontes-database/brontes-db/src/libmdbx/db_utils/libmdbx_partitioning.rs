@@ -104,7 +104,8 @@ impl LibmdbxPartitioner {
             SearcherContracts,
             Builder,
             AddressToProtocolInfo,
-            TokenDecimals
+            TokenDecimals,
+            UniswapV4Pools
         );
 
         // because we are just doing read operations. we can do all this in parallel
@@ -129,10 +130,12 @@ impl LibmdbxPartitioner {
                         CexPrice,
                         CexTrades,
                         BlockInfo,
-                        MevBlocks,
+                        MevBlocksV2,
                         InitializedState,
                         PoolCreationBlocks,
-                        TxTraces
+                        TxTraces,
+                        PricingCheckpoints,
+                        DedupStats
                     );
                     // manually dex pricing
                     let r =
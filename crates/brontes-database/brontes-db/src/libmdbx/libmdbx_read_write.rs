@@ -1,6 +1,6 @@
 use std::{ops::RangeInclusive, path::Path, sync::Arc};
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use async_trait::async_trait;
 use brontes_metrics::db_reads::LibmdbxMetrics;
 use brontes_pricing::Protocol;
@@ -20,11 +20,12 @@ use brontes_types::{
         },
         metadata::{BlockMetadata, BlockMetadataInner, Metadata},
         mev_block::MevBlockWithClassified,
+        pricing_checkpoint::PricingGraphCheckpoint,
         searcher::SearcherInfo,
         token_info::{TokenInfo, TokenInfoWithAddress},
         traits::{DBWriter, LibmdbxReader},
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, DeduplicationStats, MevBlock},
     normalized_actions::Action,
     pair::Pair,
     structured_trace::TxTrace,
@@ -460,6 +461,18 @@ impl LibmdbxReader for LibmdbxReadWriter {
         })
     }
 
+    fn try_fetch_uniswap_v4_pool(&self, pool_id: B256) -> eyre::Result<Address> {
+        self.db.view_db(|tx| {
+            tx.get::<UniswapV4Pools>(pool_id)?
+                .map(|pool| pool.0)
+                .ok_or_else(|| eyre::eyre!("entry for key {:?} in UniswapV4Pools", pool_id))
+        })
+    }
+
+    fn get_block_info(&self, block_num: u64) -> eyre::Result<BlockMetadataInner> {
+        self.fetch_block_metadata(block_num)
+    }
+
     #[brontes_macros::metrics_call(ptr=metrics, scope, db_read,"metadata_no_dex_price")]
     fn get_metadata_no_dex_price(
         &self,
@@ -846,7 +859,7 @@ impl LibmdbxReader for LibmdbxReadWriter {
         self.db.export_db(
             start_block,
             |start_key, tx| {
-                let mut cur = tx.cursor_read::<MevBlocks>()?;
+                let mut cur = tx.cursor_read::<MevBlocksV2>()?;
                 if let Some(key) = start_key {
                     let _ = cur.seek(key);
                 } else {
@@ -872,7 +885,7 @@ impl LibmdbxReader for LibmdbxReadWriter {
         self.db.export_db(
             start_block,
             |start_key, tx| {
-                let mut cur = tx.cursor_read::<MevBlocks>()?;
+                let mut cur = tx.cursor_read::<MevBlocksV2>()?;
                 if let Some(key) = start_key {
                     let _ = cur.seek(key);
                 } else {
@@ -937,6 +950,56 @@ impl LibmdbxReader for LibmdbxReadWriter {
             Ok(results)
         })
     }
+
+    #[instrument(level = "trace", skip(self))]
+    fn try_fetch_pricing_checkpoint(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Option<(u64, PricingGraphCheckpoint)>> {
+        self.db.view_db(|tx| {
+            let mut cur = tx.cursor_read::<PricingCheckpoints>()?;
+            // the entry before the first one at or past the end of the range
+            let checkpoint = if cur.seek(end_block)?.is_some() { cur.prev()? } else { cur.last()? };
+
+            Ok(checkpoint.filter(|(block, _)| *block >= start_block && *block < end_block))
+        })
+    }
+
+    #[instrument(level = "trace", skip(self))]
+    fn first_block_without_mev(&self, start_block: u64, end_block: u64) -> eyre::Result<u64> {
+        self.db.view_db(|tx| {
+            let mut cursor = tx.cursor_read::<MevBlocksV2>()?;
+            let mut next = start_block;
+
+            for entry in cursor.walk_range(start_block..end_block)? {
+                if entry?.0 != next {
+                    break
+                }
+                next += 1;
+            }
+
+            Ok(next)
+        })
+    }
+
+    #[instrument(level = "trace", skip(self))]
+    fn fetch_dedup_stats_range(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<DeduplicationStats>> {
+        self.db.view_db(|tx| {
+            let mut cursor = tx.cursor_read::<DedupStats>()?;
+            let mut stats = Vec::new();
+
+            for entry in cursor.walk_range(start_block..end_block)? {
+                stats.push(entry?.1);
+            }
+
+            Ok(stats)
+        })
+    }
 }
 
 impl DBWriter for LibmdbxReadWriter {
@@ -1032,6 +1095,12 @@ impl DBWriter for LibmdbxReadWriter {
             .send(WriterMessage::MevBlocks { block_number, block: Box::new(block), mev }.stamp())?)
     }
 
+    async fn write_dedup_stats(&self, dedup_stats: DeduplicationStats) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::DedupStats(Box::new(dedup_stats)).stamp())?)
+    }
+
     async fn write_dex_quotes(
         &self,
         block_number: u64,
@@ -1042,6 +1111,26 @@ impl DBWriter for LibmdbxReadWriter {
             .send(WriterMessage::DexQuotes { block_number, quotes }.stamp())?)
     }
 
+    async fn write_pricing_checkpoint(
+        &self,
+        block_number: u64,
+        checkpoint: PricingGraphCheckpoint,
+    ) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::PricingCheckpoint { block_number, checkpoint }.stamp())?)
+    }
+
+    async fn prune_pricing_checkpoints(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::PrunePricingCheckpoints { start_block, end_block }.stamp())?)
+    }
+
     async fn write_token_info(
         &self,
         address: Address,
@@ -1094,6 +1183,12 @@ impl DBWriter for LibmdbxReadWriter {
         )?)
     }
 
+    async fn insert_uniswap_v4_pool(&self, pool_id: B256, address: Address) -> eyre::Result<()> {
+        Ok(self
+            .tx
+            .send(WriterMessage::UniswapV4Pool { pool_id, address }.stamp())?)
+    }
+
     async fn save_traces(&self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
         Ok(self
             .tx
@@ -1337,11 +1432,20 @@ impl LibmdbxReadWriter {
     pub fn get_highest_block_number(&self) -> eyre::Result<u64> {
         self.db
             .ro_tx()?
-            .cursor_read::<MevBlocks>()?
+            .cursor_read::<MevBlocksV2>()?
             .last()?
             .map(|v| v.0)
             .ok_or_else(|| eyre::eyre!("no max block found"))
     }
+
+    pub fn get_highest_traced_block(&self) -> eyre::Result<u64> {
+        self.db
+            .ro_tx()?
+            .cursor_read::<TxTraces>()?
+            .last()?
+            .map(|v| v.0)
+            .ok_or_else(|| eyre::eyre!("no traced block found"))
+    }
 }
 
 pub fn determine_eth_prices(
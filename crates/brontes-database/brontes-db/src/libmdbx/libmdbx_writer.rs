@@ -5,7 +5,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use brontes_metrics::db_writer::WriterMetrics;
 use brontes_types::{
     db::{
@@ -16,11 +16,13 @@ use brontes_types::{
         initialized_state::{DATA_PRESENT, DEX_PRICE_FLAG, TRACE_FLAG},
         mev_block::MevBlockWithClassified,
         pool_creation_block::PoolsToAddresses,
+        pricing_checkpoint::PricingGraphCheckpoint,
         searcher::SearcherInfo,
         token_info::TokenInfo,
         traces::TxTracesInner,
+        uniswap_v4_pool::UniswapV4PoolAddress,
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, DeduplicationStats, MevBlock},
     structured_trace::TxTrace,
     FastHashMap, Protocol, UnboundedYapperReceiver,
 };
@@ -64,6 +66,7 @@ pub enum WriterMessage {
         block:        Box<MevBlock>,
         mev:          Vec<Bundle>,
     },
+    DedupStats(Box<DeduplicationStats>),
     SearcherInfo {
         eoa_address:      Address,
         contract_address: Option<Address>,
@@ -93,10 +96,22 @@ pub enum WriterMessage {
         curve_lp_token:  Option<Address>,
         classifier_name: Protocol,
     },
+    UniswapV4Pool {
+        pool_id: B256,
+        address: Address,
+    },
     Traces {
         block:  u64,
         traces: Vec<TxTrace>,
     },
+    PricingCheckpoint {
+        block_number: u64,
+        checkpoint:   PricingGraphCheckpoint,
+    },
+    PrunePricingCheckpoints {
+        start_block: u64,
+        end_block:   u64,
+    },
     Init(InitTables, Arc<Notify>),
 }
 
@@ -211,10 +226,13 @@ init!(
     TxTraces,
     CexTrades,
     DexPrice,
-    MevBlocks,
+    MevBlocksV2,
     SearcherEOAs,
     SearcherContracts,
-    InitializedState
+    InitializedState,
+    PricingCheckpoints,
+    DedupStats,
+    UniswapV4Pools
 );
 
 /// due to libmdbx's 1 write tx limit. it makes sense
@@ -242,6 +260,10 @@ impl LibmdbxWriter {
                 self.insert_pool(block, address, &tokens, curve_lp_token, classifier_name)?;
                 "pool"
             }
+            WriterMessage::UniswapV4Pool { pool_id, address } => {
+                self.insert_uniswap_v4_pool(pool_id, address)?;
+                "uniswapv4pool"
+            }
             WriterMessage::Traces { block, traces } => {
                 self.save_traces(block, traces)?;
                 "traces"
@@ -254,10 +276,22 @@ impl LibmdbxWriter {
                 self.write_token_info(address, decimals, symbol)?;
                 "tokeninfo"
             }
+            WriterMessage::PricingCheckpoint { block_number, checkpoint } => {
+                self.write_pricing_checkpoint(block_number, checkpoint)?;
+                "pricingcheckpoint"
+            }
+            WriterMessage::PrunePricingCheckpoints { start_block, end_block } => {
+                self.prune_pricing_checkpoints(start_block, end_block)?;
+                "prunepricingcheckpoints"
+            }
             WriterMessage::MevBlocks { block_number, block, mev } => {
                 self.save_mev_blocks(block_number, *block, mev)?;
                 "mevblocks"
             }
+            WriterMessage::DedupStats(dedup_stats) => {
+                self.write_dedup_stats(*dedup_stats)?;
+                "dedupstats"
+            }
             WriterMessage::BuilderInfo { builder_address, builder_info } => {
                 self.write_builder_info(builder_address, *builder_info)?;
                 "builderinfo"
@@ -407,16 +441,32 @@ impl LibmdbxWriter {
         block: MevBlock,
         mev: Vec<Bundle>,
     ) -> eyre::Result<()> {
-        let data =
-            MevBlocksData::new(block_number, MevBlockWithClassified { block, mev }).into_key_val();
+        let data = MevBlocksV2Data::new(block_number, MevBlockWithClassified { block, mev })
+            .into_key_val();
+        let (key, value) = Self::convert_into_save_bytes(data);
+
+        let entry = self.insert_queue.entry(Tables::MevBlocksV2).or_default();
+        entry.push((key.to_vec(), value));
+
+        if entry.len() > CLEAR_AM {
+            let data = std::mem::take(entry);
+            self.insert_batched_data::<MevBlocksV2>(data)?;
+        }
+
+        Ok(())
+    }
+
+    #[instrument(target = "libmdbx_read_write::write_dedup_stats", skip_all, level = "warn")]
+    fn write_dedup_stats(&mut self, dedup_stats: DeduplicationStats) -> eyre::Result<()> {
+        let data = DedupStatsData::new(dedup_stats.block_number, dedup_stats).into_key_val();
         let (key, value) = Self::convert_into_save_bytes(data);
 
-        let entry = self.insert_queue.entry(Tables::MevBlocks).or_default();
+        let entry = self.insert_queue.entry(Tables::DedupStats).or_default();
         entry.push((key.to_vec(), value));
 
         if entry.len() > CLEAR_AM {
             let data = std::mem::take(entry);
-            self.insert_batched_data::<MevBlocks>(data)?;
+            self.insert_batched_data::<DedupStats>(data)?;
         }
 
         Ok(())
@@ -467,6 +517,42 @@ impl LibmdbxWriter {
         Ok(())
     }
 
+    #[instrument(target = "libmdbx_read_write::write_pricing_checkpoint", skip_all, level = "warn")]
+    fn write_pricing_checkpoint(
+        &self,
+        block_number: u64,
+        checkpoint: PricingGraphCheckpoint,
+    ) -> eyre::Result<()> {
+        // checkpoints are few and large, so they aren't batched
+        let data = PricingCheckpointsData::new(block_number, checkpoint);
+        self.instrumented_write::<PricingCheckpoints, PricingCheckpointsData>(&[data])?;
+
+        Ok(())
+    }
+
+    #[instrument(
+        target = "libmdbx_read_write::prune_pricing_checkpoints",
+        skip_all,
+        level = "warn"
+    )]
+    fn prune_pricing_checkpoints(&self, start_block: u64, end_block: u64) -> eyre::Result<()> {
+        self.db.update_db(|tx| {
+            let blocks = tx
+                .cursor_write::<PricingCheckpoints>()?
+                .walk_range(start_block..end_block)?
+                .map(|entry| entry.map(|(block, _)| block))
+                .collect::<Result<Vec<_>, _>>()?;
+
+            for block in blocks {
+                tx.delete::<PricingCheckpoints>(block, None)?;
+            }
+
+            Ok::<(), DatabaseError>(())
+        })??;
+
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::insert_pool", skip_all, level = "warn")]
     fn insert_pool(
         &self,
@@ -514,6 +600,17 @@ impl LibmdbxWriter {
         })
     }
 
+    #[instrument(target = "libmdbx_read_write::insert_uniswap_v4_pool", skip_all, level = "warn")]
+    fn insert_uniswap_v4_pool(&self, pool_id: B256, address: Address) -> eyre::Result<()> {
+        self.instrumented_write::<UniswapV4Pools, UniswapV4PoolsData>(&[UniswapV4PoolsData::new(
+            pool_id,
+            UniswapV4PoolAddress(address),
+        )])
+        .expect("libmdbx write failure");
+
+        Ok(())
+    }
+
     #[instrument(target = "libmdbx_read_write::save_traces", skip_all, level = "warn")]
     fn save_traces(&mut self, block: u64, traces: Vec<TxTrace>) -> eyre::Result<()> {
         let data = TxTracesData::new(block, TxTracesInner { traces: Some(traces) }).into_key_val();
@@ -642,8 +739,11 @@ impl LibmdbxWriter {
                     Tables::CexTrades => {
                         self.insert_batched_data::<CexTrades>(values).unwrap();
                     }
-                    Tables::MevBlocks => {
-                        self.insert_batched_data::<MevBlocks>(values).unwrap();
+                    Tables::MevBlocksV2 => {
+                        self.insert_batched_data::<MevBlocksV2>(values).unwrap();
+                    }
+                    Tables::DedupStats => {
+                        self.insert_batched_data::<DedupStats>(values).unwrap();
                     }
                     Tables::TxTraces => {
                         self.insert_batched_data::<TxTraces>(values).unwrap();
@@ -24,11 +24,14 @@ use brontes_types::{
         metadata::{BlockMetadataInner, BlockMetadataInnerRedefined},
         mev_block::{MevBlockWithClassified, MevBlockWithClassifiedRedefined},
         pool_creation_block::{PoolsToAddresses, PoolsToAddressesRedefined},
+        pricing_checkpoint::{PricingGraphCheckpoint, PricingGraphCheckpointRedefined},
         searcher::{SearcherInfo, SearcherInfoRedefined},
         token_info::TokenInfo,
         traces::{TxTracesInner, TxTracesInnerRedefined},
         traits::LibmdbxReader,
+        uniswap_v4_pool::{UniswapV4PoolAddress, UniswapV4PoolAddressRedefined},
     },
+    mev::{DeduplicationStats, DeduplicationStatsRedefined},
     serde_utils::*,
     traits::TracingProvider,
 };
@@ -44,7 +47,7 @@ use crate::{
     parquet::ParquetExporter,
 };
 mod const_sql;
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 //
 // use brontes_types::db::initialized_state::CEX_QUOTES_FLAG;
 //
@@ -58,7 +61,7 @@ use super::{
     CompressedTable,
 };
 
-pub const NUM_TABLES: usize = 14;
+pub const NUM_TABLES: usize = 17;
 
 macro_rules! tables {
     ($($table:ident),*) => {
@@ -252,7 +255,7 @@ impl Tables {
                     )
                     .await
             }
-            Tables::MevBlocks => Ok(()),
+            Tables::MevBlocksV2 => Ok(()),
             Tables::TxTraces => {
                 initializer
                     .initialize_table_from_clickhouse::<TxTraces, TxTracesData>(
@@ -276,7 +279,12 @@ impl Tables {
                     )
                     .await
             }
-            Tables::SearcherEOAs | Tables::SearcherContracts | Tables::InitializedState => Ok(()),
+            Tables::SearcherEOAs
+            | Tables::SearcherContracts
+            | Tables::InitializedState
+            | Tables::PricingCheckpoints
+            | Tables::DedupStats
+            | Tables::UniswapV4Pools => Ok(()),
             _ => unimplemented!("'initialize_table' not implemented for {:?}", self),
         }
     }
@@ -370,10 +378,11 @@ impl Tables {
     {
         match self {
             Self::AddressMeta => exporter.export_address_metadata().await,
-            Self::MevBlocks => exporter.export_mev_blocks().await,
+            Self::MevBlocksV2 => exporter.export_mev_blocks().await,
             Self::SearcherContracts | Self::SearcherEOAs => exporter.export_searcher_info().await,
             Self::Builder => exporter.export_builder_info().await,
             Self::DexPrice => exporter.export_dex_prices().await,
+            Self::DedupStats => exporter.export_dedup_stats().await,
             _ => {
                 tracing::error!(target: "brontes::db::export", table = ?self, "Parquet export not yet supported for this table");
                 Err(eyre::eyre!("Parquet export not supported for {:?}", self))
@@ -534,14 +543,17 @@ tables!(
     BlockInfo,
     DexPrice,
     PoolCreationBlocks,
-    MevBlocks,
+    MevBlocksV2,
     TxTraces,
     Builder,
     AddressMeta,
     SearcherEOAs,
     SearcherContracts,
     InitializedState,
-    CexTrades
+    CexTrades,
+    PricingCheckpoints,
+    DedupStats,
+    UniswapV4Pools
 );
 
 /// Must be in this order when defining
@@ -980,7 +992,10 @@ compressed_table!(
 );
 
 compressed_table!(
-    Table MevBlocks {
+    /// The second layout of the mev blocks. The blocks earlier versions stored
+    /// in `MevBlocks` can't be read with the current types, so they are left
+    /// in place and their ranges are rerun.
+    Table MevBlocksV2 {
         Data {
             key: u64,
             value: MevBlockWithClassified,
@@ -1035,3 +1050,57 @@ compressed_table!(
         }
     }
 );
+
+compressed_table!(
+    Table PricingCheckpoints {
+        Data {
+            key: u64,
+            value: PricingGraphCheckpoint,
+            compressed_value: PricingGraphCheckpointRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+compressed_table!(
+    Table DedupStats {
+        Data {
+            key: u64,
+            value: DeduplicationStats,
+            compressed_value: DeduplicationStatsRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
+
+compressed_table!(
+    Table UniswapV4Pools {
+        Data {
+            key: B256,
+            value: UniswapV4PoolAddress,
+            compressed_value: UniswapV4PoolAddressRedefined
+        },
+        Init {
+            init_size: None,
+            init_method: Other,
+            http_endpoint: None
+        },
+        CLI {
+            can_insert: False
+        }
+    }
+);
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use arrow::{
-    array::{StringArray, StringBuilder},
+    array::{Float64Array, StringArray, StringBuilder},
     datatypes::{DataType, Field, Schema},
     error::ArrowError,
     record_batch::RecordBatch,
@@ -31,6 +31,12 @@ pub fn bundle_headers_to_record_batch(
 
     let profit_usd_array =
         build_float64_array(bundle_headers.iter().map(|bh| bh.profit_usd).collect());
+    let profit_usd_spread_array = Float64Array::from(
+        bundle_headers
+            .iter()
+            .map(|bh| bh.profit_usd_spread)
+            .collect::<Vec<_>>(),
+    );
     let bribe_usd_array =
         build_float64_array(bundle_headers.iter().map(|bh| bh.bribe_usd).collect());
     let mev_type_array = build_string_array(
@@ -47,6 +53,7 @@ pub fn bundle_headers_to_record_batch(
         Field::new("eoa", DataType::Utf8, false),
         Field::new("mev_contract", DataType::Utf8, true),
         Field::new("profit_usd", DataType::Float64, false),
+        Field::new("profit_usd_spread", DataType::Float64, true),
         Field::new("bribe_usd", DataType::Float64, false),
         Field::new("mev_type", DataType::Utf8, false),
     ]);
@@ -60,6 +67,7 @@ pub fn bundle_headers_to_record_batch(
             Arc::new(eoa_array),
             Arc::new(mev_contract_array),
             Arc::new(profit_usd_array),
+            Arc::new(profit_usd_spread_array),
             Arc::new(bribe_usd_array),
             Arc::new(mev_type_array),
         ],
@@ -0,0 +1,119 @@
+use std::sync::Arc;
+
+use arrow::{
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::{DeduplicationStats, SuppressedBundle};
+
+use super::utils::{
+    build_float64_array, build_record_batch, build_string_array, build_uint64_array,
+    get_string_array_from_owned,
+};
+
+pub fn dedup_stats_to_record_batch(
+    dedup_stats: &[DeduplicationStats],
+) -> Result<RecordBatch, ArrowError> {
+    let block_number_array =
+        build_uint64_array(dedup_stats.iter().map(|stats| stats.block_number).collect());
+    let inspected_array = build_uint64_array(
+        dedup_stats
+            .iter()
+            .map(|stats| stats.inspected_bundles)
+            .collect(),
+    );
+    let reported_array = build_uint64_array(
+        dedup_stats
+            .iter()
+            .map(|stats| stats.reported_bundles)
+            .collect(),
+    );
+    let suppressed_array = build_uint64_array(
+        dedup_stats
+            .iter()
+            .map(|stats| stats.total_suppressed() as u64)
+            .collect(),
+    );
+    let suppressed_profit_array = build_float64_array(
+        dedup_stats
+            .iter()
+            .map(|stats| stats.total_suppressed_profit_usd())
+            .collect(),
+    );
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("inspected_bundles", DataType::UInt64, false),
+        Field::new("reported_bundles", DataType::UInt64, false),
+        Field::new("suppressed_bundles", DataType::UInt64, false),
+        Field::new("suppressed_profit_usd", DataType::Float64, false),
+    ]);
+
+    build_record_batch(
+        schema,
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(inspected_array),
+            Arc::new(reported_array),
+            Arc::new(suppressed_array),
+            Arc::new(suppressed_profit_array),
+        ],
+    )
+}
+
+pub fn suppressed_bundles_to_record_batch(
+    suppressed: Vec<&SuppressedBundle>,
+) -> Result<RecordBatch, ArrowError> {
+    let block_number_array =
+        build_uint64_array(suppressed.iter().map(|sb| sb.block_number).collect());
+    let tx_hash_array =
+        build_string_array(suppressed.iter().map(|sb| sb.tx_hash.to_string()).collect());
+    let mev_type_array = build_string_array(
+        suppressed
+            .iter()
+            .map(|sb| sb.mev_type.to_string())
+            .collect(),
+    );
+    let inspector_array =
+        get_string_array_from_owned(suppressed.iter().map(|sb| sb.inspector.clone()).collect());
+    let profit_usd_array = build_float64_array(suppressed.iter().map(|sb| sb.profit_usd).collect());
+    let bribe_usd_array = build_float64_array(suppressed.iter().map(|sb| sb.bribe_usd).collect());
+    let dominant_tx_hash_array = build_string_array(
+        suppressed
+            .iter()
+            .map(|sb| sb.dominant_tx_hash.to_string())
+            .collect(),
+    );
+    let dominant_mev_type_array = build_string_array(
+        suppressed
+            .iter()
+            .map(|sb| sb.dominant_mev_type.to_string())
+            .collect(),
+    );
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("tx_hash", DataType::Utf8, false),
+        Field::new("mev_type", DataType::Utf8, false),
+        Field::new("inspector", DataType::Utf8, true),
+        Field::new("profit_usd", DataType::Float64, false),
+        Field::new("bribe_usd", DataType::Float64, false),
+        Field::new("dominant_tx_hash", DataType::Utf8, false),
+        Field::new("dominant_mev_type", DataType::Utf8, false),
+    ]);
+
+    build_record_batch(
+        schema,
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(tx_hash_array),
+            Arc::new(mev_type_array),
+            Arc::new(inspector_array),
+            Arc::new(profit_usd_array),
+            Arc::new(bribe_usd_array),
+            Arc::new(dominant_tx_hash_array),
+            Arc::new(dominant_mev_type_array),
+        ],
+    )
+}
@@ -123,7 +123,14 @@ fn build_schema(mev_count_array: &StructArray) -> Schema {
 fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
     let mut mev_count_builder = UInt64Builder::new();
     let mut sandwich_count_builder = UInt64Builder::new();
+    let mut multi_block_sandwich_count_builder = UInt64Builder::new();
     let mut liquidation_count_builder = UInt64Builder::new();
+    let mut oracle_backrun_count_builder = UInt64Builder::new();
+    let mut sniping_count_builder = UInt64Builder::new();
+    let mut generalized_frontrun_count_builder = UInt64Builder::new();
+    let mut intent_backrun_count_builder = UInt64Builder::new();
+    let mut custom_count_builder = UInt64Builder::new();
+    let mut nft_arb_count_builder = UInt64Builder::new();
     let mut atomic_backrun_count_builder = UInt64Builder::new();
     let mut cex_dex_count_builder = UInt64Builder::new();
     let mut jit_count_builder = UInt64Builder::new();
@@ -133,7 +140,16 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
     for block in mev_blocks {
         mev_count_builder.append_value(block.mev_count.bundle_count);
         sandwich_count_builder.append_option(block.mev_count.sandwich_count);
+        multi_block_sandwich_count_builder
+            .append_option(block.mev_count.multi_block_sandwich_count);
         liquidation_count_builder.append_option(block.mev_count.liquidation_count);
+        oracle_backrun_count_builder.append_option(block.mev_count.oracle_backrun_count);
+        sniping_count_builder.append_option(block.mev_count.sniping_count);
+        generalized_frontrun_count_builder
+            .append_option(block.mev_count.generalized_frontrun_count);
+        intent_backrun_count_builder.append_option(block.mev_count.intent_backrun_count);
+        custom_count_builder.append_option(block.mev_count.custom_count);
+        nft_arb_count_builder.append_option(block.mev_count.nft_arb_count);
         atomic_backrun_count_builder.append_option(block.mev_count.atomic_backrun_count);
         cex_dex_count_builder.append_option(block.mev_count.cex_dex_trade_count);
         jit_count_builder.append_option(block.mev_count.jit_count);
@@ -143,7 +159,14 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
 
     let mev_count_array = mev_count_builder.finish();
     let sandwich_count_array = sandwich_count_builder.finish();
+    let multi_block_sandwich_count_array = multi_block_sandwich_count_builder.finish();
     let liquidation_count_array = liquidation_count_builder.finish();
+    let oracle_backrun_count_array = oracle_backrun_count_builder.finish();
+    let sniping_count_array = sniping_count_builder.finish();
+    let generalized_frontrun_count_array = generalized_frontrun_count_builder.finish();
+    let intent_backrun_count_array = intent_backrun_count_builder.finish();
+    let custom_count_array = custom_count_builder.finish();
+    let nft_arb_count_array = nft_arb_count_builder.finish();
     let atomic_backrun_count_array = atomic_backrun_count_builder.finish();
     let cex_dex_count_array = cex_dex_count_builder.finish();
     let jit_count_array = jit_count_builder.finish();
@@ -153,7 +176,14 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
     let fields = vec![
         Field::new("mev_count", DataType::UInt64, false),
         Field::new("sandwich_count", DataType::UInt64, true),
+        Field::new("multi_block_sandwich_count", DataType::UInt64, true),
         Field::new("liquidation_count", DataType::UInt64, true),
+        Field::new("oracle_backrun_count", DataType::UInt64, true),
+        Field::new("sniping_count", DataType::UInt64, true),
+        Field::new("generalized_frontrun_count", DataType::UInt64, true),
+        Field::new("intent_backrun_count", DataType::UInt64, true),
+        Field::new("custom_count", DataType::UInt64, true),
+        Field::new("nft_arb_count", DataType::UInt64, true),
         Field::new("atomic_backrun_count", DataType::UInt64, true),
         Field::new("cex_dex_count", DataType::UInt64, true),
         Field::new("jit_count", DataType::UInt64, true),
@@ -164,7 +194,14 @@ fn get_mev_count_array(mev_blocks: &Vec<MevBlock>) -> StructArray {
     let arrays = vec![
         Arc::new(mev_count_array) as ArrayRef,
         Arc::new(sandwich_count_array) as ArrayRef,
+        Arc::new(multi_block_sandwich_count_array) as ArrayRef,
         Arc::new(liquidation_count_array) as ArrayRef,
+        Arc::new(oracle_backrun_count_array) as ArrayRef,
+        Arc::new(sniping_count_array) as ArrayRef,
+        Arc::new(generalized_frontrun_count_array) as ArrayRef,
+        Arc::new(intent_backrun_count_array) as ArrayRef,
+        Arc::new(custom_count_array) as ArrayRef,
+        Arc::new(nft_arb_count_array) as ArrayRef,
         Arc::new(atomic_backrun_count_array) as ArrayRef,
         Arc::new(cex_dex_count_array) as ArrayRef,
         Arc::new(jit_count_array) as ArrayRef,
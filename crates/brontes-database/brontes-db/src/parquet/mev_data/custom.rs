@@ -0,0 +1,73 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::CustomMev;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::gas_details::get_gas_details_list_array,
+    utils::{build_uint64_array, get_list_string_array_from_owned, get_string_array_from_owned},
+};
+
+pub fn custom_mev_to_record_batch(bundles: Vec<CustomMev>) -> Result<RecordBatch, ArrowError> {
+    let block_number_array = build_uint64_array(bundles.iter().map(|b| b.block_number).collect());
+
+    let inspector_array =
+        get_string_array_from_owned(bundles.iter().map(|b| Some(b.inspector.clone())).collect());
+
+    let tx_hashes_array = get_list_string_array_from_owned(
+        bundles
+            .iter()
+            .map(|b| {
+                b.tx_hashes
+                    .iter()
+                    .map(|tx_hash| tx_hash.to_string())
+                    .collect()
+            })
+            .collect_vec(),
+    );
+
+    let gas_details_array =
+        get_gas_details_list_array(bundles.iter().map(|b| &b.gas_details).collect_vec());
+
+    let protocols_array = get_list_string_array_from_owned(
+        bundles
+            .iter()
+            .map(|b| {
+                b.protocols
+                    .iter()
+                    .map(|protocol| protocol.to_string())
+                    .collect()
+            })
+            .collect_vec(),
+    );
+
+    let data_array =
+        get_string_array_from_owned(bundles.iter().map(|b| Some(b.data.clone())).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("inspector", DataType::Utf8, false),
+        Field::new("tx_hashes", tx_hashes_array.data_type().clone(), false),
+        Field::new("gas_details", gas_details_array.data_type().clone(), false),
+        Field::new("protocols", protocols_array.data_type().clone(), false),
+        Field::new("data", DataType::Utf8, false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(inspector_array),
+            Arc::new(tx_hashes_array),
+            Arc::new(gas_details_array),
+            Arc::new(protocols_array),
+            Arc::new(data_array),
+        ],
+    )
+}
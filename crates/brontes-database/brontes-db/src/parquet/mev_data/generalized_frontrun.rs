@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::{Array, BooleanArray},
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::GeneralizedFrontrun;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_array, swaps::get_normalized_swap_list_array,
+    },
+    utils::{
+        build_float64_array, build_uint64_array, get_list_string_array_from_owned,
+        get_string_array_from_owned,
+    },
+};
+
+pub fn generalized_frontrun_to_record_batch(
+    frontruns: Vec<GeneralizedFrontrun>,
+) -> Result<RecordBatch, ArrowError> {
+    let block_number_array = build_uint64_array(frontruns.iter().map(|f| f.block_number).collect());
+
+    let frontrun_tx_hash_array = get_string_array_from_owned(
+        frontruns
+            .iter()
+            .map(|f| Some(f.frontrun_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let frontrun_tx_index_array =
+        build_uint64_array(frontruns.iter().map(|f| f.frontrun_tx_index).collect());
+
+    let frontrun_swaps_array = get_normalized_swap_list_array(
+        frontruns
+            .iter()
+            .map(|f| f.frontrun_swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let frontrun_gas_details_array =
+        get_gas_details_array(frontruns.iter().map(|f| f.frontrun_gas_details).collect());
+
+    let victim_tx_hash_array = get_string_array_from_owned(
+        frontruns
+            .iter()
+            .map(|f| Some(f.victim_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let victim_tx_index_array =
+        build_uint64_array(frontruns.iter().map(|f| f.victim_tx_index).collect());
+
+    let victim_swaps_array = get_normalized_swap_list_array(
+        frontruns
+            .iter()
+            .map(|f| f.victim_swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let victim_gas_details_array =
+        get_gas_details_array(frontruns.iter().map(|f| f.victim_gas_details).collect());
+
+    let victim_reverted_array =
+        BooleanArray::from(frontruns.iter().map(|f| f.victim_reverted).collect_vec());
+
+    let victim_profit_usd_array =
+        build_float64_array(frontruns.iter().map(|f| f.victim_profit_usd).collect());
+
+    let shared_targets_array = get_list_string_array_from_owned(
+        frontruns
+            .iter()
+            .map(|f| {
+                f.shared_targets
+                    .iter()
+                    .map(|target| target.to_string())
+                    .collect()
+            })
+            .collect(),
+    );
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("frontrun_tx_hash", DataType::Utf8, false),
+        Field::new("frontrun_tx_index", DataType::UInt64, false),
+        Field::new("frontrun_swaps", frontrun_swaps_array.data_type().clone(), false),
+        Field::new("frontrun_gas_details", frontrun_gas_details_array.data_type().clone(), false),
+        Field::new("victim_tx_hash", DataType::Utf8, false),
+        Field::new("victim_tx_index", DataType::UInt64, false),
+        Field::new("victim_swaps", victim_swaps_array.data_type().clone(), false),
+        Field::new("victim_gas_details", victim_gas_details_array.data_type().clone(), false),
+        Field::new("victim_reverted", DataType::Boolean, false),
+        Field::new("victim_profit_usd", DataType::Float64, false),
+        Field::new("shared_targets", shared_targets_array.data_type().clone(), true),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(frontrun_tx_hash_array),
+            Arc::new(frontrun_tx_index_array),
+            Arc::new(frontrun_swaps_array),
+            Arc::new(frontrun_gas_details_array),
+            Arc::new(victim_tx_hash_array),
+            Arc::new(victim_tx_index_array),
+            Arc::new(victim_swaps_array),
+            Arc::new(victim_gas_details_array),
+            Arc::new(victim_reverted_array),
+            Arc::new(victim_profit_usd_array),
+            Arc::new(shared_targets_array),
+        ],
+    )
+}
@@ -0,0 +1,122 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::IntentBackrun;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_array, swaps::get_normalized_swap_list_array,
+    },
+    utils::{build_float64_array, build_uint64_array, get_string_array_from_owned},
+};
+
+pub fn intent_backrun_to_record_batch(
+    backruns: Vec<IntentBackrun>,
+) -> Result<RecordBatch, ArrowError> {
+    let block_number_array = build_uint64_array(backruns.iter().map(|b| b.block_number).collect());
+
+    let settlement_tx_hash_array = get_string_array_from_owned(
+        backruns
+            .iter()
+            .map(|b| Some(b.settlement_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let settlement_tx_index_array =
+        build_uint64_array(backruns.iter().map(|b| b.settlement_tx_index).collect());
+
+    let settlement_protocol_array = get_string_array_from_owned(
+        backruns
+            .iter()
+            .map(|b| Some(b.settlement_protocol.to_string()))
+            .collect(),
+    );
+
+    let solver_array = get_string_array_from_owned(
+        backruns
+            .iter()
+            .map(|b| Some(b.solver.to_string()))
+            .collect(),
+    );
+
+    let solver_swaps_array = get_normalized_swap_list_array(
+        backruns
+            .iter()
+            .map(|b| b.solver_swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let filler_profit_usd_array =
+        build_float64_array(backruns.iter().map(|b| b.filler_profit_usd).collect());
+
+    let settlement_gas_details_array =
+        get_gas_details_array(backruns.iter().map(|b| b.settlement_gas_details).collect());
+
+    let backrun_tx_hash_array = get_string_array_from_owned(
+        backruns
+            .iter()
+            .map(|b| Some(b.backrun_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let backrun_tx_index_array =
+        build_uint64_array(backruns.iter().map(|b| b.backrun_tx_index).collect());
+
+    let backrun_swaps_array = get_normalized_swap_list_array(
+        backruns
+            .iter()
+            .map(|b| b.backrun_swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let backrun_profit_usd_array =
+        build_float64_array(backruns.iter().map(|b| b.backrun_profit_usd).collect());
+
+    let backrun_gas_details_array =
+        get_gas_details_array(backruns.iter().map(|b| b.backrun_gas_details).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("settlement_tx_hash", DataType::Utf8, false),
+        Field::new("settlement_tx_index", DataType::UInt64, false),
+        Field::new("settlement_protocol", DataType::Utf8, false),
+        Field::new("solver", DataType::Utf8, false),
+        Field::new("solver_swaps", solver_swaps_array.data_type().clone(), false),
+        Field::new("filler_profit_usd", DataType::Float64, false),
+        Field::new(
+            "settlement_gas_details",
+            settlement_gas_details_array.data_type().clone(),
+            false,
+        ),
+        Field::new("backrun_tx_hash", DataType::Utf8, false),
+        Field::new("backrun_tx_index", DataType::UInt64, false),
+        Field::new("backrun_swaps", backrun_swaps_array.data_type().clone(), false),
+        Field::new("backrun_profit_usd", DataType::Float64, false),
+        Field::new("backrun_gas_details", backrun_gas_details_array.data_type().clone(), false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(settlement_tx_hash_array),
+            Arc::new(settlement_tx_index_array),
+            Arc::new(settlement_protocol_array),
+            Arc::new(solver_array),
+            Arc::new(solver_swaps_array),
+            Arc::new(filler_profit_usd_array),
+            Arc::new(settlement_gas_details_array),
+            Arc::new(backrun_tx_hash_array),
+            Arc::new(backrun_tx_index_array),
+            Arc::new(backrun_swaps_array),
+            Arc::new(backrun_profit_usd_array),
+            Arc::new(backrun_gas_details_array),
+        ],
+    )
+}
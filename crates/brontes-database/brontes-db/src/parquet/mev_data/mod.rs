@@ -1,16 +1,28 @@
 mod atomic_arb;
 
 mod cex_dex;
+mod custom;
+mod generalized_frontrun;
+mod intent_backrun;
 mod jit;
 mod jit_sandwich;
 mod liquidation;
+mod nft_arb;
+mod oracle_backrun;
 mod sandwich;
 mod searcher_tx;
+mod sniping;
 
 pub use atomic_arb::*;
+pub use custom::*;
+pub use generalized_frontrun::*;
+pub use intent_backrun::*;
 //pub use cex_dex::*;
 pub use jit::*;
 pub use jit_sandwich::*;
 pub use liquidation::*;
+pub use nft_arb::*;
+pub use oracle_backrun::*;
 pub use sandwich::*;
 pub use searcher_tx::*;
+pub use sniping::*;
@@ -0,0 +1,67 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::NftArb;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_array, nft_trades::get_normalized_nft_trade_list_array,
+    },
+    utils::{build_uint64_array, get_string_array_from_owned},
+};
+
+pub fn nft_arb_to_record_batch(nft_arbs: Vec<NftArb>) -> Result<RecordBatch, ArrowError> {
+    let block_number_array = build_uint64_array(nft_arbs.iter().map(|a| a.block_number).collect());
+
+    let tx_hash_array = get_string_array_from_owned(
+        nft_arbs
+            .iter()
+            .map(|a| Some(a.tx_hash.to_string()))
+            .collect(),
+    );
+
+    let tx_index_array = build_uint64_array(nft_arbs.iter().map(|a| a.tx_index).collect());
+
+    let buys_array = get_normalized_nft_trade_list_array(
+        nft_arbs
+            .iter()
+            .map(|a| a.buys.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let sells_array = get_normalized_nft_trade_list_array(
+        nft_arbs
+            .iter()
+            .map(|a| a.sells.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let gas_details_array = get_gas_details_array(nft_arbs.iter().map(|a| a.gas_details).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("tx_hash", DataType::Utf8, false),
+        Field::new("tx_index", DataType::UInt64, false),
+        Field::new("buys", buys_array.data_type().clone(), false),
+        Field::new("sells", sells_array.data_type().clone(), false),
+        Field::new("gas_details", gas_details_array.data_type().clone(), false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(tx_hash_array),
+            Arc::new(tx_index_array),
+            Arc::new(buys_array),
+            Arc::new(sells_array),
+            Arc::new(gas_details_array),
+        ],
+    )
+}
@@ -0,0 +1,117 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::OracleBackrun;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_array, liquidations::get_normalized_liquidation_list_array,
+        swaps::get_normalized_swap_list_array,
+    },
+    utils::{build_uint64_array, get_list_string_array_from_owned, get_string_array_from_owned},
+};
+
+pub fn oracle_backrun_to_record_batch(
+    oracle_backruns: Vec<OracleBackrun>,
+) -> Result<RecordBatch, ArrowError> {
+    let block_number_array =
+        build_uint64_array(oracle_backruns.iter().map(|ob| ob.block_number).collect());
+
+    let oracle_update_tx_hash_array = get_string_array_from_owned(
+        oracle_backruns
+            .iter()
+            .map(|ob| Some(ob.oracle_update_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let oracle_update_tx_index_array = build_uint64_array(
+        oracle_backruns
+            .iter()
+            .map(|ob| ob.oracle_update_tx_index)
+            .collect(),
+    );
+
+    let oracles_array = get_list_string_array_from_owned(
+        oracle_backruns
+            .iter()
+            .map(|ob| ob.oracles.iter().map(|oracle| oracle.to_string()).collect())
+            .collect(),
+    );
+
+    let backrun_tx_hash_array = get_string_array_from_owned(
+        oracle_backruns
+            .iter()
+            .map(|ob| Some(ob.backrun_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let backrun_tx_index_array = build_uint64_array(
+        oracle_backruns
+            .iter()
+            .map(|ob| ob.backrun_tx_index)
+            .collect(),
+    );
+
+    let latency_array = build_uint64_array(oracle_backruns.iter().map(|ob| ob.latency).collect());
+
+    let liquidations_array = get_normalized_liquidation_list_array(
+        oracle_backruns
+            .iter()
+            .map(|ob| ob.liquidations.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let swaps_array = get_normalized_swap_list_array(
+        oracle_backruns
+            .iter()
+            .map(|ob| ob.swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let liquidation_tx_hash_array = get_string_array_from_owned(
+        oracle_backruns
+            .iter()
+            .map(|ob| ob.liquidation_tx_hash.map(|tx_hash| tx_hash.to_string()))
+            .collect(),
+    );
+
+    let gas_details_array =
+        get_gas_details_array(oracle_backruns.iter().map(|ob| ob.gas_details).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("oracle_update_tx_hash", DataType::Utf8, false),
+        Field::new("oracle_update_tx_index", DataType::UInt64, false),
+        Field::new("oracles", oracles_array.data_type().clone(), true),
+        Field::new("backrun_tx_hash", DataType::Utf8, false),
+        Field::new("backrun_tx_index", DataType::UInt64, false),
+        Field::new("latency", DataType::UInt64, false),
+        Field::new("liquidations", liquidations_array.data_type().clone(), false),
+        Field::new("swaps", swaps_array.data_type().clone(), false),
+        Field::new("liquidation_tx_hash", DataType::Utf8, true),
+        Field::new("gas_details", gas_details_array.data_type().clone(), false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(oracle_update_tx_hash_array),
+            Arc::new(oracle_update_tx_index_array),
+            Arc::new(oracles_array),
+            Arc::new(backrun_tx_hash_array),
+            Arc::new(backrun_tx_index_array),
+            Arc::new(latency_array),
+            Arc::new(liquidations_array),
+            Arc::new(swaps_array),
+            Arc::new(liquidation_tx_hash_array),
+            Arc::new(gas_details_array),
+        ],
+    )
+}
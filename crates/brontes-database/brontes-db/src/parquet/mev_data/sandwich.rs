@@ -14,7 +14,10 @@ use crate::parquet::{
         gas_details::{get_gas_details_array, get_gas_details_list_array},
         swaps::get_normalized_swap_list_array,
     },
-    utils::{get_list_string_array_from_owned, get_string_array_from_owned},
+    utils::{
+        get_list_option_float_array_from_owned, get_list_string_array_from_owned,
+        get_string_array_from_owned,
+    },
 };
 
 pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch, ArrowError> {
@@ -70,6 +73,13 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
             .collect_vec(),
     );
 
+    let victim_loss_usd_array = get_list_option_float_array_from_owned(
+        sandwiches
+            .iter()
+            .map(|s| s.victim_loss_usd.clone())
+            .collect_vec(),
+    );
+
     let backrun_tx_hash_array = get_string_array_from_owned(
         sandwiches
             .iter()
@@ -102,6 +112,7 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
             victim_swaps_gas_details_array.data_type().clone(),
             false,
         ),
+        Field::new("victim_loss_usd", victim_loss_usd_array.data_type().clone(), false),
         Field::new("backrun_tx_hash", backrun_tx_hash_array.data_type().clone(), false),
         Field::new("backrun_swaps", backrun_swaps_array.data_type().clone(), false),
         Field::new("backrun_gas_details", backrun_gas_details_array.data_type().clone(), false),
@@ -116,6 +127,7 @@ pub fn sandwich_to_record_batch(sandwiches: Vec<Sandwich>) -> Result<RecordBatch
             Arc::new(victim_swaps_tx_hashes_array),
             Arc::new(victim_swaps_array),
             Arc::new(victim_swaps_gas_details_array),
+            Arc::new(victim_loss_usd_array),
             Arc::new(backrun_tx_hash_array),
             Arc::new(backrun_swaps_array),
             Arc::new(backrun_gas_details_array),
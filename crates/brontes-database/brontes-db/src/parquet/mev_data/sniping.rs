@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use arrow::{
+    array::Array,
+    datatypes::{DataType, Field, Schema},
+    error::ArrowError,
+    record_batch::RecordBatch,
+};
+use brontes_types::mev::Snipe;
+use itertools::Itertools;
+
+use crate::parquet::{
+    normalized_actions::{
+        gas_details::get_gas_details_array, swaps::get_normalized_swap_list_array,
+    },
+    utils::{
+        build_uint64_array, get_list_string_array_from_owned, get_list_uint64_array_from_owned,
+        get_string_array_from_owned,
+    },
+};
+
+pub fn snipe_to_record_batch(snipes: Vec<Snipe>) -> Result<RecordBatch, ArrowError> {
+    let block_number_array = build_uint64_array(snipes.iter().map(|s| s.block_number).collect());
+
+    let snipe_tx_hash_array = get_string_array_from_owned(
+        snipes
+            .iter()
+            .map(|s| Some(s.snipe_tx_hash.to_string()))
+            .collect(),
+    );
+
+    let pools_array = get_list_string_array_from_owned(
+        snipes
+            .iter()
+            .map(|s| s.pools.iter().map(|pool| pool.to_string()).collect())
+            .collect(),
+    );
+
+    let pool_creation_blocks_array = get_list_uint64_array_from_owned(
+        snipes
+            .iter()
+            .map(|s| s.pool_creation_blocks.clone())
+            .collect(),
+    );
+
+    let snipe_swaps_array = get_normalized_swap_list_array(
+        snipes
+            .iter()
+            .map(|s| s.snipe_swaps.iter().collect_vec())
+            .collect_vec(),
+    );
+
+    let markout_block_array = build_uint64_array(snipes.iter().map(|s| s.markout_block).collect());
+
+    let gas_details_array = get_gas_details_array(snipes.iter().map(|s| s.gas_details).collect());
+
+    let schema = Schema::new(vec![
+        Field::new("block_number", DataType::UInt64, false),
+        Field::new("snipe_tx_hash", DataType::Utf8, false),
+        Field::new("pools", pools_array.data_type().clone(), true),
+        Field::new("pool_creation_blocks", pool_creation_blocks_array.data_type().clone(), true),
+        Field::new("snipe_swaps", snipe_swaps_array.data_type().clone(), false),
+        Field::new("markout_block", DataType::UInt64, false),
+        Field::new("gas_details", gas_details_array.data_type().clone(), false),
+    ]);
+
+    RecordBatch::try_new(
+        Arc::new(schema),
+        vec![
+            Arc::new(block_number_array),
+            Arc::new(snipe_tx_hash_array),
+            Arc::new(pools_array),
+            Arc::new(pool_creation_blocks_array),
+            Arc::new(snipe_swaps_array),
+            Arc::new(markout_block_array),
+            Arc::new(gas_details_array),
+        ],
+    )
+}
@@ -33,6 +33,7 @@ use crate::Tables;
 mod address_meta;
 mod builder;
 mod bundle_header;
+mod dedup_stats;
 mod dex_price;
 mod mev_block;
 mod mev_data;
@@ -43,6 +44,7 @@ pub mod utils;
 use address_meta::address_metadata_to_record_batch;
 use builder::builder_info_to_record_batch;
 use bundle_header::bundle_headers_to_record_batch;
+use dedup_stats::{dedup_stats_to_record_batch, suppressed_bundles_to_record_batch};
 use mev_block::mev_block_to_record_batch;
 use mev_data::*;
 use searcher::searcher_info_to_record_batch;
@@ -94,6 +96,12 @@ where
             jit_sandwich,
             searcher_tx,
             liquidation,
+            oracle_backrun,
+            snipe,
+            generalized_frontrun,
+            intent_backrun,
+            nft_arb,
+            custom_mev,
         ) = {
             let mut blocks = Vec::new();
             let mut bundle_headers = Vec::new();
@@ -104,6 +112,12 @@ where
             let mut jit_sandwich = Vec::new();
             let mut searcher_tx = Vec::new();
             let mut liquidation = Vec::new();
+            let mut oracle_backrun = Vec::new();
+            let mut snipe = Vec::new();
+            let mut generalized_frontrun = Vec::new();
+            let mut intent_backrun = Vec::new();
+            let mut nft_arb = Vec::new();
+            let mut custom_mev = Vec::new();
 
             for mb in mev_blocks_iter {
                 blocks.push(mb.block);
@@ -121,6 +135,18 @@ where
                         BundleData::Liquidation(liquidation_data) => {
                             liquidation.push(liquidation_data)
                         }
+                        BundleData::OracleBackrun(oracle_backrun_data) => {
+                            oracle_backrun.push(oracle_backrun_data)
+                        }
+                        BundleData::Snipe(snipe_data) => snipe.push(snipe_data),
+                        BundleData::GeneralizedFrontrun(generalized_frontrun_data) => {
+                            generalized_frontrun.push(generalized_frontrun_data)
+                        }
+                        BundleData::IntentBackrun(intent_backrun_data) => {
+                            intent_backrun.push(intent_backrun_data)
+                        }
+                        BundleData::NftArb(nft_arb_data) => nft_arb.push(nft_arb_data),
+                        BundleData::Custom(custom_mev_data) => custom_mev.push(custom_mev_data),
                         _ => continue,
                     }
                 }
@@ -136,6 +162,12 @@ where
                 jit_sandwich,
                 searcher_tx,
                 liquidation,
+                oracle_backrun,
+                snipe,
+                generalized_frontrun,
+                intent_backrun,
+                nft_arb,
+                custom_mev,
             )
         };
 
@@ -151,7 +183,7 @@ where
                         .wrap_err("Failed to convert MEV block data to record batch")?;
                     sync_write_parquet(
                         block_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, None)?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, None)?,
                     )
                 }
             }));
@@ -165,7 +197,7 @@ where
                         .wrap_err("Failed to convert CEX-DEX data to record batch")?;
                     sync_write_parquet(
                         cex_dex_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::CexDexTrades))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::CexDexTrades))?,
                     )
                 }
             }));
@@ -179,7 +211,7 @@ where
                         .wrap_err("Failed to convert AtomicArb data to record batch")?;
                     sync_write_parquet(
                         atomic_arb_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::AtomicArb))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::AtomicArb))?,
                     )
                 }
             }));
@@ -193,7 +225,7 @@ where
                         .wrap_err("Failed to convert JIT data to record batch")?;
                     sync_write_parquet(
                         jit_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::Jit))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::Jit))?,
                     )
                 }
             }));
@@ -207,7 +239,7 @@ where
                         .wrap_err("Failed to convert Sandwich data to record batch")?;
                     sync_write_parquet(
                         sandwich_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::Sandwich))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::Sandwich))?,
                     )
                 }
             }));
@@ -221,7 +253,7 @@ where
                         .wrap_err("Failed to convert JIT Sandwich data to record batch")?;
                     sync_write_parquet(
                         jit_sandwich_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::JitSandwich))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::JitSandwich))?,
                     )
                 }
             }));
@@ -235,7 +267,7 @@ where
                         .wrap_err("Failed to convert Searcher Tx data to record batch")?;
                     sync_write_parquet(
                         searcher_tx_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::SearcherTx))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::SearcherTx))?,
                     )
                 }
             }));
@@ -249,7 +281,97 @@ where
                         .wrap_err("Failed to convert Liquidation data to record batch")?;
                     sync_write_parquet(
                         liquidation_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::Liquidation))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::Liquidation))?,
+                    )
+                }
+            }));
+        }
+
+        if !oracle_backrun.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let oracle_backrun_batch = oracle_backrun_to_record_batch(oracle_backrun)
+                        .wrap_err("Failed to convert Oracle Backrun data to record batch")?;
+                    sync_write_parquet(
+                        oracle_backrun_batch,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::OracleBackrun))?,
+                    )
+                }
+            }));
+        }
+
+        if !snipe.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let snipe_batch = snipe_to_record_batch(snipe)
+                        .wrap_err("Failed to convert Sniping data to record batch")?;
+                    sync_write_parquet(
+                        snipe_batch,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::Sniping))?,
+                    )
+                }
+            }));
+        }
+
+        if !generalized_frontrun.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let generalized_frontrun_batch = generalized_frontrun_to_record_batch(
+                        generalized_frontrun,
+                    )
+                    .wrap_err("Failed to convert Generalized Frontrun data to record batch")?;
+                    sync_write_parquet(
+                        generalized_frontrun_batch,
+                        get_path(
+                            base_dir_path,
+                            Tables::MevBlocksV2,
+                            Some(MevType::GeneralizedFrontrun),
+                        )?,
+                    )
+                }
+            }));
+        }
+
+        if !intent_backrun.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let intent_backrun_batch = intent_backrun_to_record_batch(intent_backrun)
+                        .wrap_err("Failed to convert Intent Backrun data to record batch")?;
+                    sync_write_parquet(
+                        intent_backrun_batch,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::IntentBackrun))?,
+                    )
+                }
+            }));
+        }
+
+        if !nft_arb.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let nft_arb_batch = nft_arb_to_record_batch(nft_arb)
+                        .wrap_err("Failed to convert NFT Arb data to record batch")?;
+                    sync_write_parquet(
+                        nft_arb_batch,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::NftArb))?,
+                    )
+                }
+            }));
+        }
+
+        if !custom_mev.is_empty() {
+            bundle_futures.push(tokio::task::spawn_blocking({
+                let base_dir_path = base_dir_path.clone();
+                move || {
+                    let custom_mev_batch = custom_mev_to_record_batch(custom_mev)
+                        .wrap_err("Failed to convert Custom Mev data to record batch")?;
+                    sync_write_parquet(
+                        custom_mev_batch,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::Custom))?,
                     )
                 }
             }));
@@ -263,7 +385,7 @@ where
                         .wrap_err("Failed to convert bundle headers to record batch")?;
                     sync_write_parquet(
                         bundle_batch,
-                        get_path(base_dir_path, Tables::MevBlocks, Some(MevType::Unknown))?,
+                        get_path(base_dir_path, Tables::MevBlocksV2, Some(MevType::Unknown))?,
                     )
                 }
             }));
@@ -373,6 +495,41 @@ where
         info!(target: "brontes::db::export", "Finished exporting DexPrice table.");
         Ok(())
     }
+
+    /// Exports the deduplication stats of each block and, next to them, the
+    /// bundles deduplication suppressed
+    pub async fn export_dedup_stats(&self) -> Result<(), Error> {
+        let start_block = self.start_block.unwrap_or(0);
+        let end_block = self.end_block.unwrap_or(u64::MAX);
+
+        let dedup_stats = self
+            .db
+            .fetch_dedup_stats_range(start_block, end_block)
+            .wrap_err("Failed to fetch dedup stats from the database")?;
+
+        if dedup_stats.is_empty() {
+            warn!(target: "brontes::db::export", "No dedup stats found for the given range.");
+            return Ok(());
+        }
+
+        let stats_batch = dedup_stats_to_record_batch(&dedup_stats)
+            .wrap_err("Failed to convert dedup stats to record batch")?;
+        let suppressed_batch = suppressed_bundles_to_record_batch(
+            dedup_stats
+                .iter()
+                .flat_map(|stats| &stats.suppressed)
+                .collect(),
+        )
+        .wrap_err("Failed to convert suppressed bundles to record batch")?;
+
+        let stats_path = get_path(self.base_dir_path.clone(), Tables::DedupStats, None)?;
+        let suppressed_path = stats_path.with_extension("suppressed.parquet");
+
+        sync_write_parquet(stats_batch, stats_path)?;
+        sync_write_parquet(suppressed_batch, suppressed_path)?;
+
+        Ok(())
+    }
 }
 
 async fn write_parquet(record_batch: RecordBatch, file_path: PathBuf) -> Result<()> {
@@ -432,7 +589,7 @@ pub fn get_path(
     let mut path = PathBuf::from(base_path);
     path.push(batch_type.get_default_path());
 
-    if batch_type == Tables::MevBlocks && mev_type.is_none() {
+    if batch_type == Tables::MevBlocksV2 && mev_type.is_none() {
         path.push("blocks");
     } else if let Some(mev_type) = mev_type {
         path.push("bundles");
@@ -458,12 +615,13 @@ pub fn create_file_path<P: AsRef<Path>>(base_dir: P) -> Result<PathBuf> {
 impl Tables {
     pub fn get_default_path(&self) -> &'static str {
         match self {
-            Tables::MevBlocks => DEFAULT_BLOCK_DIR,
+            Tables::MevBlocksV2 => DEFAULT_BLOCK_DIR,
             Tables::AddressMeta => DEFAULT_METADATA_DIR,
             Tables::SearcherEOAs => DEFAULT_SEARCHER_INFO_DIR,
             Tables::SearcherContracts => DEFAULT_SEARCHER_INFO_DIR,
             Tables::Builder => DEFAULT_BUILDER_INFO_DIR,
             Tables::DexPrice => DEFAULT_DEX_PRICE_DIR,
+            Tables::DedupStats => DEFAULT_DEDUP_STATS_DIR,
             _ => panic!("Unsupported table type for default path"),
         }
     }
@@ -474,3 +632,4 @@ pub const DEFAULT_METADATA_DIR: &str = "address_metadata";
 pub const DEFAULT_SEARCHER_INFO_DIR: &str = "searcher_info";
 pub const DEFAULT_BUILDER_INFO_DIR: &str = "builder-info";
 pub const DEFAULT_DEX_PRICE_DIR: &str = "dex_prices";
+pub const DEFAULT_DEDUP_STATS_DIR: &str = "dedup_stats";
@@ -3,7 +3,7 @@ use std::{fmt::Display, sync::Arc};
 use arrow::{
     array::{
         Array, BinaryArray, BinaryBuilder, Float64Array, Float64Builder, ListArray, ListBuilder,
-        StringArray, StringBuilder, UInt64Array,
+        StringArray, StringBuilder, UInt64Array, UInt64Builder,
     },
     datatypes::Schema,
     error::ArrowError,
@@ -103,6 +103,44 @@ pub fn get_list_float_array_from_owned(values: Vec<Vec<f64>>) -> ListArray {
     builder.finish()
 }
 
+pub fn get_list_option_float_array_from_owned(values: Vec<Vec<Option<f64>>>) -> ListArray {
+    let mut builder = ListBuilder::new(Float64Builder::new());
+
+    for v in values {
+        let float_builder = builder.values();
+        if v.is_empty() {
+            builder.append_null();
+            continue;
+        } else {
+            for float in v {
+                float_builder.append_option(float);
+            }
+            builder.append(true)
+        }
+    }
+
+    builder.finish()
+}
+
+pub fn get_list_uint64_array_from_owned(values: Vec<Vec<u64>>) -> ListArray {
+    let mut builder = ListBuilder::new(UInt64Builder::new());
+
+    for v in values {
+        let uint_builder = builder.values();
+        if v.is_empty() {
+            builder.append_null();
+            continue;
+        } else {
+            for value in v {
+                uint_builder.append_value(value);
+            }
+            builder.append(true)
+        }
+    }
+
+    builder.finish()
+}
+
 pub fn build_uint64_array(values: Vec<u64>) -> UInt64Array {
     UInt64Array::from(values)
 }
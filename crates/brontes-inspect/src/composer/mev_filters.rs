@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    path::Path,
+    sync::{Arc, OnceLock},
+};
 
 use brontes_types::{
     db::traits::LibmdbxReader,
@@ -6,7 +9,14 @@ use brontes_types::{
     normalized_actions::Action,
     BlockTree,
 };
+use eyre::{eyre, WrapErr};
 use lazy_static::lazy_static;
+use serde::Deserialize;
+use strum::IntoEnumIterator;
+
+pub const MEV_PRECEDENCE_FILE: &str = "config/mev_precedence.toml";
+
+static MEV_PRECEDENCE: OnceLock<&'static [MevPrecedence]> = OnceLock::new();
 
 /// Defines precedence rules among different MEV types for the purpose of
 /// deduplication.
@@ -39,14 +49,13 @@ use lazy_static::lazy_static;
 macro_rules! define_mev_precedence {
     ($($($subordinate_mev_type:ident),+ => $dominant_mev_type:ident;)+) => {
         lazy_static! {
-            pub static ref MEV_DEDUPLICATION_FILTER:
-                &'static [(MevType, FilterFn, Vec<MevType>)] = {
+            pub static ref MEV_DEDUPLICATION_FILTER: &'static [MevPrecedence] = {
                 &*Box::leak(Box::new([
-                    $((
-                        MevType::$dominant_mev_type,
-                        get_filter_fn(MevType::$dominant_mev_type),
-                        vec![$(MevType::$subordinate_mev_type),+],
-                    ),)+
+                    $(MevPrecedence {
+                        dominant:     MevType::$dominant_mev_type,
+                        filter_fn:    get_filter_fn(MevType::$dominant_mev_type),
+                        subordinates: vec![$(MevType::$subordinate_mev_type),+],
+                    },)+
                 ]))
             };
         }
@@ -61,6 +70,196 @@ pub type FilterFn = Option<
     >,
 >;
 
+/// A precedence rule used to deduplicate bundles that share transactions.
+///
+/// Every bundle the rule considers subordinate is removed if it shares a
+/// transaction with a bundle the rule considers dominant, unless
+/// [`DeduplicationRule::should_dedup`] says otherwise. Rules are matched
+/// against the bundles themselves rather than the [`BundleData`] variants, so
+/// inspectors registered outside of brontes can dedup the
+/// [`BundleData::Custom`] bundles they produce.
+pub trait DeduplicationRule: Send + Sync {
+    fn is_dominant(&self, bundle: &Bundle) -> bool;
+    fn is_subordinate(&self, bundle: &Bundle) -> bool;
+
+    /// returns true if should dedup.
+    fn should_dedup(
+        &self,
+        _tree: Arc<BlockTree<Action>>,
+        _db: Arc<Box<dyn LibmdbxReader>>,
+        _bundles: [&Bundle; 2],
+    ) -> bool {
+        true
+    }
+}
+
+/// A precedence rule between the built in mev types, defined with
+/// `define_mev_precedence!` or loaded with [`MevPrecedenceConfig`]
+pub struct MevPrecedence {
+    pub dominant:     MevType,
+    /// decides whether two overlapping bundles should be deduped, dedups all
+    /// of them if unset
+    pub filter_fn:    FilterFn,
+    pub subordinates: Vec<MevType>,
+}
+
+impl DeduplicationRule for MevPrecedence {
+    fn is_dominant(&self, bundle: &Bundle) -> bool {
+        bundle.mev_type() == self.dominant
+    }
+
+    fn is_subordinate(&self, bundle: &Bundle) -> bool {
+        self.subordinates.contains(&bundle.mev_type())
+    }
+
+    fn should_dedup(
+        &self,
+        tree: Arc<BlockTree<Action>>,
+        db: Arc<Box<dyn LibmdbxReader>>,
+        bundles: [&Bundle; 2],
+    ) -> bool {
+        self.filter_fn
+            .as_ref()
+            .map(|f| f(tree, db, bundles))
+            .unwrap_or(true)
+    }
+}
+
+/// Selects the bundles a [`CustomPrecedence`] applies to.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BundleSelector {
+    MevType(MevType),
+    /// the bundles of the custom inspector registered under this name
+    Custom(String),
+}
+
+impl BundleSelector {
+    pub fn matches(&self, bundle: &Bundle) -> bool {
+        match self {
+            Self::MevType(mev_type) => bundle.mev_type() == *mev_type,
+            Self::Custom(name) => bundle.custom_inspector() == Some(name.as_str()),
+        }
+    }
+}
+
+/// A precedence rule for the bundles of custom inspectors. Custom inspectors
+/// can return these from
+/// [`Inspector::dedup_rules`](crate::Inspector::dedup_rules) to take precedence
+/// over other bundles, or to give other bundles precedence over their own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomPrecedence {
+    pub dominant:     BundleSelector,
+    pub subordinates: Vec<BundleSelector>,
+}
+
+impl DeduplicationRule for CustomPrecedence {
+    fn is_dominant(&self, bundle: &Bundle) -> bool {
+        self.dominant.matches(bundle)
+    }
+
+    fn is_subordinate(&self, bundle: &Bundle) -> bool {
+        self.subordinates
+            .iter()
+            .any(|subordinate| subordinate.matches(bundle))
+    }
+}
+
+/// Replaces the precedence rules defined with `define_mev_precedence!`. The
+/// rules can only be set once and have to be set before the first block is
+/// inspected.
+pub fn init_mev_precedence(rules: Vec<MevPrecedence>) -> eyre::Result<()> {
+    MEV_PRECEDENCE
+        .set(&*Box::leak(rules.into_boxed_slice()))
+        .map_err(|_| eyre!("the mev precedence rules have already been set"))
+}
+
+/// The precedence rules set with [`init_mev_precedence`], or the built in
+/// `MEV_DEDUPLICATION_FILTER` if none were set
+pub fn mev_precedence() -> &'static [MevPrecedence] {
+    MEV_PRECEDENCE
+        .get()
+        .copied()
+        .unwrap_or(*MEV_DEDUPLICATION_FILTER)
+}
+
+/// The filter functions a precedence rule loaded from config can use, see
+/// [`MevPrecedence::filter_fn`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DedupFilter {
+    /// always dedup overlapping bundles
+    #[default]
+    None,
+    /// [`atomic_dedup_fn`]
+    AtomicArb,
+}
+
+impl DedupFilter {
+    pub fn filter_fn(self) -> FilterFn {
+        match self {
+            Self::None => None,
+            Self::AtomicArb => Some(Box::new(atomic_dedup_fn)),
+        }
+    }
+}
+
+/// Precedence rules loaded from `config/mev_precedence.toml`. Each `[[rule]]`
+/// replaces a line of `define_mev_precedence!`, the rules are applied in the
+/// order they are defined in.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct MevPrecedenceConfig {
+    #[serde(rename = "rule", default)]
+    pub rules: Vec<MevPrecedenceRule>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MevPrecedenceRule {
+    pub dominant:     String,
+    pub subordinates: Vec<String>,
+    #[serde(default)]
+    pub filter:       DedupFilter,
+}
+
+impl MevPrecedenceConfig {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let config = std::fs::read_to_string(path).wrap_err_with(|| {
+            format!("failed to read mev precedence config at {}", path.display())
+        })?;
+
+        Self::from_toml_str(&config)
+    }
+
+    pub fn from_toml_str(config: &str) -> eyre::Result<Self> {
+        Ok(toml::from_str(config)?)
+    }
+
+    pub fn into_precedence(self) -> eyre::Result<Vec<MevPrecedence>> {
+        self.rules
+            .into_iter()
+            .map(|rule| {
+                Ok(MevPrecedence {
+                    dominant:     parse_mev_type(&rule.dominant)?,
+                    filter_fn:    rule.filter.filter_fn(),
+                    subordinates: rule
+                        .subordinates
+                        .iter()
+                        .map(|mev_type| parse_mev_type(mev_type))
+                        .collect::<eyre::Result<_>>()?,
+                })
+            })
+            .collect()
+    }
+}
+
+/// `MevType` falls back to `Unknown` for names it doesn't know, which would
+/// silently turn a typo into a rule for unknown bundles.
+fn parse_mev_type(name: &str) -> eyre::Result<MevType> {
+    MevType::iter()
+        .find(|mev_type| mev_type.as_ref() == name)
+        .ok_or_else(|| eyre!("unknown mev type `{name}` in mev precedence config"))
+}
+
 pub fn get_filter_fn(mev_type: MevType) -> FilterFn {
     match mev_type {
         MevType::AtomicArb => Some(Box::new(atomic_dedup_fn)),
@@ -111,7 +310,54 @@ define_mev_precedence!(
     Unknown, SearcherTx => AtomicArb;
     Unknown, SearcherTx, AtomicArb => Jit;
     Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades  => Liquidation;
-    Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades  => Sandwich;
+    // liquidations are linked to the oracle backrun instead of suppressed
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades => OracleBackrun;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades => Sniping;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, Liquidation => GeneralizedFrontrun;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades => IntentBackrun;
+    Unknown, SearcherTx, AtomicArb => NftArb;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, GeneralizedFrontrun
+        => MultiBlockSandwich;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes,CexDexTrades, MultiBlockSandwich,
+        GeneralizedFrontrun => Sandwich;
     Unknown, SearcherTx, AtomicArb, Jit, CexDexQuotes, CexDexTrades=> JitCexDex;
-    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, Jit, Sandwich => JitSandwich;
+    Unknown, SearcherTx, AtomicArb, CexDexQuotes, CexDexTrades, Jit, Sandwich,
+        MultiBlockSandwich, GeneralizedFrontrun => JitSandwich;
 );
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_config_matches_builtin_precedence() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../")
+            .join(MEV_PRECEDENCE_FILE);
+        let rules = MevPrecedenceConfig::load(path)
+            .unwrap()
+            .into_precedence()
+            .unwrap();
+
+        assert_eq!(rules.len(), MEV_DEDUPLICATION_FILTER.len());
+        for (loaded, builtin) in rules.iter().zip(MEV_DEDUPLICATION_FILTER.iter()) {
+            assert_eq!(loaded.dominant, builtin.dominant);
+            assert_eq!(loaded.subordinates, builtin.subordinates);
+            assert_eq!(loaded.filter_fn.is_some(), builtin.filter_fn.is_some());
+        }
+    }
+
+    #[test]
+    fn test_unknown_mev_type() {
+        let config = MevPrecedenceConfig::from_toml_str(
+            r#"
+            [[rule]]
+            dominant = "Sandwhich"
+            subordinates = ["AtomicArb"]
+            "#,
+        )
+        .unwrap();
+
+        assert!(config.into_precedence().is_err());
+    }
+}
@@ -12,7 +12,10 @@
 //! - `MEV_COMPOSABILITY_FILTER` and `MEV_DEDUPLICATION_FILTER`: These filters,
 //!   defined using the `mev_composability` and `define_mev_precedence` macros,
 //!   respectively, establish rules for composing multiple MEV types and setting
-//!   precedence among them for deduplication.
+//!   precedence among them for deduplication. The precedence rules can be
+//!   replaced at runtime with [`init_mev_precedence`].
+//! - `DeduplicationStats`: A record of the bundles each precedence rule
+//!   suppressed, to audit how the rules change the reported totals.
 //! - Utility Functions: A collection of functions designed to assist in the
 //!   composition and deduplication processes of MEV data.
 //!
@@ -43,16 +46,19 @@ mod mev_filters;
 mod utils;
 use brontes_types::{
     db::metadata::Metadata,
-    mev::{Bundle, MevBlock, MevType, PossibleMevCollection},
+    mev::{Bundle, DeduplicationStats, MevBlock, MevType, PossibleMevCollection, SuppressedBundle},
     normalized_actions::Action,
     tree::BlockTree,
 };
 use composer_filters::{ComposeFunction, MEV_COMPOSABILITY_FILTER};
-use mev_filters::{FilterFn, MEV_DEDUPLICATION_FILTER};
+pub use mev_filters::{
+    init_mev_precedence, mev_precedence, BundleSelector, CustomPrecedence, DedupFilter,
+    DeduplicationRule, MevPrecedence, MevPrecedenceConfig, MevPrecedenceRule, MEV_PRECEDENCE_FILE,
+};
 use rayon::iter::{IntoParallelRefIterator, ParallelIterator};
 use utils::{
-    build_mev_header, filter_and_count_bundles, find_mev_with_matching_tx_hashes, sort_mev_by_type,
-    try_deduping_mev,
+    build_mev_header, filter_and_count_bundles, find_mev_with_matching_tx_hashes,
+    link_oracle_backruns, sort_mev_by_type, try_deduping_mev,
 };
 
 const DISCOVERY_PRIORITY_FEE_MULTIPLIER: f64 = 2.0;
@@ -66,6 +72,7 @@ pub struct ComposerResults {
     /// all txes with coinbase.transfers that weren't classified
     pub possible_mev_txes: PossibleMevCollection,
     pub block_analysis:    BlockAnalysis,
+    pub dedup_stats:       DeduplicationStats,
 }
 
 pub fn run_block_inspection<DB: LibmdbxReader>(
@@ -82,12 +89,31 @@ pub fn run_block_inspection<DB: LibmdbxReader>(
 
     let quote_token = orchestra[0].get_quote_token();
 
-    let (block_details, mev_details) =
-        on_orchestra_resolution(tree, possible_mev_txes, metadata, classified_mev, quote_token, db);
+    let custom_dedup_rules = orchestra
+        .iter()
+        .flat_map(|inspector| inspector.dedup_rules())
+        .map(|rule| rule.as_ref())
+        .collect_vec();
+
+    let (block_details, mev_details, dedup_stats) = on_orchestra_resolution(
+        tree,
+        possible_mev_txes,
+        metadata,
+        classified_mev,
+        quote_token,
+        &custom_dedup_rules,
+        db,
+    );
 
     let block_analysis = BlockAnalysis::new(&block_details, &mev_details);
 
-    ComposerResults { block_details, mev_details, possible_mev_txes: possible_arbs, block_analysis }
+    ComposerResults {
+        block_details,
+        mev_details,
+        possible_mev_txes: possible_arbs,
+        block_analysis,
+        dedup_stats,
+    }
 }
 
 fn run_inspectors(
@@ -140,8 +166,14 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
     metadata: Arc<Metadata>,
     orchestra_data: Vec<Bundle>,
     quote_token: Address,
+    custom_dedup_rules: &[&dyn DeduplicationRule],
     db: &'static DB,
-) -> (MevBlock, Vec<Bundle>) {
+) -> (MevBlock, Vec<Bundle>, DeduplicationStats) {
+    let mut dedup_stats = DeduplicationStats {
+        block_number: metadata.block_num,
+        inspected_bundles: orchestra_data.len() as u64,
+        ..Default::default()
+    };
     let mut sorted_mev = sort_mev_by_type(orchestra_data);
 
     MEV_COMPOSABILITY_FILTER
@@ -150,27 +182,26 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
             try_compose_mev(parent_mev_type, child_mev_type, compose_fn, &mut sorted_mev);
         });
 
-    MEV_DEDUPLICATION_FILTER.iter().for_each(
-        |(dominant_mev_type, extra_filter_fn, subordinate_mev_type)| {
-            deduplicate_mev(
-                tree.clone(),
-                db,
-                dominant_mev_type,
-                extra_filter_fn,
-                subordinate_mev_type,
-                &mut sorted_mev,
-            );
-        },
-    );
+    // the rules of custom inspectors are applied after the built in rules
+    mev_precedence()
+        .iter()
+        .map(|rule| rule as &dyn DeduplicationRule)
+        .chain(custom_dedup_rules.iter().copied())
+        .for_each(|rule| {
+            deduplicate_mev(tree.clone(), db, rule, &mut sorted_mev, &mut dedup_stats);
+        });
 
     // now that we have deduplicated cross bundles. we deduplicate
     // per mev_type
-    let sorted_mev = sorted_mev
+    let mut sorted_mev = sorted_mev
         .into_iter()
-        .map(|(mev_type, bundles)| (mev_type, SharedInspectorUtils::<DB>::dedup_bundles(bundles)))
+        .map(|(mev_type, bundles)| (mev_type, dedup_mev_type::<DB>(mev_type, bundles)))
         .collect();
 
+    link_oracle_backruns(&mut sorted_mev);
+
     let (mev_count, mut filtered_bundles) = filter_and_count_bundles(sorted_mev);
+    dedup_stats.reported_bundles = filtered_bundles.len() as u64;
 
     let header = build_mev_header(
         &metadata,
@@ -184,45 +215,83 @@ fn on_orchestra_resolution<DB: LibmdbxReader>(
     // keep order
     filtered_bundles.sort_by(|a, b| a.header.tx_index.cmp(&b.header.tx_index));
 
-    (header, filtered_bundles)
+    (header, filtered_bundles, dedup_stats)
 }
 
+/// Bundles of custom inspectors all share [`MevType::Custom`], so they are
+/// deduplicated per inspector.
+fn dedup_mev_type<DB: LibmdbxReader>(mev_type: MevType, bundles: Vec<Bundle>) -> Vec<Bundle> {
+    if mev_type != MevType::Custom {
+        return SharedInspectorUtils::<DB>::dedup_bundles(bundles)
+    }
+
+    bundles
+        .into_iter()
+        .into_group_map_by(|bundle| bundle.custom_inspector().map(str::to_owned))
+        .into_values()
+        .flat_map(SharedInspectorUtils::<DB>::dedup_bundles)
+        .collect()
+}
+
+/// Removes the bundles the rule considers subordinate to an overlapping
+/// dominant bundle. Each removed bundle is recorded as suppressed by the first
+/// dominant bundle that overlapped with it.
 fn deduplicate_mev<DB: LibmdbxReader>(
     tree: Arc<BlockTree<Action>>,
     db: &'static DB,
-    dominant_mev_type: &MevType,
-    extra_filter_function: &FilterFn,
-    subordinate_mev_types: &[MevType],
+    rule: &dyn DeduplicationRule,
     sorted_mev: &mut FastHashMap<MevType, Vec<Bundle>>,
+    dedup_stats: &mut DeduplicationStats,
 ) {
-    let Some(dominant_mev_list) = sorted_mev.get(dominant_mev_type) else { return };
+    let dominant_mev_list = sorted_mev
+        .iter()
+        .flat_map(|(&mev_type, bundles)| {
+            bundles
+                .iter()
+                .enumerate()
+                .map(move |(index, bundle)| (mev_type, index, bundle))
+        })
+        .filter(|(_, _, bundle)| rule.is_dominant(bundle))
+        .collect_vec();
 
-    let mut indexes = Vec::new();
+    // (dominant mev type, dominant index, suppressed mev type, suppressed index)
+    let mut suppressions = Vec::new();
 
-    for dominate_mev in dominant_mev_list {
+    for (dominant_mev_type, dominant_index, dominate_mev) in dominant_mev_list {
         let hashes = dominate_mev.data.mev_transaction_hashes();
 
-        for &sub_mev_type in subordinate_mev_types {
-            let Some(sub_mev_list) = sorted_mev.get(&sub_mev_type) else {
-                continue;
-            };
-            indexes.extend(
+        for (&sub_mev_type, sub_mev_list) in sorted_mev.iter() {
+            suppressions.extend(
                 try_deduping_mev(
                     tree.clone(),
                     Box::new(db),
                     dominate_mev,
                     sub_mev_list,
-                    extra_filter_function,
+                    rule,
                     &hashes,
                 )
-                .zip(vec![sub_mev_type].into_iter().cycle()),
+                .map(|index| (dominant_mev_type, dominant_index, sub_mev_type, index)),
             )
         }
     }
 
-    indexes
+    let suppressions = suppressions
+        .into_iter()
+        .unique_by(|(_, _, mev_type, index)| (*mev_type, *index))
+        .collect_vec();
+
+    dedup_stats.suppressed.extend(suppressions.iter().map(
+        |&(dominant_mev_type, dominant_index, sub_mev_type, index)| {
+            SuppressedBundle::new(
+                &sorted_mev[&sub_mev_type][index],
+                &sorted_mev[&dominant_mev_type][dominant_index],
+            )
+        },
+    ));
+
+    suppressions
         .into_iter()
-        .unique()
+        .map(|(_, _, mev_type, index)| (index, mev_type))
         .sorted_unstable_by(|a, b| b.0.cmp(&a.0))
         .for_each(|(index, mev_type)| {
             let Some(mev_list) = sorted_mev.get_mut(&mev_type) else { return };
@@ -314,7 +383,11 @@ fn try_compose_mev(
 
 #[cfg(test)]
 pub mod tests {
-    use alloy_primitives::hex;
+    use alloy_primitives::{hex, B256};
+    use brontes_core::TraceLoader;
+    use brontes_types::mev::{
+        AtomicArb, BundleData, BundleHeader, Liquidation, OracleBackrun, Sandwich,
+    };
 
     use super::*;
     use crate::{
@@ -322,6 +395,115 @@ pub mod tests {
         Inspectors,
     };
 
+    struct SandwichOverAtomicArb;
+
+    impl DeduplicationRule for SandwichOverAtomicArb {
+        fn is_dominant(&self, bundle: &Bundle) -> bool {
+            bundle.mev_type() == MevType::Sandwich
+        }
+
+        fn is_subordinate(&self, bundle: &Bundle) -> bool {
+            bundle.mev_type() == MevType::AtomicArb
+        }
+    }
+
+    fn bundle(mev_type: MevType, tx_hash: B256, data: BundleData) -> Bundle {
+        Bundle {
+            header: BundleHeader {
+                block_number: 1,
+                tx_hash,
+                mev_type,
+                profit_usd: 10.0,
+                bribe_usd: 1.0,
+                ..Default::default()
+            },
+            data,
+        }
+    }
+
+    #[brontes_macros::test]
+    async fn test_deduplicate_mev_records_suppressed() {
+        let db = TraceLoader::new().await.libmdbx;
+        let tree = Arc::new(BlockTree::new(Default::default(), 0));
+
+        let (frontrun, backrun, other) =
+            (B256::with_last_byte(1), B256::with_last_byte(2), B256::with_last_byte(3));
+        let sandwich = bundle(
+            MevType::Sandwich,
+            frontrun,
+            BundleData::Sandwich(Sandwich {
+                frontrun_tx_hash: vec![frontrun],
+                backrun_tx_hash: backrun,
+                ..Default::default()
+            }),
+        );
+        let overlapping_arb = bundle(
+            MevType::AtomicArb,
+            backrun,
+            BundleData::AtomicArb(AtomicArb { tx_hash: backrun, ..Default::default() }),
+        );
+        let other_arb = bundle(
+            MevType::AtomicArb,
+            other,
+            BundleData::AtomicArb(AtomicArb { tx_hash: other, ..Default::default() }),
+        );
+
+        let mut sorted_mev =
+            sort_mev_by_type(vec![sandwich.clone(), overlapping_arb.clone(), other_arb.clone()]);
+        let mut dedup_stats = DeduplicationStats::default();
+        deduplicate_mev(tree, db, &SandwichOverAtomicArb, &mut sorted_mev, &mut dedup_stats);
+
+        // only the arb that shares a transaction with the sandwich is dropped
+        assert_eq!(sorted_mev[&MevType::Sandwich], vec![sandwich.clone()]);
+        assert_eq!(sorted_mev[&MevType::AtomicArb], vec![other_arb]);
+
+        assert_eq!(
+            dedup_stats.suppressed,
+            vec![SuppressedBundle::new(&overlapping_arb, &sandwich)]
+        );
+        let suppressed = &dedup_stats.suppressed[0];
+        assert_eq!(suppressed.tx_hash, backrun);
+        assert_eq!(suppressed.mev_type, MevType::AtomicArb);
+        assert_eq!(suppressed.dominant_tx_hash, frontrun);
+        assert_eq!(suppressed.dominant_mev_type, MevType::Sandwich);
+        assert_eq!(dedup_stats.total_suppressed_profit_usd(), 10.0);
+    }
+
+    #[test]
+    fn test_link_oracle_backruns() {
+        let (liquidation_tx, arb_tx) = (B256::with_last_byte(1), B256::with_last_byte(2));
+        let liquidation = bundle(
+            MevType::Liquidation,
+            liquidation_tx,
+            BundleData::Liquidation(Liquidation {
+                liquidation_tx_hash: liquidation_tx,
+                ..Default::default()
+            }),
+        );
+        let oracle_backrun = |backrun_tx_hash| {
+            bundle(
+                MevType::OracleBackrun,
+                backrun_tx_hash,
+                BundleData::OracleBackrun(OracleBackrun { backrun_tx_hash, ..Default::default() }),
+            )
+        };
+
+        let mut sorted_mev = sort_mev_by_type(vec![
+            liquidation.clone(),
+            oracle_backrun(liquidation_tx),
+            oracle_backrun(arb_tx),
+        ]);
+        link_oracle_backruns(&mut sorted_mev);
+
+        // the liquidation is kept and only the backrun of its tx is linked to it
+        assert_eq!(sorted_mev[&MevType::Liquidation], vec![liquidation]);
+        let linked = sorted_mev[&MevType::OracleBackrun]
+            .iter()
+            .map(Bundle::linked_tx_hash)
+            .collect_vec();
+        assert_eq!(linked, vec![Some(liquidation_tx), None]);
+    }
+
     #[brontes_macros::test]
     pub async fn test_jit_sandwich() {
         let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 0.2).await;
@@ -3,14 +3,14 @@ use std::sync::Arc;
 use alloy_primitives::{Address, FixedBytes};
 use brontes_types::{
     db::{builder::BuilderInfo, metadata::Metadata, traits::LibmdbxReader},
-    mev::{Bundle, Mev, MevBlock, MevCount, MevType, PossibleMevCollection},
+    mev::{Bundle, BundleData, Mev, MevBlock, MevCount, MevType, PossibleMevCollection},
     normalized_actions::Action,
     tree::BlockTree,
-    FastHashMap, GasDetails, ToFloatNearest, ToScaledRational, TreeSearchBuilder,
+    FastHashMap, FastHashSet, GasDetails, ToFloatNearest, ToScaledRational, TreeSearchBuilder,
 };
 use malachite::{num::conversion::traits::RoundingFrom, rounding_modes::RoundingMode};
 
-use crate::composer::FilterFn;
+use crate::composer::DeduplicationRule;
 
 pub(crate) fn build_mev_header<DB: LibmdbxReader>(
     metadata: &Arc<Metadata>,
@@ -101,6 +101,28 @@ pub(crate) fn sort_mev_by_type(orchestra_data: Vec<Bundle>) -> FastHashMap<MevTy
         )
 }
 
+/// Links every oracle backrun to the liquidation bundle of its backrun tx.
+/// Both bundles are kept, the liquidation reports the profit of the tx.
+pub(crate) fn link_oracle_backruns(sorted_mev: &mut FastHashMap<MevType, Vec<Bundle>>) {
+    let liquidation_txes = sorted_mev
+        .get(&MevType::Liquidation)
+        .map(|bundles| {
+            bundles
+                .iter()
+                .flat_map(|bundle| bundle.data.mev_transaction_hashes())
+                .collect::<FastHashSet<_>>()
+        })
+        .unwrap_or_default();
+
+    let Some(oracle_backruns) = sorted_mev.get_mut(&MevType::OracleBackrun) else { return };
+    for bundle in oracle_backruns {
+        let BundleData::OracleBackrun(backrun) = &mut bundle.data else { continue };
+        if liquidation_txes.contains(&backrun.backrun_tx_hash) {
+            backrun.liquidation_tx_hash = Some(backrun.backrun_tx_hash);
+        }
+    }
+}
+
 /// Finds the index of the first classified mev in the list whose transaction
 /// hashes match any of the provided hashes.
 pub(crate) fn find_mev_with_matching_tx_hashes<'a>(
@@ -126,7 +148,7 @@ pub(crate) fn try_deduping_mev<'a>(
     db: Box<dyn LibmdbxReader>,
     dominate: &'a Bundle,
     mev_data_list: &'a [Bundle],
-    extra_filter_function: &'a FilterFn,
+    rule: &'a dyn DeduplicationRule,
     tx_hashes: &'a [FixedBytes<32>],
 ) -> impl Iterator<Item = usize> + 'a {
     let arc = Arc::new(db);
@@ -134,15 +156,14 @@ pub(crate) fn try_deduping_mev<'a>(
         .iter()
         .enumerate()
         .filter_map(move |(index, bundle)| {
+            if std::ptr::eq(bundle, dominate) || !rule.is_subordinate(bundle) {
+                return None
+            }
             let tx_hashes_in_mev = bundle.data.mev_transaction_hashes();
 
             let tx_hash_overlap = tx_hashes_in_mev.iter().any(|hash| tx_hashes.contains(hash));
-            let extra_args = if let Some(f) = extra_filter_function {
-                f(tree.clone(), arc.clone(), [dominate, bundle])
-            } else {
-                true
-            };
-            (tx_hash_overlap && extra_args).then_some(index)
+            (tx_hash_overlap && rule.should_dedup(tree.clone(), arc.clone(), [dominate, bundle]))
+                .then_some(index)
         })
 }
 
@@ -156,7 +177,10 @@ pub fn filter_and_count_bundles(
         let filtered_bundles: Vec<Bundle> = bundles
             .into_iter()
             .filter(|bundle| {
-                if matches!(mev_type, MevType::Sandwich | MevType::AtomicArb) {
+                if matches!(
+                    mev_type,
+                    MevType::Sandwich | MevType::MultiBlockSandwich | MevType::AtomicArb
+                ) {
                     bundle.header.profit_usd > 0.0 || bundle.header.no_pricing_calculated
                 } else {
                     true
@@ -182,6 +206,7 @@ pub fn filter_and_count_bundles(
 fn update_mev_count(mev_count: &mut MevCount, mev_type: MevType, count: u64) {
     match mev_type {
         MevType::Sandwich => mev_count.sandwich_count = Some(count),
+        MevType::MultiBlockSandwich => mev_count.multi_block_sandwich_count = Some(count),
         MevType::CexDexTrades => mev_count.cex_dex_trade_count = Some(count),
         MevType::CexDexQuotes => mev_count.cex_dex_quote_count = Some(count),
         MevType::JitCexDex => mev_count.jit_cex_dex_count = Some(count),
@@ -190,6 +215,12 @@ fn update_mev_count(mev_count: &mut MevCount, mev_type: MevType, count: u64) {
         MevType::JitSandwich => mev_count.jit_sandwich_count = Some(count),
         MevType::AtomicArb => mev_count.atomic_backrun_count = Some(count),
         MevType::Liquidation => mev_count.liquidation_count = Some(count),
+        MevType::Custom => mev_count.custom_count = Some(count),
+        MevType::GeneralizedFrontrun => mev_count.generalized_frontrun_count = Some(count),
+        MevType::IntentBackrun => mev_count.intent_backrun_count = Some(count),
+        MevType::NftArb => mev_count.nft_arb_count = Some(count),
+        MevType::Sniping => mev_count.sniping_count = Some(count),
+        MevType::OracleBackrun => mev_count.oracle_backrun_count = Some(count),
         MevType::SearcherTx => mev_count.searcher_tx_count = Some(count),
         MevType::Unknown => (),
     }
@@ -345,6 +376,7 @@ fn calculate_mev_searching_profit(bundles: &[Bundle], builder_info: &BuilderInfo
                     .map(|mev_contract| builder_info.searchers_contracts.contains(&mev_contract))
                     .unwrap_or(false)
         })
+        .filter(|bundle| bundle.linked_tx_hash().is_none())
         .fold((0.0, 0), |(accumulated_profit, accumulated_gas), bundle| {
             let profit = if bundle.mev_type() != MevType::SearcherTx {
                 bundle.header.profit_usd
@@ -453,10 +485,15 @@ pub(crate) fn pre_process(tree: Arc<BlockTree<Action>>) -> BlockPreprocessing {
 ///
 /// Returns the total priority fee, tips & profit of mev bundles in the block
 /// Ignores the profit of SearcherTx bundles as they are not considered MEV.
+/// Bundles linked to another bundle are only counted once, through the bundle
+/// they are linked to.
 fn calculate_block_mev_stats(orchestra_data: &[Bundle], base_fee: u128) -> (u128, f64, u128) {
     orchestra_data.iter().fold(
         (0u128, 0.0, 0u128),
         |(total_fee_paid, total_profit_usd, mev_bribe), bundle| {
+            if bundle.linked_tx_hash().is_some() {
+                return (total_fee_paid, total_profit_usd, mev_bribe)
+            }
             let fee_paid = bundle.data.total_priority_fee_paid(base_fee);
             let profit_usd = if bundle.mev_type() != MevType::SearcherTx {
                 bundle.header.profit_usd
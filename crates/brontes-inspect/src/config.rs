@@ -0,0 +1,263 @@
+//! Per inspector configuration.
+//!
+//! The configuration is loaded from `config/inspector_config.toml`. The
+//! `[default]` section applies to every inspector and each inspector can
+//! override any of the values in a section named after its [`Inspectors`]
+//! variant, e.g. `[Sandwich]`.
+use std::{path::Path, str::FromStr};
+
+use brontes_types::FastHashMap;
+use eyre::{eyre, WrapErr};
+use malachite::Rational;
+use serde::Deserialize;
+use toml::{Table, Value};
+
+use crate::Inspectors;
+
+pub const INSPECTOR_CONFIG_FILE: &str = "config/inspector_config.toml";
+
+const DEFAULT_SECTION: &str = "default";
+
+/// The config as it is written in the file, converted into an
+/// [`InspectorConfig`] once when it is loaded
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+struct RawInspectorConfig {
+    max_profit_usd:            f64,
+    min_profit_usd:            f64,
+    outlier_profit_multiplier: f64,
+    block_window:              Option<usize>,
+    heuristics:                FastHashMap<String, bool>,
+}
+
+impl Default for RawInspectorConfig {
+    fn default() -> Self {
+        Self {
+            max_profit_usd:            15_000_000.0,
+            min_profit_usd:            -15_000_000.0,
+            outlier_profit_multiplier: 100.0,
+            block_window:              None,
+            heuristics:                FastHashMap::default(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(try_from = "RawInspectorConfig")]
+pub struct InspectorConfig {
+    /// bundles with a profit above this are assumed to be mispriced and are
+    /// stored without a profit
+    pub max_profit_usd:            f64,
+    /// bundles with a loss below this are assumed to be mispriced and are
+    /// stored without a profit
+    pub min_profit_usd:            f64,
+    /// bundles that made more than this multiple of the bribe they paid are
+    /// reported as outliers
+    pub outlier_profit_multiplier: f64,
+    /// overrides the amount of blocks that are passed to inspectors that look
+    /// at more than one block
+    pub block_window:              Option<usize>,
+    /// inspector specific heuristics that can be turned on or off
+    pub heuristics:                FastHashMap<String, bool>,
+    max_profit:                    Rational,
+    min_profit:                    Rational,
+}
+
+impl TryFrom<RawInspectorConfig> for InspectorConfig {
+    type Error = eyre::Report;
+
+    fn try_from(raw: RawInspectorConfig) -> Result<Self, Self::Error> {
+        let to_rational = |name: &str, value: f64| {
+            Rational::try_from_float_simplest(value)
+                .map_err(|_| eyre!("`{name}` has to be a finite number, got {value}"))
+        };
+
+        let max_profit = to_rational("max_profit_usd", raw.max_profit_usd)?;
+        let min_profit = to_rational("min_profit_usd", raw.min_profit_usd)?;
+        if min_profit > max_profit {
+            return Err(eyre!(
+                "`min_profit_usd` ({}) is above `max_profit_usd` ({})",
+                raw.min_profit_usd,
+                raw.max_profit_usd
+            ))
+        }
+
+        Ok(Self {
+            max_profit_usd: raw.max_profit_usd,
+            min_profit_usd: raw.min_profit_usd,
+            outlier_profit_multiplier: raw.outlier_profit_multiplier,
+            block_window: raw.block_window,
+            heuristics: raw.heuristics,
+            max_profit,
+            min_profit,
+        })
+    }
+}
+
+impl Default for InspectorConfig {
+    fn default() -> Self {
+        RawInspectorConfig::default()
+            .try_into()
+            .expect("default inspector config is valid")
+    }
+}
+
+impl InspectorConfig {
+    pub fn max_profit(&self) -> &Rational {
+        &self.max_profit
+    }
+
+    pub fn min_profit(&self) -> &Rational {
+        &self.min_profit
+    }
+
+    /// Returns the block window set in the config or the inspectors default if
+    /// it isn't set
+    pub fn block_window_or(&self, default: usize) -> usize {
+        self.block_window.unwrap_or(default)
+    }
+
+    /// Returns whether the heuristic is enabled, falling back to the
+    /// inspectors default if it isn't set
+    pub fn heuristic(&self, name: &str, default: bool) -> bool {
+        self.heuristics.get(name).copied().unwrap_or(default)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InspectorsConfig {
+    pub default:    InspectorConfig,
+    pub inspectors: FastHashMap<Inspectors, InspectorConfig>,
+}
+
+impl InspectorsConfig {
+    pub fn load(path: impl AsRef<Path>) -> eyre::Result<Self> {
+        let path = path.as_ref();
+        let config = std::fs::read_to_string(path)
+            .wrap_err_with(|| format!("failed to read inspector config at {}", path.display()))?;
+
+        Self::from_toml_str(&config)
+    }
+
+    pub fn from_toml_str(config: &str) -> eyre::Result<Self> {
+        let mut sections: Table = toml::from_str(config)?;
+
+        let default = match sections.remove(DEFAULT_SECTION) {
+            Some(Value::Table(table)) => table,
+            Some(_) => return Err(eyre!("`{DEFAULT_SECTION}` has to be a table")),
+            None => Table::new(),
+        };
+
+        let inspectors = sections
+            .into_iter()
+            .map(|(name, overrides)| {
+                let inspector = Inspectors::from_str(&name)
+                    .map_err(|_| eyre!("unknown inspector `{name}` in inspector config"))?;
+                let Value::Table(overrides) = overrides else {
+                    return Err(eyre!("`{name}` has to be a table"))
+                };
+
+                let config = Value::Table(merge_tables(default.clone(), overrides))
+                    .try_into()
+                    .wrap_err_with(|| format!("invalid config for inspector `{name}`"))?;
+
+                Ok((inspector, config))
+            })
+            .collect::<eyre::Result<_>>()?;
+
+        Ok(Self {
+            default: Value::Table(default)
+                .try_into()
+                .wrap_err("invalid default inspector config")?,
+            inspectors,
+        })
+    }
+
+    /// Returns the config of the inspector, falling back to the default config
+    /// if it has no overrides
+    pub fn get(&self, inspector: Inspectors) -> InspectorConfig {
+        self.inspectors
+            .get(&inspector)
+            .cloned()
+            .unwrap_or_else(|| self.default.clone())
+    }
+}
+
+/// Overrides the values of `base` with the values of `overrides`, merging
+/// nested tables.
+fn merge_tables(mut base: Table, overrides: Table) -> Table {
+    for (key, value) in overrides {
+        let merged = match (base.remove(&key), value) {
+            (Some(Value::Table(base)), Value::Table(overrides)) => {
+                Value::Table(merge_tables(base, overrides))
+            }
+            (_, value) => value,
+        };
+        base.insert(key, merged);
+    }
+
+    base
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_inspector_overrides() {
+        let config = InspectorsConfig::from_toml_str(
+            r#"
+            [default]
+            max_profit_usd = 1000.0
+
+            [default.heuristics]
+            a = true
+            b = true
+
+            [Sandwich]
+            min_profit_usd = -10.0
+
+            [Sandwich.heuristics]
+            b = false
+            "#,
+        )
+        .unwrap();
+
+        let default = config.get(Inspectors::AtomicArb);
+        assert_eq!(default.max_profit_usd, 1000.0);
+        assert_eq!(default.min_profit_usd, InspectorConfig::default().min_profit_usd);
+
+        let sandwich = config.get(Inspectors::Sandwich);
+        assert_eq!(sandwich.max_profit_usd, 1000.0);
+        assert_eq!(sandwich.min_profit_usd, -10.0);
+        assert!(sandwich.heuristic("a", false));
+        assert!(!sandwich.heuristic("b", true));
+        assert!(sandwich.heuristic("c", true));
+    }
+
+    #[test]
+    fn test_invalid_profit_bounds() {
+        assert!(InspectorsConfig::from_toml_str("[default]\nmax_profit_usd = nan").is_err());
+        assert!(InspectorsConfig::from_toml_str("[Sandwich]\nmin_profit_usd = inf").is_err());
+        assert!(InspectorsConfig::from_toml_str(
+            "[default]\nmax_profit_usd = 10.0\nmin_profit_usd = 20.0"
+        )
+        .is_err());
+
+        let config = InspectorsConfig::from_toml_str("[default]\nmax_profit_usd = 10.5").unwrap();
+        assert_eq!(*config.get(Inspectors::Sandwich).max_profit(), Rational::from_signeds(21, 2));
+    }
+
+    #[test]
+    fn test_unknown_inspector() {
+        assert!(InspectorsConfig::from_toml_str("[NotAnInspector]\nblock_window = 2").is_err());
+    }
+
+    #[test]
+    fn test_load_config_file() {
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("../../")
+            .join(INSPECTOR_CONFIG_FILE);
+        InspectorsConfig::load(path).unwrap();
+    }
+}
@@ -58,11 +58,16 @@
 //! composition.
 
 pub mod composer;
+pub mod config;
 pub mod discovery;
 pub mod mev_inspectors;
+pub mod registry;
 use brontes_metrics::inspectors::OutlierMetrics;
+use composer::DeduplicationRule;
+pub use config::{InspectorConfig, InspectorsConfig};
 use mev_inspectors::searcher_activity::SearcherActivity;
 pub use mev_inspectors::*;
+pub use registry::InspectorRegistry;
 
 #[cfg(feature = "tests")]
 pub mod test_utils;
@@ -81,9 +86,14 @@ use brontes_types::{
     MultiBlockData,
 };
 use cex_dex::{markout::CexDexMarkoutInspector, quotes::CexDexQuotesInspector};
+use generalized_frontrun::GeneralizedFrontrunInspector;
+use intent_backrun::IntentBackrunInspector;
 use jit::JitCexDex;
 use liquidations::LiquidationInspector;
-use sandwich::SandwichInspector;
+use nft_arb::NftArbInspector;
+use oracle_backrun::OracleBackrunInspector;
+use sandwich::{MultiBlockSandwichInspector, SandwichInspector};
+use sniping::SnipingInspector;
 
 use crate::jit::jit_liquidity::JitInspector;
 
@@ -98,6 +108,12 @@ pub trait Inspector: Send + Sync {
     fn get_id(&self) -> &str;
     fn inspect_block(&self, data: MultiBlockData) -> Self::Result;
     fn get_quote_token(&self) -> Address;
+    /// Deduplication rules for the bundles of this inspector. Only needed by
+    /// inspectors that produce [`BundleData::Custom`] bundles, as the built in
+    /// mev types are deduplicated by the rules defined in the composer.
+    fn dedup_rules(&self) -> &[Box<dyn DeduplicationRule>] {
+        &[]
+    }
 }
 
 #[derive(
@@ -107,14 +123,20 @@ pub enum Inspectors {
     AtomicArb,
     CexDex,
     Jit,
+    GeneralizedFrontrun,
+    IntentBackrun,
     Liquidations,
+    NftArb,
+    OracleBackrun,
     Sandwich,
+    MultiBlockSandwich,
     SearcherActivity,
+    Sniping,
     CexDexMarkout,
     JitCexDex,
 }
 
-type DynMevInspector = &'static (dyn Inspector<Result = Vec<Bundle>> + 'static);
+pub type DynMevInspector = &'static (dyn Inspector<Result = Vec<Bundle>> + 'static);
 
 impl Inspectors {
     pub fn init_mev_inspector<DB: LibmdbxReader>(
@@ -123,38 +145,65 @@ impl Inspectors {
         db: &'static DB,
         cex_exchanges: &[CexExchange],
         trade_config: CexDexTradeConfig,
+        config: &InspectorsConfig,
         metrics: Option<OutlierMetrics>,
     ) -> DynMevInspector {
+        let config = config.get(*self);
+
         match &self {
             Self::AtomicArb => {
-                static_object(AtomicArbInspector::new(quote_token, db, metrics)) as DynMevInspector
-            }
-            Self::Jit => {
-                static_object(JitInspector::new(quote_token, db, metrics)) as DynMevInspector
+                static_object(AtomicArbInspector::new(quote_token, db, config, metrics))
+                    as DynMevInspector
             }
+            Self::Jit => static_object(JitInspector::new(quote_token, db, config, metrics))
+                as DynMevInspector,
 
             Self::CexDex => static_object(CexDexQuotesInspector::new(
                 quote_token,
                 db,
                 cex_exchanges,
                 trade_config.quote_offset_from_block_us,
+                config,
                 metrics,
             )) as DynMevInspector,
             Self::Sandwich => {
-                static_object(SandwichInspector::new(quote_token, db, metrics)) as DynMevInspector
+                static_object(SandwichInspector::new(quote_token, db, config, metrics))
+                    as DynMevInspector
+            }
+            Self::MultiBlockSandwich => {
+                static_object(MultiBlockSandwichInspector::new(quote_token, db, config, metrics))
+                    as DynMevInspector
+            }
+            Self::GeneralizedFrontrun => {
+                static_object(GeneralizedFrontrunInspector::new(quote_token, db, config, metrics))
+                    as DynMevInspector
+            }
+            Self::IntentBackrun => {
+                static_object(IntentBackrunInspector::new(quote_token, db, config, metrics))
+                    as DynMevInspector
             }
             Self::Liquidations => {
-                static_object(LiquidationInspector::new(quote_token, db, metrics))
+                static_object(LiquidationInspector::new(quote_token, db, config, metrics))
+                    as DynMevInspector
+            }
+            Self::NftArb => static_object(NftArbInspector::new(quote_token, db, config, metrics))
+                as DynMevInspector,
+            Self::OracleBackrun => {
+                static_object(OracleBackrunInspector::new(quote_token, db, config, metrics))
                     as DynMevInspector
             }
             Self::SearcherActivity => {
-                static_object(SearcherActivity::new(quote_token, db, metrics)) as DynMevInspector
+                static_object(SearcherActivity::new(quote_token, db, config, metrics))
+                    as DynMevInspector
             }
+            Self::Sniping => static_object(SnipingInspector::new(quote_token, db, config, metrics))
+                as DynMevInspector,
             Self::CexDexMarkout => static_object(CexDexMarkoutInspector::new(
                 quote_token,
                 db,
                 cex_exchanges,
                 trade_config,
+                config,
                 metrics,
             )) as DynMevInspector,
             Self::JitCexDex => static_object(JitCexDex {
@@ -163,9 +212,10 @@ impl Inspectors {
                     db,
                     cex_exchanges,
                     trade_config,
+                    config.clone(),
                     metrics.clone(),
                 ),
-                jit:     JitInspector::new(quote_token, db, metrics),
+                jit:     JitInspector::new(quote_token, db, config, metrics),
             }) as DynMevInspector,
         }
     }
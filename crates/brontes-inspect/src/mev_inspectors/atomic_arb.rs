@@ -17,9 +17,7 @@ use itertools::Itertools;
 use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::{Address, B256};
 
-use crate::{
-    shared_utils::SharedInspectorUtils, BlockTree, Inspector, Metadata, MAX_PROFIT, MIN_PROFIT,
-};
+use crate::{shared_utils::SharedInspectorUtils, BlockTree, Inspector, InspectorConfig, Metadata};
 
 const MAX_PRICE_DIFF: Rational = Rational::const_from_unsigneds(99, 100);
 
@@ -29,8 +27,13 @@ pub struct AtomicArbInspector<'db, DB: LibmdbxReader> {
 }
 
 impl<'db, DB: LibmdbxReader> AtomicArbInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
     }
 }
 
@@ -39,7 +42,7 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
 
     // we use a 2 block window so that we can always have a trigger tx
     fn block_window(&self) -> usize {
-        2
+        self.utils.get_config().block_window_or(2)
     }
 
     fn get_id(&self) -> &str {
@@ -59,6 +62,7 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                     Action::is_swap,
                     Action::is_transfer,
                     Action::is_eth_transfer,
+                    Action::is_wrapping,
                     Action::is_nested_action,
                 ]))
                 .t_full_map(|(tree, v)| {
@@ -86,10 +90,11 @@ impl<DB: LibmdbxReader> Inspector for AtomicArbInspector<'_, DB> {
                         metadata.clone(),
                         actions
                             .into_iter()
-                            .split_actions::<(Vec<_>, Vec<_>, Vec<_>), _>((
+                            .split_actions::<(Vec<_>, Vec<_>, Vec<_>, Vec<_>), _>((
                                 Action::try_swaps_merged,
                                 Action::try_transfer,
                                 Action::try_eth_transfer,
+                                |action: Action| action.is_wrapping().then_some(action),
                             )),
                     )
                 })
@@ -109,10 +114,15 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         trees: Vec<Arc<BlockTree<Action>>>,
         info: TxInfo,
         metadata: Arc<Metadata>,
-        data: (Vec<NormalizedSwap>, Vec<NormalizedTransfer>, Vec<NormalizedEthTransfer>),
+        data: (
+            Vec<NormalizedSwap>,
+            Vec<NormalizedTransfer>,
+            Vec<NormalizedEthTransfer>,
+            Vec<Action>,
+        ),
     ) -> Option<Bundle> {
         tracing::trace!(?info, "trying atomic");
-        let (mut swaps, transfers, eth_transfers) = data;
+        let (mut swaps, transfers, eth_transfers, wraps) = data;
         let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
 
         let mut ignore_addresses = mev_addresses.clone();
@@ -129,6 +139,7 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
             .into_iter()
             .map(Action::from)
             .chain(eth_transfers.into_iter().map(Action::from))
+            .chain(wraps)
             .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
             .account_for_actions();
 
@@ -170,7 +181,7 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
             .filter(|_| has_dex_price)
             .unwrap_or_default();
 
-        if profit >= MAX_PROFIT || profit <= MIN_PROFIT {
+        if self.utils.is_profit_out_of_bounds(&profit) {
             has_dex_price = false;
             profit = Rational::ZERO;
         }
@@ -211,7 +222,19 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
         };
         let data = BundleData::AtomicArb(backrun);
 
-        let header = self.utils.build_bundle_header(
+        let profit_usd_spread = has_dex_price
+            .then(|| {
+                self.utils.get_deltas_usd_spread(
+                    info.tx_index,
+                    &mev_addresses,
+                    &account_deltas,
+                    &metadata,
+                    false,
+                )
+            })
+            .flatten();
+
+        let mut header = self.utils.build_bundle_header(
             vec![account_deltas],
             vec![info.tx_hash],
             &info,
@@ -230,6 +253,7 @@ impl<DB: LibmdbxReader> AtomicArbInspector<'_, DB> {
                 )
             },
         );
+        header.profit_usd_spread = profit_usd_spread.map(|spread| spread.to_float());
 
         Some(Bundle { header, data })
     }
@@ -437,13 +461,36 @@ pub fn is_stable_pair(token_in: &str, token_out: &str) -> bool {
 #[cfg(test)]
 mod tests {
     use alloy_primitives::hex;
-    use brontes_types::constants::USDT_ADDRESS;
+    use brontes_types::{constants::USDT_ADDRESS, db::cex::trades::CexDexTradeConfig};
 
     use crate::{
         test_utils::{InspectorTestUtils, InspectorTxRunConfig, USDC_ADDRESS, WETH_ADDRESS},
-        Inspectors,
+        Inspector, Inspectors, InspectorsConfig,
     };
 
+    #[brontes_macros::test]
+    async fn test_block_window_override() {
+        let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 0.5).await;
+        let init = |config: &InspectorsConfig| {
+            Inspectors::AtomicArb.init_mev_inspector(
+                USDC_ADDRESS,
+                inspector_util.classifier_inspector.libmdbx,
+                &[],
+                CexDexTradeConfig::default(),
+                config,
+                None,
+            )
+        };
+
+        assert_eq!(init(&InspectorsConfig::default()).block_window(), 2);
+
+        let config = InspectorsConfig::from_toml_str("[AtomicArb]\nblock_window = 4").unwrap();
+        assert_eq!(init(&config).block_window(), 4);
+        // the override only applies to the inspector it is set for
+        let config = InspectorsConfig::from_toml_str("[Sandwich]\nblock_window = 4").unwrap();
+        assert_eq!(init(&config).block_window(), 2);
+    }
+
     #[brontes_macros::test]
     async fn test_backrun() {
         let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 0.5).await;
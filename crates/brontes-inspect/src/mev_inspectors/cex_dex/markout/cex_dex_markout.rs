@@ -46,7 +46,7 @@ use super::{
 // to classify a a negative pnl cex-dex trade as a CEX-DEX trade
 pub const FILTER_THRESHOLD: u64 = 20;
 
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
 
 pub struct CexDexMarkoutInspector<'db, DB: LibmdbxReader> {
     pub utils:     SharedInspectorUtils<'db, DB>,
@@ -60,10 +60,11 @@ impl<'db, DB: LibmdbxReader> CexDexMarkoutInspector<'db, DB> {
         db: &'db DB,
         cex_exchanges: &[CexExchange],
         trade_config: CexDexTradeConfig,
+        config: InspectorConfig,
         metrics: Option<OutlierMetrics>,
     ) -> Self {
         Self {
-            utils: SharedInspectorUtils::new(quote, db, metrics),
+            utils: SharedInspectorUtils::new(quote, db, config, metrics),
             trade_config,
             cex_exchanges: cex_exchanges.to_owned(),
         }
@@ -113,6 +114,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
                 Action::is_swap,
                 Action::is_transfer,
                 Action::is_eth_transfer,
+                Action::is_wrapping,
                 Action::is_aggregator,
                 Action::is_batch,
             ]))
@@ -275,7 +277,15 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
                 acc
             });
 
-        let header: brontes_types::mev::BundleHeader = self.utils.build_bundle_header(
+        let profit_usd_spread = self.utils.get_deltas_usd_spread(
+            tx_info.tx_index,
+            &tx_info.collect_address_set_for_accounting(),
+            &deltas,
+            &metadata,
+            true,
+        );
+
+        let mut header: brontes_types::mev::BundleHeader = self.utils.build_bundle_header(
             vec![deltas],
             vec![tx_info.tx_hash],
             &tx_info,
@@ -286,6 +296,7 @@ impl<DB: LibmdbxReader> CexDexMarkoutInspector<'_, DB> {
             false,
             |_, token, amount| Some(price_map.get(&token)? * &amount),
         );
+        header.profit_usd_spread = profit_usd_spread.map(|spread| spread.to_float());
 
         Some(Bundle { header, data: cex_dex })
     }
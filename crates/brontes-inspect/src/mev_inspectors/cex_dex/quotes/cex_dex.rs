@@ -73,7 +73,7 @@ pub const FILTER_THRESHOLD: u64 = 20;
 
 use itertools::Itertools;
 
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
 pub struct CexDexQuotesInspector<'db, DB: LibmdbxReader> {
     utils:                SharedInspectorUtils<'db, DB>,
     _quotes_fetch_offset: u64,
@@ -94,10 +94,11 @@ impl<'db, DB: LibmdbxReader> CexDexQuotesInspector<'db, DB> {
         db: &'db DB,
         cex_exchanges: &[CexExchange],
         quotes_fetch_offset: u64,
+        config: InspectorConfig,
         metrics: Option<OutlierMetrics>,
     ) -> Self {
         Self {
-            utils:                SharedInspectorUtils::new(quote, db, metrics),
+            utils:                SharedInspectorUtils::new(quote, db, config, metrics),
             _quotes_fetch_offset: quotes_fetch_offset,
             _cex_exchanges:       cex_exchanges.to_owned(),
         }
@@ -163,6 +164,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                 Action::is_swap,
                 Action::is_transfer,
                 Action::is_eth_transfer,
+                Action::is_wrapping,
                 Action::is_aggregator,
             ]))
             .filter_map(|(tx, swaps)| {
@@ -241,7 +243,15 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                 let (profit_usd, cex_dex) =
                     self.filter_possible_cex_dex(possible_cex_dex, &tx_info, &metadata)?;
 
-                let header = self.utils.build_bundle_header(
+                let profit_usd_spread = self.utils.get_deltas_usd_spread(
+                    tx_info.tx_index,
+                    &tx_info.collect_address_set_for_accounting(),
+                    &deltas,
+                    &metadata,
+                    true,
+                );
+
+                let mut header = self.utils.build_bundle_header(
                     vec![deltas],
                     vec![tx_info.tx_hash],
                     &tx_info,
@@ -252,6 +262,7 @@ impl<DB: LibmdbxReader> CexDexQuotesInspector<'_, DB> {
                     false,
                     |_, token, amount| Some(price_map.get(&token)? * amount),
                 );
+                header.profit_usd_spread = profit_usd_spread.map(|spread| spread.to_float());
 
                 Some(Bundle { header, data: cex_dex })
             })
@@ -0,0 +1,443 @@
+//! Generalized front-running. Bots simulate pending transactions and, if
+//! replaying the calldata or the path of a transaction under their own address
+//! is profitable, land the copy in front of the original. The original then
+//! either reverts or earns less than the copy. A reverted original has no
+//! actions left to compare, so it is matched on the call it was sent with.
+use std::{mem::Discriminant, sync::Arc};
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, GeneralizedFrontrun, MevType},
+    normalized_actions::{
+        accounting::{ActionAccounting, AddressDeltas},
+        Action,
+    },
+    tree::BlockTree,
+    BlockData, FastHashMap, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::{Address, Bytes, B256};
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
+
+/// the max amount of transactions between the frontrun and the copied
+/// transaction
+const MAX_FRONTRUN_DISTANCE: usize = 10;
+/// the min jaccard similarity of the contracts both transactions interacted
+/// with
+const MIN_TARGET_SIMILARITY: f64 = 0.75;
+
+pub struct GeneralizedFrontrunInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> GeneralizedFrontrunInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for GeneralizedFrontrunInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "GeneralizedFrontrun"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let fingerprints = self.get_fingerprints(tree.clone());
+
+            get_possible_frontruns(&fingerprints)
+                .into_iter()
+                .filter_map(|(frontrun, victim)| {
+                    self.calculate_frontrun(
+                        tree.clone(),
+                        metadata.clone(),
+                        &fingerprints[frontrun],
+                        &fingerprints[victim],
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::GeneralizedFrontrun, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> GeneralizedFrontrunInspector<'_, DB> {
+    fn get_fingerprints(&self, tree: Arc<BlockTree<Action>>) -> Vec<TxFingerprint> {
+        let mut actions = tree
+            .clone()
+            .collect_all(TreeSearchBuilder::default().with_action(is_any_action))
+            .collect::<FastHashMap<_, _>>();
+
+        tree.tx_roots
+            .iter()
+            .map(|root| {
+                let actions = self
+                    .utils
+                    .flatten_nested_actions_default(
+                        actions
+                            .remove(&root.tx_hash)
+                            .unwrap_or_default()
+                            .into_iter(),
+                    )
+                    .collect_vec();
+
+                TxFingerprint::new(
+                    root.tx_hash,
+                    root.head.address,
+                    root.get_root_action().is_revert(),
+                    (root.to, root.calldata.clone()),
+                    actions,
+                )
+            })
+            .collect_vec()
+    }
+
+    fn calculate_frontrun(
+        &self,
+        tree: Arc<BlockTree<Action>>,
+        metadata: Arc<Metadata>,
+        frontrun: &TxFingerprint,
+        victim: &TxFingerprint,
+    ) -> Option<Bundle> {
+        let mut infos = tree
+            .get_tx_info_batch(&[frontrun.tx_hash, victim.tx_hash], self.utils.db)
+            .into_iter();
+        let frontrun_info = infos.next()??;
+        let victim_info = infos.next()??;
+
+        let (Some(frontrun_profit), deltas) =
+            self.get_profit(&frontrun_info, metadata.clone(), frontrun.actions.clone())
+        else {
+            return None
+        };
+
+        let victim_profit = if victim.reverted {
+            Rational::ZERO
+                - metadata.get_gas_price_usd(victim_info.gas_details.gas_paid(), self.utils.quote)
+        } else {
+            self.get_profit(&victim_info, metadata.clone(), victim.actions.clone())
+                .0?
+        };
+
+        // the copy has to have paid off, at the expense of the original
+        if frontrun_profit <= Rational::ZERO || victim_profit >= frontrun_profit {
+            return None
+        }
+
+        let (profit_usd, has_dex_price) = if self.utils.is_profit_out_of_bounds(&frontrun_profit) {
+            (Rational::ZERO, false)
+        } else {
+            (frontrun_profit, true)
+        };
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![frontrun_info.tx_hash],
+            &frontrun_info,
+            profit_usd.to_float(),
+            &[frontrun_info.gas_details],
+            metadata.clone(),
+            MevType::GeneralizedFrontrun,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    frontrun_info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let shared_targets = frontrun
+            .targets
+            .intersection(&victim.targets)
+            .copied()
+            .sorted()
+            .collect_vec();
+
+        let generalized_frontrun = GeneralizedFrontrun {
+            block_number: metadata.block_num,
+            frontrun_tx_hash: frontrun_info.tx_hash,
+            frontrun_tx_index: frontrun_info.tx_index,
+            frontrun_swaps: frontrun
+                .actions
+                .iter()
+                .filter_map(|action| action.try_swaps_merged_ref().cloned())
+                .collect(),
+            frontrun_gas_details: frontrun_info.gas_details,
+            victim_tx_hash: victim_info.tx_hash,
+            victim_tx_index: victim_info.tx_index,
+            victim_swaps: victim
+                .actions
+                .iter()
+                .filter_map(|action| action.try_swaps_merged_ref().cloned())
+                .collect(),
+            victim_gas_details: victim_info.gas_details,
+            victim_reverted: victim.reverted,
+            victim_profit_usd: victim_profit.to_float(),
+            shared_targets,
+        };
+
+        Some(Bundle { header, data: BundleData::GeneralizedFrontrun(generalized_frontrun) })
+    }
+
+    /// Returns the usd profit of the transaction, net of gas, along with its
+    /// balance deltas
+    fn get_profit(
+        &self,
+        info: &TxInfo,
+        metadata: Arc<Metadata>,
+        actions: Vec<Action>,
+    ) -> (Option<Rational>, AddressDeltas) {
+        let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
+
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer() || a.is_wrapping())
+            .account_for_actions();
+
+        let gas_finalized =
+            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let profit = self
+            .utils
+            .get_deltas_usd(info.tx_index, PriceAt::After, &mev_addresses, &deltas, metadata, false)
+            .map(|rev| rev - gas_finalized);
+
+        (profit, deltas)
+    }
+}
+
+/// What a transaction did, reduced to what is needed to tell if one
+/// transaction is a copy of another
+#[derive(Debug, Clone)]
+struct TxFingerprint {
+    tx_hash:  B256,
+    eoa:      Address,
+    reverted: bool,
+    /// the contract the transaction called and its calldata
+    call:     (Address, Bytes),
+    /// the contracts the transaction interacted with
+    targets:  FastHashSet<Address>,
+    /// the classified actions of the transaction, in order, as the kind of
+    /// action and the contract it was executed on
+    path:     Vec<(Discriminant<Action>, Address)>,
+    actions:  Vec<Action>,
+}
+
+impl TxFingerprint {
+    fn new(
+        tx_hash: B256,
+        eoa: Address,
+        reverted: bool,
+        call: (Address, Bytes),
+        actions: Vec<Action>,
+    ) -> Self {
+        // the actions of a reverted transaction were rolled back
+        if reverted {
+            let targets = FastHashSet::from_iter([call.0]);
+            return Self { tx_hash, eoa, reverted, call, targets, path: vec![], actions }
+        }
+
+        let targets = actions
+            .iter()
+            .filter(|action| !action.is_revert())
+            .map(target_of)
+            .filter(|target| *target != eoa && *target != Address::ZERO)
+            .collect();
+
+        let path = actions
+            .iter()
+            .filter(|action| {
+                !(action.is_revert()
+                    || action.is_transfer()
+                    || action.is_eth_transfer()
+                    || action.is_wrapping()
+                    || action.is_unclassified())
+            })
+            .map(|action| (std::mem::discriminant(action), action.get_to_address()))
+            .collect();
+
+        Self { tx_hash, eoa, reverted, call, targets, path, actions }
+    }
+
+    fn target_similarity(&self, other: &Self) -> f64 {
+        let union = self.targets.union(&other.targets).count();
+        if union == 0 {
+            return 0.0
+        }
+
+        self.targets.intersection(&other.targets).count() as f64 / union as f64
+    }
+
+    /// A transaction can only be a copy of the other if it was sent by a
+    /// different account and either replayed the call of the reverted
+    /// original or interacted with the same contracts and executed the same
+    /// path.
+    fn is_copy_of(&self, original: &Self) -> bool {
+        if self.eoa == original.eoa || self.reverted {
+            return false
+        }
+
+        if original.reverted {
+            return self.call == original.call
+        }
+
+        self.target_similarity(original) >= MIN_TARGET_SIMILARITY
+            && !self.path.is_empty()
+            && self.path == original.path
+    }
+}
+
+fn is_any_action(_: &Action) -> bool {
+    true
+}
+
+/// The contract the action was executed on. For transfers this is the token
+/// rather than the recipient.
+fn target_of(action: &Action) -> Address {
+    match action {
+        Action::Transfer(transfer) => transfer.token.address,
+        action => action.get_to_address(),
+    }
+}
+
+/// Pairs every transaction with the closest preceding transaction that copied
+/// it. Each frontrun is only paired with a single victim.
+fn get_possible_frontruns(fingerprints: &[TxFingerprint]) -> Vec<(usize, usize)> {
+    let mut used_frontruns = FastHashSet::default();
+    let mut possible_frontruns = vec![];
+
+    for (victim_idx, victim) in fingerprints.iter().enumerate() {
+        let Some(frontrun_idx) = (victim_idx.saturating_sub(MAX_FRONTRUN_DISTANCE)..victim_idx)
+            .rev()
+            .find(|idx| !used_frontruns.contains(idx) && fingerprints[*idx].is_copy_of(victim))
+        else {
+            continue
+        };
+
+        used_frontruns.insert(frontrun_idx);
+        possible_frontruns.push((frontrun_idx, victim_idx));
+    }
+
+    possible_frontruns
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+    use brontes_core::TraceLoader;
+    use brontes_types::{
+        constants::USDC_ADDRESS,
+        db::token_info::TokenInfoWithAddress,
+        normalized_actions::{NormalizedSwap, NormalizedTransfer},
+        tree::{root::NodeData, GasDetails, Node, Root},
+    };
+    use reth_primitives::Header;
+
+    use super::*;
+
+    fn swap(pool: Address) -> Action {
+        Action::Swap(NormalizedSwap { pool, ..Default::default() })
+    }
+
+    fn transfer(token: Address) -> Action {
+        Action::Transfer(NormalizedTransfer {
+            token: TokenInfoWithAddress { address: token, ..Default::default() },
+            ..Default::default()
+        })
+    }
+
+    fn fingerprint(eoa: u8, actions: Vec<Action>) -> TxFingerprint {
+        TxFingerprint::new(
+            B256::with_last_byte(eoa),
+            Address::with_last_byte(eoa),
+            false,
+            (Address::ZERO, Bytes::new()),
+            actions,
+        )
+    }
+
+    /// A root as the tree builder creates it. The root trace of a reverted
+    /// transaction is classified as [`Action::Revert`]
+    fn root(tx_idx: u8, eoa: u8, call: (Address, Bytes), actions: Vec<Action>) -> Root<Action> {
+        Root {
+            head: Node::new(0, Address::with_last_byte(eoa), vec![]),
+            position: tx_idx as usize,
+            tx_hash: B256::with_last_byte(tx_idx),
+            to: call.0,
+            calldata: call.1,
+            private: false,
+            gas_details: GasDetails::default(),
+            total_msg_value_transfers: vec![],
+            data_store: NodeData(vec![Some(actions)]),
+        }
+    }
+
+    #[test]
+    fn test_get_possible_frontruns() {
+        let pool = Address::with_last_byte(100);
+        let other_pool = Address::with_last_byte(101);
+
+        let fingerprints = vec![
+            // copy of the tx at index 2
+            fingerprint(1, vec![swap(pool), transfer(pool)]),
+            // unrelated
+            fingerprint(2, vec![swap(other_pool)]),
+            // the original
+            fingerprint(3, vec![swap(pool), transfer(pool)]),
+            // same account as the original, not a copy
+            fingerprint(3, vec![swap(pool)]),
+        ];
+
+        assert_eq!(get_possible_frontruns(&fingerprints), vec![(0, 2)]);
+    }
+
+    #[brontes_macros::test]
+    async fn test_copy_of_reverted() {
+        let db = TraceLoader::new().await.libmdbx;
+        let inspector =
+            GeneralizedFrontrunInspector::new(USDC_ADDRESS, db, InspectorConfig::default(), None);
+
+        let (router, pool) = (Address::with_last_byte(100), Address::with_last_byte(101));
+        let call = (router, Bytes::from(hex!("7ff36ab5")));
+        let other_call = (router, Bytes::from(hex!("18cbafe5")));
+
+        let mut tree = BlockTree::new(Header::default(), 3);
+        // replays the call of the original in front of it
+        tree.insert_root(root(0, 1, call.clone(), vec![swap(pool), transfer(pool)]));
+        // calls the same contract with different calldata
+        tree.insert_root(root(1, 2, other_call, vec![swap(pool), transfer(pool)]));
+        // the original, which reverted
+        tree.insert_root(root(2, 3, call, vec![Action::Revert]));
+
+        let fingerprints = inspector.get_fingerprints(Arc::new(tree));
+
+        assert!(fingerprints[2].reverted);
+        assert_eq!(get_possible_frontruns(&fingerprints), vec![(0, 2)]);
+    }
+}
@@ -0,0 +1,342 @@
+//! Intent settlement backruns. Order flow auctions (UniswapX, Cowswap, 1inch
+//! Fusion) settle user orders through a solver, who routes the fill through
+//! on-chain pools. The price impact of the fill is then captured by a searcher
+//! that backruns the settlement. The value of the order flow is split between
+//! the two, so both the filler and the backrunner profit are tracked.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, IntentBackrun, MevType},
+    normalized_actions::{
+        accounting::{ActionAccounting, AddressDeltas},
+        Action, NormalizedBatch, NormalizedSwap,
+    },
+    BlockData, FastHashSet, MultiBlockData, Protocol, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::{Address, B256};
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
+
+/// the max amount of transactions after the settlement that we still consider
+/// to be a backrun of the settlement
+const MAX_BACKRUN_DISTANCE: usize = 10;
+
+pub struct IntentBackrunInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> IntentBackrunInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for IntentBackrunInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "IntentBackrun"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let txes = tree
+                .clone()
+                .collect_all(TreeSearchBuilder::default().with_actions([
+                    Action::is_batch,
+                    Action::is_swap,
+                    Action::is_transfer,
+                    Action::is_eth_transfer,
+                    Action::is_wrapping,
+                    Action::is_aggregator,
+                ]))
+                .map(|(tx_hash, actions)| {
+                    // the batch of a settlement is kept intact so the solver can be
+                    // attributed
+                    if actions.iter().any(is_intent_settlement) {
+                        return (tx_hash, actions)
+                    }
+
+                    let actions = self
+                        .utils
+                        .flatten_nested_actions_default(actions.into_iter())
+                        .collect_vec();
+                    (tx_hash, actions)
+                })
+                .collect_vec();
+
+            let possible_backruns = get_possible_intent_backruns(txes);
+            let tx = possible_backruns
+                .iter()
+                .flat_map(|backrun| [backrun.settlement_tx_hash, backrun.backrun_tx_hash])
+                .collect_vec();
+            let mut tx_info = tree.get_tx_info_batch(&tx, self.utils.db).into_iter();
+
+            possible_backruns
+                .into_iter()
+                .filter_map(|backrun| {
+                    let settlement_info = tx_info.next()?;
+                    let backrun_info = tx_info.next()?;
+
+                    self.calculate_intent_backrun(
+                        settlement_info?,
+                        backrun_info?,
+                        metadata.clone(),
+                        backrun,
+                    )
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::IntentBackrun, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> IntentBackrunInspector<'_, DB> {
+    fn calculate_intent_backrun(
+        &self,
+        settlement_info: TxInfo,
+        backrun_info: TxInfo,
+        metadata: Arc<Metadata>,
+        backrun: PossibleIntentBackrun,
+    ) -> Option<Bundle> {
+        let PossibleIntentBackrun {
+            batch, settlement_actions, backrun_actions, backrun_swaps, ..
+        } = backrun;
+
+        let mut solver_addresses = settlement_info.collect_address_set_for_accounting();
+        solver_addresses.insert(batch.solver);
+
+        let (filler_profit, _) = self.get_profit(
+            &settlement_info,
+            &solver_addresses,
+            metadata.clone(),
+            settlement_actions,
+        );
+
+        let (rev, deltas) = self.get_profit(
+            &backrun_info,
+            &backrun_info.collect_address_set_for_accounting(),
+            metadata.clone(),
+            backrun_actions,
+        );
+
+        let mut has_dex_price = rev.is_some();
+        let mut profit_usd = rev.unwrap_or_default();
+
+        if self.utils.is_profit_out_of_bounds(&profit_usd) {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![backrun_info.tx_hash],
+            &backrun_info,
+            profit_usd.clone().to_float(),
+            &[backrun_info.gas_details],
+            metadata.clone(),
+            MevType::IntentBackrun,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    backrun_info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let intent_backrun = IntentBackrun {
+            block_number: metadata.block_num,
+            settlement_tx_hash: settlement_info.tx_hash,
+            settlement_tx_index: settlement_info.tx_index,
+            settlement_protocol: batch.protocol,
+            solver: batch.solver,
+            solver_swaps: batch.solver_swaps.unwrap_or_default(),
+            filler_profit_usd: filler_profit.unwrap_or_default().to_float(),
+            settlement_gas_details: settlement_info.gas_details,
+            backrun_tx_hash: backrun_info.tx_hash,
+            backrun_tx_index: backrun_info.tx_index,
+            backrun_swaps,
+            backrun_profit_usd: profit_usd.to_float(),
+            backrun_gas_details: backrun_info.gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::IntentBackrun(intent_backrun) })
+    }
+
+    /// Returns the usd profit of the given addresses in the transaction, net of
+    /// gas, along with the balance deltas of the transaction
+    fn get_profit(
+        &self,
+        info: &TxInfo,
+        addresses: &FastHashSet<Address>,
+        metadata: Arc<Metadata>,
+        actions: Vec<Action>,
+    ) -> (Option<Rational>, AddressDeltas) {
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer() || a.is_wrapping())
+            .account_for_actions();
+
+        let gas_finalized =
+            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let profit = self
+            .utils
+            .get_deltas_usd(info.tx_index, PriceAt::After, addresses, &deltas, metadata, false)
+            .map(|rev| rev - gas_finalized);
+
+        (profit, deltas)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PossibleIntentBackrun {
+    settlement_tx_hash: B256,
+    batch:              NormalizedBatch,
+    settlement_actions: Vec<Action>,
+    backrun_tx_hash:    B256,
+    backrun_actions:    Vec<Action>,
+    /// the swaps of the backrun that traded back against the solver's swaps
+    backrun_swaps:      Vec<NormalizedSwap>,
+}
+
+/// Walks the block in order, pairing every intent settlement with the first
+/// transaction within [`MAX_BACKRUN_DISTANCE`] that swaps back through one of
+/// the pools the solver routed the fill through.
+fn get_possible_intent_backruns(txes: Vec<(B256, Vec<Action>)>) -> Vec<PossibleIntentBackrun> {
+    let mut possible_backruns = vec![];
+    let mut pending: Vec<(usize, B256, NormalizedBatch, Vec<Action>)> = vec![];
+
+    for (tx_index, (tx_hash, actions)) in txes.into_iter().enumerate() {
+        pending
+            .retain(|(settlement_index, ..)| tx_index - settlement_index <= MAX_BACKRUN_DISTANCE);
+
+        if let Some(batch) = actions
+            .iter()
+            .find(|action| is_intent_settlement(action))
+            .and_then(|action| action.try_batch_ref())
+            .cloned()
+        {
+            pending.push((tx_index, tx_hash, batch, actions));
+            continue
+        }
+
+        let swaps = actions
+            .iter()
+            .filter_map(Action::try_swaps_merged_ref)
+            .collect_vec();
+
+        let Some(pos) = pending
+            .iter()
+            .position(|(_, _, batch, _)| swaps.iter().any(|swap| is_reverse_of_fill(swap, batch)))
+        else {
+            continue
+        };
+
+        let (_, settlement_tx_hash, batch, settlement_actions) = pending.remove(pos);
+        let backrun_swaps = swaps
+            .into_iter()
+            .filter(|swap| is_reverse_of_fill(swap, &batch))
+            .cloned()
+            .collect_vec();
+
+        possible_backruns.push(PossibleIntentBackrun {
+            settlement_tx_hash,
+            batch,
+            settlement_actions,
+            backrun_tx_hash: tx_hash,
+            backrun_actions: actions,
+            backrun_swaps,
+        });
+    }
+
+    possible_backruns
+}
+
+fn is_intent_settlement(action: &Action) -> bool {
+    action.try_batch_ref().is_some_and(|batch| {
+        matches!(batch.protocol, Protocol::UniswapX | Protocol::Cowswap | Protocol::OneInchFusion)
+    })
+}
+
+/// A swap that trades against the price impact of one of the solver swaps, on
+/// the same pool and in the opposite direction
+fn is_reverse_of_fill(swap: &NormalizedSwap, batch: &NormalizedBatch) -> bool {
+    batch.solver_swaps.iter().flatten().any(|fill| {
+        fill.pool == swap.pool
+            && fill.token_out.address == swap.token_in.address
+            && fill.token_in.address == swap.token_out.address
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::db::token_info::TokenInfoWithAddress;
+
+    use super::*;
+
+    fn swap(pool: Address, token_in: Address, token_out: Address) -> NormalizedSwap {
+        NormalizedSwap {
+            pool,
+            token_in: TokenInfoWithAddress { address: token_in, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: token_out, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    fn settlement(fill: NormalizedSwap) -> Action {
+        Action::Batch(NormalizedBatch {
+            protocol: Protocol::UniswapX,
+            solver_swaps: Some(vec![fill]),
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_possible_intent_backruns() {
+        let pool = Address::with_last_byte(1);
+        let weth = Address::with_last_byte(2);
+        let usdc = Address::with_last_byte(3);
+
+        let txes = vec![
+            (B256::with_last_byte(0), vec![settlement(swap(pool, weth, usdc))]),
+            // same direction as the fill, not a backrun
+            (B256::with_last_byte(1), vec![Action::Swap(swap(pool, weth, usdc))]),
+            (B256::with_last_byte(2), vec![Action::Swap(swap(pool, usdc, weth))]),
+            // the settlement was already backrun
+            (B256::with_last_byte(3), vec![Action::Swap(swap(pool, usdc, weth))]),
+        ];
+
+        let backruns = get_possible_intent_backruns(txes);
+
+        assert_eq!(backruns.len(), 1);
+        assert_eq!(backruns[0].settlement_tx_hash, B256::with_last_byte(0));
+        assert_eq!(backruns[0].backrun_tx_hash, B256::with_last_byte(2));
+        assert_eq!(backruns[0].backrun_swaps, vec![swap(pool, usdc, weth)]);
+    }
+}
@@ -19,8 +19,8 @@ use reth_primitives::TxHash;
 
 use super::types::{PossibleJit, PossibleJitWithInfo};
 use crate::{
-    shared_utils::SharedInspectorUtils, Action, BlockTree, BundleData, Inspector, Metadata,
-    MAX_PROFIT, MIN_PROFIT,
+    shared_utils::SharedInspectorUtils, Action, BlockTree, BundleData, Inspector, InspectorConfig,
+    Metadata,
 };
 
 pub struct JitInspector<'db, DB: LibmdbxReader> {
@@ -28,8 +28,13 @@ pub struct JitInspector<'db, DB: LibmdbxReader> {
 }
 
 impl<'db, DB: LibmdbxReader> JitInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
     }
 }
 
@@ -119,6 +124,7 @@ impl<DB: LibmdbxReader> JitInspector<'_, DB> {
                             Action::is_burn,
                             Action::is_transfer,
                             Action::is_eth_transfer,
+                            Action::is_wrapping,
                             Action::is_nested_action,
                         ]),
                     ),
@@ -128,6 +134,7 @@ impl<DB: LibmdbxReader> JitInspector<'_, DB> {
                             || actions.is_collect()
                             || actions.is_transfer()
                             || actions.is_eth_transfer()
+                            || actions.is_wrapping()
                     },
                 )
                 .collect::<Vec<_>>()
@@ -202,7 +209,7 @@ impl<DB: LibmdbxReader> JitInspector<'_, DB> {
 
         let deltas = rem
             .into_iter()
-            .filter(|f| f.is_transfer() || f.is_eth_transfer())
+            .filter(|f| f.is_transfer() || f.is_eth_transfer() || f.is_wrapping())
             .chain(
                 info_set
                     .iter()
@@ -242,7 +249,7 @@ impl<DB: LibmdbxReader> JitInspector<'_, DB> {
             .filter(|_| has_dex_price)
             .unwrap_or_default();
 
-        if profit >= MAX_PROFIT || profit <= MIN_PROFIT {
+        if self.utils.is_profit_out_of_bounds(&profit) {
             has_dex_price = false;
             profit = Rational::ZERO;
         }
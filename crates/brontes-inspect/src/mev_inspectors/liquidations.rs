@@ -5,23 +5,31 @@ use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
     db::dex::PriceAt,
     mev::{Bundle, BundleData, Liquidation, MevType},
-    normalized_actions::{accounting::ActionAccounting, Action},
-    ActionIter, BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+    normalized_actions::{
+        accounting::{ActionAccounting, AddressDeltas},
+        Action, LiquidationKind, NormalizedLiquidation,
+    },
+    ActionIter, BlockData, FastHashSet, MultiBlockData, Protocol, ToFloatNearest,
+    TreeSearchBuilder, TxInfo,
 };
 use itertools::multizip;
 use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::{b256, Address};
 
-use super::{MAX_PROFIT, MIN_PROFIT};
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
 
 pub struct LiquidationInspector<'db, DB: LibmdbxReader> {
     utils: SharedInspectorUtils<'db, DB>,
 }
 
 impl<'db, DB: LibmdbxReader> LiquidationInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
     }
 }
 
@@ -48,20 +56,38 @@ impl<DB: LibmdbxReader> Inspector for LiquidationInspector<'_, DB> {
                     Action::is_liquidation,
                     Action::is_transfer,
                     Action::is_eth_transfer,
+                    Action::is_wrapping,
                     Action::is_aggregator,
+                    // the repaid debt is often funded with a flash loan, such as a dss flash mint
+                    Action::is_flash_loan,
                 ]))
                 .unzip();
             let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
 
-            multizip((liq, tx_info))
+            let mut txs = multizip((liq, tx_info))
                 .filter_map(|(liq, info)| {
                     let info = info?;
+                    // absorbs of several compound v3 positions are grouped under the absorb
                     let actions = self
                         .utils
-                        .flatten_nested_actions_default(liq.into_iter())
+                        .flatten_nested_actions(liq.into_iter(), &|action| {
+                            action.is_swap()
+                                || action.is_liquidation()
+                                || action.is_transfer()
+                                || action.is_eth_transfer()
+                                || action.is_wrapping()
+                        })
                         .collect::<Vec<_>>();
 
-                    self.calculate_liquidation(info, metadata.clone(), actions)
+                    Some((info, actions))
+                })
+                .collect::<Vec<_>>();
+            let linked_absorbs = link_comet_liquidations(&mut txs);
+
+            txs.into_iter()
+                .zip(linked_absorbs)
+                .filter_map(|((info, actions), absorbs)| {
+                    self.calculate_liquidation(info, metadata.clone(), actions, absorbs)
                 })
                 .collect::<Vec<_>>()
         };
@@ -78,6 +104,7 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
         info: TxInfo,
         metadata: Arc<Metadata>,
         actions: Vec<Action>,
+        linked_absorbs: Vec<TxInfo>,
     ) -> Option<Bundle> {
         let (swaps, liqs): (Vec<_>, Vec<_>) = actions
             .clone()
@@ -94,7 +121,7 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
         let deltas = actions
             .into_iter()
             .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
-            .filter(|a| a.is_eth_transfer() || a.is_transfer())
+            .filter(|a| a.is_eth_transfer() || a.is_transfer() || a.is_wrapping())
             .account_for_actions();
 
         let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
@@ -110,25 +137,42 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
             (Some(Rational::ZERO), false)
         };
 
-        let gas_finalized =
-            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+        // the absorbs linked to this purchase are part of the bundle, they don't move
+        // any tokens of the liquidator but their gas is paid for it
+        let gas_details = linked_absorbs
+            .iter()
+            .map(|absorb| absorb.gas_details)
+            .chain(std::iter::once(info.gas_details))
+            .collect::<Vec<_>>();
+        let gas_finalized = metadata.get_gas_price_usd(
+            gas_details.iter().map(|gas| gas.gas_paid()).sum(),
+            self.utils.quote,
+        );
 
         let mut profit_usd = rev
             .map(|rev| rev - &gas_finalized)
             .filter(|_| has_dex_price)
             .unwrap_or_default();
 
-        if profit_usd >= MAX_PROFIT || profit_usd <= MIN_PROFIT {
+        if self.utils.is_profit_out_of_bounds(&profit_usd) {
             has_dex_price = false;
             profit_usd = Rational::ZERO;
         }
 
+        let mut bundle_deltas = vec![AddressDeltas::default(); linked_absorbs.len()];
+        bundle_deltas.push(deltas);
+        let bundle_txes = linked_absorbs
+            .iter()
+            .map(|absorb| absorb.tx_hash)
+            .chain(std::iter::once(info.tx_hash))
+            .collect();
+
         let header = self.utils.build_bundle_header(
-            vec![deltas],
-            vec![info.tx_hash],
+            bundle_deltas,
+            bundle_txes,
             &info,
             profit_usd.to_float(),
-            &[info.gas_details],
+            &gas_details,
             metadata.clone(),
             MevType::Liquidation,
             !has_dex_price,
@@ -156,16 +200,139 @@ impl<DB: LibmdbxReader> LiquidationInspector<'_, DB> {
     }
 }
 
+/// Compound V3 splits a liquidation into the absorb of the underwater account
+/// and the purchase of the absorbed collateral, which are usually done in
+/// separate transactions. The absorbs of the block done by the same searcher
+/// are moved into the first transaction buying their collateral, so that the
+/// purchase and the absorbs it profits from end up in the same bundle. Returns
+/// the transactions whose absorbs were moved into each transaction.
+fn link_comet_liquidations(txs: &mut [(TxInfo, Vec<Action>)]) -> Vec<Vec<TxInfo>> {
+    let mut linked_txs = vec![Vec::new(); txs.len()];
+
+    for i in 0..txs.len() {
+        let purchased = txs[i]
+            .1
+            .iter()
+            .filter_map(Action::try_liquidation_ref)
+            .filter(|liq| is_collateral_purchase(liq))
+            .map(|liq| (liq.pool, liq.collateral_asset.address))
+            .collect::<FastHashSet<_>>();
+
+        if purchased.is_empty() {
+            continue
+        }
+
+        let (earlier, later) = txs.split_at_mut(i);
+        let buyer = &later[0].0;
+
+        let mut absorbs = Vec::new();
+        for (info, actions) in earlier {
+            if !same_searcher(info, buyer) {
+                continue
+            }
+
+            let (linked, kept): (Vec<_>, Vec<_>) =
+                std::mem::take(actions).into_iter().partition(|action| {
+                    action.try_liquidation_ref().is_some_and(|liq| {
+                        liq.protocol == Protocol::CompoundV3
+                            && liq.kind == LiquidationKind::Absorbed
+                            && purchased.contains(&(liq.pool, liq.collateral_asset.address))
+                    })
+                });
+
+            *actions = kept;
+            if !linked.is_empty() {
+                linked_txs[i].push(info.clone());
+                absorbs.extend(linked);
+            }
+        }
+
+        later[0].1.extend(absorbs);
+    }
+
+    linked_txs
+}
+
+fn same_searcher(a: &TxInfo, b: &TxInfo) -> bool {
+    a.eoa == b.eoa || (a.mev_contract.is_some() && a.mev_contract == b.mev_contract)
+}
+
+/// Compound V3 collateral purchases are classified with the market as the
+/// debtor, as the collateral was already absorbed by the protocol
+fn is_collateral_purchase(liquidation: &NormalizedLiquidation) -> bool {
+    liquidation.protocol == Protocol::CompoundV3 && liquidation.debtor == liquidation.pool
+}
+
 #[cfg(test)]
 mod tests {
 
-    use alloy_primitives::hex;
+    use alloy_primitives::{hex, B256};
+    use brontes_types::{db::token_info::TokenInfoWithAddress, GasDetails};
 
+    use super::*;
     use crate::{
         test_utils::{InspectorTestUtils, InspectorTxRunConfig, USDC_ADDRESS},
         Inspectors,
     };
 
+    const COMET: Address = Address::new(hex!("c3d688B66703497DAA19211EEdff47f25384cdc3"));
+    const WETH: Address = Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
+
+    fn tx(tx_index: u64, eoa: u8) -> TxInfo {
+        TxInfo::new(
+            1,
+            tx_index,
+            Address::with_last_byte(eoa),
+            None,
+            None,
+            B256::with_last_byte(tx_index as u8),
+            GasDetails::default(),
+            true,
+            false,
+            false,
+            false,
+            None,
+            None,
+            vec![],
+        )
+    }
+
+    fn comet_liquidation(debtor: Address, kind: LiquidationKind) -> Action {
+        Action::Liquidation(NormalizedLiquidation {
+            protocol: Protocol::CompoundV3,
+            pool: COMET,
+            debtor,
+            collateral_asset: TokenInfoWithAddress { address: WETH, ..Default::default() },
+            kind,
+            ..Default::default()
+        })
+    }
+
+    #[test]
+    fn test_link_comet_liquidations() {
+        let own_absorb = comet_liquidation(Address::with_last_byte(10), LiquidationKind::Absorbed);
+        let other_absorb =
+            comet_liquidation(Address::with_last_byte(11), LiquidationKind::Absorbed);
+        let purchase = comet_liquidation(COMET, LiquidationKind::Repaid);
+
+        let mut txs = vec![
+            (tx(0, 1), vec![own_absorb.clone()]),
+            (tx(1, 2), vec![other_absorb.clone()]),
+            (tx(2, 1), vec![purchase.clone()]),
+        ];
+        let linked = link_comet_liquidations(&mut txs);
+
+        assert!(txs[0].1.is_empty());
+        assert_eq!(txs[1].1, vec![other_absorb]);
+        assert_eq!(txs[2].1, vec![purchase, own_absorb]);
+
+        let linked_hashes = linked
+            .iter()
+            .map(|infos| infos.iter().map(|info| info.tx_hash).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert_eq!(linked_hashes, vec![vec![], vec![], vec![txs[0].0.tx_hash]]);
+    }
+
     #[brontes_macros::test]
     async fn test_aave_v3_liquidation() {
         let inspector_util = InspectorTestUtils::new(USDC_ADDRESS, 6.0).await;
@@ -1,13 +1,13 @@
 pub mod atomic_arb;
 pub mod cex_dex;
+pub mod generalized_frontrun;
+pub mod intent_backrun;
 
 pub mod jit;
 pub mod liquidations;
+pub mod nft_arb;
+pub mod oracle_backrun;
 pub mod sandwich;
 pub mod searcher_activity;
 pub mod shared_utils;
-
-use malachite::Rational;
-
-pub(crate) const MAX_PROFIT: Rational = Rational::const_from_unsigned(15_000_000);
-pub(crate) const MIN_PROFIT: Rational = Rational::const_from_signed(-15_000_000);
+pub mod sniping;
@@ -0,0 +1,251 @@
+//! NFT arbitrage. A searcher buys NFTs on one marketplace and sells them on
+//! another, or into a collection bid, in the same transaction. NFT AMMs
+//! (Sudoswap) price their inventory along a bonding curve, so a pool that lags
+//! the marketplace floor gets sniped by buying out of the pool and selling the
+//! NFTs to the best bid. Both are only recognised when the NFTs are bought and
+//! sold again within the transaction, NFTs held across blocks are never marked
+//! to market.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, MevType, NftArb},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedNftTrade},
+    BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
+
+pub struct NftArbInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> NftArbInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for NftArbInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "NftArb"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let (hashes, actions): (Vec<_>, Vec<_>) = tree
+                .clone()
+                .collect_all(TreeSearchBuilder::default().with_actions([
+                    Action::is_nft_trade,
+                    Action::is_transfer,
+                    Action::is_eth_transfer,
+                    Action::is_wrapping,
+                    Action::is_aggregator,
+                ]))
+                .map(|(tx_hash, actions)| {
+                    let actions = self
+                        .utils
+                        .flatten_nested_actions(actions.into_iter(), &|action| {
+                            action.is_nft_trade()
+                                || action.is_transfer()
+                                || action.is_eth_transfer()
+                                || action.is_wrapping()
+                        })
+                        .collect_vec();
+                    (tx_hash, actions)
+                })
+                .filter(|(_, actions)| actions.iter().any(Action::is_nft_trade))
+                .unzip();
+
+            let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
+
+            actions
+                .into_iter()
+                .zip(tx_info)
+                .filter_map(|(actions, info)| {
+                    self.calculate_nft_arb(info?, metadata.clone(), actions)
+                })
+                .collect::<Vec<_>>()
+        };
+
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::NftArb, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> NftArbInspector<'_, DB> {
+    fn calculate_nft_arb(
+        &self,
+        info: TxInfo,
+        metadata: Arc<Metadata>,
+        actions: Vec<Action>,
+    ) -> Option<Bundle> {
+        let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
+
+        let trades = actions
+            .iter()
+            .filter_map(Action::try_nft_trade_ref)
+            .cloned()
+            .collect_vec();
+        let (buys, sells) = get_arbed_trades(&trades, &mev_addresses)?;
+
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer() || a.is_wrapping())
+            .account_for_actions();
+
+        let gas_finalized =
+            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let rev = self.utils.get_deltas_usd(
+            info.tx_index,
+            PriceAt::After,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            false,
+        );
+        let mut has_dex_price = rev.is_some();
+
+        let mut profit_usd = rev.map(|rev| rev - &gas_finalized).unwrap_or_default();
+
+        if self.utils.is_profit_out_of_bounds(&profit_usd) {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            &info,
+            profit_usd.to_float(),
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::NftArb,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let nft_arb = NftArb {
+            block_number: metadata.block_num,
+            tx_hash: info.tx_hash,
+            tx_index: info.tx_index,
+            buys,
+            sells,
+            gas_details: info.gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::NftArb(nft_arb) })
+    }
+}
+
+/// Splits the trades of the searcher into the ones that bought and the ones
+/// that sold an NFT the searcher both bought and sold again in the
+/// transaction. Returns None if nothing was flipped.
+fn get_arbed_trades(
+    trades: &[NormalizedNftTrade],
+    searcher: &FastHashSet<Address>,
+) -> Option<(Vec<NormalizedNftTrade>, Vec<NormalizedNftTrade>)> {
+    let bought = trades
+        .iter()
+        .filter(|trade| searcher.contains(&trade.buyer))
+        .flat_map(|trade| trade.token_ids.iter().map(|id| (trade.collection, *id)))
+        .collect::<FastHashSet<_>>();
+
+    let flipped = trades
+        .iter()
+        .filter(|trade| searcher.contains(&trade.seller))
+        .flat_map(|trade| trade.token_ids.iter().map(|id| (trade.collection, *id)))
+        .filter(|token| bought.contains(token))
+        .collect::<FastHashSet<_>>();
+
+    if flipped.is_empty() {
+        return None
+    }
+
+    let trades_flipped = |party: fn(&NormalizedNftTrade) -> Address| {
+        trades
+            .iter()
+            .filter(|trade| searcher.contains(&party(trade)))
+            .filter(|trade| {
+                flipped
+                    .iter()
+                    .any(|(collection, id)| trade.contains_token(*collection, *id))
+            })
+            .cloned()
+            .collect_vec()
+    };
+
+    Some((trades_flipped(|trade| trade.buyer), trades_flipped(|trade| trade.seller)))
+}
+
+#[cfg(test)]
+mod tests {
+    use reth_primitives::U256;
+
+    use super::*;
+
+    fn trade(seller: Address, buyer: Address, collection: Address, id: u64) -> NormalizedNftTrade {
+        NormalizedNftTrade {
+            seller,
+            buyer,
+            collection,
+            token_ids: vec![U256::from(id)],
+            amounts: vec![U256::from(1)],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_get_arbed_trades() {
+        let searcher = Address::with_last_byte(1);
+        let pool = Address::with_last_byte(2);
+        let bidder = Address::with_last_byte(3);
+        let collection = Address::with_last_byte(4);
+        let addresses = FastHashSet::from_iter([searcher]);
+
+        // bought out of the pool and sold into a bid
+        let buy = trade(pool, searcher, collection, 7);
+        let sell = trade(searcher, bidder, collection, 7);
+        // bought and kept
+        let held = trade(pool, searcher, collection, 8);
+
+        let (buys, sells) =
+            get_arbed_trades(&[buy.clone(), held.clone(), sell.clone()], &addresses).unwrap();
+        assert_eq!(buys, vec![buy.clone()]);
+        assert_eq!(sells, vec![sell]);
+
+        // nothing sold again
+        assert!(get_arbed_trades(&[buy, held], &addresses).is_none());
+    }
+}
@@ -0,0 +1,343 @@
+//! Oracle extractable value (OEV). When a price feed is updated, positions on
+//! lending markets can become liquidatable and pools that are priced off the
+//! oracle go stale. Searchers capture this by landing their transaction right
+//! behind the oracle update.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::dex::PriceAt,
+    mev::{Bundle, BundleData, MevType, OracleBackrun},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
+    ActionIter, BlockData, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::{b256, Address, B256};
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
+
+/// `AnswerUpdated(int256,uint256,uint256)`, emitted by chainlink aggregators
+/// whenever a new round is reported
+const ANSWER_UPDATED_TOPIC: B256 =
+    b256!("0559884fd3a460db3073b7fc896cc77986f16e378210ded43186175bf646fc5f");
+
+/// the max amount of transactions after the oracle update that we still
+/// consider to be a backrun of the update
+const MAX_BACKRUN_DISTANCE: usize = 10;
+
+/// heuristic flag, when disabled any swap following an oracle update is kept
+const REQUIRE_LIQUIDATION_OR_ARB: &str = "require_liquidation_or_arb";
+
+pub struct OracleBackrunInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> OracleBackrunInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for OracleBackrunInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    fn get_id(&self) -> &str {
+        "OracleBackrun"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn inspect_block(&self, mut data: MultiBlockData) -> Self::Result {
+        let block = data.per_block_data.pop().expect("no blocks");
+        let BlockData { metadata, tree } = block;
+
+        let ex = || {
+            let txes = tree
+                .clone()
+                .collect_all(TreeSearchBuilder::default().with_actions([
+                    is_oracle_update,
+                    Action::is_swap,
+                    Action::is_liquidation,
+                    Action::is_transfer,
+                    Action::is_eth_transfer,
+                    Action::is_wrapping,
+                    Action::is_aggregator,
+                ]))
+                .collect::<Vec<_>>();
+
+            let possible_backruns = get_possible_oracle_backruns(txes);
+            let tx = possible_backruns
+                .iter()
+                .map(|(backrun, _)| backrun.tx_hash)
+                .collect_vec();
+            let tx_info = tree.get_tx_info_batch(&tx, self.utils.db);
+
+            possible_backruns
+                .into_iter()
+                .zip(tx_info)
+                .filter_map(|((backrun, actions), info)| {
+                    let info = info?;
+                    let actions = self
+                        .utils
+                        .flatten_nested_actions_default(actions.into_iter())
+                        .collect::<Vec<_>>();
+
+                    self.calculate_oracle_backrun(info, metadata.clone(), backrun, actions)
+                })
+                .collect::<Vec<_>>()
+        };
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::OracleBackrun, ex))
+            .unwrap_or_else(ex)
+    }
+}
+
+impl<DB: LibmdbxReader> OracleBackrunInspector<'_, DB> {
+    fn calculate_oracle_backrun(
+        &self,
+        info: TxInfo,
+        metadata: Arc<Metadata>,
+        backrun: PossibleOracleBackrun,
+        actions: Vec<Action>,
+    ) -> Option<Bundle> {
+        let (swaps, liqs): (Vec<_>, Vec<_>) = actions
+            .clone()
+            .into_iter()
+            .action_split((Action::try_swaps_merged, Action::try_liquidation));
+
+        // without a liquidation, the backrun has to be an arb over the pools that
+        // went stale with the update
+        if liqs.is_empty()
+            && self
+                .utils
+                .get_config()
+                .heuristic(REQUIRE_LIQUIDATION_OR_ARB, true)
+            && !is_closed_loop(&swaps)
+        {
+            tracing::debug!("no liquidation or arb following the oracle update");
+            return None
+        }
+
+        let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
+
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer() || a.is_wrapping())
+            .account_for_actions();
+
+        let (rev, mut has_dex_price) = if let Some(rev) = self.utils.get_deltas_usd(
+            info.tx_index,
+            PriceAt::After,
+            &mev_addresses,
+            &deltas,
+            metadata.clone(),
+            false,
+        ) {
+            (Some(rev), true)
+        } else {
+            (Some(Rational::ZERO), false)
+        };
+
+        let gas_finalized =
+            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let mut profit_usd = rev
+            .map(|rev| rev - &gas_finalized)
+            .filter(|_| has_dex_price)
+            .unwrap_or_default();
+
+        if self.utils.is_profit_out_of_bounds(&profit_usd) {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            &info,
+            profit_usd.to_float(),
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::OracleBackrun,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let oracle_backrun = OracleBackrun {
+            block_number: metadata.block_num,
+            oracle_update_tx_hash: backrun.oracle_update_tx_hash,
+            oracle_update_tx_index: backrun.oracle_update_tx_index as u64,
+            oracles: backrun.oracles,
+            backrun_tx_hash: info.tx_hash,
+            backrun_tx_index: info.tx_index,
+            latency: (backrun.backrun_tx_index - backrun.oracle_update_tx_index - 1) as u64,
+            liquidations: liqs,
+            swaps,
+            liquidation_tx_hash: None,
+            gas_details: info.gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::OracleBackrun(oracle_backrun) })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct PossibleOracleBackrun {
+    oracle_update_tx_hash:  B256,
+    oracle_update_tx_index: usize,
+    oracles:                Vec<Address>,
+    tx_hash:                B256,
+    backrun_tx_index:       usize,
+}
+
+/// Walks the block in order, pairing every transaction that follows an oracle
+/// update, up until the next update or [`MAX_BACKRUN_DISTANCE`], with the
+/// update. Transactions without a swap or liquidation are dropped.
+fn get_possible_oracle_backruns(
+    txes: Vec<(B256, Vec<Action>)>,
+) -> Vec<(PossibleOracleBackrun, Vec<Action>)> {
+    let mut last_update: Option<(usize, B256, Vec<Address>)> = None;
+    let mut possible_backruns = vec![];
+
+    for (tx_index, (tx_hash, actions)) in txes.into_iter().enumerate() {
+        let oracles = actions
+            .iter()
+            .filter_map(oracle_update_address)
+            .unique()
+            .collect_vec();
+
+        if !oracles.is_empty() {
+            last_update = Some((tx_index, tx_hash, oracles));
+            continue
+        }
+
+        let Some((update_index, update_hash, oracles)) = last_update.as_ref() else { continue };
+        if tx_index - update_index > MAX_BACKRUN_DISTANCE {
+            continue
+        }
+
+        if !actions
+            .iter()
+            .any(|action| action.is_swap() || action.is_liquidation() || action.is_aggregator())
+        {
+            continue
+        }
+
+        possible_backruns.push((
+            PossibleOracleBackrun {
+                oracle_update_tx_hash: *update_hash,
+                oracle_update_tx_index: *update_index,
+                oracles: oracles.clone(),
+                tx_hash,
+                backrun_tx_index: tx_index,
+            },
+            actions,
+        ));
+    }
+
+    possible_backruns
+}
+
+fn is_oracle_update(action: &Action) -> bool {
+    oracle_update_address(action).is_some()
+}
+
+/// If the action emitted a chainlink price update, returns the aggregator that
+/// emitted it
+fn oracle_update_address(action: &Action) -> Option<Address> {
+    let Action::Unclassified(trace) = action else { return None };
+
+    trace
+        .logs
+        .iter()
+        .find(|log| log.topics().first() == Some(&ANSWER_UPDATED_TOPIC))
+        .map(|log| log.address)
+}
+
+/// Swaps that start and end in the same token
+fn is_closed_loop(swaps: &[NormalizedSwap]) -> bool {
+    let (Some(first), Some(last)) = (swaps.first(), swaps.last()) else { return false };
+
+    swaps.len() > 1 && first.token_in.address == last.token_out.address
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::{Log, LogData};
+    use brontes_types::structured_trace::TransactionTraceWithLogs;
+    use reth_rpc_types::trace::parity::{
+        Action as TraceAction, SelfdestructAction, TransactionTrace,
+    };
+
+    use super::*;
+
+    fn oracle_update(oracle: Address) -> Action {
+        Action::Unclassified(TransactionTraceWithLogs {
+            trace:        TransactionTrace {
+                action:        TraceAction::Selfdestruct(SelfdestructAction {
+                    address:        Default::default(),
+                    balance:        Default::default(),
+                    refund_address: Default::default(),
+                }),
+                error:         None,
+                result:        None,
+                subtraces:     0,
+                trace_address: Vec::new(),
+            },
+            logs:         vec![Log {
+                address: oracle,
+                data:    LogData::new_unchecked(vec![ANSWER_UPDATED_TOPIC], Default::default()),
+            }],
+            msg_sender:   Default::default(),
+            trace_idx:    Default::default(),
+            decoded_data: None,
+        })
+    }
+
+    #[test]
+    fn test_possible_oracle_backruns() {
+        let oracle = Address::with_last_byte(1);
+        let swap = Action::Swap(NormalizedSwap::default());
+
+        let txes = vec![
+            (B256::with_last_byte(0), vec![swap.clone()]),
+            (B256::with_last_byte(1), vec![oracle_update(oracle)]),
+            (B256::with_last_byte(2), vec![]),
+            (B256::with_last_byte(3), vec![swap.clone()]),
+        ]
+        .into_iter()
+        .chain((4..16).map(|i| (B256::with_last_byte(i), vec![swap.clone()])))
+        .collect_vec();
+
+        let backruns = get_possible_oracle_backruns(txes);
+
+        // the swap before the update and the tx without actions are never
+        // backruns, neither are txes further away than the max distance
+        assert_eq!(backruns.len(), MAX_BACKRUN_DISTANCE - 1);
+
+        let (first, _) = &backruns[0];
+        assert_eq!(first.oracle_update_tx_hash, B256::with_last_byte(1));
+        assert_eq!(first.oracles, vec![oracle]);
+        assert_eq!(first.tx_hash, B256::with_last_byte(3));
+        assert_eq!(first.backrun_tx_index - first.oracle_update_tx_index - 1, 1);
+    }
+}
@@ -6,6 +6,7 @@ use std::{
 use alloy_primitives::TxHash;
 use tracing::trace;
 mod types;
+mod victim_loss;
 use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
@@ -23,8 +24,10 @@ use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::{Address, B256};
 use types::{PossibleSandwich, PossibleSandwichWithTxInfo};
 
-use super::MAX_PROFIT;
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata, MIN_PROFIT};
+mod multi_block;
+pub use multi_block::MultiBlockSandwichInspector;
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
 
 type GroupedVictims<'a> = HashMap<Address, Vec<&'a (Vec<NormalizedSwap>, Vec<NormalizedTransfer>)>>;
 
@@ -41,8 +44,13 @@ pub struct SandwichInspector<'db, DB: LibmdbxReader> {
 }
 
 impl<'db, DB: LibmdbxReader> SandwichInspector<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
     }
 }
 
@@ -82,6 +90,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             Action::is_swap,
             Action::is_transfer,
             Action::is_eth_transfer,
+            Action::is_wrapping,
             Action::is_nested_action,
         ]);
 
@@ -155,6 +164,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         self.calculate_sandwich(
             tree.clone(),
             metadata.clone(),
+            metadata.clone(),
             possible_frontruns_info,
             possible_backrun_info,
             searcher_actions,
@@ -169,6 +179,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         &self,
         tree: Arc<BlockTree<Action>>,
         metadata: Arc<Metadata>,
+        front_run_metadata: Arc<Metadata>,
         possible_front_runs_info: Vec<TxInfo>,
         backrun_info: TxInfo,
         mut searcher_actions: Vec<Vec<Action>>,
@@ -241,6 +252,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             return self.recursive_possible_sandwiches(
                 tree.clone(),
                 metadata.clone(),
+                front_run_metadata.clone(),
                 &possible_front_runs_info,
                 backrun_info,
                 &back_run_actions,
@@ -254,6 +266,18 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
 
         // if we reach this part of the code, we have found a sandwich and
         // are now going to collect the details for the given sandwich
+
+        // a frontrun that landed in an earlier block than the backrun means the
+        // sandwich spans multiple blocks
+        let mev_type = if possible_front_runs_info
+            .iter()
+            .any(|info| info.block_number != backrun_info.block_number)
+        {
+            MevType::MultiBlockSandwich
+        } else {
+            MevType::Sandwich
+        };
+
         let victim_swaps = victim_actions.into_iter().flatten().collect::<Vec<_>>();
         let back_run_swaps = back_run_actions
             .clone()
@@ -298,7 +322,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             .into_iter()
             .flatten()
             .chain(back_run_actions)
-            .filter(|f| f.is_transfer() || f.is_eth_transfer())
+            .filter(|f| f.is_transfer() || f.is_eth_transfer() || f.is_wrapping())
             .chain(
                 possible_front_runs_info
                     .iter()
@@ -313,7 +337,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         let mut has_dex_price = true;
         for (swaps, info) in front_run_swaps.iter().zip(&possible_front_runs_info) {
             has_dex_price &= self.utils.valid_pricing(
-                metadata.clone(),
+                front_run_metadata.clone(),
                 swaps,
                 searcher_deltas
                     .values()
@@ -325,7 +349,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                     .unique(),
                 info.tx_index as usize,
                 MAX_PRICE_DIFF,
-                MevType::Sandwich,
+                mev_type,
             );
         }
         has_dex_price &= self.utils.valid_pricing(
@@ -341,7 +365,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 .unique(),
             backrun_info.tx_index as usize,
             MAX_PRICE_DIFF,
-            MevType::Sandwich,
+            mev_type,
         );
 
         let mut mev_addresses: FastHashSet<Address> =
@@ -371,7 +395,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             .filter(|_| has_dex_price)
             .unwrap_or_default();
 
-        if profit_usd >= MAX_PROFIT || profit_usd <= MIN_PROFIT {
+        if self.utils.is_profit_out_of_bounds(&profit_usd) {
             has_dex_price = false;
             profit_usd = Rational::ZERO;
         }
@@ -406,7 +430,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             profit_usd.to_float(),
             &gas_details,
             metadata.clone(),
-            MevType::Sandwich,
+            mev_type,
             !has_dex_price,
             |this, token, amount| {
                 this.get_token_value_dex(
@@ -420,6 +444,13 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         );
 
         let victim_swaps = victim_swaps.into_iter().map(|(s, _)| s).collect_vec();
+        let victim_loss_usd = self.victim_loss_usd(
+            &front_run_swaps,
+            &possible_front_runs_info,
+            &victim_swaps,
+            &victim_swaps_tx_hashes,
+            &front_run_metadata,
+        );
 
         let sandwich = Sandwich {
             block_number: metadata.block_num,
@@ -429,6 +460,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
             victim_swaps_tx_hashes,
             victim_swaps_gas_details: victim_swaps_gas_details.into_iter().flatten().collect(),
             victim_swaps,
+            victim_loss_usd,
             backrun_tx_hash: backrun_info.tx_hash,
             backrun_swaps: back_run_swaps,
             backrun_gas_details: backrun_info.gas_details,
@@ -438,6 +470,55 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         Some(vec![Bundle { header, data: BundleData::Sandwich(sandwich) }])
     }
 
+    /// Prices the tokens each victim lost at the state before the first
+    /// frontrun, as the prices after it are skewed by the sandwich itself.
+    /// The losses are only known when the DEX pricer ran over the block of the
+    /// first frontrun and the victim's swaps and lost tokens could be replayed
+    /// and priced.
+    fn victim_loss_usd(
+        &self,
+        front_run_swaps: &[Vec<NormalizedSwap>],
+        front_runs_info: &[TxInfo],
+        victim_swaps: &[Vec<NormalizedSwap>],
+        victim_swaps_tx_hashes: &[Vec<B256>],
+        metadata: &Arc<Metadata>,
+    ) -> Vec<Option<f64>> {
+        let Some(pool_states) = metadata.pool_states.as_deref() else {
+            return vec![None; victim_swaps.len()]
+        };
+        let victims_per_frontrun = victim_swaps_tx_hashes.iter().map(Vec::len).collect_vec();
+        let frontrun_tx_indexes = front_runs_info
+            .iter()
+            .map(|info| (info.block_number == metadata.block_num).then_some(info.tx_index as usize))
+            .collect_vec();
+        let first_frontrun_tx_index = front_runs_info[0].tx_index as usize;
+
+        victim_loss::victim_token_losses(
+            pool_states,
+            front_run_swaps,
+            &frontrun_tx_indexes,
+            victim_swaps,
+            &victims_per_frontrun,
+        )
+        .into_iter()
+        .map(|losses| {
+            losses?
+                .into_iter()
+                .try_fold(Rational::ZERO, |acc, (token, amount)| {
+                    let loss = self.utils.get_token_value_dex(
+                        first_frontrun_tx_index,
+                        PriceAt::Before,
+                        token,
+                        &amount,
+                        metadata,
+                    )?;
+                    Some(acc + loss)
+                })
+                .map(|loss| loss.to_float())
+        })
+        .collect()
+    }
+
     /// For the given set of possible sandwich data.
     /// Calls with two different revisions.
     ///     1) front shrink
@@ -448,6 +529,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
         &self,
         tree: Arc<BlockTree<Action>>,
         metadata: Arc<Metadata>,
+        front_run_metadata: Arc<Metadata>,
         possible_front_runs_info: &[TxInfo],
         backrun_info: TxInfo,
         back_run_actions: &[Action],
@@ -493,6 +575,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 self.calculate_sandwich(
                     tree.clone(),
                     metadata.clone(),
+                    front_run_metadata.clone(),
                     possible_front_runs_info,
                     back_run_info,
                     searcher_actions.to_vec(),
@@ -531,6 +614,7 @@ impl<DB: LibmdbxReader> SandwichInspector<'_, DB> {
                 self.calculate_sandwich(
                     tree.clone(),
                     metadata.clone(),
+                    front_run_metadata,
                     possible_front_runs_info,
                     backrun_info,
                     searcher_actions,
@@ -0,0 +1,315 @@
+//! Sandwiches that span a block boundary. The frontrun lands at the end of
+//! one block and the backrun at the start of the next, which is only possible
+//! when both blocks come from the same builder or the same proposer.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    mev::{Bundle, MevType},
+    normalized_actions::Action,
+    tree::{collect_address_set_for_accounting, BlockTree},
+    BlockData, FastHashMap, FastHashSet, MultiBlockData, TreeSearchBuilder,
+};
+use itertools::Itertools;
+use reth_primitives::{Address, B256};
+
+use super::{types::PossibleSandwich, SandwichInspector};
+use crate::{Inspector, InspectorConfig};
+
+/// the max amount of transactions between the frontrun and the backrun across
+/// the two blocks
+const MAX_CROSS_BLOCK_VICTIMS: usize = 30;
+
+pub struct MultiBlockSandwichInspector<'db, DB: LibmdbxReader> {
+    inner: SandwichInspector<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> MultiBlockSandwichInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { inner: SandwichInspector::new(quote, db, config, metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for MultiBlockSandwichInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    // the frontrun is in the previous block and the backrun in the current one
+    fn block_window(&self) -> usize {
+        self.inner.utils.get_config().block_window_or(2)
+    }
+
+    fn get_id(&self) -> &str {
+        "MultiBlockSandwich"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.inner.utils.quote
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        let [.., prev, cur] = data.per_block_data.as_slice() else { return vec![] };
+
+        let execution = || self.inspect_block_pair(prev, cur);
+
+        self.inner
+            .utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::MultiBlockSandwich, execution))
+            .unwrap_or_else(execution)
+    }
+}
+
+impl<DB: LibmdbxReader> MultiBlockSandwichInspector<'_, DB> {
+    fn inspect_block_pair(&self, prev: &BlockData, cur: &BlockData) -> Vec<Bundle> {
+        if !is_same_block_producer(prev, cur) {
+            return vec![]
+        }
+
+        let search_args = TreeSearchBuilder::default().with_actions([
+            Action::is_swap,
+            Action::is_transfer,
+            Action::is_eth_transfer,
+            Action::is_nested_action,
+        ]);
+
+        get_possible_cross_block_sandwiches(prev.tree.clone(), cur.tree.clone())
+            .into_iter()
+            .filter_map(|ps| {
+                self.calculate_cross_block_sandwich(prev, cur, search_args.clone(), ps)
+            })
+            .flatten()
+            .collect::<Vec<_>>()
+    }
+
+    fn calculate_cross_block_sandwich(
+        &self,
+        prev: &BlockData,
+        cur: &BlockData,
+        search_args: TreeSearchBuilder<Action>,
+        ps: PossibleSandwich,
+    ) -> Option<Vec<Bundle>> {
+        let PossibleSandwich {
+            possible_frontruns,
+            possible_backrun,
+            mev_executor_contract,
+            victims,
+            ..
+        } = ps;
+        let frontrun = *possible_frontruns.first()?;
+
+        let (prev_victims, cur_victims): (Vec<_>, Vec<_>) = victims
+            .into_iter()
+            .flatten()
+            .partition(|victim| prev.tree.get_root(*victim).is_some());
+
+        let prev_txes = std::iter::once(frontrun)
+            .chain(prev_victims.iter().copied())
+            .collect_vec();
+        let cur_txes = cur_victims
+            .iter()
+            .copied()
+            .chain(std::iter::once(possible_backrun))
+            .collect_vec();
+
+        let tx_info_map = prev
+            .tree
+            .get_tx_info_batch(&prev_txes, self.inner.utils.db)
+            .into_iter()
+            .chain(cur.tree.get_tx_info_batch(&cur_txes, self.inner.utils.db))
+            .flatten()
+            .map(|info| (info.tx_hash, info))
+            .collect::<FastHashMap<_, _>>();
+
+        let frontrun_info = tx_info_map.get(&frontrun).cloned()?;
+        let backrun_info = tx_info_map.get(&possible_backrun).cloned()?;
+        let victim_info = prev_victims
+            .iter()
+            .chain(cur_victims.iter())
+            .map(|victim| tx_info_map.get(victim).cloned())
+            .collect::<Option<Vec<_>>>()?;
+
+        // victims are grouped per frontrun. As there is only a single frontrun,
+        // the victims of both blocks are merged into one group
+        let mut victim_actions = vec![];
+        for (tree, victims) in [(prev.tree.clone(), prev_victims), (cur.tree.clone(), cur_victims)]
+        {
+            if victims.is_empty() {
+                continue
+            }
+            victim_actions.extend(
+                self.inner
+                    .get_victim_swap_transfer(
+                        vec![victims],
+                        tree,
+                        search_args.clone(),
+                        mev_executor_contract,
+                    )?
+                    .into_iter()
+                    .flatten(),
+            );
+        }
+
+        let mut searcher_actions = prev
+            .tree
+            .clone()
+            .collect_txes(&[frontrun], search_args.clone())
+            .map(|actions| {
+                self.inner
+                    .utils
+                    .flatten_nested_actions_default(actions.into_iter())
+                    .collect_vec()
+            })
+            .collect::<Vec<_>>();
+        searcher_actions.extend(
+            cur.tree
+                .clone()
+                .collect_txes(&[possible_backrun], search_args)
+                .map(|actions| {
+                    self.inner
+                        .utils
+                        .flatten_nested_actions_default(actions.into_iter())
+                        .collect_vec()
+                }),
+        );
+
+        let black_list: FastHashSet<Address> =
+            collect_address_set_for_accounting(std::slice::from_ref(&frontrun_info));
+
+        self.inner.calculate_sandwich(
+            cur.tree.clone(),
+            cur.metadata.clone(),
+            prev.metadata.clone(),
+            vec![frontrun_info],
+            backrun_info,
+            searcher_actions,
+            vec![victim_info],
+            vec![victim_actions],
+            black_list,
+            0,
+        )
+    }
+}
+
+/// A sandwich can only span two blocks if the searcher had control over the
+/// end of the first block and the start of the second. This is the case when
+/// both blocks were built by the same builder or proposed by the same
+/// proposer.
+fn is_same_block_producer(prev: &BlockData, cur: &BlockData) -> bool {
+    if prev.block_number() + 1 != cur.block_number() {
+        return false
+    }
+
+    let same_builder = prev.tree.header.beneficiary == cur.tree.header.beneficiary;
+    let same_proposer = prev.metadata.proposer_fee_recipient.is_some()
+        && prev.metadata.proposer_fee_recipient == cur.metadata.proposer_fee_recipient;
+
+    same_builder || same_proposer
+}
+
+/// Pairs the last transaction of a sender or mev contract in the previous
+/// block with the first transaction of the same sender or mev contract in the
+/// current block. Every transaction in between is a possible victim.
+fn get_possible_cross_block_sandwiches(
+    prev_tree: Arc<BlockTree<Action>>,
+    cur_tree: Arc<BlockTree<Action>>,
+) -> Vec<PossibleSandwich> {
+    let mut last_prev_block_tx: FastHashMap<Address, usize> = FastHashMap::default();
+    for (index, root) in prev_tree.tx_roots.iter().enumerate() {
+        if root.get_root_action().is_revert() {
+            continue
+        }
+        last_prev_block_tx.insert(root.head.address, index);
+        last_prev_block_tx.insert(root.get_to_address(), index);
+    }
+
+    let mut seen: FastHashSet<Address> = FastHashSet::default();
+    let mut possible_sandwiches = vec![];
+
+    for (index, root) in cur_tree.tx_roots.iter().enumerate() {
+        if root.get_root_action().is_revert() {
+            continue
+        }
+
+        for searcher in [root.head.address, root.get_to_address()] {
+            // only the first tx of the searcher in this block can be the backrun
+            if !seen.insert(searcher) {
+                continue
+            }
+            let Some(&frontrun_index) = last_prev_block_tx.get(&searcher) else { continue };
+
+            let victims: Vec<B256> = prev_tree.tx_roots[frontrun_index + 1..]
+                .iter()
+                .chain(cur_tree.tx_roots[..index].iter())
+                .filter(|victim| !victim.get_root_action().is_revert())
+                .map(|victim| victim.tx_hash)
+                .collect();
+
+            if victims.is_empty() || victims.len() > MAX_CROSS_BLOCK_VICTIMS {
+                continue
+            }
+
+            let frontrun = &prev_tree.tx_roots[frontrun_index];
+            possible_sandwiches.push(PossibleSandwich {
+                eoa:                   frontrun.head.address,
+                possible_frontruns:    vec![frontrun.tx_hash],
+                possible_backrun:      root.tx_hash,
+                mev_executor_contract: root.get_to_address(),
+                victims:               vec![victims],
+            });
+        }
+    }
+
+    possible_sandwiches.into_iter().unique().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::db::metadata::Metadata;
+    use reth_primitives::Header;
+
+    use super::*;
+
+    fn block_data(number: u64, builder: Address, proposer: Option<Address>) -> BlockData {
+        let mut metadata = Metadata::default();
+        metadata.block_metadata.block_num = number;
+        metadata.block_metadata.proposer_fee_recipient = proposer;
+
+        let header = Header { number, beneficiary: builder, ..Default::default() };
+
+        BlockData { metadata: Arc::new(metadata), tree: Arc::new(BlockTree::new(header, 0)) }
+    }
+
+    #[test]
+    fn test_same_block_producer() {
+        let builder = Address::with_last_byte(1);
+        let other_builder = Address::with_last_byte(2);
+        let proposer = Address::with_last_byte(3);
+
+        // same builder
+        assert!(is_same_block_producer(
+            &block_data(10, builder, None),
+            &block_data(11, builder, None)
+        ));
+        // same proposer, different builders
+        assert!(is_same_block_producer(
+            &block_data(10, builder, Some(proposer)),
+            &block_data(11, other_builder, Some(proposer))
+        ));
+        // different builders, unknown proposer
+        assert!(!is_same_block_producer(
+            &block_data(10, builder, None),
+            &block_data(11, other_builder, None)
+        ));
+        // blocks aren't consecutive
+        assert!(!is_same_block_producer(
+            &block_data(10, builder, None),
+            &block_data(12, builder, None)
+        ));
+    }
+}
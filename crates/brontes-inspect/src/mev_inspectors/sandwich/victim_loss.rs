@@ -0,0 +1,193 @@
+//! Estimates how much the victims of a sandwich lost to it, by replaying their
+//! swaps on the sandwiched pools as if the frontruns never happened.
+//!
+//! Each pool is replayed from the state the DEX pricer tracked for it before
+//! its first frontrun, with the pool's own swap math. Victims that swapped on
+//! a pool the pricer doesn't track, or whose swaps it can't simulate, get no
+//! estimate. Transactions between the frontrun and the victims that aren't
+//! part of the sandwich are not replayed.
+use alloy_primitives::Address;
+use brontes_types::{normalized_actions::NormalizedSwap, price_graph_types::BlockPoolStates};
+use itertools::Itertools;
+use malachite::Rational;
+
+enum Step<'a> {
+    Frontrun(usize),
+    Victim(usize, &'a NormalizedSwap),
+}
+
+/// Returns, for each victim transaction, the tokens it received less than it
+/// would have without the frontruns, or `None` when one of its swaps on the
+/// sandwiched pools couldn't be replayed. `victims_per_frontrun` is the amount
+/// of victim transactions that follow each frontrun, in the order of
+/// `victim_swaps`. `frontrun_tx_indexes` is the index of each frontrun in the
+/// block of `pool_states`, or `None` when it is in another block.
+pub(super) fn victim_token_losses(
+    pool_states: &dyn BlockPoolStates,
+    frontrun_swaps: &[Vec<NormalizedSwap>],
+    frontrun_tx_indexes: &[Option<usize>],
+    victim_swaps: &[Vec<NormalizedSwap>],
+    victims_per_frontrun: &[usize],
+) -> Vec<Option<Vec<(Address, Rational)>>> {
+    let mut losses = vec![Some(vec![]); victim_swaps.len()];
+
+    let sandwiched_pools = frontrun_swaps
+        .iter()
+        .flatten()
+        .map(|swap| swap.pool)
+        .unique()
+        .collect_vec();
+
+    for pool in sandwiched_pools {
+        let mut steps = Vec::new();
+        let mut victims = victim_swaps.iter().enumerate();
+        for ((frontrun, tx_idx), victim_count) in frontrun_swaps
+            .iter()
+            .zip(frontrun_tx_indexes)
+            .zip(victims_per_frontrun)
+        {
+            if frontrun.iter().any(|swap| swap.pool == pool) {
+                steps.push(Step::Frontrun(tx_idx.unwrap_or(usize::MAX)));
+            }
+            for (victim_idx, swaps) in victims.by_ref().take(*victim_count) {
+                steps.extend(
+                    swaps
+                        .iter()
+                        .filter(|swap| swap.pool == pool)
+                        .map(|swap| Step::Victim(victim_idx, swap)),
+                );
+            }
+        }
+
+        // victims before the first frontrun of the pool swapped on its real state
+        let mut steps = steps
+            .into_iter()
+            .skip_while(|step| matches!(step, Step::Victim(..)));
+
+        let Some(Step::Frontrun(first_frontrun)) = steps.next() else { continue };
+        let mut counterfactual = (first_frontrun != usize::MAX)
+            .then(|| pool_states.pool_before(pool, first_frontrun))
+            .flatten();
+
+        for step in steps {
+            let Step::Victim(victim_idx, swap) = step else { continue };
+
+            // once a swap can't be replayed, the state of the pool after it is unknown
+            let Some(amount_out) = counterfactual
+                .as_mut()
+                .and_then(|pool| pool.swap(swap.token_in.address, &swap.amount_in))
+            else {
+                counterfactual = None;
+                losses[victim_idx] = None;
+                continue
+            };
+            if let Some(losses) = &mut losses[victim_idx] {
+                losses.push((swap.token_out.address, amount_out - &swap.amount_out));
+            }
+        }
+    }
+
+    losses
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+    use brontes_pricing::{
+        types::{PoolState, PoolVariants},
+        uniswap_v2::UniswapV2Pool,
+    };
+    use brontes_types::{
+        db::token_info::TokenInfoWithAddress,
+        pair::Pair,
+        price_graph_types::{SimulatedPool, SizedQuote},
+        FastHashMap,
+    };
+    use malachite::num::basic::traits::Zero;
+
+    use super::*;
+
+    const POOL: Address = Address::new(hex!("B4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc"));
+    const TOKEN_A: Address = Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
+    const TOKEN_B: Address = Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
+
+    #[derive(Debug)]
+    struct Pools(FastHashMap<(Address, usize), PoolState>);
+
+    impl BlockPoolStates for Pools {
+        fn pool_before(&self, pool: Address, tx_idx: usize) -> Option<Box<dyn SimulatedPool>> {
+            Some(Box::new(self.0.get(&(pool, tx_idx))?.clone()))
+        }
+
+        fn quote_for_size(
+            &self,
+            _pair: Pair,
+            _goes_through: Pair,
+            _amount_in: &Rational,
+            _tx_idx: usize,
+        ) -> Option<SizedQuote> {
+            None
+        }
+    }
+
+    fn pool() -> PoolState {
+        PoolState::new(
+            PoolVariants::UniswapV2(Box::new(UniswapV2Pool::new(
+                POOL,
+                TOKEN_A,
+                18,
+                TOKEN_B,
+                18,
+                1_000_000_000_000_000_000_000,
+                1_000_000_000_000_000_000_000,
+                3000,
+            ))),
+            0,
+        )
+    }
+
+    fn swap(pool: &mut PoolState, amount_in: u64) -> NormalizedSwap {
+        let amount_in = Rational::from(amount_in);
+        let amount_out = pool.swap(TOKEN_A, &amount_in).unwrap();
+
+        NormalizedSwap {
+            pool: POOL,
+            token_in: TokenInfoWithAddress { address: TOKEN_A, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: TOKEN_B, ..Default::default() },
+            amount_in,
+            amount_out,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_victim_loss_on_pool_state() {
+        let pools = Pools([((POOL, 3), pool())].into_iter().collect());
+
+        let mut block = pool();
+        let frontrun = swap(&mut block, 100);
+        let victim = swap(&mut block, 10);
+
+        let mut counterfactual = pool();
+        let expected =
+            counterfactual.swap(TOKEN_A, &Rational::from(10)).unwrap() - &victim.amount_out;
+        assert!(expected > Rational::ZERO);
+
+        let losses =
+            victim_token_losses(&pools, &[vec![frontrun]], &[Some(3)], &[vec![victim]], &[1]);
+        assert_eq!(losses, vec![Some(vec![(TOKEN_B, expected)])]);
+    }
+
+    #[test]
+    fn test_unknown_loss_without_pool_state() {
+        let pools = Pools(FastHashMap::default());
+
+        let mut block = pool();
+        let frontrun = swap(&mut block, 100);
+        let victim = swap(&mut block, 10);
+
+        let losses =
+            victim_token_losses(&pools, &[vec![frontrun]], &[Some(3)], &[vec![victim]], &[1]);
+        assert_eq!(losses, vec![None]);
+    }
+}
@@ -13,16 +13,20 @@ use itertools::multizip;
 use malachite::{num::basic::traits::Zero, Rational};
 use reth_primitives::Address;
 
-use super::{MAX_PROFIT, MIN_PROFIT};
-use crate::{shared_utils::SharedInspectorUtils, Inspector, Metadata};
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
 
 pub struct SearcherActivity<'db, DB: LibmdbxReader> {
     utils: SharedInspectorUtils<'db, DB>,
 }
 
 impl<'db, DB: LibmdbxReader> SearcherActivity<'db, DB> {
-    pub fn new(quote: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        Self { utils: SharedInspectorUtils::new(quote, db, metrics) }
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
     }
 }
 
@@ -56,8 +60,11 @@ impl<DB: LibmdbxReader> SearcherActivity<'_, DB> {
         tree: Arc<BlockTree<Action>>,
         metadata: Arc<Metadata>,
     ) -> Vec<Bundle> {
-        let search_args = TreeSearchBuilder::default()
-            .with_actions([Action::is_transfer, Action::is_eth_transfer]);
+        let search_args = TreeSearchBuilder::default().with_actions([
+            Action::is_transfer,
+            Action::is_eth_transfer,
+            Action::is_wrapping,
+        ]);
 
         let (hashes, transfers): (Vec<_>, Vec<_>) = tree.clone().collect_all(search_args).unzip();
         let tx_info = tree.get_tx_info_batch(&hashes, self.utils.db);
@@ -103,7 +110,7 @@ impl<DB: LibmdbxReader> SearcherActivity<'_, DB> {
                             .filter(|_| has_dex_price)
                             .unwrap_or_default();
 
-                        if profit >= MAX_PROFIT || profit <= MIN_PROFIT {
+                        if self.utils.is_profit_out_of_bounds(&profit) {
                             has_dex_price = false;
                             profit = Rational::ZERO;
                         }
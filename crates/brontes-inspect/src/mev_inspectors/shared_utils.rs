@@ -5,7 +5,7 @@ use brontes_database::libmdbx::LibmdbxReader;
 use brontes_metrics::inspectors::OutlierMetrics;
 use brontes_types::{
     db::{
-        dex::{BlockPrice, PriceAt},
+        dex::{BlockPrice, DexQuotes, PriceAt},
         metadata::Metadata,
         token_info::TokenInfoWithAddress,
     },
@@ -24,26 +24,34 @@ use brontes_types::{
 use itertools::Itertools;
 use malachite::{
     num::{
-        arithmetic::traits::Reciprocal,
+        arithmetic::traits::{Abs, Reciprocal},
         basic::traits::{One, Zero},
     },
     Rational,
 };
 use reth_primitives::TxHash;
 
+use crate::InspectorConfig;
+
 const CONNECTION_TH: usize = 2;
 const LOW_LIQ_TH: Rational = Rational::const_from_unsigned(50_000u64);
 
 #[derive(Debug)]
 pub struct SharedInspectorUtils<'db, DB: LibmdbxReader> {
-    pub(crate) quote: Address,
-    pub(crate) db:    &'db DB,
-    pub metrics:      Option<OutlierMetrics>,
+    pub(crate) quote:  Address,
+    pub(crate) db:     &'db DB,
+    pub(crate) config: InspectorConfig,
+    pub metrics:       Option<OutlierMetrics>,
 }
 
 impl<'db, DB: LibmdbxReader> SharedInspectorUtils<'db, DB> {
-    pub fn new(quote_address: Address, db: &'db DB, metrics: Option<OutlierMetrics>) -> Self {
-        SharedInspectorUtils { quote: quote_address, db, metrics }
+    pub fn new(
+        quote_address: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        SharedInspectorUtils { quote: quote_address, db, config, metrics }
     }
 }
 type TokenDeltas = FastHashMap<Address, Rational>;
@@ -55,6 +63,16 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         self.metrics.as_ref()
     }
 
+    pub fn get_config(&self) -> &InspectorConfig {
+        &self.config
+    }
+
+    /// Profits outside of the configured bounds are assumed to be the result of
+    /// bad pricing
+    pub fn is_profit_out_of_bounds(&self, profit: &Rational) -> bool {
+        profit >= self.config.max_profit() || profit <= self.config.min_profit()
+    }
+
     /// Calculates the USD value of the token balance deltas by address
     pub fn usd_delta_by_address(
         &self,
@@ -116,14 +134,17 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         Some(usd_deltas)
     }
 
-    // will flatten nested and filter out actions that aren't swap, transfer or
-    // eth_transfer
+    // will flatten nested and filter out actions that aren't swap, transfer,
+    // eth_transfer or wrapping
     pub fn flatten_nested_actions_default<'a>(
         &self,
         iter: impl Iterator<Item = Action> + 'a,
     ) -> impl Iterator<Item = Action> + 'a {
         self.flatten_nested_actions(iter, &|action| {
-            action.is_swap() || action.is_transfer() || action.is_eth_transfer()
+            action.is_swap()
+                || action.is_transfer()
+                || action.is_eth_transfer()
+                || action.is_wrapping()
         })
     }
 
@@ -334,6 +355,7 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             fund,
             mev_contract: info.mev_contract,
             profit_usd,
+            profit_usd_spread: None,
             bribe_usd,
             mev_type,
             no_pricing_calculated,
@@ -368,7 +390,7 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             })
             .sum::<f64>();
 
-        if profit_usd > bribe_usd * 100.0 {
+        if profit_usd > bribe_usd * self.config.outlier_profit_multiplier {
             self.metrics
                 .as_ref()
                 .inspect(|m| m.inspector_100x_profit(mev_type));
@@ -388,6 +410,7 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
             eoa: info.eoa,
             mev_contract: info.mev_contract,
             profit_usd,
+            profit_usd_spread: None,
             bribe_usd,
             mev_type,
             no_pricing_calculated,
@@ -401,16 +424,31 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         mev_addresses: FastHashSet<Address>,
         deltas: &AddressDeltas,
         metadata: Arc<Metadata>,
+    ) -> Option<Rational> {
+        self.get_full_block_price_with_quotes(
+            price_type,
+            mev_addresses,
+            deltas,
+            metadata.dex_quotes.as_ref()?,
+        )
+    }
+
+    /// Same as [`Self::get_full_block_price`] but priced with the given dex
+    /// quotes. This allows for marking out deltas to a block other than the
+    /// one that is being inspected.
+    pub fn get_full_block_price_with_quotes(
+        &self,
+        price_type: BlockPrice,
+        mev_addresses: FastHashSet<Address>,
+        deltas: &AddressDeltas,
+        dex_quotes: &DexQuotes,
     ) -> Option<Rational> {
         let mut usd_deltas = FastHashMap::default();
 
         for (address, token_deltas) in deltas {
             for (token_addr, amount) in token_deltas {
                 let pair = Pair(*token_addr, self.quote);
-                let price = metadata
-                    .dex_quotes
-                    .as_ref()?
-                    .price_for_block(pair, price_type)?;
+                let price = dex_quotes.price_for_block(pair, price_type)?;
 
                 let usd_amount = amount.clone() * price.clone();
 
@@ -458,6 +496,37 @@ impl<DB: LibmdbxReader> SharedInspectorUtils<'_, DB> {
         Some(sum)
     }
 
+    /// The uncertainty of the usd value of the deltas of `mev_addresses`, from
+    /// the spread between the dex paths each token was priced over. This is
+    /// none unless the dex prices were generated with path weighted pricing
+    /// in this run, the spread isn't stored with the prices.
+    pub fn get_deltas_usd_spread(
+        &self,
+        tx_index: u64,
+        mev_addresses: &FastHashSet<Address>,
+        deltas: &AddressDeltas,
+        metadata: &Arc<Metadata>,
+        at_or_before: bool,
+    ) -> Option<Rational> {
+        let dex_quotes = metadata.dex_quotes.as_ref()?;
+
+        deltas
+            .iter()
+            .filter(|(address, _)| mev_addresses.contains(address))
+            .flat_map(|(_, token_deltas)| token_deltas)
+            .filter_map(|(token, amount)| {
+                let pair = Pair(*token, self.quote);
+                let prices = if at_or_before {
+                    dex_quotes.price_at_or_before(pair, tx_index as usize)
+                } else {
+                    dex_quotes.price_at(pair, tx_index as usize)
+                }?;
+
+                Some(prices.spread? * amount.abs())
+            })
+            .reduce(|acc, spread| acc + spread)
+    }
+
     pub fn get_bundle_accounting(
         &self,
         bundle_txes: Vec<FixedBytes<32>>,
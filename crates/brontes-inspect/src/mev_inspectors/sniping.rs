@@ -0,0 +1,357 @@
+//! Sniping is buying into a pool in the first block it is traded in, right
+//! after it was created or funded. The sniper gets the launch price and their
+//! profit is only realized once the token has found its price, so the position
+//! is marked out against the dex quotes of a later block. The snipes of a block
+//! are therefore found once its markout block is inspected, when the markout
+//! block is the most recent block of the window.
+//!
+//! Only the first buyers of a pool are snipers, anyone buying after the pool
+//! has already been traded is following them.
+use std::sync::Arc;
+
+use brontes_database::libmdbx::LibmdbxReader;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    constants::{ETH_ADDRESS, USD_STABLES_BY_ADDRESS, WETH_ADDRESS},
+    db::dex::{BlockPrice, PriceAt},
+    mev::{Bundle, BundleData, MevType, Snipe},
+    normalized_actions::{accounting::ActionAccounting, Action, NormalizedSwap},
+    BlockData, FastHashMap, FastHashSet, MultiBlockData, ToFloatNearest, TreeSearchBuilder, TxInfo,
+};
+use itertools::Itertools;
+use malachite::{num::basic::traits::Zero, Rational};
+use reth_primitives::Address;
+
+use crate::{shared_utils::SharedInspectorUtils, Inspector, InspectorConfig, Metadata};
+
+/// pools created more than this amount of blocks ago are no longer considered
+/// to be new
+const MAX_POOL_AGE: u64 = 2;
+/// the amount of blocks after the snipe that the position is marked out at
+const MARKOUT_BLOCKS: u64 = 5;
+
+pub struct SnipingInspector<'db, DB: LibmdbxReader> {
+    utils: SharedInspectorUtils<'db, DB>,
+}
+
+impl<'db, DB: LibmdbxReader> SnipingInspector<'db, DB> {
+    pub fn new(
+        quote: Address,
+        db: &'db DB,
+        config: InspectorConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        Self { utils: SharedInspectorUtils::new(quote, db, config, metrics) }
+    }
+}
+
+impl<DB: LibmdbxReader> Inspector for SnipingInspector<'_, DB> {
+    type Result = Vec<Bundle>;
+
+    // the previous blocks are needed to know if a new pool has already been traded
+    // and the blocks after the snipe to mark it out
+    fn block_window(&self) -> usize {
+        self.utils
+            .get_config()
+            .block_window_or((MAX_POOL_AGE + MARKOUT_BLOCKS) as usize + 1)
+    }
+
+    fn get_id(&self) -> &str {
+        "Sniping"
+    }
+
+    fn get_quote_token(&self) -> Address {
+        self.utils.quote
+    }
+
+    fn inspect_block(&self, data: MultiBlockData) -> Self::Result {
+        let blocks = &data.per_block_data;
+        let Some(snipe_idx) = blocks.len().checked_sub(MARKOUT_BLOCKS as usize + 1) else {
+            return vec![]
+        };
+        let (prev, rest) = blocks.split_at(snipe_idx);
+        let (cur, markout) = (&rest[0], rest.last().expect("window has a markout block"));
+
+        let execution = || self.inspect_window(cur, prev, markout);
+
+        self.utils
+            .get_metrics()
+            .map(|m| m.run_inspector(MevType::Sniping, execution))
+            .unwrap_or_else(execution)
+    }
+}
+
+impl<DB: LibmdbxReader> SnipingInspector<'_, DB> {
+    fn inspect_window(
+        &self,
+        cur: &BlockData,
+        prev: &[BlockData],
+        markout: &BlockData,
+    ) -> Vec<Bundle> {
+        let block_number = cur.metadata.block_num;
+
+        // pools that were created in the window and pools that have already
+        // been traded, by an earlier block or an earlier tx of the current block
+        let mut new_pools: FastHashMap<Address, u64> = FastHashMap::default();
+        let mut traded_pools: FastHashSet<Address> = FastHashSet::default();
+
+        for block in prev {
+            block
+                .tree
+                .clone()
+                .collect_all(TreeSearchBuilder::default().with_actions([
+                    Action::is_swap,
+                    Action::is_new_pool,
+                    Action::is_aggregator,
+                ]))
+                .flat_map(|(_, actions)| {
+                    self.utils
+                        .flatten_nested_actions_default(actions.into_iter())
+                        .collect_vec()
+                })
+                .for_each(|action| match action {
+                    Action::NewPool(pool) => {
+                        new_pools.insert(pool.pool_address, block.metadata.block_num);
+                    }
+                    action => {
+                        if let Some(swap) = action.try_swaps_merged_ref() {
+                            traded_pools.insert(swap.pool);
+                        }
+                    }
+                });
+        }
+
+        let mut possible_snipes = vec![];
+
+        for (tx_hash, actions) in
+            cur.tree
+                .clone()
+                .collect_all(TreeSearchBuilder::default().with_actions([
+                    Action::is_swap,
+                    Action::is_new_pool,
+                    Action::is_transfer,
+                    Action::is_eth_transfer,
+                    Action::is_wrapping,
+                    Action::is_aggregator,
+                ]))
+        {
+            let actions = self
+                .utils
+                .flatten_nested_actions_default(actions.into_iter())
+                .collect_vec();
+
+            for action in &actions {
+                if let Action::NewPool(pool) = action {
+                    new_pools.insert(pool.pool_address, block_number);
+                }
+            }
+
+            let snipes = first_launch_buys(&actions, &mut traded_pools)
+                .into_iter()
+                .filter_map(|pool| {
+                    let created = self.pool_creation_block(pool, &new_pools)?;
+                    (block_number.saturating_sub(created) <= MAX_POOL_AGE)
+                        .then_some((pool, created))
+                })
+                .collect_vec();
+
+            if snipes.is_empty() {
+                continue
+            }
+
+            possible_snipes.push((tx_hash, actions, snipes));
+        }
+
+        let txes = possible_snipes
+            .iter()
+            .map(|(tx_hash, ..)| *tx_hash)
+            .collect_vec();
+        let tx_info = cur.tree.get_tx_info_batch(&txes, self.utils.db);
+
+        possible_snipes
+            .into_iter()
+            .zip(tx_info)
+            .filter_map(|((_, actions, snipes), info)| {
+                self.calculate_snipe(
+                    info?,
+                    cur.metadata.clone(),
+                    &markout.metadata,
+                    actions,
+                    snipes,
+                )
+            })
+            .collect_vec()
+    }
+
+    fn calculate_snipe(
+        &self,
+        info: TxInfo,
+        metadata: Arc<Metadata>,
+        markout: &Metadata,
+        actions: Vec<Action>,
+        snipes: Vec<(Address, u64)>,
+    ) -> Option<Bundle> {
+        let (pools, pool_creation_blocks): (Vec<_>, Vec<_>) = snipes.into_iter().unzip();
+
+        let snipe_swaps = actions
+            .iter()
+            .filter_map(Action::try_swaps_merged_ref)
+            .filter(|swap| pools.contains(&swap.pool))
+            .cloned()
+            .collect_vec();
+
+        let mev_addresses: FastHashSet<Address> = info.collect_address_set_for_accounting();
+
+        let deltas = actions
+            .into_iter()
+            .chain(info.get_total_eth_value().iter().cloned().map(Action::from))
+            .filter(|a| a.is_eth_transfer() || a.is_transfer() || a.is_wrapping())
+            .account_for_actions();
+
+        // the token has no meaningful price in the block it launched in, so the
+        // position is marked to a later block
+        let rev = markout.dex_quotes.as_ref().and_then(|quotes| {
+            self.utils.get_full_block_price_with_quotes(
+                BlockPrice::Average,
+                mev_addresses,
+                &deltas,
+                quotes,
+            )
+        });
+        let mut has_dex_price = rev.is_some();
+
+        let gas_finalized =
+            metadata.get_gas_price_usd(info.gas_details.gas_paid(), self.utils.quote);
+
+        let mut profit_usd = rev.map(|rev| rev - &gas_finalized).unwrap_or_default();
+
+        if self.utils.is_profit_out_of_bounds(&profit_usd) {
+            has_dex_price = false;
+            profit_usd = Rational::ZERO;
+        }
+
+        let header = self.utils.build_bundle_header(
+            vec![deltas],
+            vec![info.tx_hash],
+            &info,
+            profit_usd.to_float(),
+            &[info.gas_details],
+            metadata.clone(),
+            MevType::Sniping,
+            !has_dex_price,
+            |this, token, amount| {
+                this.get_token_value_dex(
+                    info.tx_index as usize,
+                    PriceAt::Average,
+                    token,
+                    &amount,
+                    &metadata,
+                )
+            },
+        );
+
+        let snipe = Snipe {
+            block_number: metadata.block_num,
+            snipe_tx_hash: info.tx_hash,
+            pools,
+            pool_creation_blocks,
+            snipe_swaps,
+            markout_block: markout.block_num,
+            gas_details: info.gas_details,
+        };
+
+        Some(Bundle { header, data: BundleData::Snipe(snipe) })
+    }
+
+    /// Pools created inside of the window are taken from the tree, older pools
+    /// are looked up in the database
+    fn pool_creation_block(
+        &self,
+        pool: Address,
+        new_pools: &FastHashMap<Address, u64>,
+    ) -> Option<u64> {
+        new_pools.get(&pool).copied().or_else(|| {
+            self.utils
+                .db
+                .get_protocol_details(pool)
+                .ok()
+                .map(|info| info.init_block)
+        })
+    }
+}
+
+/// Returns the pools the tx buys into before anyone else has traded them. Every
+/// pool the tx swaps through is marked as traded, so a later buyer of the same
+/// pool is following the sniper instead of sniping
+fn first_launch_buys(actions: &[Action], traded_pools: &mut FastHashSet<Address>) -> Vec<Address> {
+    let swaps = actions
+        .iter()
+        .filter_map(Action::try_swaps_merged_ref)
+        .collect_vec();
+
+    let buys = swaps
+        .iter()
+        .filter(|swap| !traded_pools.contains(&swap.pool) && is_launch_buy(swap))
+        .map(|swap| swap.pool)
+        .unique()
+        .collect_vec();
+
+    traded_pools.extend(swaps.iter().map(|swap| swap.pool));
+
+    buys
+}
+
+/// A launch buy pays with an established token to receive the newly
+/// launched one
+fn is_launch_buy(swap: &NormalizedSwap) -> bool {
+    is_established_token(swap.token_in.address) && !is_established_token(swap.token_out.address)
+}
+
+fn is_established_token(token: Address) -> bool {
+    token == WETH_ADDRESS || token == ETH_ADDRESS || USD_STABLES_BY_ADDRESS.contains(&token)
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{constants::USDC_ADDRESS, db::token_info::TokenInfoWithAddress};
+
+    use super::*;
+
+    fn swap(token_in: Address, token_out: Address) -> NormalizedSwap {
+        NormalizedSwap {
+            token_in: TokenInfoWithAddress { address: token_in, ..Default::default() },
+            token_out: TokenInfoWithAddress { address: token_out, ..Default::default() },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_is_launch_buy() {
+        let launched = Address::with_last_byte(1);
+
+        assert!(is_launch_buy(&swap(WETH_ADDRESS, launched)));
+        assert!(is_launch_buy(&swap(USDC_ADDRESS, launched)));
+        // selling the launched token
+        assert!(!is_launch_buy(&swap(launched, WETH_ADDRESS)));
+        // swapping between established tokens
+        assert!(!is_launch_buy(&swap(WETH_ADDRESS, USDC_ADDRESS)));
+    }
+
+    #[test]
+    fn test_only_first_buyers_snipe() {
+        let (pool, launched) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let on_pool = |swap: NormalizedSwap| Action::Swap(NormalizedSwap { pool, ..swap });
+        let buy = on_pool(swap(WETH_ADDRESS, launched));
+        let sell = on_pool(swap(launched, WETH_ADDRESS));
+
+        // the first buyer snipes the pool, the buyer after them doesn't
+        let mut traded_pools = FastHashSet::default();
+        assert_eq!(first_launch_buys(&[buy.clone()], &mut traded_pools), vec![pool]);
+        assert!(first_launch_buys(&[buy.clone()], &mut traded_pools).is_empty());
+
+        // a buy after organic flow on the pool isn't a snipe
+        let mut traded_pools = FastHashSet::default();
+        assert!(first_launch_buys(&[sell], &mut traded_pools).is_empty());
+        assert!(first_launch_buys(&[buy], &mut traded_pools).is_empty());
+    }
+}
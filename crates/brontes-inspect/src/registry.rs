@@ -0,0 +1,191 @@
+//! The inspectors brontes can run, by name.
+//!
+//! The built in inspectors are registered under the name of their
+//! [`Inspectors`] variant. Inspectors that live outside of this crate can be
+//! added with [`InspectorRegistry::register`] and are then selected by name
+//! like any of the built in ones. Custom inspectors should return their
+//! results as [`BundleData::Custom`](brontes_types::mev::BundleData::Custom)
+//! bundles and can hook into deduplication through
+//! [`Inspector::dedup_rules`].
+use alloy_primitives::Address;
+use brontes_metrics::inspectors::OutlierMetrics;
+use brontes_types::{
+    db::{
+        cex::{trades::CexDexTradeConfig, CexExchange},
+        traits::LibmdbxReader,
+    },
+    mev::Bundle,
+};
+use eyre::eyre;
+use itertools::Itertools;
+use strum::IntoEnumIterator;
+
+use crate::{DynMevInspector, Inspector, Inspectors, InspectorsConfig};
+
+enum RegisteredInspector {
+    /// Built in inspectors are only initialized once they are selected
+    Builtin(Box<dyn FnOnce() -> DynMevInspector>),
+    Custom(Box<dyn Inspector<Result = Vec<Bundle>>>),
+}
+
+impl RegisteredInspector {
+    fn init(self) -> DynMevInspector {
+        match self {
+            Self::Builtin(init) => init(),
+            // inspectors are shared with the block tasks for the whole run
+            Self::Custom(inspector) => &*Box::leak(inspector),
+        }
+    }
+}
+
+#[derive(Default)]
+pub struct InspectorRegistry {
+    inspectors: Vec<(String, RegisteredInspector)>,
+}
+
+impl InspectorRegistry {
+    /// Creates a registry with all of the built in inspectors registered
+    pub fn with_builtin_inspectors<DB: LibmdbxReader>(
+        quote_token: Address,
+        db: &'static DB,
+        cex_exchanges: &[CexExchange],
+        trade_config: CexDexTradeConfig,
+        config: &InspectorsConfig,
+        metrics: Option<OutlierMetrics>,
+    ) -> Self {
+        let inspectors = Inspectors::iter()
+            .map(|inspector| {
+                let cex_exchanges = cex_exchanges.to_vec();
+                let config = config.clone();
+                let metrics = metrics.clone();
+                let init = move || {
+                    inspector.init_mev_inspector(
+                        quote_token,
+                        db,
+                        &cex_exchanges,
+                        trade_config,
+                        &config,
+                        metrics,
+                    )
+                };
+
+                (inspector.to_string(), RegisteredInspector::Builtin(Box::new(init)))
+            })
+            .collect();
+
+        Self { inspectors }
+    }
+
+    /// Registers the inspector under the given name. Names have to be unique
+    pub fn register(
+        &mut self,
+        name: impl Into<String>,
+        inspector: Box<dyn Inspector<Result = Vec<Bundle>>>,
+    ) -> eyre::Result<()> {
+        let name = name.into();
+        if self.contains(&name) {
+            return Err(eyre!("an inspector named `{name}` is already registered"))
+        }
+
+        self.inspectors
+            .push((name, RegisteredInspector::Custom(inspector)));
+        Ok(())
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.names().any(|registered| registered == name)
+    }
+
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.inspectors.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Initializes the inspectors with the given names, or every registered
+    /// inspector if no names are given. Inspectors that aren't selected are
+    /// never initialized.
+    pub fn resolve(mut self, names: Option<&[String]>) -> eyre::Result<&'static [DynMevInspector]> {
+        let inspectors = match names {
+            Some(names) => names
+                .iter()
+                .unique()
+                .map(|name| {
+                    let idx = self
+                        .inspectors
+                        .iter()
+                        .position(|(registered, _)| registered == name)
+                        .ok_or_else(|| {
+                            eyre!(
+                                "unknown inspector `{name}`, registered inspectors are: {}",
+                                self.names().join(", ")
+                            )
+                        })?;
+
+                    Ok(self.inspectors.remove(idx).1)
+                })
+                .collect::<eyre::Result<Vec<_>>>()?,
+            None => self
+                .inspectors
+                .into_iter()
+                .map(|(_, inspector)| inspector)
+                .collect(),
+        };
+
+        Ok(&*Box::leak(
+            inspectors
+                .into_iter()
+                .map(RegisteredInspector::init)
+                .collect::<Box<[_]>>(),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::MultiBlockData;
+
+    use super::*;
+
+    struct NoopInspector;
+
+    impl Inspector for NoopInspector {
+        type Result = Vec<Bundle>;
+
+        fn get_id(&self) -> &str {
+            "Noop"
+        }
+
+        fn inspect_block(&self, _data: MultiBlockData) -> Self::Result {
+            vec![]
+        }
+
+        fn get_quote_token(&self) -> Address {
+            Address::ZERO
+        }
+    }
+
+    #[test]
+    fn test_register_and_resolve() {
+        let mut registry = InspectorRegistry::default();
+        registry.register("Noop", Box::new(NoopInspector)).unwrap();
+        registry.register("Other", Box::new(NoopInspector)).unwrap();
+
+        assert!(registry.register("Noop", Box::new(NoopInspector)).is_err());
+        assert!(registry.contains("Other"));
+
+        assert_eq!(registry.resolve(None).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_resolve_selected() {
+        let mut registry = InspectorRegistry::default();
+        registry.register("Noop", Box::new(NoopInspector)).unwrap();
+        registry.register("Other", Box::new(NoopInspector)).unwrap();
+
+        let names = ["Other".to_string(), "Other".to_string()];
+        assert_eq!(registry.resolve(Some(&names)).unwrap().len(), 1);
+
+        let mut registry = InspectorRegistry::default();
+        registry.register("Noop", Box::new(NoopInspector)).unwrap();
+        assert!(registry.resolve(Some(&["Unknown".to_string()])).is_err());
+    }
+}
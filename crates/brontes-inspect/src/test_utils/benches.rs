@@ -12,7 +12,7 @@ use brontes_types::{
 use criterion::{black_box, Criterion};
 
 use super::InspectorTestUtilsError;
-use crate::{composer::run_block_inspection, Inspectors};
+use crate::{composer::run_block_inspection, Inspectors, InspectorsConfig};
 
 pub struct InspectorBenchUtils {
     classifier_inspector: ClassifierTestUtils,
@@ -48,6 +48,7 @@ impl InspectorBenchUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &InspectorsConfig::default(),
                     None,
                 )
             })
@@ -99,6 +100,7 @@ impl InspectorBenchUtils {
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
             CexDexTradeConfig::default(),
+            &InspectorsConfig::default(),
             None,
         );
 
@@ -162,6 +164,7 @@ impl InspectorBenchUtils {
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
             CexDexTradeConfig::default(),
+            &InspectorsConfig::default(),
             None,
         );
 
@@ -216,6 +219,7 @@ impl InspectorBenchUtils {
             self.classifier_inspector.libmdbx,
             &[CexExchange::Binance],
             CexDexTradeConfig::default(),
+            &InspectorsConfig::default(),
             None,
         );
 
@@ -263,6 +267,7 @@ impl InspectorBenchUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &InspectorsConfig::default(),
                     None,
                 )
             })
@@ -322,6 +327,7 @@ impl InspectorBenchUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &InspectorsConfig::default(),
                     None,
                 )
             })
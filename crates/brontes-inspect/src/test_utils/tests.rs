@@ -38,7 +38,10 @@ use brontes_types::{
 };
 use thiserror::Error;
 
-use crate::{composer::run_block_inspection, shared_utils::SharedInspectorUtils, Inspectors};
+use crate::{
+    composer::run_block_inspection, shared_utils::SharedInspectorUtils, Inspectors,
+    InspectorsConfig,
+};
 
 type StateTests = Option<Box<dyn for<'a> Fn(&'a Bundle)>>;
 
@@ -168,6 +171,7 @@ impl InspectorTestUtils {
                 CexExchange::Kucoin,
             ],
             CexDexTradeConfig::default(),
+            &InspectorsConfig::default(),
             None,
         );
         let data = BlockData { metadata: metadata.into(), tree: tree.into() };
@@ -258,6 +262,7 @@ impl InspectorTestUtils {
                 CexExchange::Upbit,
             ],
             cex_trade_config,
+            &InspectorsConfig::default(),
             None,
         );
 
@@ -367,6 +372,7 @@ impl InspectorTestUtils {
                     self.classifier_inspector.libmdbx,
                     &[CexExchange::Binance],
                     CexDexTradeConfig::default(),
+                    &InspectorsConfig::default(),
                     None,
                 )
             })
@@ -30,6 +30,9 @@ use crate::action_classifier::{ActionDispatch, ActionMacro};
 /// The Array of log types are expected to be in the order that they are emitted
 /// in. Otherwise the decoding will fail
 ///
+/// If a call can result in different kinds of actions, use `Action` as the
+/// CallType and return the `Action` from the closure.
+///
 ///  ## Examples
 /// ```ignore
 /// action_impl!(
@@ -13,7 +13,7 @@ pub use all_pair_graph::AllPairGraph;
 use alloy_primitives::Address;
 use brontes_types::{
     pair::Pair,
-    price_graph_types::{PoolPairInfoDirection, SubGraphEdge},
+    price_graph_types::{PoolPairInfoDirection, SizedQuote, SubGraphEdge},
 };
 use itertools::Itertools;
 use malachite::{num::basic::traits::One, Rational};
@@ -83,6 +83,23 @@ impl GraphManager {
         }
     }
 
+    /// Prices pairs over all verified paths of their subgraph, weighted by
+    /// their depth at `notional` in the quote asset, instead of over the single
+    /// most liquid path. The spread of the path prices is returned
+    /// along with the price.
+    pub fn with_path_weighted_pricing(mut self, notional: Rational) -> Self {
+        self.sub_graph_registry.set_path_weighted_notional(notional);
+        self
+    }
+
+    pub fn is_path_weighted(&self) -> bool {
+        self.sub_graph_registry.is_path_weighted()
+    }
+
+    pub fn path_weighted_notional(&self) -> Option<&Rational> {
+        self.sub_graph_registry.path_weighted_notional()
+    }
+
     /// used for testing and benching
     pub fn snapshot_state(&self) -> (SubGraphRegistry, SubgraphVerifier, StateTracker) {
         (self.sub_graph_registry.clone(), self.subgraph_verifier.clone(), self.graph_state.clone())
@@ -100,6 +117,31 @@ impl GraphManager {
         self.graph_state = state;
     }
 
+    /// Encodes the finalized graph state so that pricing can later be resumed
+    /// from it. Any state for blocks past the last finalized one is left out.
+    pub fn checkpoint(&self) -> eyre::Result<Vec<u8>> {
+        Ok(bincode::serde::encode_to_vec(
+            (&self.sub_graph_registry, &self.subgraph_verifier, &self.graph_state),
+            bincode::config::standard(),
+        )?)
+    }
+
+    /// Restores the graph state from a checkpoint. The all pair graph isn't
+    /// part of the checkpoint, it is built from the pools created before the
+    /// block pricing resumes at.
+    pub fn restore_checkpoint(&mut self, checkpoint: &[u8]) -> eyre::Result<()> {
+        let ((registry, verifier, state), _): (
+            (SubGraphRegistry, SubgraphVerifier, StateTracker),
+            _,
+        ) = bincode::serde::decode_from_slice(checkpoint, bincode::config::standard())?;
+
+        self.sub_graph_registry.restore(registry);
+        self.subgraph_verifier.restore(verifier);
+        self.graph_state.restore(state);
+
+        Ok(())
+    }
+
     pub fn add_pool(&mut self, pair: Pair, pool_addr: Address, dex: Protocol, block: u64) {
         self.all_pair_graph.add_node(pair, pool_addr, dex, block);
     }
@@ -226,7 +268,7 @@ impl GraphManager {
         &mut self,
         pair: Pair,
         goes_through: Pair,
-    ) -> Option<(Rational, Rational, usize)> {
+    ) -> Option<(Rational, Rational, usize, Rational)> {
         let span = error_span!("price generation for block");
         span.in_scope(|| {
             self.sub_graph_registry.get_price(
@@ -237,6 +279,38 @@ impl GraphManager {
         })
     }
 
+    /// Quotes a swap of `amount_in` of the first token of `pair` into the
+    /// second on the finalized pool state. See
+    /// [`SubGraphRegistry::quote_for_size`].
+    pub fn quote_for_size(
+        &self,
+        pair: Pair,
+        goes_through: Pair,
+        amount_in: &Rational,
+    ) -> Option<SizedQuote> {
+        self.sub_graph_registry.quote_for_size(
+            pair,
+            goes_through,
+            amount_in,
+            &self.graph_state.finalized_state(),
+        )
+    }
+
+    /// A copy of the subgraphs the pairs were priced over. See
+    /// [`SubGraphRegistry::subset`].
+    pub fn subgraph_snapshot(
+        &self,
+        priced: impl IntoIterator<Item = (Pair, Pair)>,
+    ) -> SubGraphRegistry {
+        self.sub_graph_registry.subset(priced)
+    }
+
+    /// The pools `pair` is priced over for `goes_through`. See
+    /// [`SubGraphRegistry::subgraph_edges`].
+    pub fn subgraph_edges(&self, pair: Pair, goes_through: Pair) -> Vec<SubGraphEdge> {
+        self.sub_graph_registry.subgraph_edges(pair, goes_through)
+    }
+
     pub fn new_state(&mut self, address: Address, state: StateWithDependencies) {
         self.graph_state.new_state_for_verification(address, state);
     }
@@ -245,6 +319,12 @@ impl GraphManager {
         self.graph_state.update_pool_state(address, update);
     }
 
+    /// The state of a pool that is used for pricing, as of the last update
+    /// applied to it
+    pub fn pool_state(&self, address: Address) -> Option<&PoolState> {
+        self.graph_state.finalized_pool_state(address)
+    }
+
     pub fn has_subgraph_goes_through(&self, pair: PairWithFirstPoolHop) -> bool {
         self.sub_graph_registry.has_go_through(pair) || self.subgraph_verifier.has_go_through(pair)
     }
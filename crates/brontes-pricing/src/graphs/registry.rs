@@ -2,7 +2,11 @@ use std::collections::BTreeMap;
 
 use alloy_primitives::Address;
 use brontes_metrics::pricing::DexPricingMetrics;
-use brontes_types::{pair::Pair, FastHashMap};
+use brontes_types::{
+    pair::Pair,
+    price_graph_types::{SizedQuote, SubGraphEdge},
+    FastHashMap,
+};
 use itertools::Itertools;
 use malachite::{
     num::{
@@ -11,6 +15,7 @@ use malachite::{
     },
     Rational,
 };
+use serde::{Deserialize, Serialize};
 
 use super::{subgraph::PairSubGraph, PoolState};
 use crate::types::{PairWithFirstPoolHop, ProtocolState};
@@ -30,14 +35,24 @@ use crate::types::{PairWithFirstPoolHop, ProtocolState};
 /// Mainly functioning within the BrontesBatchPricer system, it plays a key role
 /// in providing up-to-date and reliable pricing data in the decentralized
 /// exchange context.
-#[derive(Debug, Clone)]
+///
+/// Only the finalized subgraphs are checkpointed, subgraphs pending for later
+/// blocks are rebuilt when pricing resumes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubGraphRegistry {
     /// all currently known sub-graphs
     sub_graphs:               FastHashMap<Pair, BTreeMap<Pair, PairSubGraph>>,
     /// the pending_subgrpahs that haven't been finalized yet.
+    #[serde(skip)]
     pending_finalized_graphs: FastHashMap<u64, PendingRegistry>,
     /// metrics
+    #[serde(skip)]
     metrics:                  Option<DexPricingMetrics>,
+    /// when set, pairs are priced over all paths of their subgraph weighted by
+    /// their depth at this notional in the quote asset, instead of over the
+    /// most liquid path.
+    #[serde(skip)]
+    path_weighted_notional:   Option<Rational>,
 }
 
 /// holder for subgraphs that aren't active yet to avoid race conditions
@@ -46,6 +61,12 @@ pub struct PendingRegistry {
     sub_graphs: FastHashMap<Pair, BTreeMap<Pair, PairSubGraph>>,
 }
 
+impl Default for SubGraphRegistry {
+    fn default() -> Self {
+        Self::new(None)
+    }
+}
+
 impl Drop for SubGraphRegistry {
     fn drop(&mut self) {
         let subgraphs_cnt = self.sub_graphs.values().map(|f| f.len()).sum::<usize>();
@@ -62,7 +83,108 @@ impl Drop for SubGraphRegistry {
 impl SubGraphRegistry {
     pub fn new(metrics: Option<DexPricingMetrics>) -> Self {
         let sub_graphs = FastHashMap::default();
-        Self { sub_graphs, pending_finalized_graphs: FastHashMap::default(), metrics }
+        Self {
+            sub_graphs,
+            pending_finalized_graphs: FastHashMap::default(),
+            metrics,
+            path_weighted_notional: None,
+        }
+    }
+
+    pub fn set_path_weighted_notional(&mut self, notional: Rational) {
+        self.path_weighted_notional = Some(notional);
+    }
+
+    pub fn is_path_weighted(&self) -> bool {
+        self.path_weighted_notional.is_some()
+    }
+
+    pub fn path_weighted_notional(&self) -> Option<&Rational> {
+        self.path_weighted_notional.as_ref()
+    }
+
+    /// Replaces the subgraphs with the ones of a checkpoint, keeping the
+    /// pricing config and metrics.
+    pub fn restore(&mut self, mut checkpoint: SubGraphRegistry) {
+        self.sub_graphs = std::mem::take(&mut checkpoint.sub_graphs);
+        self.pending_finalized_graphs.clear();
+    }
+
+    /// A copy of the subgraphs the pairs were priced over for their
+    /// go-throughs, along with the subgraphs of the pairs they extend to.
+    /// All go-through variants of a pair are kept when there is no subgraph
+    /// for its go-through, as pricing falls back to them.
+    pub fn subset(&self, priced: impl IntoIterator<Item = (Pair, Pair)>) -> Self {
+        let mut subset = Self::new(None);
+        subset.path_weighted_notional = self.path_weighted_notional.clone();
+
+        let copy_all = |subset: &mut Self, pair: Pair| {
+            let Some(graphs) = self.sub_graphs.get(&pair.ordered()) else { return };
+            subset
+                .sub_graphs
+                .entry(pair.ordered())
+                .or_default()
+                .extend(graphs.iter().map(|(gt, graph)| (*gt, graph.clone())));
+        };
+
+        for (pair, goes_through) in priced {
+            let Some(graph) = self
+                .sub_graphs
+                .get(&pair.ordered())
+                .and_then(|g| g.get(&goes_through.ordered()))
+            else {
+                copy_all(&mut subset, pair);
+                continue
+            };
+
+            if let Some(next) = graph.extends_to() {
+                copy_all(&mut subset, next);
+            }
+            subset
+                .sub_graphs
+                .entry(pair.ordered())
+                .or_default()
+                .insert(goes_through.ordered(), graph.clone());
+        }
+
+        subset
+    }
+
+    /// the pools on any of the subgraphs
+    pub fn pools(&self) -> impl Iterator<Item = Address> + '_ {
+        self.sub_graphs
+            .values()
+            .flat_map(|graphs| graphs.values())
+            .flat_map(|graph| graph.get_all_pools().flatten().map(|edge| edge.pool_addr))
+            .unique()
+    }
+
+    /// the price of the subgraph along with the spread of its path prices,
+    /// which is zero when pricing over the most liquid path
+    fn fetch_price(
+        &self,
+        graph: &PairSubGraph,
+        edge_state: &FastHashMap<Address, &PoolState>,
+    ) -> Option<(Rational, Rational)> {
+        match &self.path_weighted_notional {
+            Some(notional) => {
+                // an extended subgraph prices into the pair it extends to, so the
+                // notional is converted into the token it ends on
+                let notional = match graph.extends_to() {
+                    Some(next) => {
+                        let (next_price, ..) = self
+                            .get_price_all(next, edge_state)
+                            .filter(|(price, ..)| *price != Rational::ZERO)?;
+                        notional / next_price
+                    }
+                    None => notional.clone(),
+                };
+                graph.fetch_path_weighted_price(edge_state, &notional)
+            }
+            None => graph
+                .fetch_price(edge_state)
+                .map(|price| (price, Rational::ZERO)),
+        }
     }
 
     // for all subgraphs that haven't been used in a given time period, will
@@ -270,22 +392,24 @@ impl SubGraphRegistry {
         unordered_pair: Pair,
         goes_through: Pair,
         edge_state: &FastHashMap<Address, &PoolState>,
-    ) -> Option<(Rational, Rational, usize)> {
-        let (next, complete_pair, default_price, connections, liq) =
+    ) -> Option<(Rational, Rational, usize, Rational)> {
+        let (next, complete_pair, default_price, connections, liq, spread) =
             self.get_price_once(unordered_pair, goes_through, edge_state)?;
 
         if let Some(next) = next {
             // extend is assuemed stable
             let (next_price, ..) = self.get_price_all(next, edge_state)?;
 
+            let spread = spread * &next_price;
             let price = next_price * &default_price;
             if unordered_pair.eq_unordered(&complete_pair) {
-                Some((price, liq, connections))
+                Some((price, liq, connections, spread))
             } else {
-                Some((price.reciprocal(), liq, connections))
+                let spread = spread / (&price * &price);
+                Some((price.reciprocal(), liq, connections, spread))
             }
         } else {
-            Some((default_price, liq, connections))
+            Some((default_price, liq, connections, spread))
         }
     }
 
@@ -294,7 +418,7 @@ impl SubGraphRegistry {
         unordered_pair: Pair,
         goes_through: Pair,
         edge_state: &FastHashMap<Address, &PoolState>,
-    ) -> Option<(Option<Pair>, Pair, Rational, usize, Rational)> {
+    ) -> Option<(Option<Pair>, Pair, Rational, usize, Rational, Rational)> {
         let pair = unordered_pair.ordered();
 
         self.sub_graphs
@@ -302,12 +426,14 @@ impl SubGraphRegistry {
             .and_then(|g| g.get(&goes_through.ordered()))
             .map(|graph| {
                 tracing::debug!("has graph for goes through");
+                let (price, spread) = self.fetch_price(graph, edge_state)?;
                 Some((
                     graph.extends_to(),
                     graph.complete_pair(),
-                    graph.fetch_price(edge_state)?,
+                    price,
                     graph.first_hop_connections(),
                     graph.first_hop_min_liq(edge_state).unwrap_or_default(),
+                    spread,
                 ))
             })
             // this can happen when we have pools with a token that only has that one pool.
@@ -316,19 +442,122 @@ impl SubGraphRegistry {
             // that way
             .or_else(|| {
                 Some(
-                    self.get_price_all(unordered_pair, edge_state)
-                        .map(|(price, con, e)| (None, unordered_pair, price, con, e)),
+                    self.get_price_all(unordered_pair, edge_state).map(
+                        |(price, con, e, spread)| (None, unordered_pair, price, con, e, spread),
+                    ),
                 )
             })
             .flatten()
     }
 
+    /// Quotes a swap of `amount_in` of the first token of `unordered_pair` into
+    /// the second, through the subgraph the pair is priced with for
+    /// `goes_through`. Subgraphs are directed, so a pair is only quoted in the
+    /// direction it is priced in. Pools that can't quote swaps are routed
+    /// around and returned with the quote.
+    pub fn quote_for_size(
+        &self,
+        unordered_pair: Pair,
+        goes_through: Pair,
+        amount_in: &Rational,
+        edge_state: &FastHashMap<Address, &PoolState>,
+    ) -> Option<SizedQuote> {
+        let Some(graph) = self
+            .sub_graphs
+            .get(&unordered_pair.ordered())
+            .and_then(|g| g.get(&goes_through.ordered()))
+        else {
+            return self.quote_for_size_all(unordered_pair, amount_in, edge_state)
+        };
+
+        if !unordered_pair.eq_unordered(&graph.complete_pair()) {
+            return None
+        }
+
+        let mut quote = graph.quote_for_size(amount_in, edge_state)?;
+        let Some(next) = graph.extends_to() else { return Some(quote) };
+
+        // the output is swapped on through the pair the subgraph extends to
+        let next_quote = self.quote_for_size_all(next, &quote.amount_out, edge_state)?;
+        quote.amount_out = next_quote.amount_out;
+        quote.pools.extend(next_quote.pools);
+        quote.skipped_pools.extend(next_quote.skipped_pools);
+        quote.skipped_pools = quote.skipped_pools.into_iter().unique().collect();
+
+        Some(quote)
+    }
+
+    /// The edges of the subgraph `unordered_pair` is priced with for
+    /// `goes_through`, followed by the edges of the pair it extends to. Falls
+    /// back to the edges of all go-through variants of the pair when there is
+    /// no subgraph for `goes_through`, as pricing does.
+    pub fn subgraph_edges(&self, unordered_pair: Pair, goes_through: Pair) -> Vec<SubGraphEdge> {
+        let Some(graph) = self
+            .sub_graphs
+            .get(&unordered_pair.ordered())
+            .and_then(|g| g.get(&goes_through.ordered()))
+        else {
+            return self.subgraph_edges_all(unordered_pair)
+        };
+
+        let mut edges = graph.get_all_pools().flatten().copied().collect_vec();
+        if let Some(next) = graph.extends_to() {
+            edges.extend(self.subgraph_edges_all(next));
+        }
+
+        edges
+    }
+
+    /// the edges of all go-through variants of the pair that aren't extended
+    fn subgraph_edges_all(&self, unordered_pair: Pair) -> Vec<SubGraphEdge> {
+        self.sub_graphs
+            .get(&unordered_pair.ordered())
+            .into_iter()
+            .flat_map(|graphs| graphs.values())
+            .filter(|graph| graph.extends_to().is_none())
+            .flat_map(|graph| graph.get_all_pools().flatten().copied())
+            .unique()
+            .collect_vec()
+    }
+
+    /// quotes the swap on all go-through variants of the pair that price it in
+    /// the given direction, taking the one that returns the most along with
+    /// the pools any of them skipped
+    fn quote_for_size_all(
+        &self,
+        unordered_pair: Pair,
+        amount_in: &Rational,
+        edge_state: &FastHashMap<Address, &PoolState>,
+    ) -> Option<SizedQuote> {
+        let quotes = self
+            .sub_graphs
+            .get(&unordered_pair.ordered())?
+            .values()
+            .filter(|graph| {
+                graph.extends_to().is_none()
+                    && unordered_pair.eq_unordered(&graph.get_unordered_pair())
+            })
+            .filter_map(|graph| graph.quote_for_size(amount_in, edge_state))
+            .collect_vec();
+
+        let skipped_pools = quotes
+            .iter()
+            .flat_map(|quote| quote.skipped_pools.iter().copied())
+            .unique()
+            .collect_vec();
+        let best = quotes
+            .into_iter()
+            .max_by(|a, b| a.amount_out.cmp(&b.amount_out))?;
+
+        Some(SizedQuote { skipped_pools, ..best })
+    }
+
     /// for the given pair, grabs the price for all go-through variants
     pub(crate) fn get_price_all(
         &self,
         unordered_pair: Pair,
         edge_state: &FastHashMap<Address, &PoolState>,
-    ) -> Option<(Rational, usize, Rational)> {
+    ) -> Option<(Rational, usize, Rational, Rational)> {
         let pair = unordered_pair.ordered();
         let mut connections = 0;
         let mut min_liq = Rational::ZERO;
@@ -336,12 +565,13 @@ impl SubGraphRegistry {
         self.sub_graphs.get(&pair).and_then(|f| {
             let mut cnt = Rational::ZERO;
             let mut acc = Rational::ZERO;
+            let mut spread_acc = Rational::ZERO;
             for graph in f.values() {
                 if graph.extends_to().is_some() {
                     continue
                 };
 
-                let Some(next) = graph.fetch_price(edge_state) else {
+                let Some((next, spread)) = self.fetch_price(graph, edge_state) else {
                     continue;
                 };
 
@@ -355,14 +585,96 @@ impl SubGraphRegistry {
                 let default_pair = graph.get_unordered_pair();
 
                 // ensure all graph pairs are accumulated in the same way
-                acc += if !unordered_pair.eq_unordered(&default_pair) {
-                    next.reciprocal()
+                if !unordered_pair.eq_unordered(&default_pair) {
+                    spread_acc += spread / (&next * &next);
+                    acc += next.reciprocal();
                 } else {
-                    next
-                };
+                    spread_acc += spread;
+                    acc += next;
+                }
                 cnt += Rational::ONE;
             }
-            (cnt != Rational::ZERO).then(|| (acc / cnt, connections, min_liq))
+            (cnt != Rational::ZERO).then(|| (acc / &cnt, connections, min_liq, spread_acc / cnt))
         })
     }
 }
+
+#[cfg(test)]
+pub mod test {
+    use brontes_types::{
+        price_graph_types::{PoolPairInfoDirection, PoolPairInformation},
+        FastHashSet, Protocol,
+    };
+
+    use super::*;
+
+    fn build_edge(lookup_pair: Address, t0: Address, t1: Address) -> SubGraphEdge {
+        SubGraphEdge::new(PoolPairInfoDirection::new(
+            Box::leak(Box::new(PoolPairInformation::new(lookup_pair, Protocol::UniswapV2, t0, t1))),
+            true,
+        ))
+    }
+
+    macro_rules! addresses {
+        ($($var:ident),*) => {
+            let mut bytes = [0u8; 20];
+            $(
+                let $var = Address::new(bytes);
+                bytes[19] += 1;
+            )*
+        };
+    }
+
+    fn edge_set(edges: Vec<SubGraphEdge>) -> FastHashSet<SubGraphEdge> {
+        edges.into_iter().collect()
+    }
+
+    #[test]
+    fn test_subgraph_edges() {
+        addresses!(t0, t1, t2, t3);
+        let e01 = build_edge(t0, t0, t1);
+        let e12 = build_edge(t1, t1, t2);
+        let e13 = build_edge(t2, t1, t3);
+        let e32 = build_edge(t3, t3, t2);
+
+        // t0 -> t2 through t0 -> t1, extended to t1 -> t2
+        let extended = PairSubGraph::init(
+            Pair(t0, t1),
+            Pair(t0, t2),
+            Pair(t0, t1),
+            Some(Pair(t1, t2)),
+            vec![e01],
+            0,
+        );
+        // the two go-through variants of t1 -> t2
+        let direct =
+            PairSubGraph::init(Pair(t1, t2), Pair(t1, t2), Pair(t1, t2), None, vec![e12], 0);
+        let through_t3 =
+            PairSubGraph::init(Pair(t1, t2), Pair(t1, t2), Pair(t3, t2), None, vec![e13, e32], 0);
+
+        let mut registry = SubGraphRegistry::default();
+        for graph in [extended, direct, through_t3] {
+            registry.add_verified_subgraph(graph, FastHashMap::default(), 0);
+        }
+        registry.finalize_block(0);
+
+        // the extended graph is followed by all variants of the pair it extends to
+        assert_eq!(
+            edge_set(registry.subgraph_edges(Pair(t0, t2), Pair(t0, t1))),
+            edge_set(vec![e01, e12, e13, e32])
+        );
+        assert_eq!(registry.subgraph_edges(Pair(t1, t2), Pair(t1, t2)), vec![e12]);
+        assert_eq!(
+            edge_set(registry.subgraph_edges(Pair(t2, t1), Pair(t3, t2))),
+            edge_set(vec![e13, e32])
+        );
+        // a go-through without a subgraph falls back to all variants
+        assert_eq!(
+            edge_set(registry.subgraph_edges(Pair(t1, t2), Pair(t0, t1))),
+            edge_set(vec![e12, e13, e32])
+        );
+        assert!(registry
+            .subgraph_edges(Pair(t0, t3), Pair(t0, t3))
+            .is_empty());
+    }
+}
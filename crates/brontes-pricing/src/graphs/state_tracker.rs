@@ -4,6 +4,7 @@ use alloy_primitives::Address;
 use brontes_metrics::pricing::DexPricingMetrics;
 use brontes_types::FastHashMap;
 use itertools::Itertools;
+use serde::{Deserialize, Serialize};
 use tracing::debug;
 
 use crate::{
@@ -29,13 +30,16 @@ use crate::{
 /// verification completion. This careful management of pool states is essential
 /// for the BrontesBatchPricer system to provide accurate and current pricing
 /// information for tokens on decentralized exchanges.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StateTracker {
     /// state that finalized subgraphs are dependent on.
     finalized_edge_state:    FastHashMap<Address, StateWithDependencies>,
-    /// state that verification is using
+    /// state that verification is using. not checkpointed as it only
+    /// belongs to blocks that haven't been finalized
+    #[serde(skip)]
     verification_edge_state: FastHashMap<Address, PoolStateWithBlock>,
     /// state count
+    #[serde(skip)]
     metrics:                 Option<DexPricingMetrics>,
 }
 
@@ -67,6 +71,15 @@ impl StateTracker {
         }
     }
 
+    /// Replaces the finalized state with the one of a checkpoint
+    pub fn restore(&mut self, mut checkpoint: StateTracker) {
+        self.finalized_edge_state = std::mem::take(&mut checkpoint.finalized_edge_state);
+        self.verification_edge_state.clear();
+        self.metrics
+            .as_ref()
+            .inspect(|m| m.active_state.set(self.finalized_edge_state.len() as f64));
+    }
+
     pub fn remove_finalized_state_dep(&mut self, pool: Address, amount: u64) {
         self.finalized_edge_state.retain(|i_pool, state| {
             if pool != *i_pool {
@@ -91,6 +104,10 @@ impl StateTracker {
             .collect()
     }
 
+    pub fn finalized_pool_state(&self, address: Address) -> Option<&PoolState> {
+        self.finalized_edge_state.get(&address).map(|d| &d.state)
+    }
+
     pub fn all_state(&self, block: u64) -> FastHashMap<Address, &PoolState> {
         self.state_for_verification(block)
             .into_iter()
@@ -206,7 +223,7 @@ impl StateTracker {
     }
 }
 
-#[derive(Debug, Clone, derive_more::Deref)]
+#[derive(Debug, Clone, derive_more::Deref, Serialize, Deserialize)]
 pub struct StateWithDependencies {
     #[deref]
     pub state:      PoolState,
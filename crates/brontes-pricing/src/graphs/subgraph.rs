@@ -16,7 +16,7 @@ use brontes_types::{price_graph_types::*, FastHashMap, FastHashSet};
 use itertools::Itertools;
 use malachite::{
     num::{
-        arithmetic::traits::Reciprocal,
+        arithmetic::traits::{Abs, Reciprocal},
         basic::traits::{One, OneHalf, Zero},
     },
     Rational,
@@ -27,9 +27,10 @@ use petgraph::{
     prelude::*,
     visit::{VisitMap, Visitable},
 };
+use serde::{Deserialize, Serialize};
 use tracing::error;
 
-use crate::{types::ProtocolState, Pair};
+use crate::{errors::ArithmeticError, types::ProtocolState, Pair};
 
 pub struct VerificationOutcome {
     pub should_requery: bool,
@@ -38,7 +39,7 @@ pub struct VerificationOutcome {
     pub frayed_ends:    Vec<Address>,
 }
 
-#[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
+#[derive(Debug, Default, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct BadEdge {
     pub pair:         Pair,
     pub pool_address: Address,
@@ -55,6 +56,10 @@ struct BfsArgs {
 const MIN_LIQUIDITY_USD_PEGGED_TOKEN: u128 = 15_000;
 const MIN_LIQUIDITY_USD_PEGGED_TOKEN_RUNDOWN: u128 = 7_500;
 const INACTIVE_REMOVAL_PERIOD: u64 = 750;
+/// the most hops of a path used in path weighted pricing
+const MAX_PATH_HOPS: usize = 4;
+/// the most paths used in path weighted pricing
+const MAX_PRICED_PATHS: usize = 64;
 
 /// [`PairSubGraph`] is a directed subgraph, specifically designed to calculate
 /// and optimize the pricing of a particular token pair in a decentralized
@@ -77,7 +82,8 @@ const INACTIVE_REMOVAL_PERIOD: u64 = 750;
 /// ensuring the integrity and reliability of each pool's data within the
 /// subgraph and recalculating prices based on up-to-date and verified
 /// information.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(from = "PairSubGraphRepr", into = "PairSubGraphRepr")]
 pub struct PairSubGraph {
     /// the pair represented
     pub(crate) pair:          Pair,
@@ -104,6 +110,75 @@ pub struct PairSubGraph {
     remove_at:              Option<u64>,
 }
 
+/// [`PairSubGraph`] as it is checkpointed. The graph is kept as its node count
+/// and raw edges, which rebuild it with the same indexes.
+#[derive(Serialize, Deserialize)]
+struct PairSubGraphRepr {
+    pair:                   Pair,
+    complete_pair:          Pair,
+    must_go_through:        Pair,
+    node_count:             u16,
+    edges:                  Vec<(u16, u16, Vec<SubGraphEdge>)>,
+    token_to_index:         FastHashMap<Address, u16>,
+    extends_to:             Option<Pair>,
+    start_nodes_liq:        FastHashMap<Address, Rational>,
+    start_node:             u16,
+    end_node:               u16,
+    last_block_for_pricing: u64,
+    remove_at:              Option<u64>,
+}
+
+impl From<PairSubGraph> for PairSubGraphRepr {
+    fn from(subgraph: PairSubGraph) -> Self {
+        let node_count = subgraph.graph.node_count().try_into().unwrap();
+        let (_, edges) = subgraph.graph.into_nodes_edges();
+
+        Self {
+            pair: subgraph.pair,
+            complete_pair: subgraph.complete_pair,
+            must_go_through: subgraph.must_go_through,
+            node_count,
+            edges: edges
+                .into_iter()
+                .map(|edge| {
+                    (edge.source().index() as u16, edge.target().index() as u16, edge.weight)
+                })
+                .collect(),
+            token_to_index: subgraph.token_to_index,
+            extends_to: subgraph.extends_to,
+            start_nodes_liq: subgraph.start_nodes_liq,
+            start_node: subgraph.start_node,
+            end_node: subgraph.end_node,
+            last_block_for_pricing: subgraph.last_block_for_pricing.load(SeqCst),
+            remove_at: subgraph.remove_at,
+        }
+    }
+}
+
+impl From<PairSubGraphRepr> for PairSubGraph {
+    fn from(repr: PairSubGraphRepr) -> Self {
+        let mut graph = DiGraph::<(), Vec<SubGraphEdge>, u16>::default();
+        (0..repr.node_count).for_each(|_| {
+            graph.add_node(());
+        });
+        graph.extend_with_edges(repr.edges);
+
+        Self {
+            pair: repr.pair,
+            complete_pair: repr.complete_pair,
+            must_go_through: repr.must_go_through,
+            graph,
+            token_to_index: repr.token_to_index,
+            extends_to: repr.extends_to,
+            start_nodes_liq: repr.start_nodes_liq,
+            start_node: repr.start_node,
+            end_node: repr.end_node,
+            last_block_for_pricing: Arc::new(AtomicU64::new(repr.last_block_for_pricing)),
+            remove_at: repr.remove_at,
+        }
+    }
+}
+
 impl PairSubGraph {
     pub fn init(
         pair: Pair,
@@ -320,6 +395,189 @@ impl PairSubGraph {
         self.dijkstra_path(self.start_node.into(), edge_state)
     }
 
+    /// Prices the pair over every path from the start to the end node instead
+    /// of only the most liquid one. Each path is weighted by its depth, the
+    /// liquidity of its shallowest hop valued in the end token, capped at
+    /// `notional` so that every path deep enough to fill the notional counts
+    /// the same. Returns the weighted price along with the weighted mean
+    /// absolute deviation of the path prices around it.
+    pub fn fetch_path_weighted_price<T: ProtocolState>(
+        &self,
+        edge_state: &FastHashMap<Address, &T>,
+        notional: &Rational,
+    ) -> Option<(Rational, Rational)> {
+        let start: NodeIndex<u16> = self.start_node.into();
+        let mut on_path = FastHashSet::default();
+        on_path.insert(start);
+
+        let mut paths = Vec::new();
+        self.collect_path_prices(start, Rational::ONE, None, &mut on_path, edge_state, &mut paths);
+
+        let paths = paths
+            .into_iter()
+            .map(|(price, depth)| {
+                let depth = &price * depth;
+                (price, std::cmp::min(depth, notional.clone()))
+            })
+            .filter(|(_, weight)| *weight > Rational::ZERO)
+            .collect_vec();
+
+        let total_weight = paths
+            .iter()
+            .fold(Rational::ZERO, |acc, (_, weight)| acc + weight);
+        if total_weight == Rational::ZERO {
+            return None
+        }
+
+        let price = paths
+            .iter()
+            .fold(Rational::ZERO, |acc, (price, weight)| acc + price * weight)
+            / &total_weight;
+        let spread = paths
+            .iter()
+            .fold(Rational::ZERO, |acc, (path_price, weight)| {
+                acc + (path_price - &price).abs() * weight
+            })
+            / total_weight;
+
+        Some((price, spread))
+    }
+
+    /// Walks every simple path to the end node, collecting the price and depth
+    /// of each. Paths are bounded in length and count so that dense subgraphs
+    /// stay cheap to price.
+    fn collect_path_prices<T: ProtocolState>(
+        &self,
+        node: NodeIndex<u16>,
+        price: Rational,
+        depth: Option<Rational>,
+        on_path: &mut FastHashSet<NodeIndex<u16>>,
+        state: &FastHashMap<Address, &T>,
+        paths: &mut Vec<(Rational, Rational)>,
+    ) {
+        if node == self.end_node.into() {
+            paths.extend(depth.map(|depth| (price, depth)));
+            return
+        }
+
+        if on_path.len() > MAX_PATH_HOPS || paths.len() >= MAX_PRICED_PATHS {
+            return
+        }
+
+        for edge in self.graph.edges(node) {
+            let next = edge.target();
+            if on_path.contains(&next) {
+                continue
+            }
+
+            let Some((edge_price, token_0_am, _)) = edge_price(edge.weight(), state) else {
+                continue
+            };
+
+            // the liquidity of this hop, in the start token
+            let hop_depth = token_0_am / &price;
+            let depth = match &depth {
+                Some(depth) => std::cmp::min(depth.clone(), hop_depth),
+                None => hop_depth,
+            };
+
+            on_path.insert(next);
+            self.collect_path_prices(next, &price * edge_price, Some(depth), on_path, state, paths);
+            on_path.remove(&next);
+        }
+    }
+
+    /// Quotes a swap of `amount_in` of the start token into the end token. The
+    /// full amount is routed down every path, through the pool of each hop
+    /// that returns the most, and the path returning the most is taken. Pools
+    /// that can't quote a swap are routed around and returned with the quote.
+    pub fn quote_for_size<T: ProtocolState>(
+        &self,
+        amount_in: &Rational,
+        edge_state: &FastHashMap<Address, &T>,
+    ) -> Option<SizedQuote> {
+        let start: NodeIndex<u16> = self.start_node.into();
+        let mut on_path = FastHashSet::default();
+        on_path.insert(start);
+
+        let mut quotes = Vec::new();
+        let mut skipped = FastHashSet::default();
+        self.collect_path_quotes(
+            start,
+            amount_in.clone(),
+            &mut Vec::new(),
+            &mut on_path,
+            edge_state,
+            &mut quotes,
+            &mut skipped,
+        );
+        if quotes.is_empty() && !skipped.is_empty() {
+            tracing::debug!(?skipped, "no path could be quoted around the unsupported pools");
+        }
+
+        let (amount_out, pools) = quotes.into_iter().max_by(|(a, _), (b, _)| a.cmp(b))?;
+        Some(SizedQuote { amount_out, pools, skipped_pools: skipped.into_iter().collect() })
+    }
+
+    /// Walks every simple path to the end node like
+    /// [`PairSubGraph::collect_path_prices`], swapping the amount through each
+    /// hop on the way.
+    #[allow(clippy::too_many_arguments)]
+    fn collect_path_quotes<T: ProtocolState>(
+        &self,
+        node: NodeIndex<u16>,
+        amount: Rational,
+        pools: &mut Vec<Address>,
+        on_path: &mut FastHashSet<NodeIndex<u16>>,
+        state: &FastHashMap<Address, &T>,
+        quotes: &mut Vec<(Rational, Vec<Address>)>,
+        skipped: &mut FastHashSet<Address>,
+    ) {
+        if node == self.end_node.into() {
+            quotes.push((amount, pools.clone()));
+            return
+        }
+
+        if on_path.len() > MAX_PATH_HOPS || quotes.len() >= MAX_PRICED_PATHS {
+            return
+        }
+
+        for edge in self.graph.edges(node) {
+            let next = edge.target();
+            if on_path.contains(&next) {
+                continue
+            }
+
+            let Some((pool, amount_out)) = edge
+                .weight()
+                .iter()
+                .filter_map(|info| {
+                    let amount_out = match state
+                        .get(&info.pool_addr)?
+                        .amount_out(info.get_base_token(), &amount)
+                    {
+                        Ok(amount_out) => amount_out,
+                        Err(ArithmeticError::UnsupportedQuote) => {
+                            skipped.insert(info.pool_addr);
+                            return None
+                        }
+                        Err(_) => return None,
+                    };
+                    Some((info.pool_addr, amount_out))
+                })
+                .max_by(|(_, a), (_, b)| a.cmp(b))
+            else {
+                continue
+            };
+
+            on_path.insert(next);
+            pools.push(pool);
+            self.collect_path_quotes(next, amount_out, pools, on_path, state, quotes, skipped);
+            pools.pop();
+            on_path.remove(&next);
+        }
+    }
+
     pub fn get_all_pools(&self) -> impl Iterator<Item = &Vec<SubGraphEdge>> + '_ {
         self.graph.edge_weights()
     }
@@ -942,38 +1200,12 @@ impl PairSubGraph {
                     continue
                 }
 
-                let mut pxw = Rational::ZERO;
-                let mut weight = Rational::ZERO;
-                let mut token_0_am = Rational::ZERO;
-                let mut token_1_am = Rational::ZERO;
-
-                // calculate tvl of pool using the start token as the quote
-
-                for info in edge_weight {
-                    let Some(pool_state) = state.get(&info.pool_addr) else {
-                        tracing::debug!(addr=?info.pool_addr,"failed to fetch pool state while generating price");
-                        continue;
-                    };
-
-                    let Ok(pool_price) = pool_state.price(info.get_base_token()) else {
-                        continue;
-                    };
-
-                    let (t0, t1) = pool_state.tvl(info.get_base_token());
-
-                    let t0xt1 = &t0 * &t1;
-                    pxw += pool_price * &t0xt1;
-                    weight += t0xt1;
-
-                    token_0_am += t0;
-                    token_1_am += t1;
-                }
-
-                if weight == Rational::ZERO {
+                let Some((local_weighted_price, token_0_am, token_1_am)) =
+                    edge_price(edge_weight, state)
+                else {
                     continue
-                }
+                };
 
-                let local_weighted_price = pxw / weight;
                 let token_0_priced = token_0_am * price.clone().reciprocal();
                 let new_price = &price * local_weighted_price;
                 let token_1_priced = token_1_am * new_price.clone().reciprocal();
@@ -1004,6 +1236,45 @@ impl PairSubGraph {
 
 const MAX_TVL_WEIGHT: Rational = Rational::const_from_unsigned(100_000_000_000u64);
 
+/// Calculates the liquidity weighted price of the pools on an edge, along with
+/// the summed tvl of the edge in its base and quote token.
+fn edge_price<T: ProtocolState>(
+    edge_weight: &[SubGraphEdge],
+    state: &FastHashMap<Address, &T>,
+) -> Option<(Rational, Rational, Rational)> {
+    let mut pxw = Rational::ZERO;
+    let mut weight = Rational::ZERO;
+    let mut token_0_am = Rational::ZERO;
+    let mut token_1_am = Rational::ZERO;
+
+    // calculate tvl of pool using the start token as the quote
+    for info in edge_weight {
+        let Some(pool_state) = state.get(&info.pool_addr) else {
+            tracing::debug!(addr=?info.pool_addr,"failed to fetch pool state while generating price");
+            continue;
+        };
+
+        let Ok(pool_price) = pool_state.price(info.get_base_token()) else {
+            continue;
+        };
+
+        let (t0, t1) = pool_state.tvl(info.get_base_token());
+
+        let t0xt1 = &t0 * &t1;
+        pxw += pool_price * &t0xt1;
+        weight += t0xt1;
+
+        token_0_am += t0;
+        token_1_am += t1;
+    }
+
+    if weight == Rational::ZERO {
+        return None
+    }
+
+    Some((pxw / weight, token_0_am, token_1_am))
+}
+
 fn add_edge(
     graph: &mut DiGraph<(), Vec<SubGraphEdge>, u16>,
     edge_idx: EdgeIndex<u16>,
@@ -1104,6 +1375,20 @@ pub mod test {
         fn tvl(&self, _base: Address) -> (Rational, Rational) {
             self.tvl.clone()
         }
+
+        // swaps as a constant product pool holding its tvl, a pool without any
+        // stands in for one that can't quote swaps
+        fn amount_out(
+            &self,
+            _token_in: Address,
+            amount_in: &Rational,
+        ) -> Result<Rational, crate::errors::ArithmeticError> {
+            let (reserve_in, reserve_out) = &self.tvl;
+            if *reserve_in == Rational::ZERO {
+                return Err(crate::errors::ArithmeticError::UnsupportedQuote)
+            }
+            Ok(reserve_out * amount_in / (reserve_in + amount_in))
+        }
     }
 
     fn build_edge(lookup_pair: Address, t0: Address, t1: Address) -> SubGraphEdge {
@@ -1175,4 +1460,113 @@ pub mod test {
 
         assert_eq!(price, Rational::from_unsigneds(1usize, 390usize))
     }
+
+    #[test]
+    fn test_path_weighted_pricing() {
+        addresses!(t0, t1, t2, t3);
+        // t0 -> t1 -> t3 and t0 -> t2 -> t3
+        let edges = vec![
+            build_edge(t0, t0, t1),
+            build_edge(t1, t1, t3),
+            build_edge(t2, t0, t2),
+            build_edge(t3, t2, t3),
+        ];
+        let pair = Pair(t0, t3);
+        let graph = PairSubGraph::init(pair, pair, pair, None, edges, 0);
+
+        let mut state_map = FastHashMap::default();
+
+        // a path priced at 6 that is 6000 t3 deep
+        let e0 =
+            MockPoolState::new(Rational::from(2), Rational::from(1_000), Rational::from(2_000));
+        state_map.insert(t0, &e0);
+        let e1 =
+            MockPoolState::new(Rational::from(3), Rational::from(2_000), Rational::from(6_000));
+        state_map.insert(t1, &e1);
+
+        // a path priced at 5 that is 500 t3 deep
+        let e2 = MockPoolState::new(Rational::from(5), Rational::from(100), Rational::from(500));
+        state_map.insert(t2, &e2);
+        let e3 = MockPoolState::new(Rational::from(1), Rational::from(500), Rational::from(500));
+        state_map.insert(t3, &e3);
+
+        let (price, spread) = graph
+            .fetch_path_weighted_price(&state_map, &Rational::from(10_000))
+            .unwrap();
+        assert_eq!(price, Rational::from_unsigneds(77usize, 13usize));
+        assert_eq!(spread, Rational::from_unsigneds(24usize, 169usize));
+
+        // both paths fill a small notional, so they are weighted the same
+        let (price, spread) = graph
+            .fetch_path_weighted_price(&state_map, &Rational::from(100))
+            .unwrap();
+        assert_eq!(price, Rational::from_unsigneds(11usize, 2usize));
+        assert_eq!(spread, Rational::ONE_HALF);
+    }
+
+    #[test]
+    fn test_quote_for_size() {
+        addresses!(t0, t1, t2, t3);
+        // t0 -> t1 -> t3 and t0 -> t2 -> t3
+        let edges = vec![
+            build_edge(t0, t0, t1),
+            build_edge(t1, t1, t3),
+            build_edge(t2, t0, t2),
+            build_edge(t3, t2, t3),
+        ];
+        let pair = Pair(t0, t3);
+        let graph = PairSubGraph::init(pair, pair, pair, None, edges, 0);
+
+        let mut state_map = FastHashMap::default();
+        let e0 = MockPoolState::new(Rational::ONE, Rational::from(100), Rational::from(100));
+        state_map.insert(t0, &e0);
+        let e1 = MockPoolState::new(Rational::ONE, Rational::from(100), Rational::from(100));
+        state_map.insert(t1, &e1);
+        let e2 = MockPoolState::new(Rational::ONE, Rational::from(1_000), Rational::from(1_000));
+        state_map.insert(t2, &e2);
+        let e3 = MockPoolState::new(Rational::ONE, Rational::from(1_000), Rational::from(1_000));
+        state_map.insert(t3, &e3);
+
+        // the deeper path through t2 slips less: 1000 * 100 / 1100 = 1000 / 11
+        // on the first hop, then 1000 * (1000 / 11) / (1000 + 1000 / 11) = 250 / 3
+        let quote = graph
+            .quote_for_size(&Rational::from(100), &state_map)
+            .unwrap();
+        assert_eq!(quote.amount_out, Rational::from_unsigneds(250usize, 3usize));
+        assert_eq!(quote.pools, vec![t2, t3]);
+        assert!(quote.skipped_pools.is_empty());
+
+        // a pool that can't quote is routed around and reported
+        let unsupported = MockPoolState::new(Rational::ONE, Rational::ZERO, Rational::ZERO);
+        state_map.insert(t2, &unsupported);
+        let quote = graph
+            .quote_for_size(&Rational::from(100), &state_map)
+            .unwrap();
+        assert_eq!(quote.amount_out, Rational::from_unsigneds(100usize, 3usize));
+        assert_eq!(quote.pools, vec![t0, t1]);
+        assert_eq!(quote.skipped_pools, vec![t2]);
+    }
+
+    #[test]
+    fn test_checkpoint_roundtrip() {
+        addresses!(t0, t1, t2, t3);
+        let graph = make_simple_graph();
+        graph.future_use(1_000);
+
+        let config = bincode::config::standard();
+        let bytes = bincode::serde::encode_to_vec(&graph, config).unwrap();
+        let (restored, _): (PairSubGraph, _) =
+            bincode::serde::decode_from_slice(&bytes, config).unwrap();
+
+        let state = MockPoolState::new(Rational::from(2), Rational::from(100), Rational::from(100));
+        let state_map: FastHashMap<_, _> = [t0, t1, t2, t3]
+            .into_iter()
+            .map(|pool| (pool, &state))
+            .collect();
+
+        assert_eq!(restored.fetch_price(&state_map), graph.fetch_price(&state_map));
+        assert_eq!(restored.graph.raw_edges().len(), graph.graph.raw_edges().len());
+        assert!(!restored.is_expired_subgraph(1_500));
+        assert!(!restored.is_disjoint());
+    }
 }
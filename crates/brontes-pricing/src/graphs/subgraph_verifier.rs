@@ -3,6 +3,7 @@ use brontes_types::{pair::Pair, FastHashMap, FastHashSet, ToFloatNearest};
 use itertools::Itertools;
 use malachite::{num::basic::traits::Zero, Rational};
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
+use serde::{Deserialize, Serialize};
 use tracing::{error_span, instrument};
 
 use super::{
@@ -34,8 +35,10 @@ use crate::{types::PairWithFirstPoolHop, AllPairGraph, PoolPairInfoDirection, Su
 ///   the current state of the DEX, checking liquidity parameters and pool
 ///   states. This method is vital in maintaining the integrity of the pricing
 ///   system.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubgraphVerifier {
+    /// not checkpointed, pending pairs are requeried when pricing resumes
+    #[serde(skip)]
     pending_subgraphs:           FastHashMap<PairWithFirstPoolHop, Subgraph>,
     /// pruned edges of a subgraph that didn't meet liquidity params.
     /// these are stored as in the case we have a subgraph that all critical
@@ -69,6 +72,13 @@ impl SubgraphVerifier {
         }
     }
 
+    /// Replaces the verification state with the one of a checkpoint
+    pub fn restore(&mut self, mut checkpoint: SubgraphVerifier) {
+        self.subgraph_verification_state =
+            std::mem::take(&mut checkpoint.subgraph_verification_state);
+        self.pending_subgraphs.clear();
+    }
+
     pub fn get_subgraph_extends(&self, pair: PairWithFirstPoolHop) -> Option<Pair> {
         self.pending_subgraphs
             .get(&pair)
@@ -420,7 +430,7 @@ pub enum VerificationResults {
     Abort(PairWithFirstPoolHop, u64),
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct SubgraphVerificationState {
     /// contains all fully removed edges. this is so that
     /// if we don't find a edge with the wanted amount of liquidity,
@@ -485,5 +495,5 @@ impl SubgraphVerificationState {
     }
 }
 
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
 pub struct EdgesWithLiq(FastHashMap<Address, FastHashSet<BadEdge>>);
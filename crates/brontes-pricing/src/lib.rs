@@ -22,7 +22,9 @@
 
 use brontes_metrics::pricing::DexPricingMetrics;
 use brontes_types::{
-    db::dex::PriceAt, execute_on, normalized_actions::pool::NormalizedPoolConfigUpdate,
+    db::{dex::PriceAt, pricing_checkpoint::PricingGraphCheckpoint},
+    execute_on,
+    normalized_actions::pool::NormalizedPoolConfigUpdate,
     BrontesTaskExecutor, UnboundedYapperReceiver,
 };
 use rayon::iter::{IntoParallelIterator, ParallelIterator};
@@ -30,6 +32,7 @@ use rayon::iter::{IntoParallelIterator, ParallelIterator};
 use crate::graphs::StateWithDependencies;
 pub mod function_call_bench;
 mod graphs;
+pub mod pool_states;
 pub mod protocols;
 mod subgraph_query;
 pub mod types;
@@ -49,6 +52,7 @@ pub use brontes_types::price_graph_types::{
 use brontes_types::{
     db::dex::{DexPrices, DexQuotes},
     pair::Pair,
+    price_graph_types::{SharedPoolStates, SizedQuote},
     traits::TracingProvider,
     FastHashMap, FastHashSet,
 };
@@ -62,6 +66,7 @@ use malachite::{
     num::basic::traits::{One, Zero},
     Rational,
 };
+use pool_states::PoolStateHistory;
 use protocols::lazy::{LazyExchangeLoader, LazyResult, LoadResult};
 pub use protocols::{Protocol, *};
 use subgraph_query::*;
@@ -99,6 +104,11 @@ pub struct BrontesBatchPricer<T: TracingProvider> {
     /// lazy loads dex pairs so we only fetch init state that is needed
     lazy_loader:     LazyExchangeLoader<T>,
     dex_quotes:      FastHashMap<u64, DexQuotes>,
+    /// the state of the pools updated in the block being resolved, before
+    /// each transaction that updated them
+    pool_states:     PoolStateHistory,
+    /// the pool states of the last block returned
+    resolved_states: Option<(u64, SharedPoolStates)>,
     /// pairs that failed to be verified. we use this to avoid the fallback for
     /// transfers
     failed_pairs:    FastHashMap<u64, Vec<PairWithFirstPoolHop>>,
@@ -135,6 +145,8 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             update_rx,
             graph_manager,
             dex_quotes: FastHashMap::default(),
+            pool_states: PoolStateHistory::default(),
+            resolved_states: None,
             lazy_loader: LazyExchangeLoader::new(provider, executor),
             current_block,
             completed_block: current_block,
@@ -159,6 +171,51 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         self.graph_manager.snapshot_state()
     }
 
+    /// Quotes a swap of `amount_in` of the first token of `pair` into the
+    /// second, on the pool state as of the last update the pricer applied.
+    /// Inspectors quote on the state before a transaction through the
+    /// [`SharedPoolStates`] of the block instead.
+    pub fn quote_for_size(
+        &self,
+        pair: Pair,
+        goes_through: Pair,
+        amount_in: &Rational,
+    ) -> Option<SizedQuote> {
+        if pair.0 == pair.1 {
+            return Some(SizedQuote {
+                amount_out:    amount_in.clone(),
+                pools:         vec![],
+                skipped_pools: vec![],
+            })
+        }
+        self.graph_manager
+            .quote_for_size(pair, goes_through, amount_in)
+    }
+
+    /// The state of the pools the pricer updated in `block` before each of its
+    /// transactions, if it is the last block returned
+    pub fn take_pool_states(&mut self, block: u64) -> Option<SharedPoolStates> {
+        self.resolved_states
+            .take_if(|(resolved, _)| *resolved == block)
+            .map(|(_, states)| states)
+    }
+
+    /// The pools of the subgraph `pair` is priced over for `goes_through`, as
+    /// of the last block returned
+    pub fn subgraph_edges(&self, pair: Pair, goes_through: Pair) -> Vec<SubGraphEdge> {
+        self.graph_manager.subgraph_edges(pair, goes_through)
+    }
+
+    /// Checkpoints the graph state as of the last block returned, see
+    /// [`GraphManager::checkpoint`]
+    pub fn checkpoint_graph_state(&self) -> eyre::Result<PricingGraphCheckpoint> {
+        Ok(PricingGraphCheckpoint::new(
+            self.quote_asset,
+            self.graph_manager.path_weighted_notional().cloned(),
+            self.graph_manager.checkpoint()?,
+        ))
+    }
+
     /// testing / benching utils
     pub fn set_state(
         &mut self,
@@ -294,15 +351,29 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         });
     }
 
+    /// The price of the pair along with the liquidity and connections of its
+    /// first hop. The spread of the path prices is only returned when pricing
+    /// is path weighted.
     fn get_dex_price(
         &mut self,
         pool_pair: Pair,
         goes_through: Pair,
-    ) -> Option<(Rational, Rational, usize)> {
+    ) -> Option<(Rational, Rational, usize, Option<Rational>)> {
+        let path_weighted = self.graph_manager.is_path_weighted();
         if pool_pair.0 == pool_pair.1 {
-            return Some((Rational::ONE, Rational::from(1_000_000), usize::MAX))
+            return Some((
+                Rational::ONE,
+                Rational::from(1_000_000),
+                usize::MAX,
+                path_weighted.then_some(Rational::ZERO),
+            ))
         }
-        self.graph_manager.get_price(pool_pair, goes_through)
+
+        self.graph_manager.get_price(pool_pair, goes_through).map(
+            |(price, liq, connections, spread)| {
+                (price, liq, connections, path_weighted.then_some(spread))
+            },
+        )
     }
 
     /// For a given block number and tx idx, finds the path to the following
@@ -372,7 +443,8 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
 
         let flipped_pool = pool_pair.flip();
 
-        if let Some((price0, pool_liq, connections)) = self.get_dex_price(pair0, pool_pair) {
+        if let Some((price0, pool_liq, connections, spread)) = self.get_dex_price(pair0, pool_pair)
+        {
             let mut bad = false;
             self.failed_pairs.retain(|r_block, s| {
                 if block != *r_block {
@@ -401,12 +473,15 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                     goes_through: pool_pair,
                     first_hop_connections: connections,
                     is_transfer,
+                    spread,
                 };
                 self.store_dex_price(block, tx_idx, pair0, price0);
             }
         };
 
-        if let Some((price1, pool_liq, connections)) = self.get_dex_price(pair1, flipped_pool) {
+        if let Some((price1, pool_liq, connections, spread)) =
+            self.get_dex_price(pair1, flipped_pool)
+        {
             let mut bad = false;
             self.failed_pairs.retain(|r_block, s| {
                 if block != *r_block {
@@ -434,6 +509,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                     goes_through: flipped_pool,
                     first_hop_connections: connections,
                     is_transfer,
+                    spread,
                 };
                 self.store_dex_price(block, tx_idx, pair1, price1);
             }
@@ -444,6 +520,11 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         let tx_idx = msg.tx_idx;
         let block = msg.block;
         let is_transfer = msg.is_transfer();
+        if let Some(state) = self.graph_manager.pool_state(addr) {
+            self.pool_states
+                .record_before_update(addr, tx_idx as usize, state);
+        }
+
         let Some(pool_pair) = msg.get_pair(self.quote_asset) else {
             error!(?addr, "failed to get pair for pool");
             self.graph_manager.update_state(addr, msg);
@@ -463,8 +544,10 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         let price0_post = self.get_dex_price(pair0, pool_pair);
         let price1_post = self.get_dex_price(pair1, flipped_pool);
 
-        if let (Some((price0_pre, _, con)), Some((price0_post, pool_liq, _))) =
-            (price0_pre, price0_post)
+        if let (
+            Some((price0_pre, _, con, pre_spread)),
+            Some((price0_post, pool_liq, _, post_spread)),
+        ) = (price0_pre, price0_post)
         {
             let mut bad = false;
             self.failed_pairs.retain(|r_block, s| {
@@ -497,6 +580,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                         pool_liquidity: pool_liq,
                         first_hop_connections: con,
                         is_transfer,
+                        spread: std::cmp::max(pre_spread, post_spread),
                     },
                 );
             } else {
@@ -512,8 +596,10 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
             debug!(?tx_idx, ?block, ?pair0, ?pool_pair, "no pricing for pair");
         }
 
-        if let (Some((price1_pre, _, con)), Some((price1_post, pool_liq, _))) =
-            (price1_pre, price1_post)
+        if let (
+            Some((price1_pre, _, con, pre_spread)),
+            Some((price1_post, pool_liq, _, post_spread)),
+        ) = (price1_pre, price1_post)
         {
             let mut bad = false;
             self.failed_pairs.retain(|r_block, s| {
@@ -545,6 +631,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
                         pool_liquidity: pool_liq,
                         first_hop_connections: con,
                         is_transfer,
+                        spread: std::cmp::max(pre_spread, post_spread),
                     },
                 );
             } else {
@@ -1037,6 +1124,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         }
 
         let block = self.completed_block;
+        self.resolve_pool_states(block);
 
         let mut res = self
             .dex_quotes
@@ -1054,6 +1142,30 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         self.should_return().then_some((block, res))
     }
 
+    /// Completes the pool states of the block with the state the block left
+    /// each pool in, along with a copy of the subgraphs the block was priced
+    /// over so swaps can be quoted on them. Has to run before the quotes of
+    /// the block are taken.
+    fn resolve_pool_states(&mut self, block: u64) {
+        let priced = self
+            .dex_quotes
+            .get(&block)
+            .into_iter()
+            .flat_map(|quotes| quotes.0.iter().flatten())
+            .flat_map(|prices| {
+                prices
+                    .iter()
+                    .map(|(pair, price)| (*pair, price.goes_through))
+            })
+            .unique()
+            .collect_vec();
+        let graphs = self.graph_manager.subgraph_snapshot(priced);
+
+        let states = std::mem::take(&mut self.pool_states)
+            .finalize(graphs, |pool| self.graph_manager.pool_state(pool).cloned());
+        self.resolved_states = Some((block, Arc::new(states)));
+    }
+
     // checks skip
     fn should_return(&mut self) -> bool {
         // remove ones lower than completed
@@ -1168,6 +1280,7 @@ impl<T: TracingProvider> BrontesBatchPricer<T> {
         }
 
         let block = self.completed_block;
+        self.resolve_pool_states(block);
 
         let mut res = self
             .dex_quotes
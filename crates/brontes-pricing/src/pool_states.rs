@@ -0,0 +1,214 @@
+use alloy_primitives::Address;
+use brontes_types::{
+    pair::Pair,
+    price_graph_types::{BlockPoolStates, SimulatedPool, SizedQuote},
+    FastHashMap,
+};
+use malachite::Rational;
+
+use crate::{types::PoolState, SubGraphRegistry};
+
+/// The state of each pool on the subgraphs the pricer priced a block over,
+/// before every transaction that updated it and at the end of the block
+#[derive(Debug, Default)]
+pub struct PoolStateHistory {
+    pools:  FastHashMap<Address, PoolHistory>,
+    /// the subgraphs the block was priced over, set once the block is resolved
+    graphs: SubGraphRegistry,
+}
+
+#[derive(Debug, Default)]
+struct PoolHistory {
+    /// the state of the pool before each transaction that updated it, in
+    /// transaction order
+    before_tx:    Vec<(usize, PoolState)>,
+    end_of_block: Option<PoolState>,
+}
+
+impl PoolStateHistory {
+    /// Records the state of the pool before an update of the transaction at
+    /// `tx_idx`. Only the first update of each transaction is recorded, as the
+    /// later ones start from a state the transaction already changed.
+    pub fn record_before_update(&mut self, pool: Address, tx_idx: usize, state: &PoolState) {
+        let history = self.pools.entry(pool).or_default();
+        if history
+            .before_tx
+            .last()
+            .is_some_and(|(last_idx, _)| *last_idx == tx_idx)
+        {
+            return
+        }
+
+        history.before_tx.push((tx_idx, state.clone()));
+    }
+
+    /// Records the state each pool was left in at the end of the block, along
+    /// with the subgraphs the block was priced over. The pools of the
+    /// subgraphs that weren't updated keep their end of block state over the
+    /// whole block.
+    pub fn finalize(
+        mut self,
+        graphs: SubGraphRegistry,
+        end_state: impl Fn(Address) -> Option<PoolState>,
+    ) -> Self {
+        for pool in graphs.pools() {
+            self.pools.entry(pool).or_default();
+        }
+        for (pool, history) in &mut self.pools {
+            history.end_of_block = end_state(*pool);
+        }
+        self.graphs = graphs;
+
+        self
+    }
+
+    /// the state of all pools before the transaction at `tx_idx`
+    fn state_at(&self, tx_idx: usize) -> FastHashMap<Address, &PoolState> {
+        self.pools
+            .iter()
+            .filter_map(|(pool, history)| Some((*pool, history.before(tx_idx)?)))
+            .collect()
+    }
+}
+
+impl PoolHistory {
+    fn before(&self, tx_idx: usize) -> Option<&PoolState> {
+        // no transaction between `tx_idx` and the next update changed the pool
+        self.before_tx
+            .iter()
+            .find(|(update_idx, _)| *update_idx >= tx_idx)
+            .map(|(_, state)| state)
+            .or(self.end_of_block.as_ref())
+    }
+}
+
+impl BlockPoolStates for PoolStateHistory {
+    fn pool_before(&self, pool: Address, tx_idx: usize) -> Option<Box<dyn SimulatedPool>> {
+        let state = self.pools.get(&pool)?.before(tx_idx)?;
+
+        Some(Box::new(state.clone()))
+    }
+
+    fn quote_for_size(
+        &self,
+        pair: Pair,
+        goes_through: Pair,
+        amount_in: &Rational,
+        tx_idx: usize,
+    ) -> Option<SizedQuote> {
+        if pair.0 == pair.1 {
+            return Some(SizedQuote {
+                amount_out:    amount_in.clone(),
+                pools:         vec![],
+                skipped_pools: vec![],
+            })
+        }
+
+        self.graphs
+            .quote_for_size(pair, goes_through, amount_in, &self.state_at(tx_idx))
+    }
+}
+
+impl SimulatedPool for PoolState {
+    fn swap(&mut self, token_in: Address, amount_in: &Rational) -> Option<Rational> {
+        PoolState::swap(self, token_in, amount_in).ok()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+    use brontes_types::price_graph_types::{
+        PoolPairInfoDirection, PoolPairInformation, SubGraphEdge,
+    };
+
+    use super::*;
+    use crate::{types::PoolVariants, uniswap_v2::UniswapV2Pool, PairSubGraph, Protocol};
+
+    fn pool_state(reserve_0: u128) -> PoolState {
+        PoolState::new(
+            PoolVariants::UniswapV2(Box::new(UniswapV2Pool::new(
+                hex!("B4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc").into(),
+                hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").into(),
+                6,
+                hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").into(),
+                18,
+                reserve_0,
+                20_000_000_000_000_000_000_000,
+                3000,
+            ))),
+            0,
+        )
+    }
+
+    #[test]
+    fn test_pool_before_tx() {
+        let usdc = hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").into();
+        let (at_2, at_5, at_end) = (pool_state(1_000), pool_state(2_000), pool_state(3_000));
+        let pool = at_2.address();
+
+        let mut history = PoolStateHistory::default();
+        history.record_before_update(pool, 2, &at_2);
+        // a second update in the same transaction is already past its start
+        history.record_before_update(pool, 2, &at_end);
+        history.record_before_update(pool, 5, &at_5);
+        let history = history.finalize(SubGraphRegistry::default(), |_| Some(at_end.clone()));
+
+        let quote = |tx_idx| {
+            history
+                .pool_before(pool, tx_idx)
+                .unwrap()
+                .swap(usdc, &Rational::from(1))
+                .unwrap()
+        };
+        let expected = |state: &PoolState| state.get_amount_out(usdc, &Rational::from(1)).unwrap();
+
+        assert_eq!(quote(0), expected(&at_2));
+        assert_eq!(quote(2), expected(&at_2));
+        assert_eq!(quote(3), expected(&at_5));
+        assert_eq!(quote(6), expected(&at_end));
+        assert!(history.pool_before(Address::ZERO, 0).is_none());
+    }
+
+    #[test]
+    fn test_quote_for_size_before_tx() {
+        let usdc = hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").into();
+        let weth = hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").into();
+        let (at_2, at_end) = (pool_state(1_000_000_000), pool_state(2_000_000_000));
+        let pool = at_2.address();
+
+        let pair = Pair(usdc, weth);
+        let edge = SubGraphEdge::new(PoolPairInfoDirection::new(
+            Box::leak(Box::new(PoolPairInformation::new(pool, Protocol::UniswapV2, usdc, weth))),
+            true,
+        ));
+        let mut graphs = SubGraphRegistry::default();
+        graphs.add_verified_subgraph(
+            PairSubGraph::init(pair, pair, pair, None, vec![edge], 0),
+            FastHashMap::default(),
+            0,
+        );
+        graphs.finalize_block(0);
+
+        let mut history = PoolStateHistory::default();
+        history.record_before_update(pool, 2, &at_2);
+        let history = history.finalize(graphs, |_| Some(at_end.clone()));
+
+        let amount_in = Rational::from(100);
+        let expected = |state: &PoolState| state.get_amount_out(usdc, &amount_in).unwrap();
+        let quote = |tx_idx| {
+            history
+                .quote_for_size(pair, pair, &amount_in, tx_idx)
+                .unwrap()
+        };
+
+        assert_eq!(quote(1).amount_out, expected(&at_2));
+        assert_eq!(quote(3).amount_out, expected(&at_end));
+        assert_eq!(quote(3).pools, vec![pool]);
+        assert!(quote(3).skipped_pools.is_empty());
+        // pairs are only quoted in the direction they are priced in
+        assert!(history
+            .quote_for_size(pair.flip(), pair.flip(), &amount_in, 0)
+            .is_none());
+    }
+}
@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use alloy_primitives::{hex, Address, U256};
+use alloy_sol_macro::sol;
+use brontes_types::traits::TracingProvider;
+
+use super::{BalancerPool, BalancerPoolKind};
+use crate::{
+    errors::AmmError,
+    protocols::{
+        make_call_request,
+        pool_balances::{get_token_decimals, PoolBalances},
+        stable_math::RATE_ONE,
+    },
+};
+
+pub const BALANCER_V2_VAULT: Address =
+    Address::new(hex!("BA12222222228d8Ba445958a75a0704d566BF2C8"));
+
+sol!(
+    interface IBalancerV2Vault {
+        function getPoolTokens(bytes32 poolId) external view returns (
+            address[] tokens,
+            uint256[] balances,
+            uint256 lastChangeBlock
+        );
+    }
+);
+
+sol!(
+    interface IBalancerV2Pool {
+        function getPoolId() external view returns (bytes32);
+        function getNormalizedWeights() external view returns (uint256[]);
+        function getAmplificationParameter() external view returns (
+            uint256 value,
+            bool isUpdating,
+            uint256 precision
+        );
+        function getScalingFactors() external view returns (uint256[]);
+        function getRateProviders() external view returns (address[]);
+    }
+);
+
+sol!(
+    interface IRateProvider {
+        function getRate() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface IBalancerV1Pool {
+        function getCurrentTokens() external view returns (address[]);
+        function getBalance(address token) external view returns (uint256);
+        function getNormalizedWeight(address token) external view returns (uint256);
+    }
+);
+
+/// Loads a vault pool. Weighted pools are told apart from stable pools by
+/// which of their getters doesn't revert.
+pub async fn get_balancer_v2_pool_data<M: TracingProvider>(
+    pool: &mut BalancerPool,
+    block: u64,
+    middleware: Arc<M>,
+) -> Result<(), AmmError> {
+    let address = pool.address;
+    let pool_id =
+        make_call_request(IBalancerV2Pool::getPoolIdCall {}, &middleware, address, Some(block))
+            .await?
+            ._0;
+
+    let call = IBalancerV2Vault::getPoolTokensCall { poolId: pool_id };
+    let res = make_call_request(call, &middleware, BALANCER_V2_VAULT, Some(block)).await?;
+
+    // composable stable pools hold their own pool token
+    let keep = res
+        .tokens
+        .iter()
+        .map(|token| *token != address)
+        .collect::<Vec<_>>();
+    let (tokens, balances): (Vec<_>, Vec<_>) = res
+        .tokens
+        .into_iter()
+        .zip(res.balances)
+        .filter(|(token, _)| *token != address)
+        .unzip();
+    let decimals = get_token_decimals(&tokens, block, &middleware).await?;
+
+    let call = IBalancerV2Pool::getNormalizedWeightsCall {};
+    pool.kind = if let Ok(weights) =
+        make_call_request(call, &middleware, address, Some(block)).await
+    {
+        BalancerPoolKind::Weighted { weights: weights._0 }
+    } else {
+        let call = IBalancerV2Pool::getAmplificationParameterCall {};
+        let amp = make_call_request(call, &middleware, address, Some(block))
+            .await
+            .map_err(|_| AmmError::UnsupportedProtocol)?;
+
+        let rates = get_stable_pool_rates(address, &keep, &decimals, block, &middleware).await?;
+
+        BalancerPoolKind::Stable { amp: amp.value, precision: amp.precision, rates }
+    };
+
+    pool.balances = PoolBalances::new(tokens, decimals, balances);
+
+    Ok(())
+}
+
+/// The rate of each token of a stable pool besides its own pool token,
+/// scaled by 1e18. `keep` marks the registered tokens that aren't the pool
+/// token and `decimals` are the decimals of those.
+///
+/// The scaling factors fold the decimals and the rate of the token together,
+/// the decimals are taken back out of them. Pools that predate them are asked
+/// for the rate providers of their tokens instead, whose current rate can be
+/// a little ahead of the rate the pool has cached. Pools without either
+/// don't scale their balances.
+async fn get_stable_pool_rates<M: TracingProvider>(
+    address: Address,
+    keep: &[bool],
+    decimals: &[u8],
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<Vec<U256>, AmmError> {
+    let call = IBalancerV2Pool::getScalingFactorsCall {};
+    if let Ok(factors) = make_call_request(call, middleware, address, Some(block)).await {
+        return kept_rates(address, factors._0, keep, decimals)
+    }
+
+    let call = IBalancerV2Pool::getRateProvidersCall {};
+    let Ok(providers) = make_call_request(call, middleware, address, Some(block)).await else {
+        return Ok(vec![RATE_ONE; decimals.len()])
+    };
+
+    let mut rates = Vec::with_capacity(providers._0.len());
+    for provider in providers._0 {
+        if provider.is_zero() {
+            rates.push(RATE_ONE);
+            continue
+        }
+
+        let call = IRateProvider::getRateCall {};
+        rates.push(
+            make_call_request(call, middleware, provider, Some(block))
+                .await?
+                ._0,
+        );
+    }
+
+    // rate providers don't fold in the decimals
+    kept_rates(address, rates, keep, &vec![18; decimals.len()])
+}
+
+/// Drops the rate of the pool token and takes the decimals out of the others
+fn kept_rates(
+    address: Address,
+    rates: Vec<U256>,
+    keep: &[bool],
+    decimals: &[u8],
+) -> Result<Vec<U256>, AmmError> {
+    if rates.len() != keep.len() {
+        return Err(AmmError::BatchRequestError(address))
+    }
+
+    let mut decimals = decimals.iter();
+    rates
+        .into_iter()
+        .zip(keep)
+        .filter(|(_, keep)| **keep)
+        .map(|(rate, _)| {
+            let decimals = decimals
+                .next()
+                .ok_or(AmmError::BatchRequestError(address))?;
+            Ok(rate / U256::from(10).pow(U256::from(18u8.saturating_sub(*decimals))))
+        })
+        .collect()
+}
+
+/// Loads a pool of the first version, which are all weighted and hold their
+/// own balances.
+pub async fn get_balancer_v1_pool_data<M: TracingProvider>(
+    pool: &mut BalancerPool,
+    block: u64,
+    middleware: Arc<M>,
+) -> Result<(), AmmError> {
+    let address = pool.address;
+    let tokens = make_call_request(
+        IBalancerV1Pool::getCurrentTokensCall {},
+        &middleware,
+        address,
+        Some(block),
+    )
+    .await?
+    ._0;
+
+    let mut balances = Vec::with_capacity(tokens.len());
+    let mut weights = Vec::with_capacity(tokens.len());
+    for token in &tokens {
+        let call = IBalancerV1Pool::getBalanceCall { token: *token };
+        balances.push(
+            make_call_request(call, &middleware, address, Some(block))
+                .await?
+                ._0,
+        );
+
+        let call = IBalancerV1Pool::getNormalizedWeightCall { token: *token };
+        weights.push(
+            make_call_request(call, &middleware, address, Some(block))
+                .await?
+                ._0,
+        );
+    }
+
+    let decimals = get_token_decimals(&tokens, block, &middleware).await?;
+    pool.balances = PoolBalances::new(tokens, decimals, balances);
+    pool.kind = BalancerPoolKind::Weighted { weights };
+
+    Ok(())
+}
@@ -0,0 +1,166 @@
+pub mod batch_request;
+
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, pair::Pair, traits::TracingProvider, ToScaledRational,
+};
+use malachite::{num::basic::traits::Zero, Rational};
+use serde::{Deserialize, Serialize};
+
+use self::batch_request::{get_balancer_v1_pool_data, get_balancer_v2_pool_data};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    protocols::{
+        pool_balances::{other_token, PoolBalances},
+        stable_math,
+    },
+    Protocol, UpdatableProtocol,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BalancerPoolKind {
+    /// the weight of each token, scaled by 1e18
+    Weighted { weights: Vec<U256> },
+    /// the amplification coefficient, scaled by `precision`, and the rate of
+    /// each token, scaled by 1e18. Tokens without a rate provider have a rate
+    /// of one.
+    Stable { amp: U256, precision: U256, rates: Vec<U256> },
+}
+
+impl Default for BalancerPoolKind {
+    fn default() -> Self {
+        Self::Weighted { weights: vec![] }
+    }
+}
+
+/// A balancer weighted or stable pool. The pool can hold more than two tokens
+/// but is priced along the pair it has in the pricing graph.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalancerPool {
+    pub address:  Address,
+    #[serde(with = "brontes_types::serde_utils::protocol_variant")]
+    pub protocol: Protocol,
+    pub pair:     Pair,
+    pub balances: PoolBalances,
+    pub kind:     BalancerPoolKind,
+}
+
+#[async_trait]
+impl UpdatableProtocol for BalancerPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Applies the balance changes of a swap, mint or burn on this pool
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.sync_from_action(self.address, action)
+    }
+
+    /// The pool balances are synced from the classified actions, the vault
+    /// only logs deltas
+    fn sync_from_log(&mut self, _: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self.balances.pair_indexes(&self.pair, base_token)?;
+
+        match &self.kind {
+            // (B_quote / W_quote) / (B_base / W_base)
+            BalancerPoolKind::Weighted { weights } => {
+                let weight = |idx: usize| weights[idx].to_scaled_rational(18);
+                let (base_weight, quote_weight) = (weight(base), weight(quote));
+                let base_balance = self.balances.scaled(base);
+                if base_balance == Rational::ZERO || quote_weight == Rational::ZERO {
+                    return Err(ArithmeticError::ZeroBalance)
+                }
+
+                Ok(self.balances.scaled(quote) / quote_weight / (base_balance / base_weight))
+            }
+            BalancerPoolKind::Stable { amp, precision, rates } => {
+                stable_math::spot_price(&self.balances, rates, *amp, *precision, base, quote)
+            }
+        }
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+}
+
+impl BalancerPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        pair: Pair,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let mut pool = BalancerPool { address, protocol, pair, ..Default::default() };
+        match protocol {
+            Protocol::BalancerV1 => get_balancer_v1_pool_data(&mut pool, block, middleware).await?,
+            Protocol::BalancerV2 => get_balancer_v2_pool_data(&mut pool, block, middleware).await?,
+            _ => return Err(AmmError::UnsupportedProtocol),
+        }
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        let kind_is_populated = match &self.kind {
+            BalancerPoolKind::Weighted { weights } => {
+                weights.len() == self.balances.len() && weights.iter().all(|w| !w.is_zero())
+            }
+            BalancerPoolKind::Stable { amp, precision, rates } => {
+                !amp.is_zero() && !precision.is_zero() && rates.len() == self.balances.len()
+            }
+        };
+
+        kind_is_populated
+            && self.balances.is_populated()
+            && self.balances.pair_indexes(&self.pair, self.pair.0).is_ok()
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, other_token(&self.pair, base))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weighted_price() {
+        let (bal, weth) = (Address::with_last_byte(1), Address::with_last_byte(2));
+        let one = U256::from(10).pow(U256::from(18));
+
+        // an 80/20 pool holding 800k bal and 100 weth values bal at 0.0005 weth
+        let pool = BalancerPool {
+            address:  Address::with_last_byte(3),
+            protocol: Protocol::BalancerV2,
+            pair:     Pair(bal, weth),
+            balances: PoolBalances::new(
+                vec![bal, weth],
+                vec![18, 18],
+                vec![U256::from(800_000) * one, U256::from(100) * one],
+            ),
+            kind:     BalancerPoolKind::Weighted {
+                weights: vec![
+                    one * U256::from(8) / U256::from(10),
+                    one * U256::from(2) / U256::from(10),
+                ],
+            },
+        };
+
+        assert_eq!(pool.calculate_price(bal).unwrap(), Rational::from_unsigneds(1u64, 2000u64));
+        assert_eq!(pool.calculate_price(weth).unwrap(), Rational::from(2000));
+    }
+}
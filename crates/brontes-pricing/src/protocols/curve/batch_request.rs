@@ -0,0 +1,250 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_macro::sol;
+use brontes_types::traits::TracingProvider;
+
+use super::{CurveCryptoPool, CurveStablePool};
+use crate::{
+    errors::AmmError,
+    protocols::{
+        make_call_request,
+        pool_balances::{get_token_decimals, PoolBalances},
+        stable_math::RATE_ONE,
+    },
+    Protocol,
+};
+
+/// the most coins a curve pool holds
+const MAX_COINS: usize = 8;
+
+sol!(
+    interface ICurvePool {
+        function coins(uint256 i) external view returns (address);
+        function balances(uint256 i) external view returns (uint256);
+        function A() external view returns (uint256);
+    }
+);
+
+// the first generation of pools index their coins with an int128
+sol!(
+    interface ICurvePoolInt128 {
+        function coins(int128 i) external view returns (address);
+        function balances(int128 i) external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveNgPool {
+        function stored_rates() external view returns (uint256[]);
+    }
+);
+
+sol!(
+    interface ICurveMetaPool {
+        function base_pool() external view returns (address);
+    }
+);
+
+sol!(
+    interface ICurveLpToken {
+        function minter() external view returns (address);
+    }
+);
+
+sol!(
+    interface ICurveBasePool {
+        function get_virtual_price() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveCryptoPool {
+        function gamma() external view returns (uint256);
+        function D() external view returns (uint256);
+        function price_scale() external view returns (uint256);
+    }
+);
+
+sol!(
+    interface ICurveTriCryptoPool {
+        function price_scale(uint256 k) external view returns (uint256);
+    }
+);
+
+async fn get_coin<M: TracingProvider>(
+    address: Address,
+    idx: usize,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<(Address, U256), AmmError> {
+    let i = U256::from(idx);
+    if let Ok(coin) =
+        make_call_request(ICurvePool::coinsCall { i }, middleware, address, Some(block)).await
+    {
+        let call = ICurvePool::balancesCall { i };
+        let balance = make_call_request(call, middleware, address, Some(block)).await?;
+        return Ok((coin._0, balance._0))
+    }
+
+    let i = idx as i128;
+    let coin =
+        make_call_request(ICurvePoolInt128::coinsCall { i }, middleware, address, Some(block))
+            .await?;
+    let call = ICurvePoolInt128::balancesCall { i };
+    let balance = make_call_request(call, middleware, address, Some(block)).await?;
+
+    Ok((coin._0, balance._0))
+}
+
+/// Fetches the coins of the pool, their balances and decimals. The coin
+/// getters revert past the last coin.
+async fn get_pool_balances<M: TracingProvider>(
+    address: Address,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<PoolBalances, AmmError> {
+    let mut coins = Vec::new();
+    let mut balances = Vec::new();
+    for idx in 0..MAX_COINS {
+        let Ok((coin, balance)) = get_coin(address, idx, block, middleware).await else { break };
+        coins.push(coin);
+        balances.push(balance);
+    }
+
+    if coins.len() < 2 {
+        return Err(AmmError::BatchRequestError(address))
+    }
+
+    let decimals = get_token_decimals(&coins, block, middleware).await?;
+
+    Ok(PoolBalances::new(coins, decimals, balances))
+}
+
+pub async fn get_curve_stable_pool_data<M: TracingProvider>(
+    pool: &mut CurveStablePool,
+    block: u64,
+    middleware: Arc<M>,
+) -> Result<(), AmmError> {
+    pool.balances = get_pool_balances(pool.address, block, &middleware).await?;
+    pool.amp = make_call_request(ICurvePool::ACall {}, &middleware, pool.address, Some(block))
+        .await?
+        ._0;
+    pool.rates = get_stable_pool_rates(pool, block, &middleware).await?;
+
+    Ok(())
+}
+
+/// The rate of each coin of a stableswap pool. Stableswap-ng pools report
+/// theirs, which fold in the decimals of the coin, the oracle rate of yield
+/// bearing coins and, for metapools, the virtual price of the base pool. The
+/// older metapools value their base pool token, their last coin, at the
+/// virtual price of the base pool.
+async fn get_stable_pool_rates<M: TracingProvider>(
+    pool: &CurveStablePool,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<Vec<U256>, AmmError> {
+    let coins = pool.balances.len();
+    match pool.protocol {
+        Protocol::CurveStableSwapNgPlainPool | Protocol::CurveStableSwapNgMetaPool => {
+            let call = ICurveNgPool::stored_ratesCall {};
+            let rates = make_call_request(call, middleware, pool.address, Some(block))
+                .await?
+                ._0;
+            if rates.len() != coins {
+                return Err(AmmError::BatchRequestError(pool.address))
+            }
+
+            Ok(rates
+                .into_iter()
+                .zip(&pool.balances.decimals)
+                .map(|(rate, decimals)| {
+                    rate / U256::from(10).pow(U256::from(18u8.saturating_sub(*decimals)))
+                })
+                .collect())
+        }
+        Protocol::CurveV1MetaPool | Protocol::CurveV2MetaPool | Protocol::CurvecrvUSDMetaPool => {
+            let base_pool_token = pool.balances.tokens[coins - 1];
+            let mut rates = vec![RATE_ONE; coins];
+            rates[coins - 1] =
+                get_base_pool_virtual_price(pool.address, base_pool_token, block, middleware)
+                    .await?;
+
+            Ok(rates)
+        }
+        _ => Ok(vec![RATE_ONE; coins]),
+    }
+}
+
+/// The virtual price of the base pool of a metapool. Factory metapools don't
+/// expose their base pool, it is found from the minter of the base pool
+/// token, or is the token itself for the pools that are their own token.
+async fn get_base_pool_virtual_price<M: TracingProvider>(
+    address: Address,
+    base_pool_token: Address,
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<U256, AmmError> {
+    let mut base_pools = Vec::with_capacity(3);
+    let call = ICurveMetaPool::base_poolCall {};
+    if let Ok(base_pool) = make_call_request(call, middleware, address, Some(block)).await {
+        base_pools.push(base_pool._0);
+    }
+    let call = ICurveLpToken::minterCall {};
+    if let Ok(minter) = make_call_request(call, middleware, base_pool_token, Some(block)).await {
+        base_pools.push(minter._0);
+    }
+    base_pools.push(base_pool_token);
+
+    for base_pool in base_pools {
+        let call = ICurveBasePool::get_virtual_priceCall {};
+        if let Ok(price) = make_call_request(call, middleware, base_pool, Some(block)).await {
+            return Ok(price._0)
+        }
+    }
+
+    Err(AmmError::BatchRequestError(address))
+}
+
+pub async fn get_curve_crypto_pool_data<M: TracingProvider>(
+    pool: &mut CurveCryptoPool,
+    block: u64,
+    middleware: Arc<M>,
+) -> Result<(), AmmError> {
+    let address = pool.address;
+    pool.balances = get_pool_balances(address, block, &middleware).await?;
+    pool.amp = make_call_request(ICurvePool::ACall {}, &middleware, address, Some(block))
+        .await?
+        ._0;
+    pool.gamma =
+        make_call_request(ICurveCryptoPool::gammaCall {}, &middleware, address, Some(block))
+            .await?
+            ._0;
+    pool.d = make_call_request(ICurveCryptoPool::DCall {}, &middleware, address, Some(block))
+        .await?
+        ._0;
+
+    // two coin pools only have the one price scale
+    pool.price_scale = if pool.balances.len() == 2 {
+        let call = ICurveCryptoPool::price_scaleCall {};
+        vec![
+            make_call_request(call, &middleware, address, Some(block))
+                .await?
+                ._0,
+        ]
+    } else {
+        let mut price_scale = Vec::with_capacity(pool.balances.len() - 1);
+        for k in 0..pool.balances.len() - 1 {
+            let call = ICurveTriCryptoPool::price_scaleCall { k: U256::from(k) };
+            price_scale.push(
+                make_call_request(call, &middleware, address, Some(block))
+                    .await?
+                    ._0,
+            );
+        }
+        price_scale
+    };
+
+    Ok(())
+}
@@ -0,0 +1,197 @@
+//! The cryptoswap invariant of the curve v2 pools,
+//! `K * D^(n - 1) * sum(x) + prod(x) = K * D^n + (D / n)^n` with
+//! `K0 = prod(x) * n^n / D^n` and `K = A * K0 * gamma^2 / (gamma + 1 - K0)^2`.
+//!
+//! The pools solve it in fixed point with their own approximations of the
+//! derivatives. Here it is solved exactly in rationals, with the invariant
+//! rounded to 18 decimals between Newton steps to keep the fractions from
+//! growing. `x` are the balances multiplied by the price scale of their coin,
+//! so that a balanced pool holds the same value of each.
+use malachite::{
+    num::{
+        arithmetic::traits::{Abs, Pow},
+        basic::traits::{One, Zero},
+        conversion::traits::RoundingFrom,
+    },
+    rounding_modes::RoundingMode,
+    Natural, Rational,
+};
+
+const MAX_ITERATIONS: usize = 255;
+/// the decimals the invariant is rounded to between steps
+const PRECISION: u64 = 18;
+/// the inverse of the relative change at which the invariant has converged,
+/// the same as the pools use
+const CONVERGENCE: u64 = 100_000_000_000_000;
+
+struct Terms {
+    n:      u64,
+    sum:    Rational,
+    prod:   Rational,
+    k0:     Rational,
+    k:      Rational,
+    dk_dk0: Rational,
+}
+
+impl Terms {
+    fn new(x: &[Rational], a: &Rational, gamma: &Rational, d: &Rational) -> Option<Self> {
+        let n = x.len() as u64;
+        let sum = x.iter().fold(Rational::ZERO, |acc, x| acc + x);
+        let prod = x.iter().fold(Rational::ONE, |acc, x| acc * x);
+
+        let k0 = &prod * Rational::from(n).pow(n) / d.pow(n);
+        let g1k0 = gamma + Rational::ONE - &k0;
+        if g1k0 == Rational::ZERO {
+            return None
+        }
+
+        let gamma_sq = gamma.pow(2u64);
+        let k = a * &k0 * &gamma_sq / (&g1k0).pow(2u64);
+        let dk_dk0 = a * gamma_sq * (gamma + Rational::ONE + &k0) / g1k0.pow(3u64);
+
+        Some(Self { n, sum, prod, k0, k, dk_dk0 })
+    }
+
+    fn invariant(&self, d: &Rational) -> Rational {
+        let Self { n, sum, prod, k, .. } = self;
+        k * d.pow(n - 1) * sum + prod - k * d.pow(*n) - (d / Rational::from(*n)).pow(*n)
+    }
+
+    fn d_invariant_d_d(&self, d: &Rational) -> Rational {
+        let Self { n, sum, k0, k, dk_dk0, .. } = self;
+        let dk_dd = -(dk_dk0 * Rational::from(*n) * k0 / d);
+
+        dk_dd * (d.pow(n - 1) * sum - d.pow(*n))
+            + k * (Rational::from(n - 1) * d.pow(n - 2) * sum - Rational::from(*n) * d.pow(n - 1))
+            - (d / Rational::from(*n)).pow(n - 1)
+    }
+
+    fn d_invariant_d_x(&self, d: &Rational, x: &Rational) -> Rational {
+        let Self { n, sum, prod, k0, k, dk_dk0 } = self;
+
+        dk_dk0 * k0 / x * (d.pow(n - 1) * sum - d.pow(*n)) + k * d.pow(n - 1) + prod / x
+    }
+}
+
+fn is_valid(x: &[Rational]) -> bool {
+    x.len() >= 2 && x.iter().all(|x| *x > Rational::ZERO)
+}
+
+/// Rounds to the nearest multiple of `10^-PRECISION`
+fn round(value: &Rational) -> Rational {
+    let scale = Rational::from(Natural::from(10u64).pow(PRECISION));
+    Rational::from(Natural::rounding_from(&(value * &scale), RoundingMode::Nearest).0) / scale
+}
+
+/// Solves the invariant for `D` with Newton's method, starting from
+/// `initial_d` if it is positive. `a` is the amplification coefficient without
+/// the `n^n` and `A_MULTIPLIER` factors the pools store it with.
+pub fn compute_d(
+    x: &[Rational],
+    a: &Rational,
+    gamma: &Rational,
+    initial_d: &Rational,
+) -> Option<Rational> {
+    if !is_valid(x) {
+        return None
+    }
+
+    // D of a balanced pool is the sum of its balances
+    let mut d = if *initial_d > Rational::ZERO {
+        initial_d.clone()
+    } else {
+        x.iter().fold(Rational::ZERO, |acc, x| acc + x)
+    };
+
+    for _ in 0..MAX_ITERATIONS {
+        let terms = Terms::new(x, a, gamma, &d)?;
+        let slope = terms.d_invariant_d_d(&d);
+        if slope == Rational::ZERO {
+            return None
+        }
+
+        let mut next = round(&(&d - terms.invariant(&d) / slope));
+        if next <= Rational::ZERO {
+            next = round(&(&d / Rational::from(2u64)));
+        }
+
+        if (&next - &d).abs() * Rational::from(CONVERGENCE) <= next {
+            return Some(next)
+        }
+        d = next;
+    }
+
+    None
+}
+
+/// The marginal price of `x[base]` in `x[quote]` at the invariant `d`, the
+/// ratio of the gradients of the invariant along the two balances.
+pub fn spot_price(
+    x: &[Rational],
+    a: &Rational,
+    gamma: &Rational,
+    d: &Rational,
+    base: usize,
+    quote: usize,
+) -> Option<Rational> {
+    if !is_valid(x) || *d <= Rational::ZERO {
+        return None
+    }
+
+    let terms = Terms::new(x, a, gamma, d)?;
+    let quote_gradient = terms.d_invariant_d_x(d, &x[quote]);
+    if quote_gradient == Rational::ZERO {
+        return None
+    }
+
+    let price = terms.d_invariant_d_x(d, &x[base]) / quote_gradient;
+    (price > Rational::ZERO).then_some(price)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // tricrypto2
+    fn params() -> (Rational, Rational) {
+        let a = Rational::from_unsigneds(1_707_629u64, 27u64 * 10_000);
+        let gamma = Rational::from_unsigneds(11_809_167_828_997u64, 10u64.pow(18));
+        (a, gamma)
+    }
+
+    fn balances(x: &[u64]) -> Vec<Rational> {
+        x.iter().map(|x| Rational::from(*x)).collect()
+    }
+
+    #[test]
+    fn test_balanced_pool() {
+        let (a, gamma) = params();
+        let x = balances(&[1_000_000, 1_000_000, 1_000_000]);
+        let d = compute_d(&x, &a, &gamma, &Rational::ZERO).unwrap();
+        assert_eq!(d, Rational::from(3_000_000));
+
+        let price = spot_price(&x, &a, &gamma, &d, 0, 1).unwrap();
+        assert_eq!(price, Rational::ONE);
+    }
+
+    #[test]
+    fn test_imbalanced_pool() {
+        let (a, gamma) = params();
+        let x = balances(&[2_000_000, 1_000_000]);
+        let d = compute_d(&x, &a, &gamma, &Rational::ZERO).unwrap();
+
+        // the coin the pool holds more of is cheaper, but by less than it would
+        // be in a constant product pool
+        let price = spot_price(&x, &a, &gamma, &d, 0, 1).unwrap();
+        assert!(price < Rational::ONE);
+        assert!(price > Rational::from_unsigneds(1u64, 2u64));
+
+        let inverse = spot_price(&x, &a, &gamma, &d, 1, 0).unwrap();
+        assert_eq!(&price * inverse, Rational::ONE);
+
+        // starting from a nearby invariant lands on the same one
+        let nearby = &d * Rational::from_unsigneds(101u64, 100u64);
+        let from_initial = compute_d(&x, &a, &gamma, &nearby).unwrap();
+        assert!((from_initial - &d).abs() * Rational::from(1_000_000_000_000u64) < d);
+    }
+}
@@ -0,0 +1,254 @@
+pub mod batch_request;
+pub mod crypto_math;
+
+use std::sync::Arc;
+
+use alloy_primitives::{Address, Log, U256};
+use async_trait::async_trait;
+use brontes_types::{
+    normalized_actions::Action, pair::Pair, traits::TracingProvider, ToScaledRational,
+};
+use malachite::{
+    num::{arithmetic::traits::Pow, basic::traits::One},
+    Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use self::batch_request::{get_curve_crypto_pool_data, get_curve_stable_pool_data};
+use crate::{
+    errors::{AmmError, ArithmeticError, EventLogError},
+    protocols::{
+        pool_balances::{other_token, PoolBalances},
+        stable_math,
+    },
+    Protocol, UpdatableProtocol,
+};
+
+/// `A()` of the cryptoswap pools is scaled by this and by `n^n`
+const A_MULTIPLIER: u64 = 10_000;
+
+/// A curve stableswap pool. The pool can hold more than two coins but is
+/// priced along the pair it has in the pricing graph.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurveStablePool {
+    pub address:  Address,
+    #[serde(with = "brontes_types::serde_utils::protocol_variant")]
+    pub protocol: Protocol,
+    pub pair:     Pair,
+    pub balances: PoolBalances,
+    /// the amplification coefficient as returned by `A()`
+    pub amp:      U256,
+    /// the rate the balance of each coin is valued at, scaled by 1e18. This
+    /// is the virtual price of the base pool for the base pool token of a
+    /// metapool and the oracle rate of the yield bearing coins of a
+    /// stableswap-ng pool. It is kept at the value it had when the pool was
+    /// loaded.
+    pub rates:    Vec<U256>,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurveStablePool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Applies the balance changes of a swap, mint or burn on this pool
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.sync_from_action(self.address, action)
+    }
+
+    /// Stableswap pools don't log their balances, they are synced from the
+    /// classified actions instead
+    fn sync_from_log(&mut self, _: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self.balances.pair_indexes(&self.pair, base_token)?;
+        stable_math::spot_price(&self.balances, &self.rates, self.amp, U256::from(1), base, quote)
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+}
+
+impl CurveStablePool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        pair: Pair,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let mut pool = CurveStablePool { address, protocol, pair, ..Default::default() };
+        get_curve_stable_pool_data(&mut pool, block, middleware).await?;
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        self.balances.is_populated()
+            && !self.amp.is_zero()
+            && self.rates.len() == self.balances.len()
+            && self.balances.pair_indexes(&self.pair, self.pair.0).is_ok()
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, other_token(&self.pair, base))
+    }
+}
+
+/// A curve cryptoswap pool, either a two coin pool or a tricrypto pool.
+///
+/// The price scale only moves when the pool rebalances, which can't be
+/// followed from the classified actions. It is kept at the value it had when
+/// the pool was loaded.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CurveCryptoPool {
+    pub address:     Address,
+    #[serde(with = "brontes_types::serde_utils::protocol_variant")]
+    pub protocol:    Protocol,
+    pub pair:        Pair,
+    pub balances:    PoolBalances,
+    /// `A * n^n * A_MULTIPLIER`, as returned by `A()`
+    pub amp:         U256,
+    /// scaled by 1e18
+    pub gamma:       U256,
+    /// the invariant when the pool was loaded, where solving for the current
+    /// one starts from
+    pub d:           U256,
+    /// the price of each coin after the first in the first coin, scaled by
+    /// 1e18
+    pub price_scale: Vec<U256>,
+}
+
+#[async_trait]
+impl UpdatableProtocol for CurveCryptoPool {
+    fn address(&self) -> Address {
+        self.address
+    }
+
+    /// Applies the balance changes of a swap, mint or burn on this pool
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        self.balances.sync_from_action(self.address, action)
+    }
+
+    /// Cryptoswap pools don't log their balances, they are synced from the
+    /// classified actions instead
+    fn sync_from_log(&mut self, _: Log) -> Result<(), AmmError> {
+        Err(AmmError::EventLogError(EventLogError::InvalidEventSignature))
+    }
+
+    fn calculate_price(&self, base_token: Address) -> Result<Rational, ArithmeticError> {
+        let (base, quote) = self.balances.pair_indexes(&self.pair, base_token)?;
+        if !self.balances.is_populated() {
+            return Err(ArithmeticError::ZeroBalance)
+        }
+
+        let price_scale = self.price_scale();
+        let xp = (0..self.balances.len())
+            .map(|idx| self.balances.scaled(idx) * &price_scale[idx])
+            .collect::<Vec<_>>();
+
+        let n = self.balances.len() as u64;
+        let a = self.amp.to_scaled_rational(0)
+            / Rational::from(A_MULTIPLIER)
+            / Rational::from(n).pow(n);
+        let gamma = self.gamma.to_scaled_rational(18);
+        let d = self.d.to_scaled_rational(18);
+
+        let d = crypto_math::compute_d(&xp, &a, &gamma, &d)
+            .ok_or(ArithmeticError::InvariantNotConverged)?;
+        let price = crypto_math::spot_price(&xp, &a, &gamma, &d, base, quote)
+            .ok_or(ArithmeticError::InvariantNotConverged)?;
+
+        // the price of the scaled balances back in coin units
+        Ok(price * &price_scale[base] / &price_scale[quote])
+    }
+
+    fn tokens(&self) -> Vec<Address> {
+        self.balances.tokens.clone()
+    }
+}
+
+impl CurveCryptoPool {
+    pub async fn new_load_on_block<M: TracingProvider>(
+        address: Address,
+        protocol: Protocol,
+        pair: Pair,
+        middleware: Arc<M>,
+        block: u64,
+    ) -> Result<Self, AmmError> {
+        let mut pool = CurveCryptoPool { address, protocol, pair, ..Default::default() };
+        get_curve_crypto_pool_data(&mut pool, block, middleware).await?;
+
+        if !pool.data_is_populated() {
+            return Err(AmmError::NoStateError(address))
+        }
+
+        Ok(pool)
+    }
+
+    pub fn data_is_populated(&self) -> bool {
+        self.balances.is_populated()
+            && !self.amp.is_zero()
+            && !self.gamma.is_zero()
+            && self.price_scale.len() + 1 == self.balances.len()
+            && self.balances.pair_indexes(&self.pair, self.pair.0).is_ok()
+    }
+
+    pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
+        self.balances.get_tvl(base, other_token(&self.pair, base))
+    }
+
+    /// the price scale of every coin, the first coin is the unit
+    fn price_scale(&self) -> Vec<Rational> {
+        std::iter::once(Rational::ONE)
+            .chain(
+                self.price_scale
+                    .iter()
+                    .map(|scale| scale.to_scaled_rational(18)),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_crypto_pool_price_in_coin_units() {
+        let (usdt, wbtc, weth) =
+            (Address::with_last_byte(1), Address::with_last_byte(2), Address::with_last_byte(3));
+        let one = |decimals: u64| U256::from(10).pow(U256::from(decimals));
+
+        // a balanced tricrypto pool holding 1m usdt of each coin
+        let pool = CurveCryptoPool {
+            address:     Address::with_last_byte(4),
+            protocol:    Protocol::CurveTriCryptoPool,
+            pair:        Pair(wbtc, weth),
+            balances:    PoolBalances::new(
+                vec![usdt, wbtc, weth],
+                vec![6, 8, 18],
+                vec![
+                    U256::from(1_000_000) * one(6),
+                    U256::from(40) * one(8),
+                    U256::from(500) * one(18),
+                ],
+            ),
+            amp:         U256::from(1_707_629),
+            gamma:       U256::from(11_809_167_828_997u64),
+            d:           U256::ZERO,
+            price_scale: vec![U256::from(25_000) * one(18), U256::from(2_000) * one(18)],
+        };
+
+        assert_eq!(pool.calculate_price(wbtc).unwrap(), Rational::from_unsigneds(25u64, 2u64));
+        assert_eq!(pool.calculate_price(weth).unwrap(), Rational::from_unsigneds(2u64, 25u64));
+    }
+}
@@ -73,6 +73,18 @@ pub enum ArithmeticError {
     UniswapV3MathError(#[from] UniswapV3MathError),
     #[error("v2 div by zero")]
     UniV2DivZero,
+    #[error("Token {0:?} is not held by the pool")]
+    TokenNotInPool(Address),
+    #[error("Pool has a zero balance")]
+    ZeroBalance,
+    #[error("Pool invariant did not converge")]
+    InvariantNotConverged,
+    #[error("Pool rates don't match its tokens")]
+    MissingRates,
+    #[error("Pool doesn't hold enough liquidity to fill the swap")]
+    InsufficientLiquidity,
+    #[error("Swaps can't be quoted on this pool")]
+    UnsupportedQuote,
 }
 
 #[derive(Error, Debug)]
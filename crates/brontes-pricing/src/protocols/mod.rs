@@ -1,5 +1,9 @@
+pub mod balancer;
+pub mod curve;
 pub mod errors;
 pub mod lazy;
+pub mod pool_balances;
+pub mod stable_math;
 pub mod uniswap_v2;
 pub mod uniswap_v3;
 
@@ -13,9 +17,11 @@ use malachite::Rational;
 use tracing::{debug, warn};
 
 use crate::{
+    balancer::BalancerPool,
+    curve::{CurveCryptoPool, CurveStablePool},
     lazy::{PoolFetchError, PoolFetchSuccess},
     protocols::errors::{AmmError, ArithmeticError},
-    types::PairWithFirstPoolHop,
+    types::{PairWithFirstPoolHop, PoolVariants},
     uniswap_v2::UniswapV2Pool,
     uniswap_v3::UniswapV3Pool,
     LoadResult, PoolState,
@@ -52,6 +58,20 @@ impl LoadState for Protocol {
                 | Self::SushiSwapV3
                 | Self::PancakeSwapV2
                 | Self::PancakeSwapV3
+                | Self::CurveBasePool2
+                | Self::CurveBasePool3
+                | Self::CurveBasePool4
+                | Self::CurveV1MetaPool
+                | Self::CurveV2MetaPool
+                | Self::CurveV2PlainPool
+                | Self::CurvecrvUSDMetaPool
+                | Self::CurvecrvUSDPlainPool
+                | Self::CurveStableSwapNgPlainPool
+                | Self::CurveStableSwapNgMetaPool
+                | Self::CurveCryptoSwapPool
+                | Self::CurveTriCryptoPool
+                | Self::BalancerV1
+                | Self::BalancerV2
         )
     }
 
@@ -65,14 +85,19 @@ impl LoadState for Protocol {
     ) -> Result<PoolFetchSuccess, PoolFetchError> {
         match self {
             Self::UniswapV2 | Self::SushiSwapV2 | Self::PancakeSwapV2 => {
-                let (pool, res) = if let Ok(pool) =
-                    UniswapV2Pool::new_load_on_block(address, provider.clone(), block_number - 1)
-                        .await
+                let fee = if matches!(self, Self::PancakeSwapV2) { 2500 } else { 3000 };
+                let (pool, res) = if let Ok(pool) = UniswapV2Pool::new_load_on_block(
+                    address,
+                    fee,
+                    provider.clone(),
+                    block_number - 1,
+                )
+                .await
                 {
                     (pool, LoadResult::Ok)
                 } else {
                     (
-                        UniswapV2Pool::new_load_on_block(address, provider, block_number)
+                        UniswapV2Pool::new_load_on_block(address, fee, provider, block_number)
                             .await
                             .map_err(|e| {
                                 debug!(?pool_pair,protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
@@ -120,6 +145,84 @@ impl LoadState for Protocol {
                     res,
                 ))
             }
+            Self::CurveBasePool2
+            | Self::CurveBasePool3
+            | Self::CurveBasePool4
+            | Self::CurveV1MetaPool
+            | Self::CurveV2MetaPool
+            | Self::CurveV2PlainPool
+            | Self::CurvecrvUSDMetaPool
+            | Self::CurvecrvUSDPlainPool
+            | Self::CurveStableSwapNgPlainPool
+            | Self::CurveStableSwapNgMetaPool => {
+                let (pool, res) = load_before_or_on_block(block_number, |block| {
+                    CurveStablePool::new_load_on_block(
+                        address,
+                        self,
+                        pool_pair,
+                        provider.clone(),
+                        block,
+                    )
+                })
+                .await
+                .map_err(|e| {
+                    debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                    (address, self, block_number, pool_pair, fp, e)
+                })?;
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(PoolVariants::CurveStable(Box::new(pool)), block_number),
+                    res,
+                ))
+            }
+            Self::CurveCryptoSwapPool | Self::CurveTriCryptoPool => {
+                let (pool, res) = load_before_or_on_block(block_number, |block| {
+                    CurveCryptoPool::new_load_on_block(
+                        address,
+                        self,
+                        pool_pair,
+                        provider.clone(),
+                        block,
+                    )
+                })
+                .await
+                .map_err(|e| {
+                    debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                    (address, self, block_number, pool_pair, fp, e)
+                })?;
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(PoolVariants::CurveCrypto(Box::new(pool)), block_number),
+                    res,
+                ))
+            }
+            Self::BalancerV1 | Self::BalancerV2 => {
+                let (pool, res) = load_before_or_on_block(block_number, |block| {
+                    BalancerPool::new_load_on_block(
+                        address,
+                        self,
+                        pool_pair,
+                        provider.clone(),
+                        block,
+                    )
+                })
+                .await
+                .map_err(|e| {
+                    debug!(?pool_pair, protocol=%self, %block_number, pool_address=?address, err=%e, "lazy load failed");
+                    (address, self, block_number, pool_pair, fp, e)
+                })?;
+
+                Ok((
+                    block_number,
+                    address,
+                    PoolState::new(PoolVariants::Balancer(Box::new(pool)), block_number),
+                    res,
+                ))
+            }
             rest => {
                 warn!(protocol=?rest, "no state updater is build for");
                 Err((address, self, block_number, pool_pair, fp, AmmError::UnsupportedProtocol))
@@ -127,3 +230,20 @@ impl LoadState for Protocol {
         }
     }
 }
+
+/// Loads the pool as of the end of the block before, falling back to the end
+/// of the block for pools that are created in it.
+async fn load_before_or_on_block<P, F, Fut>(
+    block_number: u64,
+    load: F,
+) -> Result<(P, LoadResult), AmmError>
+where
+    F: Fn(u64) -> Fut,
+    Fut: Future<Output = Result<P, AmmError>>,
+{
+    if let Ok(pool) = load(block_number - 1).await {
+        return Ok((pool, LoadResult::Ok))
+    }
+
+    Ok((load(block_number).await?, LoadResult::PoolInitOnBlock))
+}
@@ -0,0 +1,258 @@
+use std::sync::Arc;
+
+use alloy_primitives::{Address, U256};
+use alloy_sol_macro::sol;
+use brontes_types::{
+    constants::{ETH_ADDRESS, WETH_ADDRESS},
+    normalized_actions::Action,
+    pair::Pair,
+    traits::TracingProvider,
+    ToScaledRational,
+};
+use malachite::{
+    num::{arithmetic::traits::Pow, basic::traits::Zero, conversion::traits::RoundingFrom},
+    rounding_modes::RoundingMode,
+    Natural, Rational,
+};
+use serde::{Deserialize, Serialize};
+
+use super::make_call_request;
+use crate::errors::{AmmError, ArithmeticError};
+
+sol!(
+    interface IErc20Decimals {
+        function decimals() external view returns (uint8);
+    }
+);
+
+/// The token balances of a pool that can hold more than two tokens. Curve and
+/// Balancer pools don't emit a sync event with their reserves, so the balances
+/// are kept in step with the swaps, mints and burns classified on the pool.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PoolBalances {
+    pub tokens:   Vec<Address>,
+    pub decimals: Vec<u8>,
+    pub balances: Vec<U256>,
+}
+
+impl PoolBalances {
+    pub fn new(tokens: Vec<Address>, decimals: Vec<u8>, balances: Vec<U256>) -> Self {
+        // native eth is priced as weth
+        let tokens = tokens
+            .into_iter()
+            .map(|token| if token == ETH_ADDRESS { WETH_ADDRESS } else { token })
+            .collect();
+
+        Self { tokens, decimals, balances }
+    }
+
+    pub fn len(&self) -> usize {
+        self.tokens.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tokens.is_empty()
+    }
+
+    pub fn index_of(&self, token: Address) -> Option<usize> {
+        self.tokens.iter().position(|t| *t == token)
+    }
+
+    pub fn is_populated(&self) -> bool {
+        self.len() >= 2
+            && self.len() == self.decimals.len()
+            && self.len() == self.balances.len()
+            && self.balances.iter().all(|balance| !balance.is_zero())
+    }
+
+    /// The indexes of `base` and the other token of `pair`
+    pub fn pair_indexes(
+        &self,
+        pair: &Pair,
+        base: Address,
+    ) -> Result<(usize, usize), ArithmeticError> {
+        let idx = |token| {
+            self.index_of(token)
+                .ok_or(ArithmeticError::TokenNotInPool(token))
+        };
+
+        Ok((idx(base)?, idx(other_token(pair, base))?))
+    }
+
+    /// The balance of the token at `idx`, scaled by its decimals
+    pub fn scaled(&self, idx: usize) -> Rational {
+        self.balances[idx].to_scaled_rational(self.decimals[idx])
+    }
+
+    /// The balances scaled to 18 decimals, the precision the pool math runs at
+    pub fn normalized(&self) -> Vec<U256> {
+        self.balances
+            .iter()
+            .zip(&self.decimals)
+            .map(|(balance, decimals)| {
+                if *decimals <= 18 {
+                    balance.saturating_mul(U256::from(10).pow(U256::from(18 - decimals)))
+                } else {
+                    balance / U256::from(10).pow(U256::from(decimals - 18))
+                }
+            })
+            .collect()
+    }
+
+    /// The balances of `base` and `quote`, scaled by their decimals
+    pub fn get_tvl(&self, base: Address, quote: Address) -> (Rational, Rational) {
+        let balance = |token| {
+            self.index_of(token)
+                .map(|idx| self.scaled(idx))
+                .unwrap_or(Rational::ZERO)
+        };
+
+        (balance(base), balance(quote))
+    }
+
+    /// Applies the balance changes of a swap, mint or burn on `pool`. Nothing
+    /// is applied if any of the tokens aren't held by the pool.
+    pub fn sync_from_action(&mut self, pool: Address, action: Action) -> Result<(), AmmError> {
+        let mut balances = self.balances.clone();
+
+        match action {
+            Action::Swap(swap) if swap.pool == pool => {
+                self.apply(&mut balances, pool, swap.token_in.address, &swap.amount_in, true)?;
+                self.apply(&mut balances, pool, swap.token_out.address, &swap.amount_out, false)?;
+            }
+            Action::SwapWithFee(swap) if swap.pool == pool => {
+                self.apply(&mut balances, pool, swap.token_in.address, &swap.amount_in, true)?;
+                self.apply(&mut balances, pool, swap.token_out.address, &swap.amount_out, false)?;
+            }
+            Action::Mint(mint) if mint.pool == pool => {
+                for (token, amount) in mint.token.iter().zip(&mint.amount) {
+                    self.apply(&mut balances, pool, token.address, amount, true)?;
+                }
+            }
+            Action::Burn(burn) if burn.pool == pool => {
+                for (token, amount) in burn.token.iter().zip(&burn.amount) {
+                    self.apply(&mut balances, pool, token.address, amount, false)?;
+                }
+            }
+            _ => return Err(AmmError::SyncError(pool)),
+        }
+
+        self.balances = balances;
+
+        Ok(())
+    }
+
+    fn apply(
+        &self,
+        balances: &mut [U256],
+        pool: Address,
+        token: Address,
+        amount: &Rational,
+        add: bool,
+    ) -> Result<(), AmmError> {
+        let idx = self.index_of(token).ok_or(AmmError::SyncError(pool))?;
+
+        let amount = amount * Rational::from(Natural::from(10u64).pow(self.decimals[idx] as u64));
+        if amount < Rational::ZERO {
+            return Err(AmmError::SyncError(pool))
+        }
+        let amount = Natural::rounding_from(&amount, RoundingMode::Nearest).0;
+        let amount = U256::checked_from_limbs_slice(&amount.to_limbs_asc())
+            .ok_or(AmmError::SyncError(pool))?;
+
+        balances[idx] =
+            if add { balances[idx].checked_add(amount) } else { balances[idx].checked_sub(amount) }
+                .ok_or(AmmError::SyncError(pool))?;
+
+        Ok(())
+    }
+}
+
+/// The token of `pair` that isn't `token`
+pub fn other_token(pair: &Pair, token: Address) -> Address {
+    if pair.0 == token {
+        pair.1
+    } else {
+        pair.0
+    }
+}
+
+/// Fetches the decimals of each token, native eth has 18
+pub async fn get_token_decimals<M: TracingProvider>(
+    tokens: &[Address],
+    block: u64,
+    middleware: &Arc<M>,
+) -> Result<Vec<u8>, AmmError> {
+    let mut decimals = Vec::with_capacity(tokens.len());
+    for token in tokens {
+        if *token == ETH_ADDRESS {
+            decimals.push(18);
+            continue
+        }
+
+        let call = IErc20Decimals::decimalsCall {};
+        decimals.push(
+            make_call_request(call, middleware, *token, Some(block))
+                .await?
+                ._0,
+        );
+    }
+
+    Ok(decimals)
+}
+
+#[cfg(test)]
+mod tests {
+    use brontes_types::{
+        db::token_info::{TokenInfo, TokenInfoWithAddress},
+        normalized_actions::NormalizedSwap,
+    };
+
+    use super::*;
+
+    fn token(address: Address, decimals: u8) -> TokenInfoWithAddress {
+        TokenInfoWithAddress { address, inner: TokenInfo { decimals, symbol: String::new() } }
+    }
+
+    #[test]
+    fn test_sync_from_swap() {
+        let pool = Address::with_last_byte(1);
+        let (dai, usdc, usdt) =
+            (Address::with_last_byte(2), Address::with_last_byte(3), Address::with_last_byte(4));
+        let mut balances = PoolBalances::new(
+            vec![dai, usdc, usdt],
+            vec![18, 6, 6],
+            vec![
+                U256::from(1_000_000u64) * U256::from(10).pow(U256::from(18)),
+                U256::from(1_000_000_000_000u64),
+                U256::from(1_000_000_000_000u64),
+            ],
+        );
+
+        let swap = NormalizedSwap {
+            pool,
+            token_in: token(usdc, 6),
+            token_out: token(dai, 18),
+            amount_in: Rational::from(100),
+            amount_out: Rational::from(99),
+            ..Default::default()
+        };
+        balances.sync_from_action(pool, Action::Swap(swap)).unwrap();
+
+        assert_eq!(balances.scaled(0), Rational::from(1_000_000 - 99));
+        assert_eq!(balances.scaled(1), Rational::from(1_000_000 + 100));
+        assert_eq!(balances.scaled(2), Rational::from(1_000_000));
+
+        // a token the pool doesn't hold leaves the balances untouched
+        let swap = NormalizedSwap {
+            pool,
+            token_in: token(usdc, 6),
+            token_out: token(Address::with_last_byte(5), 18),
+            amount_in: Rational::from(100),
+            amount_out: Rational::from(99),
+            ..Default::default()
+        };
+        assert!(balances.sync_from_action(pool, Action::Swap(swap)).is_err());
+        assert_eq!(balances.scaled(1), Rational::from(1_000_000 + 100));
+    }
+}
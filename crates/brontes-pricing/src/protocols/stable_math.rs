@@ -0,0 +1,191 @@
+//! The stableswap invariant shared by curve stableswap pools and balancer
+//! stable pools. Both store the amplification coefficient so that
+//! `Ann = amp * n`, giving the invariant
+//! `Ann * sum(x) + D = Ann * D + D^(n + 1) / (n^n * prod(x))`.
+//!
+//! Both pools run the math on their balances multiplied by a rate per token,
+//! which folds in the decimals and, for yield bearing tokens and base pool
+//! tokens, the value of the token. The rates here are on top of the
+//! decimals, scaled by 1e18, and are one for plain tokens.
+use alloy_primitives::U256;
+use brontes_types::ToScaledRational;
+use malachite::{
+    num::{
+        arithmetic::traits::Pow,
+        basic::traits::{One, Zero},
+    },
+    Rational,
+};
+
+use super::pool_balances::PoolBalances;
+use crate::errors::ArithmeticError;
+
+const MAX_ITERATIONS: usize = 255;
+
+/// the rate of a token without one
+pub const RATE_ONE: U256 = U256::from_limbs([1_000_000_000_000_000_000, 0, 0, 0]);
+
+/// The balances scaled to 18 decimals and multiplied by their rates
+pub fn rated_balances(balances: &PoolBalances, rates: &[U256]) -> Option<Vec<U256>> {
+    balances
+        .normalized()
+        .into_iter()
+        .zip(rates)
+        .map(|(balance, rate)| balance.checked_mul(*rate)?.checked_div(RATE_ONE))
+        .collect()
+}
+
+/// Solves the invariant for `D` with Newton's method, the same way the pools
+/// do on chain. `xp` are the balances normalized to 18 decimals and `amp` is
+/// scaled by `a_precision`.
+pub fn compute_d(xp: &[U256], amp: U256, a_precision: U256) -> Option<U256> {
+    let n = U256::from(xp.len());
+    let sum = xp
+        .iter()
+        .try_fold(U256::ZERO, |acc, x| acc.checked_add(*x))?;
+    if sum.is_zero() {
+        return Some(U256::ZERO)
+    }
+
+    let ann = amp.checked_mul(n)?;
+    let mut d = sum;
+    for _ in 0..MAX_ITERATIONS {
+        let mut d_p = d;
+        for x in xp {
+            d_p = d_p.checked_mul(d)?.checked_div(x.checked_mul(n)?)?;
+        }
+
+        let prev = d;
+        let numerator = ann
+            .checked_mul(sum)?
+            .checked_div(a_precision)?
+            .checked_add(d_p.checked_mul(n)?)?
+            .checked_mul(d)?;
+        let denominator = ann
+            .checked_sub(a_precision)?
+            .checked_mul(d)?
+            .checked_div(a_precision)?
+            .checked_add(d_p.checked_mul(n + U256::from(1))?)?;
+        d = numerator.checked_div(denominator)?;
+
+        if d.abs_diff(prev) <= U256::from(1) {
+            return Some(d)
+        }
+    }
+
+    None
+}
+
+/// The marginal price of the token at `base` in the token at `quote`. At a
+/// fixed `D` the gradient of the invariant along `x_i` is
+/// `Ann + D^(n + 1) / (n^n * prod(x) * x_i)`, the price is the ratio of the
+/// gradients of the two tokens, each multiplied by the rate of its token.
+pub fn spot_price(
+    balances: &PoolBalances,
+    rates: &[U256],
+    amp: U256,
+    a_precision: U256,
+    base: usize,
+    quote: usize,
+) -> Result<Rational, ArithmeticError> {
+    if !balances.is_populated() {
+        return Err(ArithmeticError::ZeroBalance)
+    }
+    if rates.len() != balances.len() || rates.iter().any(|rate| rate.is_zero()) {
+        return Err(ArithmeticError::MissingRates)
+    }
+
+    let xp = rated_balances(balances, rates).ok_or(ArithmeticError::InvariantNotConverged)?;
+    let d = compute_d(&xp, amp, a_precision).ok_or(ArithmeticError::InvariantNotConverged)?;
+    let d = d.to_scaled_rational(18);
+
+    let n = balances.len() as u64;
+    let rates = rates
+        .iter()
+        .map(|rate| rate.to_scaled_rational(18))
+        .collect::<Vec<_>>();
+    let x = (0..balances.len())
+        .map(|idx| balances.scaled(idx) * &rates[idx])
+        .collect::<Vec<_>>();
+    let prod = x.iter().fold(Rational::ONE, |acc, x| acc * x);
+    if prod == Rational::ZERO {
+        return Err(ArithmeticError::ZeroBalance)
+    }
+
+    let ann = amp.to_scaled_rational(0) / a_precision.to_scaled_rational(0) * Rational::from(n);
+    let d_p = d.pow(n + 1) / (Rational::from(n).pow(n) * prod);
+
+    Ok((&ann + &d_p / &x[base]) * &rates[base] / ((ann + d_p / &x[quote]) * &rates[quote]))
+}
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+
+    use super::*;
+
+    fn pool(balances: &[u64]) -> PoolBalances {
+        let n = balances.len();
+        PoolBalances::new(
+            (1..=n as u8).map(Address::with_last_byte).collect(),
+            vec![18; n],
+            balances
+                .iter()
+                .map(|b| U256::from(*b) * U256::from(10).pow(U256::from(18)))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_balanced_pool() {
+        let pool = pool(&[1_000_000, 1_000_000, 1_000_000]);
+        let d = compute_d(&pool.normalized(), U256::from(2000), U256::from(1)).unwrap();
+        assert!(
+            d.abs_diff(U256::from(3_000_000) * U256::from(10).pow(U256::from(18))) <= U256::from(1)
+        );
+
+        let price =
+            spot_price(&pool, &[RATE_ONE; 3], U256::from(2000), U256::from(1), 0, 1).unwrap();
+        assert_eq!(price, Rational::ONE);
+    }
+
+    #[test]
+    fn test_imbalanced_pool() {
+        let pool = pool(&[2_000_000, 1_000_000]);
+
+        // the token the pool holds more of is cheaper, but by less than it would
+        // be in a constant product pool
+        let price =
+            spot_price(&pool, &[RATE_ONE; 2], U256::from(200), U256::from(1), 0, 1).unwrap();
+        assert!(price < Rational::ONE);
+        assert!(price > Rational::from_unsigneds(1u64, 2u64));
+
+        let inverse =
+            spot_price(&pool, &[RATE_ONE; 2], U256::from(200), U256::from(1), 1, 0).unwrap();
+        assert_eq!(price * inverse, Rational::ONE);
+
+        // a higher amplification keeps the price closer to the peg
+        let amplified =
+            spot_price(&pool, &[RATE_ONE; 2], U256::from(200_000), U256::from(100), 0, 1).unwrap();
+        assert!(
+            amplified
+                > spot_price(&pool, &[RATE_ONE; 2], U256::from(200), U256::from(1), 0, 1).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_rated_pool() {
+        // a metapool holding 1.1m of its coin and 1m base pool tokens with a
+        // virtual price of 1.1 holds the same value of each
+        let pool = pool(&[1_100_000, 1_000_000]);
+        let rates = [RATE_ONE, RATE_ONE * U256::from(11) / U256::from(10)];
+
+        let price = spot_price(&pool, &rates, U256::from(200), U256::from(1), 1, 0).unwrap();
+        assert_eq!(price, Rational::from_unsigneds(11u64, 10u64));
+
+        assert!(matches!(
+            spot_price(&pool, &[RATE_ONE], U256::from(200), U256::from(1), 1, 0),
+            Err(ArithmeticError::MissingRates)
+        ));
+    }
+}
@@ -10,7 +10,8 @@ use alloy_sol_types::SolEvent;
 use async_trait::async_trait;
 use brontes_types::{normalized_actions::Action, traits::TracingProvider, ToScaledRational};
 use malachite::{
-    num::{arithmetic::traits::Pow, basic::traits::Zero},
+    num::{arithmetic::traits::Pow, basic::traits::Zero, conversion::traits::RoundingFrom},
+    rounding_modes::RoundingMode,
     Natural, Rational,
 };
 use serde::{Deserialize, Serialize};
@@ -47,6 +48,8 @@ pub const SYNC_EVENT_SIGNATURE: B256 = FixedBytes([
     28, 65, 30, 154, 150, 224, 113, 36, 28, 47, 33, 247, 114, 107, 23, 174, 137, 227, 202, 180,
     199, 139, 229, 14, 6, 43, 3, 169, 255, 251, 186, 209,
 ]);
+/// pool fees are in hundredths of a bip, the same as uniswap v3
+pub const FEE_DENOMINATOR: u32 = 1_000_000;
 
 #[derive(
     Debug, Clone, Default, Serialize, Deserialize, RlpEncodable, RlpDecodable, Hash, PartialEq, Eq,
@@ -59,6 +62,7 @@ pub struct UniswapV2Pool {
     pub token_b_decimals: u8,
     pub reserve_0:        u128,
     pub reserve_1:        u128,
+    /// in hundredths of a bip, e.g. 3000 for 0.3%
     pub fee:              u32,
 }
 
@@ -68,8 +72,19 @@ impl UpdatableProtocol for UniswapV2Pool {
         self.address
     }
 
-    fn sync_from_action(&mut self, _action: Action) -> Result<(), AmmError> {
-        todo!("syncing from actions is currently not supported for v2")
+    /// Applies the reserve changes of a swap on this pool
+    fn sync_from_action(&mut self, action: Action) -> Result<(), AmmError> {
+        let Action::Swap(swap) = action else { return Err(AmmError::SyncError(self.address)) };
+        if swap.pool != self.address {
+            return Err(AmmError::SyncError(self.address))
+        }
+
+        self.apply_swap(
+            swap.token_in.address,
+            &swap.amount_in,
+            swap.token_out.address,
+            &swap.amount_out,
+        )
     }
 
     fn sync_from_log(&mut self, log: Log) -> Result<(), AmmError> {
@@ -132,18 +147,19 @@ impl UniswapV2Pool {
 
     pub async fn new_load_on_block<M: TracingProvider>(
         pair_addr: Address,
+        fee: u32,
         middleware: Arc<M>,
         block: u64,
     ) -> Result<Self, AmmError> {
         let mut pool = UniswapV2Pool {
-            address:          pair_addr,
-            token_a:          Address::ZERO,
+            address: pair_addr,
+            token_a: Address::ZERO,
             token_a_decimals: 0,
-            token_b:          Address::ZERO,
+            token_b: Address::ZERO,
             token_b_decimals: 0,
-            reserve_0:        0,
-            reserve_1:        0,
-            fee:              0,
+            reserve_0: 0,
+            reserve_1: 0,
+            fee,
         };
 
         pool.populate_data(Some(block), middleware).await?;
@@ -219,6 +235,87 @@ impl UniswapV2Pool {
         }
     }
 
+    /// The amount of the other token the pool returns for `amount_in` of
+    /// `token_in`. Both amounts are scaled by the decimals of their token.
+    pub fn get_amount_out(
+        &self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        if token_in != self.token_a && token_in != self.token_b {
+            return Err(ArithmeticError::TokenNotInPool(token_in))
+        }
+
+        let (reserve_in, reserve_out) = self.get_tvl(token_in);
+        if reserve_in == Rational::ZERO || reserve_out == Rational::ZERO {
+            return Err(ArithmeticError::UniV2DivZero)
+        }
+
+        let amount_in_with_fee = amount_in * Rational::from(FEE_DENOMINATOR - self.fee)
+            / Rational::from(FEE_DENOMINATOR);
+
+        Ok(&amount_in_with_fee * reserve_out / (reserve_in + &amount_in_with_fee))
+    }
+
+    /// Swaps `amount_in` of `token_in` on the reserves of the pool, returning
+    /// the amount of the other token it returns
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        let amount_out = self.get_amount_out(token_in, amount_in)?;
+        let token_out = if token_in == self.token_a { self.token_b } else { self.token_a };
+
+        self.apply_swap(token_in, amount_in, token_out, &amount_out)
+            .map_err(|_| ArithmeticError::RoundingError)?;
+
+        Ok(amount_out)
+    }
+
+    fn apply_swap(
+        &mut self,
+        token_in: Address,
+        amount_in: &Rational,
+        token_out: Address,
+        amount_out: &Rational,
+    ) -> Result<(), AmmError> {
+        let amount_in = self.to_reserve_units(token_in, amount_in)?;
+        let amount_out = self.to_reserve_units(token_out, amount_out)?;
+
+        let (reserve_in, reserve_out) = if token_in == self.token_a {
+            (&mut self.reserve_0, &mut self.reserve_1)
+        } else {
+            (&mut self.reserve_1, &mut self.reserve_0)
+        };
+
+        *reserve_in = reserve_in
+            .checked_add(amount_in)
+            .ok_or(AmmError::SyncError(self.address))?;
+        *reserve_out = reserve_out
+            .checked_sub(amount_out)
+            .ok_or(AmmError::SyncError(self.address))?;
+
+        Ok(())
+    }
+
+    fn to_reserve_units(&self, token: Address, amount: &Rational) -> Result<u128, AmmError> {
+        let decimals = if token == self.token_a {
+            self.token_a_decimals
+        } else if token == self.token_b {
+            self.token_b_decimals
+        } else {
+            return Err(AmmError::SyncError(self.address))
+        };
+
+        let amount = amount * Rational::from(Natural::from(10u64).pow(decimals as u64));
+        if amount < Rational::ZERO {
+            return Err(AmmError::SyncError(self.address))
+        }
+
+        Ok(u128::rounding_from(&amount, RoundingMode::Nearest).0)
+    }
+
     pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
         if self.token_a == base {
             (
@@ -233,3 +330,39 @@ impl UniswapV2Pool {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::hex;
+
+    use super::*;
+
+    #[test]
+    fn test_swap_updates_reserves() {
+        let mut pool = UniswapV2Pool::new(
+            hex!("B4e16d0168e52d35CaCD2c6185b44281Ec28C9Dc").into(),
+            hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48").into(),
+            6,
+            hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2").into(),
+            18,
+            50_000_000_000_000,
+            20_000_000_000_000_000_000_000,
+            3000,
+        );
+
+        // 0.3% of the input is kept by the pool
+        let amount_in = Rational::from(100_000);
+        let expected = Rational::from(99_700) * Rational::from(20_000)
+            / (Rational::from(50_000_000) + Rational::from(99_700));
+
+        let amount_out = pool.swap(pool.token_a, &amount_in).unwrap();
+        assert_eq!(amount_out, expected);
+        assert_eq!(pool.reserve_0, 50_100_000_000_000);
+
+        // the next swap is quoted on the reserves the first one left
+        let second = pool.get_amount_out(pool.token_a, &amount_in).unwrap();
+        assert!(second < amount_out);
+
+        assert!(pool.swap(Address::with_last_byte(1), &amount_in).is_err());
+    }
+}
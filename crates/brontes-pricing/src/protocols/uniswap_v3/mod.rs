@@ -9,17 +9,28 @@ use async_trait::async_trait;
 use brontes_types::{
     normalized_actions::Action, traits::TracingProvider, FastHashMap, ToScaledRational,
 };
-use malachite::Rational;
+use malachite::{
+    num::{
+        arithmetic::traits::Reciprocal,
+        basic::traits::Zero,
+        conversion::traits::{ConvertibleFrom, RoundingFrom},
+    },
+    rounding_modes::RoundingMode,
+    Natural, Rational,
+};
 use serde::{Deserialize, Serialize};
 
 use self::batch_request::get_v3_pool_data_batch_request;
 use super::make_call_request;
 #[cfg(feature = "uni-v3-ticks")]
 use crate::uniswap_v3::batch_request::get_uniswap_v3_tick_data_batch_request;
-#[cfg(feature = "uni-v3-ticks")]
-use crate::uniswap_v3::uniswap_v3_math::tick_math::{MAX_TICK, MIN_TICK};
 use crate::{
     errors::{AmmError, ArithmeticError, EventLogError},
+    uniswap_v3::uniswap_v3_math::{
+        error::UniswapV3MathError,
+        tick_bitmap,
+        tick_math::{get_sqrt_ratio_at_tick, MAX_TICK, MIN_TICK},
+    },
     UpdatableProtocol,
 };
 
@@ -156,6 +167,24 @@ impl Info {
     }
 }
 
+/// Where a swap simulated on a pool leaves it
+struct SimulatedSwap {
+    amount_out:     Rational,
+    raw_amount_in:  U256,
+    raw_amount_out: U256,
+    sqrt_price_x96: U256,
+    liquidity:      u128,
+}
+
+fn rational_to_u256(value: &Rational) -> Option<U256> {
+    let (value, _) = Natural::rounding_from(value, RoundingMode::Floor);
+    U256::checked_from_limbs_slice(&value.to_limbs_asc())
+}
+
+fn rational_to_u128(value: &Rational) -> Option<u128> {
+    u128::convertible_from(value).then(|| u128::rounding_from(value, RoundingMode::Floor).0)
+}
+
 #[async_trait]
 impl UpdatableProtocol for UniswapV3Pool {
     fn address(&self) -> Address {
@@ -478,6 +507,159 @@ impl UniswapV3Pool {
         Ok(())
     }
 
+    /// The amount of the other token the pool returns for `amount_in` of
+    /// `token_in`. Both amounts are scaled by the decimals of their token.
+    ///
+    /// The swap is stepped across the initialized ticks the pool has loaded,
+    /// with the liquidity changing as each one is crossed. Without loaded ticks
+    /// the current liquidity is assumed to extend over the whole price range.
+    pub fn get_amount_out(
+        &self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        Ok(self.simulate_swap(token_in, amount_in)?.amount_out)
+    }
+
+    /// Swaps `amount_in` of `token_in` on the pool, moving its price and active
+    /// liquidity to where the swap leaves them. Returns the amount of the other
+    /// token the pool returns, see [`UniswapV3Pool::get_amount_out`].
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        let SimulatedSwap { amount_out, raw_amount_in, raw_amount_out, sqrt_price_x96, liquidity } =
+            self.simulate_swap(token_in, amount_in)?;
+
+        self.sqrt_price = sqrt_price_x96;
+        self.liquidity = liquidity;
+        self.tick = uniswap_v3_math::tick_math::get_tick_at_sqrt_ratio(sqrt_price_x96)?;
+
+        if token_in == self.token_a {
+            self.reserve_0 = self.reserve_0.saturating_add(raw_amount_in);
+            self.reserve_1 = self.reserve_1.saturating_sub(raw_amount_out);
+        } else {
+            self.reserve_1 = self.reserve_1.saturating_add(raw_amount_in);
+            self.reserve_0 = self.reserve_0.saturating_sub(raw_amount_out);
+        }
+
+        Ok(amount_out)
+    }
+
+    fn simulate_swap(
+        &self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<SimulatedSwap, ArithmeticError> {
+        if token_in != self.token_a && token_in != self.token_b {
+            return Err(ArithmeticError::TokenNotInPool(token_in))
+        }
+        if self.liquidity == 0 || self.sqrt_price.is_zero() || self.tick_spacing <= 0 {
+            return Err(UniswapV3MathError::LiquidityIsZero.into())
+        }
+
+        let zero_for_one = token_in == self.token_a;
+        let (decimals_in, decimals_out) = if zero_for_one {
+            (self.token_a_decimals, self.token_b_decimals)
+        } else {
+            (self.token_b_decimals, self.token_a_decimals)
+        };
+        let unit = |decimals: u8| {
+            U256::from(10)
+                .pow(U256::from(decimals))
+                .to_scaled_rational(0)
+        };
+        let q96 = (U256::from(1) << 96).to_scaled_rational(0);
+
+        let raw_amount_in = amount_in * unit(decimals_in);
+        // the fee is a fixed share of every step, so it's taken up front
+        let mut remaining =
+            &raw_amount_in * Rational::from(1_000_000 - self.fee) / Rational::from(1_000_000);
+        let mut amount_out = Rational::ZERO;
+        let mut sqrt_price = self.sqrt_price.to_scaled_rational(0) / &q96;
+        let mut liquidity = Rational::from(self.liquidity);
+        let mut tick = self.tick;
+
+        while remaining > Rational::ZERO {
+            let (tick_next, initialized) = if self.tick_bitmap.is_empty() {
+                (if zero_for_one { MIN_TICK } else { MAX_TICK }, false)
+            } else {
+                tick_bitmap::next_initialized_tick_within_one_word(
+                    &self.tick_bitmap,
+                    tick,
+                    self.tick_spacing,
+                    zero_for_one,
+                )?
+            };
+            let tick_next = tick_next.clamp(MIN_TICK, MAX_TICK);
+            let sqrt_price_next = get_sqrt_ratio_at_tick(tick_next)?.to_scaled_rational(0) / &q96;
+
+            // the input that moves the price to the next tick
+            let step_in = if zero_for_one {
+                &liquidity * ((&sqrt_price_next).reciprocal() - (&sqrt_price).reciprocal())
+            } else {
+                &liquidity * (&sqrt_price_next - &sqrt_price)
+            };
+
+            let sqrt_price_after = if remaining < step_in {
+                let delta = &remaining / &liquidity;
+                remaining = Rational::ZERO;
+                if zero_for_one {
+                    ((&sqrt_price).reciprocal() + delta).reciprocal()
+                } else {
+                    &sqrt_price + delta
+                }
+            } else {
+                remaining -= step_in;
+                sqrt_price_next
+            };
+
+            amount_out += if zero_for_one {
+                &liquidity * (&sqrt_price - &sqrt_price_after)
+            } else {
+                &liquidity * ((&sqrt_price).reciprocal() - (&sqrt_price_after).reciprocal())
+            };
+            sqrt_price = sqrt_price_after;
+
+            if remaining == Rational::ZERO {
+                break
+            }
+            if tick_next == MIN_TICK || tick_next == MAX_TICK {
+                return Err(ArithmeticError::InsufficientLiquidity)
+            }
+
+            if initialized {
+                let liquidity_net = self
+                    .ticks
+                    .get(&tick_next)
+                    .map(|info| info.liquidity_net)
+                    .unwrap_or_default();
+                // crossing a tick downwards removes the liquidity it adds upwards
+                liquidity +=
+                    Rational::from(if zero_for_one { -liquidity_net } else { liquidity_net });
+                if liquidity < Rational::ZERO {
+                    return Err(UniswapV3MathError::LiquiditySub.into())
+                }
+            }
+
+            tick = if zero_for_one { tick_next - 1 } else { tick_next };
+        }
+
+        let sqrt_price_x96 =
+            rational_to_u256(&(sqrt_price * q96)).ok_or(ArithmeticError::SqrtPriceOverflow)?;
+        let liquidity = rational_to_u128(&liquidity).ok_or(ArithmeticError::U128ConversionError)?;
+
+        Ok(SimulatedSwap {
+            raw_amount_in: rational_to_u256(&raw_amount_in)
+                .ok_or(ArithmeticError::RoundingError)?,
+            raw_amount_out: rational_to_u256(&amount_out).ok_or(ArithmeticError::RoundingError)?,
+            amount_out: amount_out / unit(decimals_out),
+            sqrt_price_x96,
+            liquidity,
+        })
+    }
+
     pub fn get_tvl(&self, base: Address) -> (Rational, Rational) {
         if self.token_a == base {
             (
@@ -514,3 +696,70 @@ pub struct Tick {
     pub seconds_outside: u32,
     pub initialized: bool,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_amount_out_on_current_liquidity() {
+        let one = 10u128.pow(18);
+        let mut pool = UniswapV3Pool {
+            token_a: Address::with_last_byte(1),
+            token_a_decimals: 18,
+            token_b: Address::with_last_byte(2),
+            token_b_decimals: 18,
+            liquidity: one,
+            sqrt_price: U256::from(1) << 96,
+            tick_spacing: 60,
+            ..Default::default()
+        };
+
+        // 1 / sqrt(p') = 1 + 1 halves the price, returning half a token
+        let amount_out = pool
+            .get_amount_out(pool.token_a, &Rational::from(1))
+            .unwrap();
+        assert_eq!(amount_out, Rational::from_unsigneds(1u64, 2u64));
+
+        let amount_out = pool
+            .get_amount_out(pool.token_b, &Rational::from(1))
+            .unwrap();
+        assert_eq!(amount_out, Rational::from_unsigneds(1u64, 2u64));
+
+        // the fee is taken from the input
+        pool.fee = 3000;
+        let amount_out = pool
+            .get_amount_out(pool.token_a, &Rational::from(1))
+            .unwrap();
+        assert_eq!(amount_out, Rational::from_unsigneds(997u64, 1997u64));
+    }
+
+    #[test]
+    fn test_swap_moves_price() {
+        let one = 10u128.pow(18);
+        let mut pool = UniswapV3Pool {
+            token_a: Address::with_last_byte(1),
+            token_a_decimals: 18,
+            token_b: Address::with_last_byte(2),
+            token_b_decimals: 18,
+            liquidity: one,
+            sqrt_price: U256::from(1) << 96,
+            tick_spacing: 60,
+            reserve_0: U256::from(one),
+            reserve_1: U256::from(one),
+            ..Default::default()
+        };
+
+        let amount_out = pool.swap(pool.token_a, &Rational::from(1)).unwrap();
+        assert_eq!(amount_out, Rational::from_unsigneds(1u64, 2u64));
+        // 1 / sqrt(p') = 2
+        assert_eq!(pool.sqrt_price, U256::from(1) << 95);
+        assert_eq!(pool.liquidity, one);
+        assert_eq!(pool.reserve_0, U256::from(2 * one));
+        assert_eq!(pool.reserve_1, U256::from(one / 2));
+
+        // the second swap starts where the first left the price, 1 / sqrt(p'') = 3
+        let amount_out = pool.swap(pool.token_a, &Rational::from(1)).unwrap();
+        assert_eq!(amount_out, Rational::from_unsigneds(1u64, 6u64));
+    }
+}
@@ -7,10 +7,15 @@ use brontes_types::{
     pair::Pair,
 };
 use malachite::Rational;
+use serde::{Deserialize, Serialize};
 
 use crate::{
-    errors::ArithmeticError, uniswap_v2::UniswapV2Pool, uniswap_v3::UniswapV3Pool, LoadState,
-    Protocol, UpdatableProtocol,
+    balancer::BalancerPool,
+    curve::{CurveCryptoPool, CurveStablePool},
+    errors::ArithmeticError,
+    uniswap_v2::UniswapV2Pool,
+    uniswap_v3::UniswapV3Pool,
+    LoadState, Protocol, UpdatableProtocol,
 };
 
 wrap_fixed_bytes!(extra_derives:[],
@@ -54,6 +59,13 @@ impl PairWithFirstPoolHop {
 pub trait ProtocolState: Debug {
     fn price(&self, base: Address) -> Result<Rational, ArithmeticError>;
     fn tvl(&self, base: Address) -> (Rational, Rational);
+    /// the amount of the other token the pool returns for `amount_in` of
+    /// `token_in`
+    fn amount_out(
+        &self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError>;
 }
 
 impl ProtocolState for PoolState {
@@ -64,9 +76,17 @@ impl ProtocolState for PoolState {
     fn price(&self, base: Address) -> Result<Rational, ArithmeticError> {
         self.get_price(base)
     }
+
+    fn amount_out(
+        &self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        self.get_amount_out(token_in, amount_in)
+    }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct PoolState {
     variant:         PoolVariants,
     pub last_update: u64,
@@ -92,6 +112,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => Pair(v.token_a, v.token_b),
             PoolVariants::UniswapV3(v) => Pair(v.token_a, v.token_b),
+            PoolVariants::CurveStable(v) => v.pair,
+            PoolVariants::CurveCrypto(v) => v.pair,
+            PoolVariants::Balancer(v) => v.pair,
         }
     }
 
@@ -99,6 +122,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(_) => Protocol::UniswapV2,
             PoolVariants::UniswapV3(_) => Protocol::UniswapV3,
+            PoolVariants::CurveStable(v) => v.protocol,
+            PoolVariants::CurveCrypto(v) => v.protocol,
+            PoolVariants::Balancer(v) => v.protocol,
         }
     }
 
@@ -108,13 +134,16 @@ impl PoolState {
             return
         }
         self.last_update = state.block;
-        self.variant.increment_state(state.logs);
+        self.variant.increment_state(state);
     }
 
     pub fn address(&self) -> Address {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.address(),
             PoolVariants::UniswapV3(v) => v.address(),
+            PoolVariants::CurveStable(v) => v.address(),
+            PoolVariants::CurveCrypto(v) => v.address(),
+            PoolVariants::Balancer(v) => v.address(),
         }
     }
 
@@ -122,6 +151,9 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.get_tvl(base),
             PoolVariants::UniswapV3(v) => v.get_tvl(base),
+            PoolVariants::CurveStable(v) => v.get_tvl(base),
+            PoolVariants::CurveCrypto(v) => v.get_tvl(base),
+            PoolVariants::Balancer(v) => v.get_tvl(base),
         }
     }
 
@@ -129,27 +161,80 @@ impl PoolState {
         match &self.variant {
             PoolVariants::UniswapV2(v) => v.calculate_price(base),
             PoolVariants::UniswapV3(v) => v.calculate_price(base),
+            PoolVariants::CurveStable(v) => v.calculate_price(base),
+            PoolVariants::CurveCrypto(v) => v.calculate_price(base),
+            PoolVariants::Balancer(v) => v.calculate_price(base),
+        }
+    }
+
+    /// Quotes a swap of `amount_in` of `token_in` on the current state of the
+    /// pool. Both amounts are scaled by the decimals of their token.
+    pub fn get_amount_out(
+        &self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        match &self.variant {
+            PoolVariants::UniswapV2(v) => v.get_amount_out(token_in, amount_in),
+            PoolVariants::UniswapV3(v) => v.get_amount_out(token_in, amount_in),
+            PoolVariants::CurveStable(_)
+            | PoolVariants::CurveCrypto(_)
+            | PoolVariants::Balancer(_) => Err(ArithmeticError::UnsupportedQuote),
+        }
+    }
+
+    /// Swaps `amount_in` of `token_in` on the pool, leaving it in the state
+    /// the swap would have. Amounts are scaled as in
+    /// [`PoolState::get_amount_out`].
+    pub fn swap(
+        &mut self,
+        token_in: Address,
+        amount_in: &Rational,
+    ) -> Result<Rational, ArithmeticError> {
+        match &mut self.variant {
+            PoolVariants::UniswapV2(v) => v.swap(token_in, amount_in),
+            PoolVariants::UniswapV3(v) => v.swap(token_in, amount_in),
+            PoolVariants::CurveStable(_)
+            | PoolVariants::CurveCrypto(_)
+            | PoolVariants::Balancer(_) => Err(ArithmeticError::UnsupportedQuote),
         }
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PoolVariants {
     UniswapV2(Box<UniswapV2Pool>),
     UniswapV3(Box<UniswapV3Pool>),
+    CurveStable(Box<CurveStablePool>),
+    CurveCrypto(Box<CurveCryptoPool>),
+    Balancer(Box<BalancerPool>),
 }
 
 impl PoolVariants {
-    fn increment_state(&mut self, logs: Vec<Log>) {
-        for log in logs {
-            let _ = match self {
-                PoolVariants::UniswapV3(a) => a.sync_from_log(log),
-                PoolVariants::UniswapV2(a) => a.sync_from_log(log),
-            };
+    fn increment_state(&mut self, update: PoolUpdate) {
+        match self {
+            PoolVariants::UniswapV3(a) => sync_from_logs(a.as_mut(), update.logs),
+            PoolVariants::UniswapV2(a) => sync_from_logs(a.as_mut(), update.logs),
+            // these pools don't log their balances
+            PoolVariants::CurveStable(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::CurveCrypto(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
+            PoolVariants::Balancer(a) => {
+                let _ = a.sync_from_action(update.action);
+            }
         }
     }
 }
 
+fn sync_from_logs(pool: &mut impl UpdatableProtocol, logs: Vec<Log>) {
+    for log in logs {
+        let _ = pool.sync_from_log(log);
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum DexPriceMsg {
     /// marker for only updating loaded state and not generating prices
@@ -202,6 +287,9 @@ impl PoolUpdate {
             || self.action.is_batch()
             || self.action.is_aggregator()
             || self.action.is_eth_transfer()
+            || self.action.is_lending()
+            || self.action.is_nft()
+            || self.action.is_wrapping()
         {
             return None
         }
@@ -9,6 +9,7 @@ pub const ALUSD_ADDRESS: Address = Address::new(hex!("bc6da0fe9ad5f3b0d581602889
 pub const USTC_ADDRESS: Address = Address::new(hex!("a47c8bf37f92abed4a126bda807a7b7498661acd"));
 pub const MIM_ADDRESS: Address = Address::new(hex!("99d8a9c45b2eca8864373a26d1459e3dff1e17f3"));
 pub const WETH_ADDRESS: Address = Address::new(hex!("C02aaA39b223FE8D0A0e5C4F27eAD9083C756Cc2"));
+pub const STETH_ADDRESS: Address = Address::new(hex!("ae7ab96520DE3A18E5e111B5EaAb095312D7fE84"));
 pub const USDT_ADDRESS: Address = Address::new(hex!("dAC17F958D2ee523a2206206994597C13D831ec7"));
 pub const USDC_ADDRESS: Address = Address::new(hex!("A0b86991c6218b36c1d19D4a2e9Eb0cE3606eB48"));
 pub const FRAX_ADDRESS: Address = Address::new(hex!("853d955acef822db058eb8505911ed77f175b99e"));
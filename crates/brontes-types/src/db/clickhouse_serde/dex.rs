@@ -111,6 +111,7 @@ pub mod dex_quote {
                             ),
                             is_transfer:           t,
                             first_hop_connections: c as usize,
+                            spread:                None,
                         },
                     )
                 },
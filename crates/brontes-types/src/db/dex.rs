@@ -14,7 +14,7 @@ use malachite::{
     },
     Natural, Rational,
 };
-use redefined::Redefined;
+use redefined::{Redefined, RedefinedConvert};
 use reth_db::DatabaseError;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
@@ -33,17 +33,7 @@ use crate::{
 ///
 /// The `goes_through` field, indicates the token pair of the pool
 /// that generated the action that caused the pricing event.
-#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize, Redefined)]
-#[redefined_attr(derive(
-    Debug,
-    Clone,
-    PartialEq,
-    Eq,
-    Serialize,
-    rDeserialize,
-    rSerialize,
-    Archive
-))]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize, Serialize)]
 pub struct DexPrices {
     pub pre_state:             Rational,
     pub post_state:            Rational,
@@ -58,6 +48,40 @@ pub struct DexPrices {
     /// have. If it is only 1. then we highly discount the accuracy of the
     /// price.
     pub first_hop_connections: usize,
+    /// the weighted deviation of the prices of the paths the pair was priced
+    /// over, the larger of the pre and post state. Only set when the prices
+    /// were generated with path weighted pricing. It isn't stored, prices read
+    /// back from the database don't have one.
+    #[serde(default)]
+    pub spread:                Option<Rational>,
+}
+
+/// The layout dex prices are stored with in libmdbx. It is kept to the fields
+/// prices were first stored with so that the stored quotes stay readable.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, rSerialize, rDeserialize, Archive, Redefined)]
+#[redefined(DexPrices)]
+#[redefined_attr(to_source = "self.into_prices()")]
+pub struct DexPricesRedefined {
+    pub pre_state:             RationalRedefined,
+    pub post_state:            RationalRedefined,
+    pub pool_liquidity:        RationalRedefined,
+    pub goes_through:          PairRedefined,
+    pub is_transfer:           bool,
+    pub first_hop_connections: usize,
+}
+
+impl DexPricesRedefined {
+    fn into_prices(self) -> DexPrices {
+        DexPrices {
+            pre_state:             self.pre_state.to_source(),
+            post_state:            self.post_state.to_source(),
+            pool_liquidity:        self.pool_liquidity.to_source(),
+            goes_through:          self.goes_through.to_source(),
+            is_transfer:           self.is_transfer,
+            first_hop_connections: self.first_hop_connections,
+            spread:                None,
+        }
+    }
 }
 
 impl Display for DexPrices {
@@ -129,6 +153,7 @@ impl DexQuotes {
                 first_hop_connections: usize::MAX,
                 goes_through:          Pair::default(),
                 is_transfer:           false,
+                spread:                None,
             })
         }
 
@@ -168,6 +193,7 @@ impl DexQuotes {
                 pool_liquidity:        Rational::from(1_000_000),
                 goes_through:          Pair::default(),
                 is_transfer:           false,
+                spread:                None,
             })
         }
 
@@ -197,6 +223,7 @@ impl DexQuotes {
                 pool_liquidity:        Rational::from(1_000_000),
                 goes_through:          Pair::default(),
                 is_transfer:           false,
+                spread:                None,
             })
         }
 
@@ -378,6 +405,7 @@ impl<'de> Deserialize<'de> for DexQuoteWithIndex {
                             ),
                             is_transfer:           t,
                             first_hop_connections: c as usize,
+                            spread:                None,
                         },
                     )
                 },
@@ -455,3 +483,26 @@ impl DexQuotesWithBlockNumber {
             .collect_vec()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use alloy_primitives::Address;
+
+    use super::*;
+
+    #[test]
+    fn test_stored_prices_drop_spread() {
+        let prices = DexPrices {
+            pre_state:             Rational::from(2),
+            post_state:            Rational::from(3),
+            pool_liquidity:        Rational::from(1_000),
+            goes_through:          Pair(Address::with_last_byte(1), Address::with_last_byte(2)),
+            is_transfer:           false,
+            first_hop_connections: 4,
+            spread:                Some(Rational::from_unsigneds(1u64, 10u64)),
+        };
+
+        let stored = DexPricesRedefined::from_source(prices.clone());
+        assert_eq!(stored.to_source(), DexPrices { spread: None, ..prices });
+    }
+}
@@ -20,7 +20,7 @@ use crate::{
     implement_table_value_codecs_with_zc,
     pair::Pair,
     serde_utils::{option_addresss, u256, vec_txhash},
-    FastHashSet,
+    FastHashSet, SharedPoolStates,
 };
 #[allow(unused_imports)]
 use crate::{db::cex::CexExchange, normalized_actions::NormalizedSwap};
@@ -85,6 +85,9 @@ pub struct Metadata {
     pub dex_quotes:     Option<DexQuotes>,
     pub builder_info:   Option<BuilderInfo>,
     pub cex_trades:     Option<CexTradeMap>,
+    /// the pool states the DEX pricer tracked over the block. Only set when the
+    /// pricer is run, not when the DEX prices are read from libmdbx
+    pub pool_states:    Option<SharedPoolStates>,
 }
 
 impl Metadata {
@@ -133,6 +136,11 @@ impl Metadata {
         self
     }
 
+    pub fn with_pool_states(mut self, pool_states: Option<SharedPoolStates>) -> Self {
+        self.pool_states = pool_states;
+        self
+    }
+
     pub fn with_builder_info(mut self, builder_info: BuilderInfo) -> Self {
         self.builder_info = Some(builder_info);
         self
@@ -196,6 +204,13 @@ impl BlockMetadata {
         builder_info: Option<BuilderInfo>,
         cex_trades: Option<CexTradeMap>,
     ) -> Metadata {
-        Metadata { block_metadata: self, cex_quotes, dex_quotes, builder_info, cex_trades }
+        Metadata {
+            block_metadata: self,
+            cex_quotes,
+            dex_quotes,
+            builder_info,
+            cex_trades,
+            pool_states: None,
+        }
     }
 }
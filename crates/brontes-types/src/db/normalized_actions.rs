@@ -151,6 +151,14 @@ pub enum ActionKind {
     Burn,
     Collect,
     Liquidation,
+    Loan,
+    Repayment,
+    Deposit,
+    Withdraw,
+    NftTransfer,
+    NftTrade,
+    Wrap,
+    Unwrap,
     Unclassified,
     SelfDestruct,
     EthTransfer,
@@ -171,6 +179,14 @@ impl From<&Action> for ActionKind {
             Action::Burn(_) => ActionKind::Burn,
             Action::Transfer(_) => ActionKind::Transfer,
             Action::Liquidation(_) => ActionKind::Liquidation,
+            Action::Loan(_) => ActionKind::Loan,
+            Action::Repayment(_) => ActionKind::Repayment,
+            Action::Deposit(_) => ActionKind::Deposit,
+            Action::Withdraw(_) => ActionKind::Withdraw,
+            Action::NftTransfer(_) => ActionKind::NftTransfer,
+            Action::NftTrade(_) => ActionKind::NftTrade,
+            Action::Wrap(_) => ActionKind::Wrap,
+            Action::Unwrap(_) => ActionKind::Unwrap,
             Action::Collect(_) => ActionKind::Collect,
             Action::SelfDestruct(_) => ActionKind::SelfDestruct,
             Action::EthTransfer(_) => ActionKind::EthTransfer,
@@ -0,0 +1,68 @@
+use alloy_primitives::Address;
+use malachite::Rational;
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    db::redefined_types::{malachite::RationalRedefined, primitives::AddressRedefined},
+    implement_table_value_codecs_with_zc,
+};
+
+/// The dex pricing graph state as of the end of a block, which pricing can be
+/// resumed from. The state is encoded by `brontes-pricing`.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct PricingGraphCheckpoint {
+    /// the asset the graph prices against, a checkpoint is only valid for
+    /// pricing against the same asset
+    pub quote_asset:            Address,
+    /// the notional pairs were priced at when path weighted pricing was
+    /// enabled, a checkpoint is only valid for pricing with the same one
+    pub path_weighted_notional: Option<Rational>,
+    pub state:                  Vec<u8>,
+}
+
+impl PricingGraphCheckpoint {
+    pub fn new(
+        quote_asset: Address,
+        path_weighted_notional: Option<Rational>,
+        state: Vec<u8>,
+    ) -> Self {
+        Self { quote_asset, path_weighted_notional, state }
+    }
+
+    /// Whether pricing with the given config can resume from the checkpoint
+    pub fn is_compatible(
+        &self,
+        quote_asset: Address,
+        path_weighted_notional: Option<&Rational>,
+    ) -> bool {
+        self.quote_asset == quote_asset
+            && self.path_weighted_notional.as_ref() == path_weighted_notional
+    }
+}
+
+implement_table_value_codecs_with_zc!(PricingGraphCheckpointRedefined);
+
+#[cfg(test)]
+mod tests {
+    use redefined::RedefinedConvert;
+
+    use super::*;
+
+    #[test]
+    fn test_checkpoint_compatibility() {
+        let quote = Address::with_last_byte(1);
+        let notional = Rational::from(10_000);
+        let checkpoint = PricingGraphCheckpoint::new(quote, Some(notional.clone()), vec![1, 2, 3]);
+
+        let stored = PricingGraphCheckpointRedefined::from_source(checkpoint.clone());
+        assert_eq!(stored.to_source(), checkpoint);
+
+        assert!(checkpoint.is_compatible(quote, Some(&notional)));
+        assert!(!checkpoint.is_compatible(quote, None));
+        assert!(!checkpoint.is_compatible(quote, Some(&Rational::from(1_000))));
+        assert!(!checkpoint.is_compatible(Address::with_last_byte(2), Some(&notional)));
+    }
+}
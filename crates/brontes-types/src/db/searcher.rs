@@ -12,7 +12,7 @@ use strum::AsRefStr;
 use crate::{
     db::redefined_types::primitives::AddressRedefined,
     implement_table_value_codecs_with_zc,
-    mev::{BundleHeader, MevCount, MevType},
+    mev::{BundleHeader, MevCount, MevCountRedefined, MevType},
     serde_utils::{addresss, option_addresss, vec_address},
 };
 
@@ -24,7 +24,6 @@ pub struct SearcherInfo {
     #[redefined(same_fields)]
     #[serde(default)]
     pub fund:              Fund,
-    #[redefined(same_fields)]
     #[serde(default)]
     pub mev_count:         MevCount,
     #[redefined(same_fields)]
@@ -69,10 +68,17 @@ impl SearcherInfo {
             MevType::CexDexRfq => self.mev_count.cex_dex_rfq_count,
             MevType::JitCexDex => self.mev_count.jit_cex_dex_count,
             MevType::Sandwich => self.mev_count.sandwich_count,
+            MevType::MultiBlockSandwich => self.mev_count.multi_block_sandwich_count,
             MevType::Jit => self.mev_count.jit_count,
             MevType::JitSandwich => self.mev_count.jit_sandwich_count,
             MevType::AtomicArb => self.mev_count.atomic_backrun_count,
             MevType::Liquidation => self.mev_count.liquidation_count,
+            MevType::Custom => self.mev_count.custom_count,
+            MevType::GeneralizedFrontrun => self.mev_count.generalized_frontrun_count,
+            MevType::IntentBackrun => self.mev_count.intent_backrun_count,
+            MevType::NftArb => self.mev_count.nft_arb_count,
+            MevType::Sniping => self.mev_count.sniping_count,
+            MevType::OracleBackrun => self.mev_count.oracle_backrun_count,
             MevType::SearcherTx => self.mev_count.searcher_tx_count,
             MevType::Unknown => None,
         }
@@ -221,7 +227,7 @@ impl TollByType {
                         .add(header.profit_usd),
                 )
             }
-            MevType::Sandwich => {
+            MevType::Sandwich | MevType::MultiBlockSandwich => {
                 self.sandwich = Some(self.sandwich.unwrap_or_default().add(header.profit_usd))
             }
             MevType::AtomicArb => {
@@ -263,7 +269,7 @@ impl TollByType {
                         .add(header.bribe_usd),
                 )
             }
-            MevType::Sandwich => {
+            MevType::Sandwich | MevType::MultiBlockSandwich => {
                 self.sandwich = Some(self.sandwich.unwrap_or_default().add(header.bribe_usd))
             }
             MevType::AtomicArb => {
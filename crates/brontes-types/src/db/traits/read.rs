@@ -1,4 +1,4 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 
 use crate::{
     db::{
@@ -7,11 +7,13 @@ use crate::{
         builder::BuilderInfo,
         cex::trades::CexTradeMap,
         dex::{DexQuoteWithIndex, DexQuotes},
-        metadata::Metadata,
+        metadata::{BlockMetadataInner, Metadata},
         mev_block::MevBlockWithClassified,
+        pricing_checkpoint::PricingGraphCheckpoint,
         searcher::SearcherInfo,
         token_info::TokenInfoWithAddress,
     },
+    mev::DeduplicationStats,
     pair::Pair,
     structured_trace::TxTrace,
     FastHashMap, Protocol,
@@ -103,6 +105,9 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
 
     fn get_metadata(&self, block_num: u64, quote_asset: Address) -> eyre::Result<Metadata>;
 
+    /// returns the block hash, timestamp and relay data stored for the block
+    fn get_block_info(&self, block_num: u64) -> eyre::Result<BlockMetadataInner>;
+
     fn get_cex_trades(&self, block: u64) -> eyre::Result<CexTradeMap>;
 
     fn try_fetch_address_metadata(&self, address: Address)
@@ -146,6 +151,10 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
 
     fn get_protocol_details(&self, address: Address) -> eyre::Result<ProtocolInfo>;
 
+    /// returns the address the Uniswap V4 pool with the given pool id is
+    /// tracked under
+    fn try_fetch_uniswap_v4_pool(&self, pool_id: B256) -> eyre::Result<Address>;
+
     /// returns protocol details with the tokens sorted from smallest to
     /// biggest. This is needed as for some reason the tokens in the
     /// database for a given protocol don't seems to always be ordered
@@ -166,4 +175,24 @@ pub trait LibmdbxReader: Send + Sync + Unpin + 'static {
         start_block: u64,
         end_block: u64,
     ) -> eyre::Result<Vec<(u64, DexQuoteWithIndex)>>;
+
+    /// returns the deduplication stats of the blocks in
+    /// `start_block..end_block`
+    fn fetch_dedup_stats_range(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Vec<DeduplicationStats>>;
+
+    /// returns the latest dex pricing graph checkpoint taken at a block in
+    /// `start_block..end_block`
+    fn try_fetch_pricing_checkpoint(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> eyre::Result<Option<(u64, PricingGraphCheckpoint)>>;
+
+    /// returns the first block in `start_block..end_block` that has no mev
+    /// block stored, or `end_block` if they all do
+    fn first_block_without_mev(&self, start_block: u64, end_block: u64) -> eyre::Result<u64>;
 }
@@ -1,12 +1,12 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use futures::Future;
 
 use crate::{
     db::{
         address_metadata::AddressMetadata, block_analysis::BlockAnalysis, builder::BuilderInfo,
-        dex::DexQuotes, searcher::SearcherInfo,
+        dex::DexQuotes, pricing_checkpoint::PricingGraphCheckpoint, searcher::SearcherInfo,
     },
-    mev::{Bundle, MevBlock},
+    mev::{Bundle, DeduplicationStats, MevBlock},
     normalized_actions::Action,
     structured_trace::TxTrace,
     BlockTree, Protocol,
@@ -34,6 +34,26 @@ pub trait DBWriter: Send + Unpin + 'static {
         self.inner().write_dex_quotes(block_number, quotes)
     }
 
+    fn write_pricing_checkpoint(
+        &self,
+        block_number: u64,
+        checkpoint: PricingGraphCheckpoint,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner()
+            .write_pricing_checkpoint(block_number, checkpoint)
+    }
+
+    /// removes the dex pricing graph checkpoints taken at a block in
+    /// `start_block..end_block`
+    fn prune_pricing_checkpoints(
+        &self,
+        start_block: u64,
+        end_block: u64,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner()
+            .prune_pricing_checkpoints(start_block, end_block)
+    }
+
     fn write_token_info(
         &self,
         address: Address,
@@ -52,6 +72,13 @@ pub trait DBWriter: Send + Unpin + 'static {
         self.inner().save_mev_blocks(block_number, block, mev)
     }
 
+    fn write_dedup_stats(
+        &self,
+        dedup_stats: DeduplicationStats,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().write_dedup_stats(dedup_stats)
+    }
+
     fn write_searcher_info(
         &self,
         eoa_address: Address,
@@ -110,6 +137,14 @@ pub trait DBWriter: Send + Unpin + 'static {
             .insert_pool(block, address, tokens, curve_lp_token, classifier_name)
     }
 
+    fn insert_uniswap_v4_pool(
+        &self,
+        pool_id: B256,
+        address: Address,
+    ) -> impl Future<Output = eyre::Result<()>> + Send {
+        self.inner().insert_uniswap_v4_pool(pool_id, address)
+    }
+
     fn insert_tree(
         &self,
         tree: BlockTree<Action>,
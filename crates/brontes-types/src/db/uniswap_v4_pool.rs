@@ -0,0 +1,15 @@
+use alloy_primitives::Address;
+use redefined::Redefined;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use crate::{db::redefined_types::primitives::*, implement_table_value_codecs_with_zc};
+
+/// The address a Uniswap V4 pool is tracked under. V4 pools don't have an
+/// address, they are identified by the 32 byte id of their pool key and are
+/// given an address derived from it when they are initialized.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Serialize, Deserialize, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct UniswapV4PoolAddress(pub Address);
+
+implement_table_value_codecs_with_zc!(UniswapV4PoolAddressRedefined);
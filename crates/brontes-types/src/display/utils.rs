@@ -105,6 +105,19 @@ pub fn display_sandwich(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result
                 if let Some(gas_details) = victim_gas_details {
                     gas_details.pretty_print_with_spaces(f, 16)?;
                 }
+
+                match sandwich_data.victim_loss_usd.get(k) {
+                    Some(Some(loss_usd)) => writeln!(
+                        f,
+                        "          - {}: ${:.2}",
+                        "Estimated loss".bright_blue(),
+                        loss_usd
+                    )?,
+                    Some(None) => {
+                        writeln!(f, "          - {}: unknown", "Estimated loss".bright_blue())?
+                    }
+                    None => (),
+                }
             }
         }
     }
@@ -554,6 +567,561 @@ pub fn display_liquidation(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Resu
     Ok(())
 }
 
+pub fn display_oracle_backrun(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+          ____  ________      __
+         / __ \|  ____\ \    / /
+        | |  | | |__   \ \  / /
+        | |  | |  __|   \ \/ /
+        | |__| | |____   \  /
+         \____/|______|   \/
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let oracle_backrun_data = match &bundle.data {
+        BundleData::OracleBackrun(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Oracle Update Section
+    writeln!(f, "\n{}\n", "Oracle Update".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&oracle_backrun_data.oracle_update_tx_hash)
+    )?;
+    for oracle in &oracle_backrun_data.oracles {
+        writeln!(f, " - {}: {}", "Oracle".bright_blue(), formate_etherscan_address_url(oracle))?;
+    }
+
+    // Backrun Section
+    writeln!(f, "\n{}\n", "Backrun".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&oracle_backrun_data.backrun_tx_hash)
+    )?;
+    writeln!(
+        f,
+        " - {}: {} transaction(s) after the update",
+        "Latency".bright_blue(),
+        oracle_backrun_data.latency
+    )?;
+
+    if !oracle_backrun_data.liquidations.is_empty() {
+        writeln!(f, "\n{}\n", "Liquidations".bright_yellow().underline())?;
+        for (i, liquidation) in oracle_backrun_data.liquidations.iter().enumerate() {
+            writeln!(f, " - {}:", format!("Liquidation {}", i + 1).bright_blue())?;
+            liquidation.pretty_print(f, 8)?;
+        }
+    }
+
+    if !oracle_backrun_data.swaps.is_empty() {
+        writeln!(f, "\n{}\n", "Swaps".bright_yellow().underline())?;
+        for (i, swap) in oracle_backrun_data.swaps.iter().enumerate() {
+            writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+        }
+    }
+
+    // Gas Details Section
+    writeln!(f, "\n - {}:", "Gas Details".bright_blue())?;
+    oracle_backrun_data
+        .gas_details
+        .pretty_print_with_spaces(f, 8)?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_snipe(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+          _____       _
+         / ____|     (_)
+        | (___  _ __  _ _ __   ___
+         \___ \| '_ \| | '_ \ / _ \
+         ____) | | | | | |_) |  __/
+        |_____/|_| |_|_| .__/ \___|
+                       | |
+                       |_|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let snipe_data = match &bundle.data {
+        BundleData::Snipe(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Snipe Transaction Section
+    writeln!(f, "\n{}\n", "Snipe".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&snipe_data.snipe_tx_hash)
+    )?;
+    for (pool, created) in snipe_data
+        .pools
+        .iter()
+        .zip(snipe_data.pool_creation_blocks.iter())
+    {
+        writeln!(
+            f,
+            " - {}: {} (created in block {})",
+            "Pool".bright_blue(),
+            formate_etherscan_address_url(pool),
+            created
+        )?;
+    }
+    writeln!(f, " - {}: {}", "Marked out at block".bright_blue(), snipe_data.markout_block)?;
+
+    // Swaps Section
+    writeln!(f, "\n{}\n", "Snipe Swaps".bright_yellow().underline())?;
+    for (i, swap) in snipe_data.snipe_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+
+    // Gas Details Section
+    writeln!(f, "\n - {}:", "Gas Details".bright_blue())?;
+    snipe_data.gas_details.pretty_print_with_spaces(f, 8)?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_generalized_frontrun(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+          ______                _
+         |  ____|              | |
+         | |__ _ __ ___  _ __ | |_ _ __ _   _ _ __
+         |  __| '__/ _ \| '_ \| __| '__| | | | '_ \
+         | |  | | | (_) | | | | |_| |  | |_| | | | |
+         |_|  |_|  \___/|_| |_|\__|_|   \__,_|_| |_|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let frontrun_data = match &bundle.data {
+        BundleData::GeneralizedFrontrun(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Frontrun Section
+    writeln!(f, "\n{}\n", "Frontrun".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&frontrun_data.frontrun_tx_hash)
+    )?;
+    for (i, swap) in frontrun_data.frontrun_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+    writeln!(f, "\n - {}:", "Gas Details".bright_blue())?;
+    frontrun_data
+        .frontrun_gas_details
+        .pretty_print_with_spaces(f, 8)?;
+
+    // Victim Section
+    writeln!(f, "\n{}\n", "Copied Transaction".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&frontrun_data.victim_tx_hash)
+    )?;
+    writeln!(f, " - {}: {}", "Reverted".bright_blue(), frontrun_data.victim_reverted)?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Victim Profit (USD)".bright_blue(),
+        format_profit(frontrun_data.victim_profit_usd)
+    )?;
+    for (i, swap) in frontrun_data.victim_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+    for target in &frontrun_data.shared_targets {
+        writeln!(
+            f,
+            " - {}: {}",
+            "Shared Target".bright_blue(),
+            formate_etherscan_address_url(target)
+        )?;
+    }
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_intent_backrun(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+          _____       _             _
+         |_   _|     | |           | |
+           | |  _ __ | |_ ___ _ __ | |_
+           | | | '_ \| __/ _ \ '_ \| __|
+          _| |_| | | | ||  __/ | | | |_
+         |_____|_| |_|\__\___|_| |_|\__|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let backrun_data = match &bundle.data {
+        BundleData::IntentBackrun(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Settlement Section
+    writeln!(f, "\n{}\n", "Settlement".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&backrun_data.settlement_tx_hash)
+    )?;
+    writeln!(f, " - {}: {}", "Protocol".bright_blue(), backrun_data.settlement_protocol)?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Solver".bright_blue(),
+        formate_etherscan_address_url(&backrun_data.solver)
+    )?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Filler Profit (USD)".bright_blue(),
+        format_profit(backrun_data.filler_profit_usd)
+    )?;
+    for (i, swap) in backrun_data.solver_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+
+    // Backrun Section
+    writeln!(f, "\n{}\n", "Backrun".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&backrun_data.backrun_tx_hash)
+    )?;
+    for (i, swap) in backrun_data.backrun_swaps.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), swap)?;
+    }
+    writeln!(f, "\n - {}:", "Gas Details".bright_blue())?;
+    backrun_data
+        .backrun_gas_details
+        .pretty_print_with_spaces(f, 8)?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_nft_arb(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let ascii_header = indoc! {r#"
+
+          _   _ ______ _______
+         | \ | |  ____|__   __|
+         |  \| | |__     | |
+         | . ` |  __|    | |
+         | |\  | |       | |
+         |_| \_|_|       |_|
+
+    "#};
+
+    for line in ascii_header.lines() {
+        writeln!(f, "{}", line.bright_red())?;
+    }
+
+    let nft_arb_data = match &bundle.data {
+        BundleData::NftArb(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    // Arb Transaction Section
+    writeln!(f, "\n{}\n", "NFT Arb".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Transaction".bright_blue(),
+        format_etherscan_url(&nft_arb_data.tx_hash)
+    )?;
+
+    // Trades Section
+    writeln!(f, "\n{}\n", "Buys".bright_yellow().underline())?;
+    for (i, trade) in nft_arb_data.buys.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), trade)?;
+    }
+
+    writeln!(f, "\n{}\n", "Sells".bright_yellow().underline())?;
+    for (i, trade) in nft_arb_data.sells.iter().enumerate() {
+        writeln!(f, "    {}: {}", format!(" - {}", i + 1).green(), trade)?;
+    }
+
+    // Gas Details Section
+    writeln!(f, "\n - {}:", "Gas Details".bright_blue())?;
+    nft_arb_data.gas_details.pretty_print_with_spaces(f, 8)?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
+pub fn display_custom_mev(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
+    let custom_data = match &bundle.data {
+        BundleData::Custom(data) => data,
+        _ => panic!("Wrong bundle type"),
+    };
+
+    writeln!(f, "{} {}", "Custom Mev:".bold().bright_red(), custom_data.inspector.bold())?;
+
+    // MEV Bot Details
+    writeln!(f, "\n{}: \n", "Transaction Details".bold().underline().bright_yellow())?;
+    writeln!(f, "   - EOA: {}", bundle.header.eoa)?;
+
+    match bundle.header.mev_contract {
+        Some(contract) => {
+            writeln!(f, "   - Mev Contract: {}", contract)?;
+        }
+        None => {
+            writeln!(f, "   - Mev Contract: None")?;
+        }
+    }
+
+    writeln!(f, "\n{}\n", "Transactions".bright_yellow().underline())?;
+    for (tx_hash, gas_details) in custom_data
+        .tx_hashes
+        .iter()
+        .zip(custom_data.gas_details.iter())
+    {
+        writeln!(f, " - {}: {}", "Transaction".bright_blue(), format_etherscan_url(tx_hash))?;
+        gas_details.pretty_print_with_spaces(f, 8)?;
+    }
+    writeln!(f, "\n - {}: {}", "Data".bright_blue(), custom_data.data)?;
+
+    // Profitability Section
+    writeln!(f, "\n{}\n", "Profitability".bright_yellow().underline())?;
+    writeln!(
+        f,
+        " - {}: {}",
+        "Bundle Profit (USD)".bright_white(),
+        format_profit(bundle.header.profit_usd)
+            .to_string()
+            .bright_white()
+    )?;
+    writeln!(
+        f,
+        " - {}: {}\n",
+        "Bribe (USD)".bright_white(),
+        format_bribe(bundle.header.bribe_usd)
+            .to_string()
+            .bright_red()
+    )?;
+
+    bundle
+        .header
+        .balance_deltas
+        .iter()
+        .for_each(|tx_delta| writeln!(f, "{}", tx_delta).expect("Failed to write balance deltas"));
+    Ok(())
+}
+
 pub fn display_jit_liquidity(bundle: &Bundle, f: &mut fmt::Formatter) -> fmt::Result {
     let ascii_header = indoc! {r#"
 
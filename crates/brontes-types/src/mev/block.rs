@@ -188,17 +188,24 @@ fn format_profit(value: f64) -> String {
 #[serde_as]
 #[derive(Debug, Deserialize, PartialEq, Serialize, Row, Clone, Default, rDeser, rSer, Archive)]
 pub struct MevCount {
-    pub bundle_count:         u64,
-    pub sandwich_count:       Option<u64>,
-    pub cex_dex_trade_count:  Option<u64>,
-    pub cex_dex_quote_count:  Option<u64>,
-    pub cex_dex_rfq_count:    Option<u64>,
-    pub jit_cex_dex_count:    Option<u64>,
-    pub jit_count:            Option<u64>,
-    pub jit_sandwich_count:   Option<u64>,
-    pub atomic_backrun_count: Option<u64>,
-    pub liquidation_count:    Option<u64>,
-    pub searcher_tx_count:    Option<u64>,
+    pub bundle_count:               u64,
+    pub sandwich_count:             Option<u64>,
+    pub cex_dex_trade_count:        Option<u64>,
+    pub cex_dex_quote_count:        Option<u64>,
+    pub cex_dex_rfq_count:          Option<u64>,
+    pub jit_cex_dex_count:          Option<u64>,
+    pub jit_count:                  Option<u64>,
+    pub jit_sandwich_count:         Option<u64>,
+    pub atomic_backrun_count:       Option<u64>,
+    pub liquidation_count:          Option<u64>,
+    pub searcher_tx_count:          Option<u64>,
+    pub multi_block_sandwich_count: Option<u64>,
+    pub oracle_backrun_count:       Option<u64>,
+    pub sniping_count:              Option<u64>,
+    pub generalized_frontrun_count: Option<u64>,
+    pub intent_backrun_count:       Option<u64>,
+    pub custom_count:               Option<u64>,
+    pub nft_arb_count:              Option<u64>,
 }
 
 impl MevCount {
@@ -214,6 +221,10 @@ impl MevCount {
             MevType::Sandwich => {
                 self.sandwich_count = Some(self.sandwich_count.unwrap_or_default().add(1))
             }
+            MevType::MultiBlockSandwich => {
+                self.multi_block_sandwich_count =
+                    Some(self.multi_block_sandwich_count.unwrap_or_default().add(1))
+            }
             MevType::AtomicArb => {
                 self.atomic_backrun_count =
                     Some(self.atomic_backrun_count.unwrap_or_default().add(1))
@@ -225,6 +236,27 @@ impl MevCount {
             MevType::Liquidation => {
                 self.liquidation_count = Some(self.liquidation_count.unwrap_or_default().add(1))
             }
+            MevType::OracleBackrun => {
+                self.oracle_backrun_count =
+                    Some(self.oracle_backrun_count.unwrap_or_default().add(1))
+            }
+            MevType::Sniping => {
+                self.sniping_count = Some(self.sniping_count.unwrap_or_default().add(1))
+            }
+            MevType::GeneralizedFrontrun => {
+                self.generalized_frontrun_count =
+                    Some(self.generalized_frontrun_count.unwrap_or_default().add(1))
+            }
+            MevType::IntentBackrun => {
+                self.intent_backrun_count =
+                    Some(self.intent_backrun_count.unwrap_or_default().add(1))
+            }
+            MevType::NftArb => {
+                self.nft_arb_count = Some(self.nft_arb_count.unwrap_or_default().add(1))
+            }
+            MevType::Custom => {
+                self.custom_count = Some(self.custom_count.unwrap_or_default().add(1))
+            }
             MevType::SearcherTx => {
                 self.searcher_tx_count = Some(self.searcher_tx_count.unwrap_or_default().add(1))
             }
@@ -237,6 +269,45 @@ impl MevCount {
 }
 self_convert_redefined!(MevCount);
 
+/// The layout mev counts are stored with in the searcher tables. It is kept to
+/// the counts searchers were first stored with so that the stored searchers
+/// stay readable, the counts of the mev types added since read back as `None`.
+#[derive(Debug, PartialEq, Clone, Serialize, rSer, rDeser, Archive, Redefined)]
+#[redefined(MevCount)]
+#[redefined_attr(to_source = "self.into_count()")]
+pub struct MevCountRedefined {
+    pub bundle_count:         u64,
+    pub sandwich_count:       Option<u64>,
+    pub cex_dex_trade_count:  Option<u64>,
+    pub cex_dex_quote_count:  Option<u64>,
+    pub cex_dex_rfq_count:    Option<u64>,
+    pub jit_cex_dex_count:    Option<u64>,
+    pub jit_count:            Option<u64>,
+    pub jit_sandwich_count:   Option<u64>,
+    pub atomic_backrun_count: Option<u64>,
+    pub liquidation_count:    Option<u64>,
+    pub searcher_tx_count:    Option<u64>,
+}
+
+impl MevCountRedefined {
+    fn into_count(self) -> MevCount {
+        MevCount {
+            bundle_count: self.bundle_count,
+            sandwich_count: self.sandwich_count,
+            cex_dex_trade_count: self.cex_dex_trade_count,
+            cex_dex_quote_count: self.cex_dex_quote_count,
+            cex_dex_rfq_count: self.cex_dex_rfq_count,
+            jit_cex_dex_count: self.jit_cex_dex_count,
+            jit_count: self.jit_count,
+            jit_sandwich_count: self.jit_sandwich_count,
+            atomic_backrun_count: self.atomic_backrun_count,
+            liquidation_count: self.liquidation_count,
+            searcher_tx_count: self.searcher_tx_count,
+            ..Default::default()
+        }
+    }
+}
+
 impl fmt::Display for MevCount {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "  - MEV Count: {}", self.bundle_count.to_string().bold())?;
@@ -244,6 +315,9 @@ impl fmt::Display for MevCount {
         if let Some(count) = self.sandwich_count {
             writeln!(f, "    - Sandwich: {}", count.to_string().bold())?;
         }
+        if let Some(count) = self.multi_block_sandwich_count {
+            writeln!(f, "    - Multi Block Sandwich: {}", count.to_string().bold())?;
+        }
         if let Some(count) = self.cex_dex_trade_count {
             writeln!(f, "    - Trade Cex-Dex: {}", count.to_string().bold())?;
         }
@@ -265,6 +339,24 @@ impl fmt::Display for MevCount {
         if let Some(count) = self.liquidation_count {
             writeln!(f, "    - Liquidation: {}", count.to_string().bold())?;
         }
+        if let Some(count) = self.oracle_backrun_count {
+            writeln!(f, "    - Oracle Backrun: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.sniping_count {
+            writeln!(f, "    - Sniping: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.generalized_frontrun_count {
+            writeln!(f, "    - Generalized Frontrun: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.intent_backrun_count {
+            writeln!(f, "    - Intent Backrun: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.nft_arb_count {
+            writeln!(f, "    - NFT Arb: {}", count.to_string().bold())?;
+        }
+        if let Some(count) = self.custom_count {
+            writeln!(f, "    - Custom: {}", count.to_string().bold())?;
+        }
         if let Some(count) = self.searcher_tx_count {
             writeln!(f, "    - Searcher TXs: {}", count.to_string().bold())?;
         }
@@ -368,6 +460,13 @@ impl Serialize for MevBlock {
             "mev_count.sandwich_count",
             &vec![self.mev_count.sandwich_count.unwrap_or_default()],
         )?;
+        ser_struct.serialize_field(
+            "mev_count.multi_block_sandwich_count",
+            &vec![self
+                .mev_count
+                .multi_block_sandwich_count
+                .unwrap_or_default()],
+        )?;
         ser_struct.serialize_field(
             "mev_count.cex_dex_trade_count",
             &vec![self.mev_count.cex_dex_trade_count.unwrap_or_default()],
@@ -396,6 +495,33 @@ impl Serialize for MevBlock {
             "mev_count.liquidation_count",
             &vec![self.mev_count.liquidation_count.unwrap_or_default()],
         )?;
+        ser_struct.serialize_field(
+            "mev_count.oracle_backrun_count",
+            &vec![self.mev_count.oracle_backrun_count.unwrap_or_default()],
+        )?;
+        ser_struct.serialize_field(
+            "mev_count.sniping_count",
+            &vec![self.mev_count.sniping_count.unwrap_or_default()],
+        )?;
+        ser_struct.serialize_field(
+            "mev_count.generalized_frontrun_count",
+            &vec![self
+                .mev_count
+                .generalized_frontrun_count
+                .unwrap_or_default()],
+        )?;
+        ser_struct.serialize_field(
+            "mev_count.intent_backrun_count",
+            &vec![self.mev_count.intent_backrun_count.unwrap_or_default()],
+        )?;
+        ser_struct.serialize_field(
+            "mev_count.custom_count",
+            &vec![self.mev_count.custom_count.unwrap_or_default()],
+        )?;
+        ser_struct.serialize_field(
+            "mev_count.nft_arb_count",
+            &vec![self.mev_count.nft_arb_count.unwrap_or_default()],
+        )?;
 
         ser_struct.serialize_field("eth_price", &self.eth_price)?;
         ser_struct.serialize_field("total_gas_used", &self.total_gas_used)?;
@@ -508,6 +634,7 @@ impl DbRow for MevBlock {
         "block_number",
         "mev_count.mev_count",
         "mev_count.sandwich_count",
+        "mev_count.multi_block_sandwich_count",
         "mev_count.cex_dex_quote_count",
         "mev_count.cex_dex_trade_count",
         "mev_count.cex_dex_rfq_count",
@@ -515,6 +642,12 @@ impl DbRow for MevBlock {
         "mev_count.jit_sandwich_count",
         "mev_count.atomic_backrun_count",
         "mev_count.liquidation_count",
+        "mev_count.oracle_backrun_count",
+        "mev_count.sniping_count",
+        "mev_count.generalized_frontrun_count",
+        "mev_count.intent_backrun_count",
+        "mev_count.custom_count",
+        "mev_count.nft_arb_count",
         "eth_price",
         "total_gas_used",
         "total_priority_fee",
@@ -32,6 +32,12 @@ pub enum BundleData {
     CexDex(CexDex),
     Liquidation(Liquidation),
     Unknown(SearcherTx),
+    OracleBackrun(OracleBackrun),
+    Snipe(Snipe),
+    GeneralizedFrontrun(GeneralizedFrontrun),
+    IntentBackrun(IntentBackrun),
+    Custom(CustomMev),
+    NftArb(NftArb),
 }
 
 impl Default for BundleData {
@@ -50,6 +56,12 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_type(),
             BundleData::CexDexQuote(m) => m.mev_type(),
             BundleData::Liquidation(m) => m.mev_type(),
+            BundleData::OracleBackrun(m) => m.mev_type(),
+            BundleData::Snipe(m) => m.mev_type(),
+            BundleData::GeneralizedFrontrun(m) => m.mev_type(),
+            BundleData::IntentBackrun(m) => m.mev_type(),
+            BundleData::NftArb(m) => m.mev_type(),
+            BundleData::Custom(m) => m.mev_type(),
             BundleData::Unknown(m) => m.mev_type(),
         }
     }
@@ -63,6 +75,12 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_gas_paid(),
             BundleData::CexDexQuote(m) => m.total_gas_paid(),
             BundleData::Liquidation(m) => m.total_gas_paid(),
+            BundleData::OracleBackrun(m) => m.total_gas_paid(),
+            BundleData::Snipe(m) => m.total_gas_paid(),
+            BundleData::GeneralizedFrontrun(m) => m.total_gas_paid(),
+            BundleData::IntentBackrun(m) => m.total_gas_paid(),
+            BundleData::NftArb(m) => m.total_gas_paid(),
+            BundleData::Custom(m) => m.total_gas_paid(),
             BundleData::Unknown(s) => s.total_gas_paid(),
         }
     }
@@ -76,6 +94,12 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.total_priority_fee_paid(base_fee),
             BundleData::CexDexQuote(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Liquidation(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::OracleBackrun(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::Snipe(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::GeneralizedFrontrun(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::IntentBackrun(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::NftArb(m) => m.total_priority_fee_paid(base_fee),
+            BundleData::Custom(m) => m.total_priority_fee_paid(base_fee),
             BundleData::Unknown(s) => s.total_priority_fee_paid(base_fee),
         }
     }
@@ -89,6 +113,12 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.bribe(),
             BundleData::CexDexQuote(m) => m.bribe(),
             BundleData::Liquidation(m) => m.bribe(),
+            BundleData::OracleBackrun(m) => m.bribe(),
+            BundleData::Snipe(m) => m.bribe(),
+            BundleData::GeneralizedFrontrun(m) => m.bribe(),
+            BundleData::IntentBackrun(m) => m.bribe(),
+            BundleData::NftArb(m) => m.bribe(),
+            BundleData::Custom(m) => m.bribe(),
             BundleData::Unknown(s) => s.bribe(),
         }
     }
@@ -102,6 +132,12 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.mev_transaction_hashes(),
             BundleData::CexDexQuote(m) => m.mev_transaction_hashes(),
             BundleData::Liquidation(m) => m.mev_transaction_hashes(),
+            BundleData::OracleBackrun(m) => m.mev_transaction_hashes(),
+            BundleData::Snipe(m) => m.mev_transaction_hashes(),
+            BundleData::GeneralizedFrontrun(m) => m.mev_transaction_hashes(),
+            BundleData::IntentBackrun(m) => m.mev_transaction_hashes(),
+            BundleData::NftArb(m) => m.mev_transaction_hashes(),
+            BundleData::Custom(m) => m.mev_transaction_hashes(),
             BundleData::Unknown(s) => s.mev_transaction_hashes(),
         }
     }
@@ -115,6 +151,12 @@ impl Mev for BundleData {
             BundleData::CexDex(m) => m.protocols(),
             BundleData::CexDexQuote(m) => m.protocols(),
             BundleData::Liquidation(m) => m.protocols(),
+            BundleData::OracleBackrun(m) => m.protocols(),
+            BundleData::Snipe(m) => m.protocols(),
+            BundleData::GeneralizedFrontrun(m) => m.protocols(),
+            BundleData::IntentBackrun(m) => m.protocols(),
+            BundleData::NftArb(m) => m.protocols(),
+            BundleData::Custom(m) => m.protocols(),
             BundleData::Unknown(s) => s.protocols(),
         }
     }
@@ -162,6 +204,42 @@ impl From<Liquidation> for BundleData {
     }
 }
 
+impl From<OracleBackrun> for BundleData {
+    fn from(value: OracleBackrun) -> Self {
+        Self::OracleBackrun(value)
+    }
+}
+
+impl From<Snipe> for BundleData {
+    fn from(value: Snipe) -> Self {
+        Self::Snipe(value)
+    }
+}
+
+impl From<GeneralizedFrontrun> for BundleData {
+    fn from(value: GeneralizedFrontrun) -> Self {
+        Self::GeneralizedFrontrun(value)
+    }
+}
+
+impl From<IntentBackrun> for BundleData {
+    fn from(value: IntentBackrun) -> Self {
+        Self::IntentBackrun(value)
+    }
+}
+
+impl From<NftArb> for BundleData {
+    fn from(value: NftArb) -> Self {
+        Self::NftArb(value)
+    }
+}
+
+impl From<CustomMev> for BundleData {
+    fn from(value: CustomMev) -> Self {
+        Self::Custom(value)
+    }
+}
+
 impl Serialize for BundleData {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
@@ -175,6 +253,14 @@ impl Serialize for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.serialize(serializer),
             BundleData::CexDexQuote(cex_dex) => cex_dex.serialize(serializer),
             BundleData::Liquidation(liquidation) => liquidation.serialize(serializer),
+            BundleData::OracleBackrun(oracle_backrun) => oracle_backrun.serialize(serializer),
+            BundleData::Snipe(snipe) => snipe.serialize(serializer),
+            BundleData::GeneralizedFrontrun(generalized_frontrun) => {
+                generalized_frontrun.serialize(serializer)
+            }
+            BundleData::IntentBackrun(intent_backrun) => intent_backrun.serialize(serializer),
+            BundleData::NftArb(nft_arb) => nft_arb.serialize(serializer),
+            BundleData::Custom(custom_mev) => custom_mev.serialize(serializer),
             BundleData::Unknown(s) => s.serialize(serializer),
         }
     }
@@ -190,6 +276,14 @@ impl InsertRow for BundleData {
             BundleData::CexDex(cex_dex) => cex_dex.get_column_names(),
             BundleData::CexDexQuote(cex_dex) => cex_dex.get_column_names(),
             BundleData::Liquidation(liquidation) => liquidation.get_column_names(),
+            BundleData::OracleBackrun(oracle_backrun) => oracle_backrun.get_column_names(),
+            BundleData::Snipe(snipe) => snipe.get_column_names(),
+            BundleData::GeneralizedFrontrun(generalized_frontrun) => {
+                generalized_frontrun.get_column_names()
+            }
+            BundleData::IntentBackrun(intent_backrun) => intent_backrun.get_column_names(),
+            BundleData::NftArb(nft_arb) => nft_arb.get_column_names(),
+            BundleData::Custom(custom_mev) => custom_mev.get_column_names(),
             BundleData::Unknown(s) => s.get_column_names(),
         }
     }
@@ -4,7 +4,7 @@ use alloy_primitives::Address;
 use clickhouse::{DbRow, Row};
 use colored::Colorize;
 use itertools::Itertools;
-use redefined::Redefined;
+use redefined::{Redefined, RedefinedConvert};
 use reth_primitives::B256;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{ser::SerializeStruct, Deserialize, Serialize};
@@ -27,8 +27,7 @@ use crate::{
 };
 
 #[serde_as]
-#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
-#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default)]
 pub struct BundleHeader {
     pub block_number: u64,
 
@@ -40,19 +39,62 @@ pub struct BundleHeader {
     pub eoa:                   Address,
     #[serde(with = "option_addresss")]
     pub mev_contract:          Option<Address>,
-    #[redefined(same_fields)]
     #[serde(default)]
     pub fund:                  Fund,
     pub profit_usd:            f64,
+    /// the uncertainty of `profit_usd` from the spread between the dex paths
+    /// the tokens were priced over. Only set by the inspectors that price
+    /// their profit on single transaction dex quotes when path weighted
+    /// pricing is used. It isn't stored in libmdbx.
+    #[serde(default)]
+    pub profit_usd_spread:     Option<f64>,
     // Total tx cost in USD
     pub bribe_usd:             f64,
-    #[redefined(same_fields)]
     pub mev_type:              MevType,
     // if we generated this arb without pricing
     pub no_pricing_calculated: bool,
     pub balance_deltas:        Vec<TransactionAccounting>,
 }
 
+/// The layout bundle headers are stored with in libmdbx. It is kept to the
+/// fields headers were first stored with so that the stored bundles stay
+/// readable, the profit spread is only exported to clickhouse.
+#[derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive, Redefined)]
+#[redefined(BundleHeader)]
+#[redefined_attr(to_source = "self.into_header()")]
+pub struct BundleHeaderRedefined {
+    pub block_number:          u64,
+    pub tx_index:              u64,
+    pub tx_hash:               B256Redefined,
+    pub eoa:                   AddressRedefined,
+    pub mev_contract:          Option<AddressRedefined>,
+    pub fund:                  Fund,
+    pub profit_usd:            f64,
+    pub bribe_usd:             f64,
+    pub mev_type:              MevType,
+    pub no_pricing_calculated: bool,
+    pub balance_deltas:        Vec<TransactionAccountingRedefined>,
+}
+
+impl BundleHeaderRedefined {
+    fn into_header(self) -> BundleHeader {
+        BundleHeader {
+            block_number:          self.block_number,
+            tx_index:              self.tx_index,
+            tx_hash:               self.tx_hash.to_source(),
+            eoa:                   self.eoa.to_source(),
+            mev_contract:          self.mev_contract.to_source(),
+            fund:                  self.fund,
+            profit_usd:            self.profit_usd,
+            profit_usd_spread:     None,
+            bribe_usd:             self.bribe_usd,
+            mev_type:              self.mev_type,
+            no_pricing_calculated: self.no_pricing_calculated,
+            balance_deltas:        self.balance_deltas.to_source(),
+        }
+    }
+}
+
 #[serde_as]
 #[derive(Debug, Deserialize, Row, PartialEq, Clone, Default, Serialize, Redefined)]
 #[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
@@ -124,7 +166,7 @@ impl Serialize for BundleHeader {
     where
         S: serde::Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("BundleHeader", 12)?;
+        let mut ser_struct = serializer.serialize_struct("BundleHeader", 13)?;
 
         ser_struct.serialize_field("block_number", &self.block_number)?;
         ser_struct.serialize_field("tx_index", &self.tx_index)?;
@@ -134,6 +176,7 @@ impl Serialize for BundleHeader {
             .serialize_field("mev_contract", &self.mev_contract.map(|a| format!("{:?}", a)))?;
         ser_struct.serialize_field("fund", &self.fund)?;
         ser_struct.serialize_field("profit_usd", &self.profit_usd)?;
+        ser_struct.serialize_field("profit_usd_spread", &self.profit_usd_spread)?;
         ser_struct.serialize_field("bribe_usd", &self.bribe_usd)?;
         ser_struct.serialize_field("mev_type", &self.mev_type)?;
         ser_struct.serialize_field("no_pricing_calculated", &self.no_pricing_calculated)?;
@@ -206,6 +249,7 @@ impl DbRow for BundleHeader {
         "mev_contract",
         "fund",
         "profit_usd",
+        "profit_usd_spread",
         "bribe_usd",
         "mev_type",
         "no_pricing_calculated",
@@ -48,12 +48,31 @@ impl Bundle {
     pub fn mev_type(&self) -> MevType {
         self.header.mev_type
     }
+
+    /// The name of the inspector that found the bundle, if it was found by an
+    /// inspector registered outside of brontes
+    pub fn custom_inspector(&self) -> Option<&str> {
+        match &self.data {
+            BundleData::Custom(data) => Some(&data.inspector),
+            _ => None,
+        }
+    }
+
+    /// The tx of the bundle this bundle is linked to. A linked bundle covers
+    /// the same transaction as the bundle it is linked to, so it isn't
+    /// counted again in the block totals
+    pub fn linked_tx_hash(&self) -> Option<B256> {
+        match &self.data {
+            BundleData::OracleBackrun(data) => data.liquidation_tx_hash,
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Display for Bundle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self.header.mev_type {
-            MevType::Sandwich => display_sandwich(self, f)?,
+            MevType::Sandwich | MevType::MultiBlockSandwich => display_sandwich(self, f)?,
             MevType::CexDexTrades | MevType::JitCexDex => display_cex_dex(self, f)?,
             MevType::CexDexQuotes => display_cex_dex_quotes(self, f)?,
             MevType::CexDexRfq => {
@@ -66,6 +85,12 @@ impl fmt::Display for Bundle {
             MevType::Jit => display_jit_liquidity(self, f)?,
             MevType::AtomicArb => display_atomic_backrun(self, f)?,
             MevType::Liquidation => display_liquidation(self, f)?,
+            MevType::OracleBackrun => display_oracle_backrun(self, f)?,
+            MevType::Sniping => display_snipe(self, f)?,
+            MevType::GeneralizedFrontrun => display_generalized_frontrun(self, f)?,
+            MevType::IntentBackrun => display_intent_backrun(self, f)?,
+            MevType::NftArb => display_nft_arb(self, f)?,
+            MevType::Custom => display_custom_mev(self, f)?,
             MevType::JitSandwich => display_jit_liquidity_sandwich(self, f)?,
             MevType::SearcherTx => display_searcher_tx(self, f)?,
             MevType::Unknown => (),
@@ -104,16 +129,30 @@ pub enum MevType {
     SearcherTx,
     #[default]
     Unknown,
+    MultiBlockSandwich,
+    OracleBackrun,
+    Sniping,
+    GeneralizedFrontrun,
+    IntentBackrun,
+    Custom,
+    NftArb,
 }
 
 impl MevType {
     pub fn use_cex_pricing_for_deltas(&self) -> bool {
         match self {
             MevType::Sandwich
+            | MevType::MultiBlockSandwich
             | MevType::JitSandwich
             | MevType::Jit
             | MevType::AtomicArb
             | MevType::Liquidation
+            | MevType::Custom
+            | MevType::IntentBackrun
+            | MevType::NftArb
+            | MevType::GeneralizedFrontrun
+            | MevType::Sniping
+            | MevType::OracleBackrun
             | MevType::SearcherTx
             | MevType::Unknown => false,
             MevType::CexDexRfq
@@ -131,10 +170,16 @@ impl MevType {
             | MevType::CexDexTrades => "cex-dex",
             MevType::AtomicArb => "atomic-arb",
             MevType::Jit => "jit",
-            MevType::Sandwich => "sandwich",
+            MevType::Sandwich | MevType::MultiBlockSandwich => "sandwich",
             MevType::JitSandwich => "jit-sandwich",
             MevType::SearcherTx => "searcher-tx",
             MevType::Liquidation => "liquidation",
+            MevType::OracleBackrun => "oracle-backrun",
+            MevType::Sniping => "sniping",
+            MevType::GeneralizedFrontrun => "generalized-frontrun",
+            MevType::IntentBackrun => "intent-backrun",
+            MevType::NftArb => "nft-arb",
+            MevType::Custom => "custom",
             MevType::Unknown => "header",
         }
     }
@@ -149,10 +194,17 @@ impl From<String> for MevType {
             "CexDexTrades" => MevType::CexDexTrades,
             "CexDexRfq" => MevType::CexDexRfq,
             "Sandwich" => MevType::Sandwich,
+            "MultiBlockSandwich" => MevType::MultiBlockSandwich,
             "Jit" => MevType::Jit,
             "Liquidation" => MevType::Liquidation,
             "JitSandwich" => MevType::JitSandwich,
             "AtomicArb" => MevType::AtomicArb,
+            "OracleBackrun" => MevType::OracleBackrun,
+            "Sniping" => MevType::Sniping,
+            "GeneralizedFrontrun" => MevType::GeneralizedFrontrun,
+            "IntentBackrun" => MevType::IntentBackrun,
+            "NftArb" => MevType::NftArb,
+            "Custom" => MevType::Custom,
             "SearcherTx" => MevType::SearcherTx,
             _ => MevType::Unknown,
         }
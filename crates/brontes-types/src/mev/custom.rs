@@ -0,0 +1,115 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, ClickhouseVecGasDetails, Protocol};
+#[allow(unused_imports)]
+use crate::{display::utils::display_sandwich, normalized_actions::*, GasDetails};
+
+/// A bundle found by an inspector that was registered from outside of brontes.
+/// As brontes doesn't know the shape of the data these inspectors produce, it
+/// is stored as json alongside what is needed to account for the bundle.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct CustomMev {
+    pub block_number: u64,
+    /// the name the inspector that found the bundle was registered under
+    pub inspector:    String,
+    pub tx_hashes:    Vec<B256>,
+    /// gas details of each of the transactions in `tx_hashes`
+    #[redefined(same_fields)]
+    pub gas_details:  Vec<GasDetails>,
+    #[redefined(same_fields)]
+    pub protocols:    Vec<Protocol>,
+    /// the inspector specific data, serialized as json
+    pub data:         String,
+}
+
+impl Mev for CustomMev {
+    fn mev_type(&self) -> MevType {
+        MevType::Custom
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        self.tx_hashes.clone()
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.iter().map(|gd| gd.gas_paid()).sum()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details
+            .iter()
+            .map(|gd| gd.priority_fee_paid(base_fee))
+            .sum()
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details
+            .iter()
+            .filter_map(|gd| gd.coinbase_transfer)
+            .sum()
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.protocols.iter().copied().collect()
+    }
+}
+
+impl Serialize for CustomMev {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("CustomMev", 9)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("inspector", &self.inspector)?;
+
+        let gas_details: ClickhouseVecGasDetails =
+            (self.tx_hashes.clone(), self.gas_details.clone()).into();
+        ser_struct.serialize_field("gas_details.tx_hash", &gas_details.tx_hash)?;
+        ser_struct
+            .serialize_field("gas_details.coinbase_transfer", &gas_details.coinbase_transfer)?;
+        ser_struct.serialize_field("gas_details.priority_fee", &gas_details.priority_fee)?;
+        ser_struct.serialize_field("gas_details.gas_used", &gas_details.gas_used)?;
+        ser_struct
+            .serialize_field("gas_details.effective_gas_price", &gas_details.effective_gas_price)?;
+
+        ser_struct.serialize_field(
+            "protocols",
+            &self
+                .protocols
+                .iter()
+                .map(|protocol| protocol.to_string())
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field("data", &self.data)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for CustomMev {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "inspector",
+        "gas_details.tx_hash",
+        "gas_details.coinbase_transfer",
+        "gas_details.priority_fee",
+        "gas_details.gas_used",
+        "gas_details.effective_gas_price",
+        "protocols",
+        "data",
+    ];
+}
@@ -0,0 +1,153 @@
+use clickhouse::DbRow;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{ser::SerializeStruct, Deserialize, Serialize};
+
+use super::{Bundle, MevType};
+use crate::{
+    db::redefined_types::primitives::*, implement_table_value_codecs_with_zc, FastHashMap,
+};
+
+/// A bundle that a precedence rule removed in favour of an overlapping
+/// dominant bundle
+#[derive(Debug, Deserialize, PartialEq, Clone, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct SuppressedBundle {
+    pub block_number:      u64,
+    pub tx_hash:           B256,
+    #[redefined(same_fields)]
+    pub mev_type:          MevType,
+    /// the name of the inspector that found the bundle, for custom bundles
+    pub inspector:         Option<String>,
+    pub profit_usd:        f64,
+    pub bribe_usd:         f64,
+    /// the bundle that was kept. If several dominant bundles overlapped with
+    /// the suppressed bundle, this is the first one the rule was applied with
+    pub dominant_tx_hash:  B256,
+    #[redefined(same_fields)]
+    pub dominant_mev_type: MevType,
+}
+
+impl SuppressedBundle {
+    pub fn new(suppressed: &Bundle, dominant: &Bundle) -> Self {
+        Self {
+            block_number:      suppressed.header.block_number,
+            tx_hash:           suppressed.header.tx_hash,
+            mev_type:          suppressed.header.mev_type,
+            inspector:         suppressed.custom_inspector().map(str::to_string),
+            profit_usd:        suppressed.header.profit_usd,
+            bribe_usd:         suppressed.header.bribe_usd,
+            dominant_tx_hash:  dominant.header.tx_hash,
+            dominant_mev_type: dominant.header.mev_type,
+        }
+    }
+}
+
+impl Serialize for SuppressedBundle {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("SuppressedBundle", 8)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("tx_hash", &format!("{:?}", &self.tx_hash))?;
+        ser_struct.serialize_field("mev_type", &self.mev_type)?;
+        ser_struct.serialize_field("inspector", &self.inspector)?;
+        ser_struct.serialize_field("profit_usd", &self.profit_usd)?;
+        ser_struct.serialize_field("bribe_usd", &self.bribe_usd)?;
+        ser_struct.serialize_field("dominant_tx_hash", &format!("{:?}", &self.dominant_tx_hash))?;
+        ser_struct.serialize_field("dominant_mev_type", &self.dominant_mev_type)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for SuppressedBundle {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "tx_hash",
+        "mev_type",
+        "inspector",
+        "profit_usd",
+        "bribe_usd",
+        "dominant_tx_hash",
+        "dominant_mev_type",
+    ];
+}
+
+/// How deduplication changed the bundles of a block
+#[derive(Debug, Default, Deserialize, PartialEq, Clone, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct DeduplicationStats {
+    pub block_number:      u64,
+    /// the number of bundles the inspectors found
+    pub inspected_bundles: u64,
+    /// the number of bundles left after composition and deduplication
+    pub reported_bundles:  u64,
+    pub suppressed:        Vec<SuppressedBundle>,
+}
+
+/// The bundles one precedence rule suppressed in a block
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SuppressionStats {
+    pub count:      usize,
+    pub profit_usd: f64,
+}
+
+impl DeduplicationStats {
+    pub fn total_suppressed(&self) -> usize {
+        self.suppressed.len()
+    }
+
+    pub fn total_suppressed_profit_usd(&self) -> f64 {
+        self.suppressed.iter().map(|bundle| bundle.profit_usd).sum()
+    }
+
+    /// The suppressed bundles keyed by the mev type of the dominant bundle and
+    /// the mev type of the bundle it suppressed
+    pub fn by_precedence(&self) -> FastHashMap<(MevType, MevType), SuppressionStats> {
+        let mut stats = FastHashMap::<_, SuppressionStats>::default();
+        for bundle in &self.suppressed {
+            let entry = stats
+                .entry((bundle.dominant_mev_type, bundle.mev_type))
+                .or_default();
+            entry.count += 1;
+            entry.profit_usd += bundle.profit_usd;
+        }
+
+        stats
+    }
+}
+
+/// The suppressed bundles are stored in their own table, so only the totals
+/// of the block are part of its row
+impl Serialize for DeduplicationStats {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("DeduplicationStats", 5)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("inspected_bundles", &self.inspected_bundles)?;
+        ser_struct.serialize_field("reported_bundles", &self.reported_bundles)?;
+        ser_struct.serialize_field("suppressed_bundles", &(self.total_suppressed() as u64))?;
+        ser_struct.serialize_field("suppressed_profit_usd", &self.total_suppressed_profit_usd())?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for DeduplicationStats {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "inspected_bundles",
+        "reported_bundles",
+        "suppressed_bundles",
+        "suppressed_profit_usd",
+    ];
+}
+
+implement_table_value_codecs_with_zc!(DeduplicationStatsRedefined);
@@ -0,0 +1,177 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use alloy_primitives::Address;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, Protocol};
+#[allow(unused_imports)]
+use crate::{display::utils::display_sandwich, normalized_actions::*, GasDetails};
+
+/// A transaction that copied the calldata or the profitable path of a later
+/// transaction in the same block, leaving the original transaction to revert
+/// or to earn less.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct GeneralizedFrontrun {
+    pub block_number:         u64,
+    pub frontrun_tx_hash:     B256,
+    pub frontrun_tx_index:    u64,
+    pub frontrun_swaps:       Vec<NormalizedSwap>,
+    #[redefined(same_fields)]
+    pub frontrun_gas_details: GasDetails,
+    /// the transaction that was copied
+    pub victim_tx_hash:       B256,
+    pub victim_tx_index:      u64,
+    pub victim_swaps:         Vec<NormalizedSwap>,
+    #[redefined(same_fields)]
+    pub victim_gas_details:   GasDetails,
+    pub victim_reverted:      bool,
+    /// the profit the victim made after being frontrun
+    pub victim_profit_usd:    f64,
+    /// the contracts that both transactions interacted with
+    pub shared_targets:       Vec<Address>,
+}
+
+impl Mev for GeneralizedFrontrun {
+    fn mev_type(&self) -> MevType {
+        MevType::GeneralizedFrontrun
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.frontrun_tx_hash]
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.frontrun_gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.frontrun_gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.frontrun_gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.frontrun_swaps
+            .iter()
+            .map(|swap| swap.protocol)
+            .collect()
+    }
+}
+
+impl Serialize for GeneralizedFrontrun {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("GeneralizedFrontrun", 26)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("frontrun_tx_hash", &format!("{:?}", self.frontrun_tx_hash))?;
+        ser_struct.serialize_field("frontrun_tx_index", &self.frontrun_tx_index)?;
+
+        let frontrun_swaps: ClickhouseVecNormalizedSwap = self
+            .frontrun_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("frontrun_swaps.trace_idx", &frontrun_swaps.trace_index)?;
+        ser_struct.serialize_field("frontrun_swaps.from", &frontrun_swaps.from)?;
+        ser_struct.serialize_field("frontrun_swaps.recipient", &frontrun_swaps.recipient)?;
+        ser_struct.serialize_field("frontrun_swaps.pool", &frontrun_swaps.pool)?;
+        ser_struct.serialize_field("frontrun_swaps.token_in", &frontrun_swaps.token_in)?;
+        ser_struct.serialize_field("frontrun_swaps.token_out", &frontrun_swaps.token_out)?;
+        ser_struct.serialize_field("frontrun_swaps.amount_in", &frontrun_swaps.amount_in)?;
+        ser_struct.serialize_field("frontrun_swaps.amount_out", &frontrun_swaps.amount_out)?;
+
+        let frontrun_gas_details = (
+            self.frontrun_gas_details.coinbase_transfer,
+            self.frontrun_gas_details.priority_fee,
+            self.frontrun_gas_details.gas_used,
+            self.frontrun_gas_details.effective_gas_price,
+        );
+        ser_struct.serialize_field("frontrun_gas_details", &frontrun_gas_details)?;
+
+        ser_struct.serialize_field("victim_tx_hash", &format!("{:?}", self.victim_tx_hash))?;
+        ser_struct.serialize_field("victim_tx_index", &self.victim_tx_index)?;
+
+        let victim_swaps: ClickhouseVecNormalizedSwap = self
+            .victim_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("victim_swaps.trace_idx", &victim_swaps.trace_index)?;
+        ser_struct.serialize_field("victim_swaps.from", &victim_swaps.from)?;
+        ser_struct.serialize_field("victim_swaps.recipient", &victim_swaps.recipient)?;
+        ser_struct.serialize_field("victim_swaps.pool", &victim_swaps.pool)?;
+        ser_struct.serialize_field("victim_swaps.token_in", &victim_swaps.token_in)?;
+        ser_struct.serialize_field("victim_swaps.token_out", &victim_swaps.token_out)?;
+        ser_struct.serialize_field("victim_swaps.amount_in", &victim_swaps.amount_in)?;
+        ser_struct.serialize_field("victim_swaps.amount_out", &victim_swaps.amount_out)?;
+
+        let victim_gas_details = (
+            self.victim_gas_details.coinbase_transfer,
+            self.victim_gas_details.priority_fee,
+            self.victim_gas_details.gas_used,
+            self.victim_gas_details.effective_gas_price,
+        );
+        ser_struct.serialize_field("victim_gas_details", &victim_gas_details)?;
+
+        ser_struct.serialize_field("victim_reverted", &self.victim_reverted)?;
+        ser_struct.serialize_field("victim_profit_usd", &self.victim_profit_usd)?;
+        ser_struct.serialize_field(
+            "shared_targets",
+            &self
+                .shared_targets
+                .iter()
+                .map(|target| format!("{:?}", target))
+                .collect::<Vec<_>>(),
+        )?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for GeneralizedFrontrun {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "frontrun_tx_hash",
+        "frontrun_tx_index",
+        "frontrun_swaps.trace_idx",
+        "frontrun_swaps.from",
+        "frontrun_swaps.recipient",
+        "frontrun_swaps.pool",
+        "frontrun_swaps.token_in",
+        "frontrun_swaps.token_out",
+        "frontrun_swaps.amount_in",
+        "frontrun_swaps.amount_out",
+        "frontrun_gas_details",
+        "victim_tx_hash",
+        "victim_tx_index",
+        "victim_swaps.trace_idx",
+        "victim_swaps.from",
+        "victim_swaps.recipient",
+        "victim_swaps.pool",
+        "victim_swaps.token_in",
+        "victim_swaps.token_out",
+        "victim_swaps.amount_in",
+        "victim_swaps.amount_out",
+        "victim_gas_details",
+        "victim_reverted",
+        "victim_profit_usd",
+        "shared_targets",
+    ];
+}
@@ -0,0 +1,178 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use alloy_primitives::Address;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, Protocol};
+#[allow(unused_imports)]
+use crate::{display::utils::display_sandwich, normalized_actions::*, GasDetails};
+
+/// A transaction that backran the settlement of an intent based order flow
+/// auction (UniswapX, Cowswap, 1inch Fusion). The value the settlement created
+/// is split between the solver that filled the orders and the searcher that
+/// backran the settlement.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct IntentBackrun {
+    pub block_number:           u64,
+    pub settlement_tx_hash:     B256,
+    pub settlement_tx_index:    u64,
+    #[redefined(same_fields)]
+    pub settlement_protocol:    Protocol,
+    pub solver:                 Address,
+    /// the swaps the solver executed to fill the orders
+    pub solver_swaps:           Vec<NormalizedSwap>,
+    /// the profit the solver made filling the orders, net of gas
+    pub filler_profit_usd:      f64,
+    #[redefined(same_fields)]
+    pub settlement_gas_details: GasDetails,
+    pub backrun_tx_hash:        B256,
+    pub backrun_tx_index:       u64,
+    pub backrun_swaps:          Vec<NormalizedSwap>,
+    /// the profit the backrunner made, net of gas
+    pub backrun_profit_usd:     f64,
+    #[redefined(same_fields)]
+    pub backrun_gas_details:    GasDetails,
+}
+
+impl Mev for IntentBackrun {
+    fn mev_type(&self) -> MevType {
+        MevType::IntentBackrun
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.settlement_tx_hash, self.backrun_tx_hash]
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.backrun_gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.backrun_gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.backrun_gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.backrun_swaps
+            .iter()
+            .map(|swap| swap.protocol)
+            .chain(std::iter::once(self.settlement_protocol))
+            .collect()
+    }
+}
+
+impl Serialize for IntentBackrun {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("IntentBackrun", 27)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct
+            .serialize_field("settlement_tx_hash", &format!("{:?}", self.settlement_tx_hash))?;
+        ser_struct.serialize_field("settlement_tx_index", &self.settlement_tx_index)?;
+        ser_struct.serialize_field("settlement_protocol", &self.settlement_protocol.to_string())?;
+        ser_struct.serialize_field("solver", &format!("{:?}", self.solver))?;
+
+        let solver_swaps: ClickhouseVecNormalizedSwap = self
+            .solver_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("solver_swaps.trace_idx", &solver_swaps.trace_index)?;
+        ser_struct.serialize_field("solver_swaps.from", &solver_swaps.from)?;
+        ser_struct.serialize_field("solver_swaps.recipient", &solver_swaps.recipient)?;
+        ser_struct.serialize_field("solver_swaps.pool", &solver_swaps.pool)?;
+        ser_struct.serialize_field("solver_swaps.token_in", &solver_swaps.token_in)?;
+        ser_struct.serialize_field("solver_swaps.token_out", &solver_swaps.token_out)?;
+        ser_struct.serialize_field("solver_swaps.amount_in", &solver_swaps.amount_in)?;
+        ser_struct.serialize_field("solver_swaps.amount_out", &solver_swaps.amount_out)?;
+
+        ser_struct.serialize_field("filler_profit_usd", &self.filler_profit_usd)?;
+
+        let settlement_gas_details = (
+            self.settlement_gas_details.coinbase_transfer,
+            self.settlement_gas_details.priority_fee,
+            self.settlement_gas_details.gas_used,
+            self.settlement_gas_details.effective_gas_price,
+        );
+        ser_struct.serialize_field("settlement_gas_details", &settlement_gas_details)?;
+
+        ser_struct.serialize_field("backrun_tx_hash", &format!("{:?}", self.backrun_tx_hash))?;
+        ser_struct.serialize_field("backrun_tx_index", &self.backrun_tx_index)?;
+
+        let backrun_swaps: ClickhouseVecNormalizedSwap = self
+            .backrun_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("backrun_swaps.trace_idx", &backrun_swaps.trace_index)?;
+        ser_struct.serialize_field("backrun_swaps.from", &backrun_swaps.from)?;
+        ser_struct.serialize_field("backrun_swaps.recipient", &backrun_swaps.recipient)?;
+        ser_struct.serialize_field("backrun_swaps.pool", &backrun_swaps.pool)?;
+        ser_struct.serialize_field("backrun_swaps.token_in", &backrun_swaps.token_in)?;
+        ser_struct.serialize_field("backrun_swaps.token_out", &backrun_swaps.token_out)?;
+        ser_struct.serialize_field("backrun_swaps.amount_in", &backrun_swaps.amount_in)?;
+        ser_struct.serialize_field("backrun_swaps.amount_out", &backrun_swaps.amount_out)?;
+
+        ser_struct.serialize_field("backrun_profit_usd", &self.backrun_profit_usd)?;
+
+        let backrun_gas_details = (
+            self.backrun_gas_details.coinbase_transfer,
+            self.backrun_gas_details.priority_fee,
+            self.backrun_gas_details.gas_used,
+            self.backrun_gas_details.effective_gas_price,
+        );
+        ser_struct.serialize_field("backrun_gas_details", &backrun_gas_details)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for IntentBackrun {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "settlement_tx_hash",
+        "settlement_tx_index",
+        "settlement_protocol",
+        "solver",
+        "solver_swaps.trace_idx",
+        "solver_swaps.from",
+        "solver_swaps.recipient",
+        "solver_swaps.pool",
+        "solver_swaps.token_in",
+        "solver_swaps.token_out",
+        "solver_swaps.amount_in",
+        "solver_swaps.amount_out",
+        "filler_profit_usd",
+        "settlement_gas_details",
+        "backrun_tx_hash",
+        "backrun_tx_index",
+        "backrun_swaps.trace_idx",
+        "backrun_swaps.from",
+        "backrun_swaps.recipient",
+        "backrun_swaps.pool",
+        "backrun_swaps.token_in",
+        "backrun_swaps.token_out",
+        "backrun_swaps.amount_in",
+        "backrun_swaps.amount_out",
+        "backrun_profit_usd",
+        "backrun_gas_details",
+    ];
+}
@@ -182,6 +182,7 @@ pub fn compose_sandwich_jit(mev: Vec<Bundle>) -> Option<Bundle> {
         eoa:                   jit_classified.eoa,
         mev_contract:          classified_sandwich.mev_contract,
         profit_usd:            classified_sandwich.profit_usd,
+        profit_usd_spread:     classified_sandwich.profit_usd_spread,
         balance_deltas:        classified_sandwich.balance_deltas,
         bribe_usd:             classified_sandwich.bribe_usd,
         no_pricing_calculated: classified_sandwich.no_pricing_calculated,
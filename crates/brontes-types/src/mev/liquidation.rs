@@ -113,6 +113,9 @@ impl Serialize for Liquidation {
             "liquidations.liquidated_collateral",
             &liquidations.liquidated_collateral,
         )?;
+        ser_struct.serialize_field("liquidations.auction_price", &liquidations.auction_price)?;
+        ser_struct.serialize_field("liquidations.oracle_price", &liquidations.oracle_price)?;
+        ser_struct.serialize_field("liquidations.kind", &liquidations.kind)?;
 
         let gas_details = (
             self.gas_details.coinbase_transfer,
@@ -147,6 +150,9 @@ impl DbRow for Liquidation {
         "liquidations.debt_asset",
         "liquidations.covered_debt",
         "liquidations.liquidated_collateral",
+        "liquidations.auction_price",
+        "liquidations.oracle_price",
+        "liquidations.kind",
         "gas_details",
     ];
 }
@@ -16,6 +16,20 @@ pub mod block;
 pub use block::*;
 pub mod searcher_tx;
 pub use searcher_tx::*;
+pub mod oracle_backrun;
+pub use oracle_backrun::*;
+pub mod sniping;
+pub use sniping::*;
+pub mod generalized_frontrun;
+pub use generalized_frontrun::*;
+pub mod intent_backrun;
+pub use intent_backrun::*;
+pub mod nft_arb;
+pub use nft_arb::*;
+pub mod custom;
+pub use custom::*;
+pub mod dedup;
+pub use dedup::*;
 
 pub mod cex_dex_quotes;
 pub use cex_dex_quotes::*;
@@ -0,0 +1,142 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, Protocol};
+#[allow(unused_imports)]
+use crate::{display::utils::display_sandwich, normalized_actions::*, GasDetails};
+
+/// A transaction that bought NFTs and sold them again in the same
+/// transaction, either across marketplaces or against an NFT AMM pool. Buying
+/// an underpriced NFT out of a Sudoswap pool and dumping it into a marketplace
+/// bid is the NFT-AMM snipe.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NftArb {
+    pub block_number: u64,
+    pub tx_hash:      B256,
+    pub tx_index:     u64,
+    /// the trades that bought the arbed NFTs
+    pub buys:         Vec<NormalizedNftTrade>,
+    /// the trades that sold the arbed NFTs
+    pub sells:        Vec<NormalizedNftTrade>,
+    #[redefined(same_fields)]
+    pub gas_details:  GasDetails,
+}
+
+impl Mev for NftArb {
+    fn mev_type(&self) -> MevType {
+        MevType::NftArb
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.tx_hash]
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.buys
+            .iter()
+            .chain(self.sells.iter())
+            .map(|trade| trade.protocol)
+            .collect()
+    }
+}
+
+impl Serialize for NftArb {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("NftArb", 20)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("tx_hash", &format!("{:?}", self.tx_hash))?;
+        ser_struct.serialize_field("tx_index", &self.tx_index)?;
+
+        let buys: ClickhouseVecNormalizedNftTrade = self
+            .buys
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("buys.trace_idx", &buys.trace_index)?;
+        ser_struct.serialize_field("buys.marketplace", &buys.marketplace)?;
+        ser_struct.serialize_field("buys.seller", &buys.seller)?;
+        ser_struct.serialize_field("buys.buyer", &buys.buyer)?;
+        ser_struct.serialize_field("buys.collection", &buys.collection)?;
+        ser_struct.serialize_field("buys.token_ids", &buys.token_ids)?;
+        ser_struct.serialize_field("buys.payment_token", &buys.payment_token)?;
+        ser_struct.serialize_field("buys.price", &buys.price)?;
+
+        let sells: ClickhouseVecNormalizedNftTrade = self
+            .sells
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("sells.trace_idx", &sells.trace_index)?;
+        ser_struct.serialize_field("sells.marketplace", &sells.marketplace)?;
+        ser_struct.serialize_field("sells.seller", &sells.seller)?;
+        ser_struct.serialize_field("sells.buyer", &sells.buyer)?;
+        ser_struct.serialize_field("sells.collection", &sells.collection)?;
+        ser_struct.serialize_field("sells.token_ids", &sells.token_ids)?;
+        ser_struct.serialize_field("sells.payment_token", &sells.payment_token)?;
+        ser_struct.serialize_field("sells.price", &sells.price)?;
+
+        let gas_details = (
+            self.gas_details.coinbase_transfer,
+            self.gas_details.priority_fee,
+            self.gas_details.gas_used,
+            self.gas_details.effective_gas_price,
+        );
+        ser_struct.serialize_field("gas_details", &gas_details)?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for NftArb {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "tx_hash",
+        "tx_index",
+        "buys.trace_idx",
+        "buys.marketplace",
+        "buys.seller",
+        "buys.buyer",
+        "buys.collection",
+        "buys.token_ids",
+        "buys.payment_token",
+        "buys.price",
+        "sells.trace_idx",
+        "sells.marketplace",
+        "sells.seller",
+        "sells.buyer",
+        "sells.collection",
+        "sells.token_ids",
+        "sells.payment_token",
+        "sells.price",
+        "gas_details",
+    ];
+}
@@ -0,0 +1,179 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use alloy_primitives::Address;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, Protocol};
+#[allow(unused_imports)]
+use crate::{display::utils::display_sandwich, normalized_actions::*, GasDetails};
+
+/// A transaction that captured the value unlocked by an oracle price update
+/// (OEV), either by liquidating a position that the update made unhealthy or
+/// by arbing the pools that are priced off the oracle.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct OracleBackrun {
+    pub block_number:           u64,
+    pub oracle_update_tx_hash:  B256,
+    pub oracle_update_tx_index: u64,
+    /// the price feeds that were updated in the oracle update tx
+    pub oracles:                Vec<Address>,
+    pub backrun_tx_hash:        B256,
+    pub backrun_tx_index:       u64,
+    /// amount of transactions between the oracle update and the backrun
+    pub latency:                u64,
+    pub liquidations:           Vec<NormalizedLiquidation>,
+    pub swaps:                  Vec<NormalizedSwap>,
+    /// the tx of the `Liquidation` bundle the backrun is linked to. The
+    /// liquidation bundle reports the profit of the backrun
+    pub liquidation_tx_hash:    Option<B256>,
+    #[redefined(same_fields)]
+    pub gas_details:            GasDetails,
+}
+
+impl Mev for OracleBackrun {
+    fn mev_type(&self) -> MevType {
+        MevType::OracleBackrun
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.backrun_tx_hash]
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.liquidations
+            .iter()
+            .map(|liquidation| liquidation.protocol)
+            .chain(self.swaps.iter().map(|swap| swap.protocol))
+            .collect()
+    }
+}
+
+impl Serialize for OracleBackrun {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("OracleBackrun", 25)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field(
+            "oracle_update_tx_hash",
+            &format!("{:?}", self.oracle_update_tx_hash),
+        )?;
+        ser_struct.serialize_field("oracle_update_tx_index", &self.oracle_update_tx_index)?;
+        ser_struct.serialize_field(
+            "oracles",
+            &self
+                .oracles
+                .iter()
+                .map(|oracle| format!("{:?}", oracle))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field("backrun_tx_hash", &format!("{:?}", self.backrun_tx_hash))?;
+        ser_struct.serialize_field("backrun_tx_index", &self.backrun_tx_index)?;
+        ser_struct.serialize_field("latency", &self.latency)?;
+
+        let liquidations: ClickhouseVecNormalizedLiquidation = self
+            .liquidations
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("liquidations.trace_idx", &liquidations.trace_index)?;
+        ser_struct.serialize_field("liquidations.pool", &liquidations.pool)?;
+        ser_struct.serialize_field("liquidations.liquidator", &liquidations.liquidator)?;
+        ser_struct.serialize_field("liquidations.debtor", &liquidations.debtor)?;
+        ser_struct
+            .serialize_field("liquidations.collateral_asset", &liquidations.collateral_asset)?;
+        ser_struct.serialize_field("liquidations.debt_asset", &liquidations.debt_asset)?;
+        ser_struct.serialize_field("liquidations.covered_debt", &liquidations.covered_debt)?;
+        ser_struct.serialize_field(
+            "liquidations.liquidated_collateral",
+            &liquidations.liquidated_collateral,
+        )?;
+
+        let swaps: ClickhouseVecNormalizedSwap = self
+            .swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("swaps.trace_idx", &swaps.trace_index)?;
+        ser_struct.serialize_field("swaps.from", &swaps.from)?;
+        ser_struct.serialize_field("swaps.recipient", &swaps.recipient)?;
+        ser_struct.serialize_field("swaps.pool", &swaps.pool)?;
+        ser_struct.serialize_field("swaps.token_in", &swaps.token_in)?;
+        ser_struct.serialize_field("swaps.token_out", &swaps.token_out)?;
+        ser_struct.serialize_field("swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("swaps.amount_out", &swaps.amount_out)?;
+        ser_struct.serialize_field(
+            "liquidation_tx_hash",
+            &self
+                .liquidation_tx_hash
+                .map(|tx_hash| format!("{:?}", tx_hash)),
+        )?;
+
+        let gas_details = (
+            self.gas_details.coinbase_transfer,
+            self.gas_details.priority_fee,
+            self.gas_details.gas_used,
+            self.gas_details.effective_gas_price,
+        );
+
+        ser_struct.serialize_field("gas_details", &(gas_details))?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for OracleBackrun {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "oracle_update_tx_hash",
+        "oracle_update_tx_index",
+        "oracles",
+        "backrun_tx_hash",
+        "backrun_tx_index",
+        "latency",
+        "liquidations.trace_idx",
+        "liquidations.pool",
+        "liquidations.liquidator",
+        "liquidations.debtor",
+        "liquidations.collateral_asset",
+        "liquidations.debt_asset",
+        "liquidations.covered_debt",
+        "liquidations.liquidated_collateral",
+        "swaps.trace_idx",
+        "swaps.from",
+        "swaps.recipient",
+        "swaps.pool",
+        "swaps.token_in",
+        "swaps.token_out",
+        "swaps.amount_in",
+        "swaps.amount_out",
+        "liquidation_tx_hash",
+        "gas_details",
+    ];
+}
@@ -81,6 +81,11 @@ pub struct Sandwich {
     /// Gas details for each victim transaction.
     #[redefined(same_fields)]
     pub victim_swaps_gas_details: Vec<GasDetails>,
+    /// Estimated loss in usd of each victim transaction, compared to
+    /// executing without the frontruns. `None` for victims whose swaps
+    /// couldn't be replayed or priced.
+    #[serde(default)]
+    pub victim_loss_usd:          Vec<Option<f64>>,
     /// Transaction hashes of the backrunning transactions.
     pub backrun_tx_hash:          B256,
     /// Swaps executed in each backrunning transaction.
@@ -166,7 +171,7 @@ impl Serialize for Sandwich {
     where
         S: Serializer,
     {
-        let mut ser_struct = serializer.serialize_struct("Sandwich", 35)?;
+        let mut ser_struct = serializer.serialize_struct("Sandwich", 36)?;
         ser_struct.serialize_field("block_number", &self.block_number)?;
 
         // frontrun
@@ -237,6 +242,7 @@ impl Serialize for Sandwich {
             "victim_gas_details.effective_gas_price",
             &victim_gas_details.effective_gas_price,
         )?;
+        ser_struct.serialize_field("victim_loss_usd", &self.victim_loss_usd)?;
 
         // backrun
         let fixed_str_backrun_tx_hash = format!("{:?}", &self.backrun_tx_hash);
@@ -318,6 +324,7 @@ impl DbRow for Sandwich {
         "victim_gas_details.priority_fee",
         "victim_gas_details.gas_used",
         "victim_gas_details.effective_gas_price",
+        "victim_loss_usd",
         "backrun_tx_hash",
         "backrun_swaps.tx_hash",
         "backrun_swaps.trace_idx",
@@ -0,0 +1,129 @@
+use std::fmt::Debug;
+
+use ::clickhouse::DbRow;
+use ::serde::ser::{SerializeStruct, Serializer};
+use ahash::HashSet;
+use alloy_primitives::Address;
+use redefined::Redefined;
+use reth_primitives::B256;
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use super::{Mev, MevType};
+use crate::{db::redefined_types::primitives::*, Protocol};
+#[allow(unused_imports)]
+use crate::{display::utils::display_sandwich, normalized_actions::*, GasDetails};
+
+/// A transaction that was one of the first to buy from a freshly created or
+/// freshly funded pool.
+#[serde_as]
+#[derive(Debug, Deserialize, PartialEq, Clone, Default, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct Snipe {
+    pub block_number:         u64,
+    pub snipe_tx_hash:        B256,
+    /// the pools that were sniped
+    pub pools:                Vec<Address>,
+    /// the block each of the sniped pools was created in
+    pub pool_creation_blocks: Vec<u64>,
+    pub snipe_swaps:          Vec<NormalizedSwap>,
+    /// the block who's dex quotes the position was marked to
+    pub markout_block:        u64,
+    #[redefined(same_fields)]
+    pub gas_details:          GasDetails,
+}
+
+impl Mev for Snipe {
+    fn mev_type(&self) -> MevType {
+        MevType::Sniping
+    }
+
+    fn mev_transaction_hashes(&self) -> Vec<B256> {
+        vec![self.snipe_tx_hash]
+    }
+
+    fn total_gas_paid(&self) -> u128 {
+        self.gas_details.gas_paid()
+    }
+
+    fn total_priority_fee_paid(&self, base_fee: u128) -> u128 {
+        self.gas_details.priority_fee_paid(base_fee)
+    }
+
+    fn bribe(&self) -> u128 {
+        self.gas_details.coinbase_transfer.unwrap_or(0)
+    }
+
+    fn protocols(&self) -> HashSet<Protocol> {
+        self.snipe_swaps.iter().map(|swap| swap.protocol).collect()
+    }
+}
+
+impl Serialize for Snipe {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut ser_struct = serializer.serialize_struct("Snipe", 14)?;
+
+        ser_struct.serialize_field("block_number", &self.block_number)?;
+        ser_struct.serialize_field("snipe_tx_hash", &format!("{:?}", self.snipe_tx_hash))?;
+        ser_struct.serialize_field(
+            "pools",
+            &self
+                .pools
+                .iter()
+                .map(|pool| format!("{:?}", pool))
+                .collect::<Vec<_>>(),
+        )?;
+        ser_struct.serialize_field("pool_creation_blocks", &self.pool_creation_blocks)?;
+
+        let swaps: ClickhouseVecNormalizedSwap = self
+            .snipe_swaps
+            .clone()
+            .try_into()
+            .map_err(serde::ser::Error::custom)?;
+
+        ser_struct.serialize_field("snipe_swaps.trace_idx", &swaps.trace_index)?;
+        ser_struct.serialize_field("snipe_swaps.from", &swaps.from)?;
+        ser_struct.serialize_field("snipe_swaps.recipient", &swaps.recipient)?;
+        ser_struct.serialize_field("snipe_swaps.pool", &swaps.pool)?;
+        ser_struct.serialize_field("snipe_swaps.token_in", &swaps.token_in)?;
+        ser_struct.serialize_field("snipe_swaps.token_out", &swaps.token_out)?;
+        ser_struct.serialize_field("snipe_swaps.amount_in", &swaps.amount_in)?;
+        ser_struct.serialize_field("snipe_swaps.amount_out", &swaps.amount_out)?;
+
+        ser_struct.serialize_field("markout_block", &self.markout_block)?;
+
+        let gas_details = (
+            self.gas_details.coinbase_transfer,
+            self.gas_details.priority_fee,
+            self.gas_details.gas_used,
+            self.gas_details.effective_gas_price,
+        );
+
+        ser_struct.serialize_field("gas_details", &(gas_details))?;
+
+        ser_struct.end()
+    }
+}
+
+impl DbRow for Snipe {
+    const COLUMN_NAMES: &'static [&'static str] = &[
+        "block_number",
+        "snipe_tx_hash",
+        "pools",
+        "pool_creation_blocks",
+        "snipe_swaps.trace_idx",
+        "snipe_swaps.from",
+        "snipe_swaps.recipient",
+        "snipe_swaps.pool",
+        "snipe_swaps.token_in",
+        "snipe_swaps.token_out",
+        "snipe_swaps.amount_in",
+        "snipe_swaps.amount_out",
+        "markout_block",
+        "gas_details",
+    ];
+}
@@ -6,6 +6,7 @@ use malachite::Rational;
 use reth_primitives::Address;
 use serde::{Deserialize, Serialize};
 
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
 use crate::{db::token_info::TokenInfoWithAddress, FastHashMap, Protocol};
 
 #[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
@@ -20,6 +21,13 @@ pub struct NormalizedLoan {
     pub msg_value:    U256,
 }
 
+impl TokenAccounting for NormalizedLoan {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.lender, self.loaned_token.address, -self.loan_amount.clone(), delta_map);
+        apply_delta(self.borrower, self.loaned_token.address, self.loan_amount.clone(), delta_map);
+    }
+}
+
 #[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
 pub struct NormalizedRepayment {
     pub protocol:         Protocol,
@@ -31,3 +39,61 @@ pub struct NormalizedRepayment {
     pub collateral:       FastHashMap<TokenInfoWithAddress, Rational>,
     pub msg_value:        U256,
 }
+
+impl TokenAccounting for NormalizedRepayment {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(
+            self.borrower,
+            self.repayed_token.address,
+            -self.repayment_amount.clone(),
+            delta_map,
+        );
+        apply_delta(
+            self.lender,
+            self.repayed_token.address,
+            self.repayment_amount.clone(),
+            delta_map,
+        );
+    }
+}
+
+/// Collateral or liquidity supplied to a lending pool
+#[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedDeposit {
+    pub protocol:     Protocol,
+    pub trace_index:  u64,
+    pub pool:         Address,
+    pub from:         Address,
+    /// the account that is credited with the deposit
+    pub on_behalf_of: Address,
+    pub token:        TokenInfoWithAddress,
+    pub amount:       Rational,
+    pub msg_value:    U256,
+}
+
+impl TokenAccounting for NormalizedDeposit {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.from, self.token.address, -self.amount.clone(), delta_map);
+        apply_delta(self.pool, self.token.address, self.amount.clone(), delta_map);
+    }
+}
+
+/// Collateral or liquidity withdrawn from a lending pool
+#[derive(Debug, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedWithdraw {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    pub pool:        Address,
+    pub from:        Address,
+    pub to:          Address,
+    pub token:       TokenInfoWithAddress,
+    pub amount:      Rational,
+    pub msg_value:   U256,
+}
+
+impl TokenAccounting for NormalizedWithdraw {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.pool, self.token.address, -self.amount.clone(), delta_map);
+        apply_delta(self.to, self.token.address, self.amount.clone(), delta_map);
+    }
+}
@@ -4,7 +4,7 @@ use alloy_primitives::U256;
 use clickhouse::Row;
 use colored::Colorize;
 use malachite::Rational;
-use redefined::Redefined;
+use redefined::{self_convert_redefined, Redefined};
 use reth_primitives::Address;
 use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
 use serde::{Deserialize, Serialize};
@@ -33,10 +33,53 @@ pub struct NormalizedLiquidation {
     pub covered_debt:          Rational,
     pub liquidated_collateral: Rational,
     pub msg_value:             U256,
+    #[redefined(same_fields)]
+    #[serde(default)]
+    pub kind:                  LiquidationKind,
+    /// Price the collateral is sold at by an auction, in debt asset per
+    /// collateral
+    #[serde(default)]
+    pub auction_price:         Option<Rational>,
+    /// Price the oracle reported for the collateral when its auction started
+    #[serde(default)]
+    pub oracle_price:          Option<Rational>,
+}
+
+/// How the debt of a liquidated position is covered
+#[derive(
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    Serialize,
+    Deserialize,
+    rSerialize,
+    rDeserialize,
+    Archive,
+)]
+pub enum LiquidationKind {
+    /// The liquidator repays the debt and receives the collateral in exchange
+    #[default]
+    Repaid,
+    /// The debt and the collateral are taken over by the protocol, be it by a
+    /// stability pool, an auction or a redistribution to the other positions.
+    /// The liquidator is at most paid a compensation for triggering it.
+    Absorbed,
 }
 
+self_convert_redefined!(LiquidationKind);
+
 impl TokenAccounting for NormalizedLiquidation {
     fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        // the liquidator doesn't receive the collateral, any compensation it is
+        // paid is accounted for by its transfers
+        if self.kind == LiquidationKind::Absorbed {
+            return
+        }
+
         let debt_covered = self.covered_debt.clone();
         // Liquidator sends debt_asset to the pool, effectively swapping the debt asset
         // for the liquidatee's collateral
@@ -154,6 +197,9 @@ pub struct ClickhouseVecNormalizedLiquidation {
     pub debt_asset:            Vec<(String, String)>,
     pub covered_debt:          Vec<([u8; 32], [u8; 32])>,
     pub liquidated_collateral: Vec<([u8; 32], [u8; 32])>,
+    pub auction_price:         Vec<([u8; 32], [u8; 32])>,
+    pub oracle_price:          Vec<([u8; 32], [u8; 32])>,
+    pub kind:                  Vec<String>,
 }
 
 impl TryFrom<Vec<NormalizedLiquidation>> for ClickhouseVecNormalizedLiquidation {
@@ -188,6 +234,46 @@ impl TryFrom<Vec<NormalizedLiquidation>> for ClickhouseVecNormalizedLiquidation
                 .iter()
                 .map(|val| rational_to_u256_fraction(&val.liquidated_collateral))
                 .collect::<eyre::Result<Vec<_>>>()?,
+            // liquidations that aren't auctioned have no prices, written as zero
+            auction_price:         value
+                .iter()
+                .map(|val| {
+                    rational_to_u256_fraction(&val.auction_price.clone().unwrap_or_default())
+                })
+                .collect::<eyre::Result<Vec<_>>>()?,
+            oracle_price:          value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.oracle_price.clone().unwrap_or_default()))
+                .collect::<eyre::Result<Vec<_>>>()?,
+            kind:                  value.iter().map(|val| format!("{:?}", val.kind)).collect(),
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use redefined::RedefinedConvert;
+
+    use super::*;
+
+    #[test]
+    fn test_stored_liquidation_keeps_kind_and_prices() {
+        let liquidation = NormalizedLiquidation {
+            protocol: Protocol::MakerDog,
+            trace_index: 4,
+            pool: Address::with_last_byte(1),
+            liquidator: Address::with_last_byte(2),
+            debtor: Address::with_last_byte(3),
+            covered_debt: Rational::from(10),
+            liquidated_collateral: Rational::from(2),
+            msg_value: U256::from(7),
+            kind: LiquidationKind::Absorbed,
+            auction_price: Some(Rational::from(5)),
+            oracle_price: Some(Rational::from(6)),
+            ..Default::default()
+        };
+
+        let stored = NormalizedLiquidationRedefined::from_source(liquidation.clone());
+        assert_eq!(stored.to_source(), liquidation);
+    }
+}
@@ -8,10 +8,12 @@ pub mod lending;
 pub mod liquidation;
 pub mod liquidity;
 pub mod multi_callframe;
+pub mod nft;
 pub mod pool;
 pub mod self_destruct;
 pub mod swaps;
 pub mod transfer;
+pub mod wrap;
 use std::fmt::Debug;
 
 use ::clickhouse::DbRow;
@@ -26,11 +28,13 @@ pub use lending::*;
 pub use liquidation::*;
 pub use liquidity::*;
 pub use multi_callframe::*;
+pub use nft::*;
 pub use pool::*;
 use reth_rpc_types::trace::parity::Action as TraceAction;
 pub use self_destruct::*;
 pub use swaps::*;
 pub use transfer::*;
+pub use wrap::*;
 
 use crate::{
     structured_trace::{TraceActions, TransactionTraceWithLogs},
@@ -90,6 +94,14 @@ impl NormalizedAction for Action {
             Self::Burn(b) => b.trace_index,
             Self::Transfer(t) => t.trace_index,
             Self::Liquidation(t) => t.trace_index,
+            Self::Loan(l) => l.trace_index,
+            Self::Repayment(r) => r.trace_index,
+            Self::Deposit(d) => d.trace_index,
+            Self::Withdraw(w) => w.trace_index,
+            Self::NftTransfer(n) => n.trace_index,
+            Self::NftTrade(n) => n.trace_index,
+            Self::Wrap(w) => w.trace_index,
+            Self::Unwrap(u) => u.trace_index,
             Self::Collect(c) => c.trace_index,
             Self::SelfDestruct(c) => c.trace_index,
             Self::EthTransfer(e) => e.trace_index,
@@ -114,6 +126,14 @@ pub enum Action {
     Burn(NormalizedBurn),
     Collect(NormalizedCollect),
     Liquidation(NormalizedLiquidation),
+    Loan(NormalizedLoan),
+    Repayment(NormalizedRepayment),
+    Deposit(NormalizedDeposit),
+    Withdraw(NormalizedWithdraw),
+    NftTransfer(NormalizedNftTransfer),
+    NftTrade(NormalizedNftTrade),
+    Wrap(NormalizedWrap),
+    Unwrap(NormalizedUnwrap),
     SelfDestruct(SelfdestructWithIndex),
     EthTransfer(NormalizedEthTransfer),
     NewPool(NormalizedNewPool),
@@ -135,6 +155,14 @@ impl InsertRow for Action {
             Action::Burn(_) => NormalizedBurn::COLUMN_NAMES,
             Action::Collect(_) => NormalizedCollect::COLUMN_NAMES,
             Action::Liquidation(_) => NormalizedLiquidation::COLUMN_NAMES,
+            Action::Loan(_) => NormalizedLoan::COLUMN_NAMES,
+            Action::Repayment(_) => NormalizedRepayment::COLUMN_NAMES,
+            Action::Deposit(_) => NormalizedDeposit::COLUMN_NAMES,
+            Action::Withdraw(_) => NormalizedWithdraw::COLUMN_NAMES,
+            Action::NftTransfer(_) => NormalizedNftTransfer::COLUMN_NAMES,
+            Action::NftTrade(_) => NormalizedNftTrade::COLUMN_NAMES,
+            Action::Wrap(_) => NormalizedWrap::COLUMN_NAMES,
+            Action::Unwrap(_) => NormalizedUnwrap::COLUMN_NAMES,
             Action::SelfDestruct(_) => todo!("joe pls dome this"),
             Action::EthTransfer(_) => todo!("joe pls dome this"),
             Action::NewPool(_) => todo!(),
@@ -161,6 +189,14 @@ impl serde::Serialize for Action {
             Action::Burn(b) => b.serialize(serializer),
             Action::Collect(c) => c.serialize(serializer),
             Action::Liquidation(c) => c.serialize(serializer),
+            Action::Loan(l) => l.serialize(serializer),
+            Action::Repayment(r) => r.serialize(serializer),
+            Action::Deposit(d) => d.serialize(serializer),
+            Action::Withdraw(w) => w.serialize(serializer),
+            Action::NftTransfer(n) => n.serialize(serializer),
+            Action::NftTrade(n) => n.serialize(serializer),
+            Action::Wrap(w) => w.serialize(serializer),
+            Action::Unwrap(u) => u.serialize(serializer),
             Action::SelfDestruct(sd) => sd.serialize(serializer),
             Action::EthTransfer(et) => et.serialize(serializer),
             Action::Unclassified(trace) => (trace).serialize(serializer),
@@ -205,6 +241,30 @@ impl Action {
                     from: t.liquidator,
                     ..Default::default()
                 }),
+                Self::Loan(l) => (!l.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: l.msg_value,
+                    to: l.lender,
+                    from: l.borrower,
+                    ..Default::default()
+                }),
+                Self::Repayment(r) => (!r.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: r.msg_value,
+                    to: r.lender,
+                    from: r.borrower,
+                    ..Default::default()
+                }),
+                Self::Deposit(d) => (!d.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: d.msg_value,
+                    to: d.pool,
+                    from: d.from,
+                    ..Default::default()
+                }),
+                Self::Withdraw(w) => (!w.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: w.msg_value,
+                    to: w.pool,
+                    from: w.from,
+                    ..Default::default()
+                }),
                 Self::Unclassified(u) => (!u.get_msg_value().is_zero() && !u.is_delegate_call())
                     .then(|| NormalizedEthTransfer {
                         value: u.get_msg_value(),
@@ -218,9 +278,28 @@ impl Action {
                     from: a.from,
                     ..Default::default()
                 }),
+                Self::NftTrade(n) => (!n.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: n.msg_value,
+                    to: n.marketplace,
+                    from: n.buyer,
+                    ..Default::default()
+                }),
+                Self::Wrap(w) => (!w.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: w.msg_value,
+                    to: w.wrapped.address,
+                    from: w.from,
+                    ..Default::default()
+                }),
+                Self::Unwrap(u) => (!u.msg_value.is_zero()).then(|| NormalizedEthTransfer {
+                    value: u.msg_value,
+                    to: u.wrapped.address,
+                    from: u.from,
+                    ..Default::default()
+                }),
                 Self::Mint(_) => None,
                 Self::Burn(_) => None,
                 Self::Transfer(_) => None,
+                Self::NftTransfer(_) => None,
                 Self::Collect(_) => None,
                 Self::SelfDestruct(_) => None,
                 Self::EthTransfer(_) => None,
@@ -251,6 +330,14 @@ impl Action {
             Self::Burn(b) => b.trace_index,
             Self::Transfer(t) => t.trace_index,
             Self::Liquidation(t) => t.trace_index,
+            Self::Loan(l) => l.trace_index,
+            Self::Repayment(r) => r.trace_index,
+            Self::Deposit(d) => d.trace_index,
+            Self::Withdraw(w) => w.trace_index,
+            Self::NftTransfer(n) => n.trace_index,
+            Self::NftTrade(n) => n.trace_index,
+            Self::Wrap(w) => w.trace_index,
+            Self::Unwrap(u) => u.trace_index,
             Self::Collect(c) => c.trace_index,
             Self::SelfDestruct(c) => c.trace_index,
             Self::EthTransfer(e) => e.trace_index,
@@ -325,6 +412,14 @@ impl Action {
             Action::Transfer(t) => t.to,
             Action::Collect(c) => c.pool,
             Action::Liquidation(c) => c.pool,
+            Action::Loan(l) => l.lender,
+            Action::Repayment(r) => r.lender,
+            Action::Deposit(d) => d.pool,
+            Action::Withdraw(w) => w.pool,
+            Action::NftTransfer(n) => n.to,
+            Action::NftTrade(n) => n.marketplace,
+            Action::Wrap(w) => w.wrapped.address,
+            Action::Unwrap(u) => u.wrapped.address,
             Action::SelfDestruct(c) => c.get_refund_address(),
             Action::Unclassified(t) => match &t.trace.action {
                 reth_rpc_types::trace::parity::Action::Call(c) => c.to,
@@ -351,6 +446,14 @@ impl Action {
             Action::Transfer(t) => t.from,
             Action::Collect(c) => c.from,
             Action::Liquidation(c) => c.liquidator,
+            Action::Loan(l) => l.borrower,
+            Action::Repayment(r) => r.borrower,
+            Action::Deposit(d) => d.from,
+            Action::Withdraw(w) => w.from,
+            Action::NftTransfer(n) => n.from,
+            Action::NftTrade(n) => n.buyer,
+            Action::Wrap(w) => w.from,
+            Action::Unwrap(u) => u.from,
             Action::SelfDestruct(c) => c.get_address(),
             Action::Unclassified(t) => match &t.trace.action {
                 reth_rpc_types::trace::parity::Action::Call(c) => c.to,
@@ -395,6 +498,56 @@ impl Action {
         matches!(self, Action::Liquidation(_))
     }
 
+    pub const fn is_loan(&self) -> bool {
+        matches!(self, Action::Loan(_))
+    }
+
+    pub const fn is_repayment(&self) -> bool {
+        matches!(self, Action::Repayment(_))
+    }
+
+    pub const fn is_deposit(&self) -> bool {
+        matches!(self, Action::Deposit(_))
+    }
+
+    pub const fn is_withdraw(&self) -> bool {
+        matches!(self, Action::Withdraw(_))
+    }
+
+    /// Any action that changes a lending position
+    pub const fn is_lending(&self) -> bool {
+        matches!(
+            self,
+            Action::Loan(_) | Action::Repayment(_) | Action::Deposit(_) | Action::Withdraw(_)
+        )
+    }
+
+    pub const fn is_nft_transfer(&self) -> bool {
+        matches!(self, Action::NftTransfer(_))
+    }
+
+    pub const fn is_nft_trade(&self) -> bool {
+        matches!(self, Action::NftTrade(_))
+    }
+
+    /// Any action that moves an NFT
+    pub const fn is_nft(&self) -> bool {
+        matches!(self, Action::NftTransfer(_) | Action::NftTrade(_))
+    }
+
+    pub const fn is_wrap(&self) -> bool {
+        matches!(self, Action::Wrap(_))
+    }
+
+    pub const fn is_unwrap(&self) -> bool {
+        matches!(self, Action::Unwrap(_))
+    }
+
+    /// Any action that wraps or unwraps a token
+    pub const fn is_wrapping(&self) -> bool {
+        matches!(self, Action::Wrap(_) | Action::Unwrap(_))
+    }
+
     pub const fn is_batch(&self) -> bool {
         matches!(self, Action::Batch(_))
     }
@@ -445,6 +598,13 @@ impl Action {
             Action::Burn(b) => b.protocol,
             Action::Collect(c) => c.protocol,
             Action::Liquidation(c) => c.protocol,
+            Action::Loan(l) => l.protocol,
+            Action::Repayment(r) => r.protocol,
+            Action::Deposit(d) => d.protocol,
+            Action::Withdraw(w) => w.protocol,
+            Action::NftTrade(n) => n.protocol,
+            Action::Wrap(w) => w.protocol,
+            Action::Unwrap(u) => u.protocol,
             Action::NewPool(p) => p.protocol,
             Action::PoolConfigUpdate(p) => p.protocol,
             Action::Aggregator(a) => a.protocol,
@@ -523,10 +683,18 @@ extra_impls!(
     (Transfer, NormalizedTransfer),
     (EthTransfer, NormalizedEthTransfer),
     (Liquidation, NormalizedLiquidation),
+    (Loan, NormalizedLoan),
+    (Repayment, NormalizedRepayment),
+    (Deposit, NormalizedDeposit),
+    (Withdraw, NormalizedWithdraw),
     (FlashLoan, NormalizedFlashLoan),
     (Aggregator, NormalizedAggregator),
     (Batch, NormalizedBatch),
-    (NewPool, NormalizedNewPool)
+    (NewPool, NormalizedNewPool),
+    (NftTransfer, NormalizedNftTransfer),
+    (NftTrade, NormalizedNftTrade),
+    (Wrap, NormalizedWrap),
+    (Unwrap, NormalizedUnwrap)
 );
 
 /// Custom impl for itering over swaps and swap with fee
@@ -572,6 +740,14 @@ impl TokenAccounting for Action {
             Action::FlashLoan(flash_loan) => flash_loan.apply_token_deltas(delta_map),
             Action::Aggregator(aggregator) => aggregator.apply_token_deltas(delta_map),
             Action::Liquidation(liquidation) => liquidation.apply_token_deltas(delta_map),
+            Action::Loan(loan) => loan.apply_token_deltas(delta_map),
+            Action::Repayment(repayment) => repayment.apply_token_deltas(delta_map),
+            Action::Deposit(deposit) => deposit.apply_token_deltas(delta_map),
+            Action::Withdraw(withdraw) => withdraw.apply_token_deltas(delta_map),
+            Action::NftTransfer(nft_transfer) => nft_transfer.apply_token_deltas(delta_map),
+            Action::NftTrade(nft_trade) => nft_trade.apply_token_deltas(delta_map),
+            Action::Wrap(wrap) => wrap.apply_token_deltas(delta_map),
+            Action::Unwrap(unwrap) => unwrap.apply_token_deltas(delta_map),
             Action::Batch(batch) => batch.apply_token_deltas(delta_map),
             Action::Burn(burn) => burn.apply_token_deltas(delta_map),
             Action::Mint(mint) => mint.apply_token_deltas(delta_map),
@@ -0,0 +1,136 @@
+use std::{
+    fmt,
+    fmt::{Debug, Display},
+};
+
+use clickhouse::Row;
+use colored::Colorize;
+use malachite::Rational;
+use redefined::Redefined;
+use reth_primitives::{Address, U256};
+use rkyv::{Archive, Deserialize as rDeserialize, Serialize as rSerialize};
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{AddressDeltas, TokenAccounting};
+use crate::{
+    db::{
+        redefined_types::{malachite::*, primitives::*},
+        token_info::{TokenInfoWithAddress, TokenInfoWithAddressRedefined},
+    },
+    rational_to_u256_fraction, Protocol, ToFloatNearest,
+};
+
+/// A transfer of an ERC721 or ERC1155 token. ERC721 transfers always have an
+/// amount of one.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Row, PartialEq, Eq, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedNftTransfer {
+    pub trace_index: u64,
+    pub collection:  Address,
+    pub token_id:    U256,
+    pub amount:      U256,
+    pub from:        Address,
+    pub to:          Address,
+}
+
+/// NFTs aren't priced, the transfer has no fungible token deltas
+impl TokenAccounting for NormalizedNftTransfer {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+/// An order filled on an NFT marketplace or NFT AMM. The buyer pays `price`
+/// in `payment_token` for `amounts` of each of the `token_ids` of the
+/// collection. The price includes the marketplace fees and creator royalties.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, Row, PartialEq, Eq, Redefined)]
+#[redefined_attr(derive(Debug, PartialEq, Clone, Serialize, rSerialize, rDeserialize, Archive))]
+pub struct NormalizedNftTrade {
+    #[redefined(same_fields)]
+    pub protocol:      Protocol,
+    pub trace_index:   u64,
+    /// The marketplace contract, or the pair for NFT AMMs
+    pub marketplace:   Address,
+    pub seller:        Address,
+    pub buyer:         Address,
+    pub collection:    Address,
+    pub token_ids:     Vec<U256>,
+    pub amounts:       Vec<U256>,
+    pub payment_token: TokenInfoWithAddress,
+    pub price:         Rational,
+    pub msg_value:     U256,
+}
+
+impl NormalizedNftTrade {
+    pub fn contains_token(&self, collection: Address, token_id: U256) -> bool {
+        self.collection == collection && self.token_ids.contains(&token_id)
+    }
+}
+
+impl Display for NormalizedNftTrade {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let price = format!("{:.4}", self.price.clone().to_float()).red();
+        let token_ids = self
+            .token_ids
+            .iter()
+            .map(|id| format!("#{id}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let protocol: colored::ColoredString = self.protocol.to_string().bold();
+        write!(
+            f,
+            "Buy {} {} for {} {} via {}",
+            self.collection, token_ids, price, self.payment_token.symbol, protocol
+        )
+    }
+}
+
+/// The payment of a trade settles through transfers that are accounted for
+/// on their own, so the trade itself has no token deltas
+impl TokenAccounting for NormalizedNftTrade {
+    fn apply_token_deltas(&self, _delta_map: &mut AddressDeltas) {}
+}
+
+pub struct ClickhouseVecNormalizedNftTrade {
+    pub trace_index:   Vec<u64>,
+    pub marketplace:   Vec<String>,
+    pub seller:        Vec<String>,
+    pub buyer:         Vec<String>,
+    pub collection:    Vec<String>,
+    pub token_ids:     Vec<Vec<String>>,
+    pub payment_token: Vec<(String, String)>,
+    pub price:         Vec<([u8; 32], [u8; 32])>,
+}
+
+impl TryFrom<Vec<NormalizedNftTrade>> for ClickhouseVecNormalizedNftTrade {
+    type Error = eyre::Report;
+
+    fn try_from(value: Vec<NormalizedNftTrade>) -> eyre::Result<Self> {
+        Ok(ClickhouseVecNormalizedNftTrade {
+            trace_index:   value.iter().map(|val| val.trace_index).collect(),
+            marketplace:   value
+                .iter()
+                .map(|val| format!("{:?}", val.marketplace))
+                .collect(),
+            seller:        value
+                .iter()
+                .map(|val| format!("{:?}", val.seller))
+                .collect(),
+            buyer:         value.iter().map(|val| format!("{:?}", val.buyer)).collect(),
+            collection:    value
+                .iter()
+                .map(|val| format!("{:?}", val.collection))
+                .collect(),
+            token_ids:     value
+                .iter()
+                .map(|val| val.token_ids.iter().map(|id| id.to_string()).collect())
+                .collect(),
+            payment_token: value
+                .iter()
+                .map(|val| val.payment_token.clickhouse_fmt())
+                .collect(),
+            price:         value
+                .iter()
+                .map(|val| rational_to_u256_fraction(&val.price))
+                .collect::<eyre::Result<Vec<_>>>()?,
+        })
+    }
+}
@@ -1,4 +1,4 @@
-use alloy_primitives::Address;
+use alloy_primitives::{Address, B256};
 use serde::Deserialize;
 
 use crate::Protocol;
@@ -9,6 +9,9 @@ pub struct NormalizedNewPool {
     pub protocol:     Protocol,
     pub pool_address: Address,
     pub tokens:       Vec<Address>,
+    /// the id of pools that are identified by it instead of an address, such
+    /// as Uniswap V4 pools
+    pub pool_id:      Option<B256>,
 }
 
 impl TryFrom<NormalizedNewPool> for NormalizedPoolConfigUpdate {
@@ -0,0 +1,101 @@
+use std::fmt::Debug;
+
+use clickhouse::Row;
+use malachite::Rational;
+use reth_primitives::{Address, U256};
+use serde::{Deserialize, Serialize};
+
+use super::accounting::{apply_delta, AddressDeltas, TokenAccounting};
+use crate::{db::token_info::TokenInfoWithAddress, Protocol};
+
+/// Wrapping an underlying asset into its wrapper token: WETH deposits, Lido
+/// submits, wstETH wraps and Rocket Pool rETH mints. The wrapper token is
+/// minted to the recipient.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedWrap {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    /// the account that paid the underlying asset
+    pub from:        Address,
+    pub recipient:   Address,
+    pub underlying:  TokenInfoWithAddress,
+    pub wrapped:     TokenInfoWithAddress,
+    pub amount_in:   Rational,
+    pub amount_out:  Rational,
+    pub msg_value:   U256,
+}
+
+/// The underlying asset is paid with msg value or an ERC20 transfer, both of
+/// which are accounted for on their own. The mint of the wrapper token has no
+/// transfer call, so it is the only delta the wrap applies.
+impl TokenAccounting for NormalizedWrap {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.recipient, self.wrapped.address, self.amount_out.clone(), delta_map);
+    }
+}
+
+/// Unwrapping a wrapper token back into its underlying asset: WETH
+/// withdrawals, wstETH unwraps and rETH burns. The wrapper token is burnt from
+/// `from`.
+#[derive(Debug, Default, Serialize, Clone, Row, PartialEq, Eq, Deserialize)]
+pub struct NormalizedUnwrap {
+    pub protocol:    Protocol,
+    pub trace_index: u64,
+    /// the account the wrapper token is burnt from
+    pub from:        Address,
+    pub recipient:   Address,
+    pub wrapped:     TokenInfoWithAddress,
+    pub underlying:  TokenInfoWithAddress,
+    pub amount_in:   Rational,
+    pub amount_out:  Rational,
+    pub msg_value:   U256,
+}
+
+/// The underlying asset is paid out with an eth transfer or an ERC20 transfer,
+/// both of which are accounted for on their own. Only the burn of the wrapper
+/// token is applied.
+impl TokenAccounting for NormalizedUnwrap {
+    fn apply_token_deltas(&self, delta_map: &mut AddressDeltas) {
+        apply_delta(self.from, self.wrapped.address, -self.amount_in.clone(), delta_map);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::{ETH_ADDRESS, WETH_ADDRESS},
+        normalized_actions::{accounting::ActionAccounting, Action, NormalizedEthTransfer},
+        ToScaledRational,
+    };
+
+    #[test]
+    fn test_wrap_nets_out_eth() {
+        let searcher = Address::with_last_byte(1);
+        let one = U256::from(10).pow(U256::from(18));
+
+        let wrap = Action::Wrap(NormalizedWrap {
+            protocol: Protocol::Weth,
+            from: searcher,
+            recipient: searcher,
+            underlying: TokenInfoWithAddress::native_eth(),
+            wrapped: TokenInfoWithAddress::weth(),
+            amount_in: one.to_scaled_rational(18),
+            amount_out: one.to_scaled_rational(18),
+            msg_value: one,
+            ..Default::default()
+        });
+        let eth_in = Action::EthTransfer(NormalizedEthTransfer {
+            from: searcher,
+            to: WETH_ADDRESS,
+            value: one,
+            ..Default::default()
+        });
+
+        let deltas = vec![wrap, eth_in].into_iter().account_for_actions();
+        let searcher_deltas = deltas.get(&searcher).unwrap();
+
+        assert_eq!(searcher_deltas.get(&WETH_ADDRESS), Some(&one.to_scaled_rational(18)));
+        assert_eq!(searcher_deltas.get(&ETH_ADDRESS), Some(&-one.to_scaled_rational(18)));
+    }
+}
@@ -1,14 +1,19 @@
-use std::ops::{Deref, DerefMut};
+use std::{
+    fmt::Debug,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
 
 use alloy_primitives::Address;
-use serde::{Deserialize, Serialize};
+use malachite::Rational;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 use crate::{pair::Pair, FastHashMap, Protocol};
 
 #[derive(Debug, Clone, Default, PartialEq)]
 pub struct SubGraphsEntry(pub FastHashMap<u64, Vec<SubGraphEdge>>);
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct SubGraphEdge {
     pub info: PoolPairInfoDirection,
 }
@@ -34,6 +39,7 @@ impl SubGraphEdge {
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Hash, PartialOrd, Ord)]
 pub struct PoolPairInformation {
     pub pool_addr: Address,
+    #[serde(with = "crate::serde_utils::protocol_variant")]
     pub dex_type:  Protocol,
     pub token_0:   Address,
     pub token_1:   Address,
@@ -57,6 +63,22 @@ impl PoolPairInfoDirection {
     }
 }
 
+/// Serialized along with the pool info it points to. Deserializing leaks the
+/// info, as the pricing graph holds it for the lifetime of the program.
+impl Serialize for PoolPairInfoDirection {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.info, self.token_0_in).serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for PoolPairInfoDirection {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (info, token_0_in) = <(PoolPairInformation, bool)>::deserialize(deserializer)?;
+
+        Ok(Self::new(Box::leak(Box::new(info)), token_0_in))
+    }
+}
+
 impl Deref for PoolPairInfoDirection {
     type Target = PoolPairInformation;
 
@@ -102,3 +124,49 @@ impl PoolPairInfoDirection {
         }
     }
 }
+
+/// The state of the pools the DEX pricer tracked over a block, as of each of
+/// its transactions. It lets the inspectors replay swaps on the state a pool
+/// was in before a transaction.
+pub trait BlockPoolStates: Debug + Send + Sync {
+    /// The state of the pool before the transaction at `tx_idx`, or `None` if
+    /// the pool isn't on a subgraph the pricer priced a pair over in the block
+    fn pool_before(&self, pool: Address, tx_idx: usize) -> Option<Box<dyn SimulatedPool>>;
+
+    /// Quotes a swap of `amount_in` of the first token of `pair` into the
+    /// second, through the subgraph the pair is priced with for
+    /// `goes_through`, on the state the pools were in before the transaction
+    /// at `tx_idx`. Only the pairs the pricer priced in the block can be
+    /// quoted, in the direction they are priced in.
+    fn quote_for_size(
+        &self,
+        pair: Pair,
+        goes_through: Pair,
+        amount_in: &Rational,
+        tx_idx: usize,
+    ) -> Option<SizedQuote>;
+}
+
+/// A swap quoted through the subgraph of a pair
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizedQuote {
+    /// the amount of the second token of the pair the swap returns, scaled by
+    /// its decimals
+    pub amount_out:    Rational,
+    /// the pools of the path the swap is routed through
+    pub pools:         Vec<Address>,
+    /// the pools of the subgraph that can't quote a swap and were routed
+    /// around. The quote can be worse than the pair would fill at when this
+    /// isn't empty.
+    pub skipped_pools: Vec<Address>,
+}
+
+pub type SharedPoolStates = Arc<dyn BlockPoolStates>;
+
+/// A copy of the state of a pool that swaps can be replayed on
+pub trait SimulatedPool: Debug + Send + Sync {
+    /// Swaps `amount_in` of `token_in` on the pool, returning the amount of the
+    /// other token it returns. Both amounts are scaled by the decimals of their
+    /// token. Returns `None` if the pool can't quote the swap.
+    fn swap(&mut self, token_in: Address, amount_in: &Rational) -> Option<Rational>;
+}
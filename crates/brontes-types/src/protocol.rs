@@ -103,6 +103,27 @@ utils!(
         Dodo,
         #[default]
         Unknown,
+        UniswapV4,
+        CompoundV3,
+        MakerDog,
+        MakerClipper,
+        MorphoBlue,
+        LiquityV1,
+        BalancerV3,
+        CurveStableSwapNgPlainPool,
+        CurveStableSwapNgMetaPool,
+        ParaswapV6,
+        KyberSwapMetaAggregator,
+        OdosV2,
+        ZeroXSettler,
+        SeaportV1_5,
+        SeaportV1_6,
+        Blur,
+        SudoswapV2,
+        Weth,
+        LidoStEth,
+        LidoWstEth,
+        RocketPoolREth,
     }
 );
 
@@ -147,6 +168,27 @@ impl Protocol {
             Protocol::PropellerLabsSolver => ("Propeller Labs Solver", ""),
             Protocol::Dodo => ("Dodo", "V1/V2"),
             Protocol::Unknown => ("Unknown", "Unknown"),
+            Protocol::UniswapV4 => ("Uniswap", "V4"),
+            Protocol::CompoundV3 => ("Compound", "V3"),
+            Protocol::MakerDog => ("Maker", "Dog"),
+            Protocol::MakerClipper => ("Maker", "Clipper"),
+            Protocol::MorphoBlue => ("Morpho", "Blue"),
+            Protocol::LiquityV1 => ("Liquity", "V1"),
+            Protocol::BalancerV3 => ("Balancer", "V3"),
+            Protocol::CurveStableSwapNgPlainPool => ("Curve.fi", "StableSwap NG Plain"),
+            Protocol::CurveStableSwapNgMetaPool => ("Curve.fi", "StableSwap NG Metapool"),
+            Protocol::ParaswapV6 => ("Paraswap", "V6"),
+            Protocol::KyberSwapMetaAggregator => ("KyberSwap", "MetaAggregator"),
+            Protocol::OdosV2 => ("Odos", "V2"),
+            Protocol::ZeroXSettler => ("ZeroX", "Settler"),
+            Protocol::SeaportV1_5 => ("Seaport", "V1_5"),
+            Protocol::SeaportV1_6 => ("Seaport", "V1_6"),
+            Protocol::Blur => ("Blur", "Exchange"),
+            Protocol::SudoswapV2 => ("Sudoswap", "V2"),
+            Protocol::Weth => ("WETH", "WETH9"),
+            Protocol::LidoStEth => ("Lido", "stETH"),
+            Protocol::LidoWstEth => ("Lido", "wstETH"),
+            Protocol::RocketPoolREth => ("RocketPool", "rETH"),
         }
     }
 
@@ -173,6 +215,27 @@ impl Protocol {
             "dodov1/v2" => Protocol::Dodo,
             "pancakeswapv2" => Protocol::PancakeSwapV2,
             "pancakeswapv3" => Protocol::PancakeSwapV3,
+            "uniswapv4" => Protocol::UniswapV4,
+            "compoundv3" => Protocol::CompoundV3,
+            "makerdog" => Protocol::MakerDog,
+            "makerclipper" => Protocol::MakerClipper,
+            "morphoblue" => Protocol::MorphoBlue,
+            "liquityv1" => Protocol::LiquityV1,
+            "balancerv3" => Protocol::BalancerV3,
+            "curve.fistableswap ng plain" => Protocol::CurveStableSwapNgPlainPool,
+            "curve.fistableswap ng metapool" => Protocol::CurveStableSwapNgMetaPool,
+            "paraswapv6" => Protocol::ParaswapV6,
+            "kyberswapmetaaggregator" => Protocol::KyberSwapMetaAggregator,
+            "odosv2" => Protocol::OdosV2,
+            "zeroxsettler" => Protocol::ZeroXSettler,
+            "seaportv1_5" => Protocol::SeaportV1_5,
+            "seaportv1_6" => Protocol::SeaportV1_6,
+            "blurexchange" => Protocol::Blur,
+            "sudoswapv2" => Protocol::SudoswapV2,
+            "wethweth9" => Protocol::Weth,
+            "lidosteth" => Protocol::LidoStEth,
+            "lidowsteth" => Protocol::LidoWstEth,
+            "rocketpoolreth" => Protocol::RocketPoolREth,
             _ => Protocol::Unknown,
         }
     }
@@ -231,6 +294,27 @@ impl fmt::Display for Protocol {
                 Protocol::PropellerLabsSolver => "Propeller Labs",
                 Protocol::Dodo => "Dodo",
                 Protocol::Unknown => "Unknown",
+                Protocol::UniswapV4 => "Uni V4",
+                Protocol::CompoundV3 => "Compound V3",
+                Protocol::MakerDog => "Maker Dog",
+                Protocol::MakerClipper => "Maker Clipper",
+                Protocol::MorphoBlue => "Morpho Blue",
+                Protocol::LiquityV1 => "Liquity V1",
+                Protocol::BalancerV3 => "Balancer V3",
+                Protocol::CurveStableSwapNgPlainPool => "Curve NG Plain",
+                Protocol::CurveStableSwapNgMetaPool => "Curve NG Meta",
+                Protocol::ParaswapV6 => "Paraswap V6",
+                Protocol::KyberSwapMetaAggregator => "KyberSwap",
+                Protocol::OdosV2 => "Odos V2",
+                Protocol::ZeroXSettler => "0x Settler",
+                Protocol::SeaportV1_5 => "Seaport V1.5",
+                Protocol::SeaportV1_6 => "Seaport V1.6",
+                Protocol::Blur => "Blur",
+                Protocol::SudoswapV2 => "Sudoswap V2",
+                Protocol::Weth => "WETH",
+                Protocol::LidoStEth => "Lido stETH",
+                Protocol::LidoWstEth => "Lido wstETH",
+                Protocol::RocketPoolREth => "Rocket Pool rETH",
             }
         )
     }
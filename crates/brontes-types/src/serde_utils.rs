@@ -360,6 +360,31 @@ pub mod protocol {
     }
 }
 
+/// Serializes a protocol by its variant name, as some protocols share their
+/// display name
+pub mod protocol_variant {
+
+    use serde::{
+        de::{Deserialize, Deserializer},
+        ser::{Serialize, Serializer},
+    };
+
+    use crate::protocol::Protocol;
+
+    pub fn serialize<S: Serializer>(u: &Protocol, serializer: S) -> Result<S::Ok, S::Error> {
+        format!("{u:?}").serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(deserializer: D) -> Result<Protocol, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let variant: String = Deserialize::deserialize(deserializer)?;
+
+        Ok(Protocol::parse_string(variant))
+    }
+}
+
 pub mod addresss {
 
     use std::{fmt::Debug, str::FromStr};
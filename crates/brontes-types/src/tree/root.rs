@@ -5,7 +5,7 @@ use clickhouse::Row;
 use colored::Colorize;
 use itertools::Itertools;
 use redefined::self_convert_redefined;
-use reth_primitives::{Address, B256};
+use reth_primitives::{Address, Bytes, B256};
 use serde::{Deserialize, Serialize};
 
 use super::Node;
@@ -54,6 +54,11 @@ pub struct Root<V: NormalizedAction> {
     pub head: Node,
     pub position: usize,
     pub tx_hash: B256,
+    /// the contract the transaction called
+    pub to: Address,
+    /// the calldata the transaction was sent with. Kept as the actions of a
+    /// reverted transaction don't describe what it tried to do
+    pub calldata: Bytes,
     pub private: bool,
     pub gas_details: GasDetails,
     /// all msg.value transfers that aren't classified as